@@ -12,9 +12,9 @@ use isograph_schema::{
     ClientScalarSelectable, ClientSelectable, CompilationProfile, FlattenedDataModelSelectable,
     IsographDatabase, LINK_FIELD_NAME, MemoRefClientSelectable,
     client_scalar_selectable_selection_set_for_parent_query, flattened_entity_named,
-    initial_variable_context, selectable_reader_selection_set,
+    initial_variable_context, is_javascript_file, selectable_reader_selection_set,
 };
-use isograph_schema::{RefetchedPathsMap, UserWrittenClientTypeInfo};
+use isograph_schema::{RefetchedPathsMap, ScalarTypeImports, UserWrittenClientTypeInfo};
 use prelude::Postfix;
 use std::{borrow::Cow, collections::BTreeSet, path::PathBuf};
 
@@ -31,7 +31,7 @@ use crate::{
     },
     import_statements::{
         param_type_imports_to_import_param_statement, param_type_imports_to_import_statement,
-        reader_imports_to_import_statement,
+        reader_imports_to_import_statement, scalar_type_imports_to_import_statement,
     },
     reader_ast::generate_reader_ast,
 };
@@ -174,9 +174,20 @@ pub(crate) fn generate_eager_reader_artifacts<TCompilationProfile: CompilationPr
             parent_object_entity.name, client_selectable_name
         );
         let parameters = variable_definitions.iter();
-        let parameters_types = generate_parameters(db, parameters);
-        let parameters_content =
-            format!("export type {reader_parameters_type} = {parameters_types}\n");
+        let mut scalar_type_imports = ScalarTypeImports::default();
+        let parameters_types = generate_parameters(db, parameters, &mut scalar_type_imports);
+        let custom_scalar_type_imports = scalar_type_imports_to_import_statement(
+            db.get_isograph_config(),
+            &scalar_type_imports,
+            EntityNameAndSelectableName {
+                parent_entity_name: parent_object_entity.name.item,
+                selectable_name: client_selectable_name,
+            }
+            .wrap_some(),
+        );
+        let parameters_content = format!(
+            "{custom_scalar_type_imports}export type {reader_parameters_type} = {parameters_types}\n"
+        );
         path_and_contents.push(ArtifactPathAndContent {
             file_content: parameters_content.into(),
             artifact_path: ArtifactPath {
@@ -290,6 +301,7 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TCompilationProfile: Com
 
     let mut param_type_imports = BTreeSet::new();
     let mut loadable_fields = BTreeSet::new();
+    let mut scalar_type_imports = ScalarTypeImports::default();
     let mut updatable_fields = false;
     let selection_set_for_parent_query = match client_selectable {
         SelectionType::Scalar(scalar) => client_scalar_selectable_selection_set_for_parent_query(
@@ -318,6 +330,7 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TCompilationProfile: Com
         &selection_set_for_parent_query,
         &mut param_type_imports,
         &mut loadable_fields,
+        &mut scalar_type_imports,
         1,
     );
     let updatable_data_type = generate_client_selectable_updatable_data_type(
@@ -326,19 +339,32 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TCompilationProfile: Com
         &selection_set_for_parent_query,
         &mut param_type_imports,
         &mut loadable_fields,
+        &mut scalar_type_imports,
         1,
         &mut updatable_fields,
     );
 
+    let client_selectable_name = match client_selectable {
+        SelectionType::Object(o) => o.name,
+        SelectionType::Scalar(s) => s.name,
+    };
+
     let param_type_import_statement =
         param_type_imports_to_import_statement(&param_type_imports, file_extensions);
+    // The updatable data type contains the same selections as the param type, so
+    // it uses the same custom scalar types.
+    let custom_scalar_type_imports = scalar_type_imports_to_import_statement(
+        db.get_isograph_config(),
+        &scalar_type_imports,
+        EntityNameAndSelectableName {
+            parent_entity_name: parent_object_entity.name.item,
+            selectable_name: client_selectable_name,
+        }
+        .wrap_some(),
+    );
     let reader_param_type = format!(
         "{}__{}__param",
-        parent_object_entity.name,
-        match client_selectable {
-            SelectionType::Scalar(s) => s.name,
-            SelectionType::Object(o) => o.name,
-        }
+        parent_object_entity.name, client_selectable_name
     );
 
     let start_update_imports = if updatable_fields {
@@ -390,6 +416,7 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TCompilationProfile: Com
 
     let param_type_content = format!(
         "{param_type_import_statement}\
+        {custom_scalar_type_imports}\
         {start_update_imports}\
         {loadable_field_imports}\
         {parameters_import}\n\
@@ -399,11 +426,6 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TCompilationProfile: Com
         {start_update_type}\
         }};\n",
     );
-    let client_selectable_name = match client_selectable {
-        SelectionType::Object(o) => o.name,
-        SelectionType::Scalar(s) => s.name,
-    };
-
     ArtifactPathAndContent {
         file_content: param_type_content.into(),
         artifact_path: ArtifactPath {
@@ -554,6 +576,12 @@ fn generate_function_import_statement(
     };
 
     let file_name = match file_extensions {
+        // Files such as Vue single-file components can only be imported with their extension.
+        GenerateFileExtensionsOption::ExcludeExtensionsInFileImports
+            if !is_javascript_file(&relative_path) =>
+        {
+            &normalized_file_name
+        }
         GenerateFileExtensionsOption::ExcludeExtensionsInFileImports => {
             let extension_char_count_including_dot =
                 relative_path.extension().map(|x| x.len() + 1).unwrap_or(0);
@@ -572,6 +600,7 @@ fn generate_function_import_statement(
 fn generate_parameters<'a, TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     argument_definitions: impl Iterator<Item = &'a VariableDeclaration>,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     let mut s = "{\n".to_string();
     let indent = "  ";
@@ -581,7 +610,7 @@ fn generate_parameters<'a, TCompilationProfile: CompilationProfile>(
             "{indent}readonly {}{}: {},\n",
             arg.name.item,
             if is_optional { "?" } else { "" },
-            format_parameter_type(db, arg.type_.item.reference(), 1)
+            format_parameter_type(db, arg.type_.item.reference(), 1, scalar_type_imports)
         ));
     }
     s.push_str("};");
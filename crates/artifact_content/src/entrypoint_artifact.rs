@@ -5,14 +5,17 @@ use crate::{
         RESOLVER_PARAM_TYPE, RESOLVER_READER, RefetchQueryArtifactImport,
     },
     imperatively_loaded_fields::get_paths_and_contents_for_imperatively_loaded_field,
-    normalization_ast_text::generate_normalization_ast_text,
+    import_statements::scalar_type_imports_to_import_statement,
+    normalization_ast_text::{
+        generate_deferred_normalization_ast_text, generate_normalization_ast_text,
+    },
     operation_text::{OperationText, generate_operation_text},
     persisted_documents::PersistedDocuments,
     raw_response_type::generate_raw_response_type,
 };
 use common_lang_types::{
     ArtifactPath, ArtifactPathAndContent, EntityName, EntityNameAndSelectableName,
-    ExpectEntityToExist, ExpectSelectableToExist, QueryOperationName, SelectableName,
+    ExpectEntityToExist, ExpectSelectableToExist, OperationId, QueryOperationName, SelectableName,
 };
 use isograph_config::GenerateFileExtensionsOption;
 use isograph_lang_types::{
@@ -24,8 +27,9 @@ use isograph_schema::{
     ClientFieldVariant, ClientScalarSelectable, CompilationProfile, EntrypointDeclarationInfo,
     FieldToCompletedMergeTraversalStateMap, FieldTraversalResult, FlattenedDataModelEntity, Format,
     IsographDatabase, MergedSelectionMap, NetworkProtocol, NormalizationKey, RootRefetchedPath,
-    ScalarClientFieldTraversalState, WrapMergedSelectionMapResult, WrappedMergedSelectionMap,
-    WrappedSelectionMapSelection, client_scalar_selectable_selection_set_for_parent_query,
+    ScalarClientFieldTraversalState, ScalarTypeImports, WrapMergedSelectionMapResult,
+    WrappedMergedSelectionMap, WrappedSelectionMapSelection,
+    client_scalar_selectable_selection_set_for_parent_query,
     create_merged_selection_map_for_field_and_insert_into_global_map,
     current_target_merged_selections, flattened_entity_named, get_reachable_variables,
     initial_variable_context, selectable_named,
@@ -210,9 +214,13 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_scalar_selectable_traver
 
     let normalization_ast_text =
         generate_normalization_ast_text(inner_merged_selection_map.values(), 1);
+    let deferred_selections_text =
+        generate_deferred_normalization_ast_text(inner_merged_selection_map.values(), 1)
+            .map(|text| format!("  deferredSelections: {text},\n"))
+            .unwrap_or_default();
 
     let merged_selection_map = WrappedMergedSelectionMap::new(inner_merged_selection_map.clone());
-    let operation_text = generate_operation_text(
+    let (operation_text, operation_id) = generate_operation_text(
         db,
         query_name,
         merged_selection_map.reference(),
@@ -221,6 +229,9 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_scalar_selectable_traver
         persisted_documents,
         1,
     );
+    let strip_query_text = persisted_documents
+        .as_ref()
+        .is_some_and(|persisted_documents| persisted_documents.options.strip_query_text);
 
     let directive_set = info
         .map(|info| info.directive_set)
@@ -228,16 +239,21 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_scalar_selectable_traver
 
     let field_name = query_name.unchecked_conversion();
     let type_name = parent_object_entity.name;
+    let is_subscription =
+        TCompilationProfile::NetworkProtocol::is_subscription_root_entity(db, root_entity);
 
     let entrypoint_file_content = entrypoint_file_content(
         file_extensions,
         query_name,
         &operation_text,
+        operation_id,
+        strip_query_text,
         parent_object_entity,
         &refetch_query_artifact_import,
         entrypoint.name,
         root_object_entity.name.item,
         &directive_set,
+        is_subscription,
         match entrypoint.variant.reference() {
             ClientFieldVariant::UserWritten(info) => {
                 from_isograph_field_directives(&info.directive_set).expect(
@@ -254,26 +270,36 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_scalar_selectable_traver
         },
     );
 
-    let raw_response_type = generate_raw_response_type(db, root_entity, merged_selection_map, 0);
+    let mut scalar_type_imports = ScalarTypeImports::default();
+    let raw_response_type = generate_raw_response_type(
+        db,
+        root_entity,
+        merged_selection_map,
+        0,
+        &mut scalar_type_imports,
+    );
 
     let mut path_and_contents = Vec::with_capacity(refetch_paths_with_variables.len() + 3);
-    path_and_contents.push(ArtifactPathAndContent {
-        file_content: format!("export default '{query_text}';").into(),
-        artifact_path: ArtifactPath {
-            file_name: *QUERY_TEXT_FILE_NAME,
-            type_and_field: EntityNameAndSelectableName {
-                parent_entity_name: type_name.item,
-                selectable_name: field_name,
-            }
-            .wrap_some(),
-        },
-    });
+    if !strip_query_text {
+        path_and_contents.push(ArtifactPathAndContent {
+            file_content: format!("export default '{query_text}';").into(),
+            artifact_path: ArtifactPath {
+                file_name: *QUERY_TEXT_FILE_NAME,
+                type_and_field: EntityNameAndSelectableName {
+                    parent_entity_name: type_name.item,
+                    selectable_name: field_name,
+                }
+                .wrap_some(),
+            },
+        });
+    }
     path_and_contents.push(ArtifactPathAndContent {
         file_content: format!(
             "import type {{NormalizationAst}} from '@isograph/react';\n\
             const normalizationAst: NormalizationAst = {{\n\
             {}kind: \"NormalizationAst\",\n\
             {}selections: {normalization_ast_text},\n\
+            {deferred_selections_text}\
             }};\n\
             export default normalizationAst;\n",
             "  ", "  "
@@ -290,8 +316,19 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_scalar_selectable_traver
     });
     path_and_contents.push(ArtifactPathAndContent {
         file_content: format!(
-            "export type {}__{}__{} = {raw_response_type}\n",
-            type_name, field_name, *RAW_RESPONSE_TYPE
+            "{}export type {}__{}__{} = {raw_response_type}\n",
+            scalar_type_imports_to_import_statement(
+                db.get_isograph_config(),
+                &scalar_type_imports,
+                EntityNameAndSelectableName {
+                    parent_entity_name: type_name.item,
+                    selectable_name: field_name,
+                }
+                .wrap_some()
+            ),
+            type_name,
+            field_name,
+            *RAW_RESPONSE_TYPE
         )
         .into(),
         artifact_path: ArtifactPath {
@@ -408,11 +445,14 @@ fn entrypoint_file_content<TCompilationProfile: CompilationProfile>(
     file_extensions: GenerateFileExtensionsOption,
     query_name: QueryOperationName,
     operation_text: &OperationText,
+    operation_id: Option<OperationId>,
+    strip_query_text: bool,
     parent_type: &FlattenedDataModelEntity<TCompilationProfile>,
     refetch_query_artifact_import: &RefetchQueryArtifactImport,
     field_name: SelectableName,
     concrete_type: EntityName,
     directive_set: &EntrypointDirectiveSet,
+    is_subscription: bool,
     field_directive_set: ClientScalarSelectableDirectiveSet,
 ) -> String {
     let ts_file_extension = file_extensions.ts();
@@ -430,6 +470,21 @@ fn entrypoint_file_content<TCompilationProfile: CompilationProfile>(
     let normalization_text_file_name = *NORMALIZATION_AST;
     let indent = "  ";
 
+    let query_text_import = if strip_query_text {
+        "".to_string()
+    } else {
+        format!("import queryText from './{query_text_file_name}{ts_file_extension}';\n")
+    };
+    let operation_id_export = operation_id
+        .map(|operation_id| format!("export const operationId = \"{operation_id}\";\n"))
+        .unwrap_or_default();
+
+    let (entrypoint_type_name, entrypoint_kind) = if is_subscription {
+        ("IsographSubscriptionEntrypoint", "SubscriptionEntrypoint")
+    } else {
+        ("IsographEntrypoint", "Entrypoint")
+    };
+
     let (normalization_ast_type_name, normalization_ast_import, normalization_ast_code) = {
         let file_path = format!("'./{normalization_text_file_name}{ts_file_extension}'");
         match directive_set {
@@ -498,22 +553,22 @@ fn entrypoint_file_content<TCompilationProfile: CompilationProfile>(
     };
 
     format!(
-        "import type {{IsographEntrypoint, \
+        "import type {{{entrypoint_type_name}, \
         {normalization_ast_type_name}, RefetchQueryNormalizationArtifactWrapper}} from '@isograph/react';\n\
         import {{{entrypoint_params_typename}}} from './{param_type_file_name}{ts_file_extension}';\n\
         import {{{entrypoint_output_type_name}}} from './{output_type_file_name}{ts_file_extension}';\n\
         import type {{{raw_response_type_name}}} from './{raw_response_type_file_name}{ts_file_extension}';\n\
         {reader_import}\
-        import queryText from './{query_text_file_name}{ts_file_extension}';\n\
+        {query_text_import}\
         {normalization_ast_import}\
         {refetch_query_artifact_import}\n\n\
-        const artifact: IsographEntrypoint<\n\
+        const artifact: {entrypoint_type_name}<\n\
         {indent}{entrypoint_params_typename},\n\
         {indent}{entrypoint_output_type_name},\n\
         {indent}{normalization_ast_type_name},\n\
         {indent}{raw_response_type_name}\n\
         > = {{\n\
-        {indent}kind: \"Entrypoint\",\n\
+        {indent}kind: \"{entrypoint_kind}\",\n\
         {indent}networkRequestInfo: {{\n\
         {indent}  kind: \"NetworkRequestInfo\",\n\
         {indent}  operation: {operation_text},\n\
@@ -522,6 +577,7 @@ fn entrypoint_file_content<TCompilationProfile: CompilationProfile>(
         {indent}concreteType: \"{concrete_type}\",\n\
         {reader_code}\n\
         }};\n\n\
+        {operation_id_export}\
         export default artifact;\n",
     )
 }
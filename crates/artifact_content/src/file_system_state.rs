@@ -2,7 +2,8 @@ use pico::Index;
 use prelude::Postfix;
 use std::{
     collections::{HashMap, HashSet},
-    path::Path,
+    fs, io,
+    path::{Path, PathBuf},
 };
 
 use crate::operation_text::hash;
@@ -10,6 +11,7 @@ use common_lang_types::{
     ArtifactFileName, ArtifactHash, ArtifactPathAndContent, EntityName, FileContent,
     FileSystemOperation, SelectableName,
 };
+use intern::string_key::Intern;
 use isograph_config::PersistedDocumentsHashAlgorithm;
 
 #[derive(Debug, Clone, Default)]
@@ -20,6 +22,17 @@ pub struct FileSystemState {
         EntityName,
         HashMap<SelectableName, HashMap<ArtifactFileName, (Index<FileContent>, ArtifactHash)>>,
     >,
+    /// Entries in the artifact directory that the compiler would not have written,
+    /// e.g. a file directly in `Entity/`. Only [`FileSystemState::from_disk`] finds
+    /// these, and [`FileSystemState::diff`] deletes them, just like recreating the
+    /// artifact directory would.
+    stray_paths: Vec<StrayPath>,
+}
+
+#[derive(Debug, Clone)]
+enum StrayPath {
+    File(PathBuf),
+    Directory(PathBuf),
 }
 
 impl FileSystemState {
@@ -159,10 +172,125 @@ impl FileSystemState {
             }
         }
 
+        let deleted_directories = operations
+            .iter()
+            .filter_map(|operation| match operation {
+                FileSystemOperation::DeleteDirectory(path) => path.clone().wrap_some(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for stray_path in &old.stray_paths {
+            let (StrayPath::File(path) | StrayPath::Directory(path)) = stray_path;
+            // The stray path is already deleted along with its directory
+            if deleted_directories
+                .iter()
+                .any(|deleted_directory| path.starts_with(deleted_directory))
+            {
+                continue;
+            }
+            operations.push(match stray_path {
+                StrayPath::File(path) => FileSystemOperation::DeleteFile(path.clone()),
+                StrayPath::Directory(path) => FileSystemOperation::DeleteDirectory(path.clone()),
+            });
+        }
+
         operations
     }
 }
 
+impl FileSystemState {
+    /// Reads the artifacts that are currently on disk in the artifact directory.
+    ///
+    /// Files directly in the artifact directory are root files, and files two
+    /// directories deep (i.e. `Entity/selectable/file`) are nested files. Anything
+    /// else (e.g. a file directly in `Entity/`, or a directory in `Entity/selectable/`)
+    /// is not something the compiler would have written, and is recorded as a stray
+    /// path, so that the diff deletes it. Empty directories are recorded as well.
+    ///
+    /// The resulting state is only meant to be the `old` side of [`FileSystemState::diff`],
+    /// which never reads the indexes of the old state. So, the indexes do not refer to
+    /// anything meaningful.
+    pub fn from_disk(artifact_directory: &Path) -> io::Result<Self> {
+        let mut state = FileSystemState::default();
+        if !artifact_directory.exists() {
+            return state.wrap_ok();
+        }
+
+        let mut index = 0;
+        let mut next_value = |path: &Path| -> io::Result<(Index<FileContent>, ArtifactHash)> {
+            let content = fs::read(path)?;
+            let value = (
+                Index::new(index),
+                ArtifactHash::from(hash(
+                    &String::from_utf8_lossy(&content),
+                    PersistedDocumentsHashAlgorithm::Md5,
+                )),
+            );
+            index += 1;
+            value.wrap_ok()
+        };
+
+        for entry in fs::read_dir(artifact_directory)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let entity_name = entry.file_name().to_string_lossy().intern();
+
+            if file_type.is_file() {
+                state
+                    .root_files
+                    .insert(entity_name.into(), next_value(&entry.path())?);
+                continue;
+            }
+            if !file_type.is_dir() {
+                state.stray_paths.push(StrayPath::File(entry.path()));
+                continue;
+            }
+
+            state.nested_files.entry(entity_name.into()).or_default();
+            for selectable_entry in fs::read_dir(entry.path())? {
+                let selectable_entry = selectable_entry?;
+                if !selectable_entry.file_type()?.is_dir() {
+                    state
+                        .stray_paths
+                        .push(StrayPath::File(selectable_entry.path()));
+                    continue;
+                }
+                let selectable_name = selectable_entry.file_name().to_string_lossy().intern();
+
+                state
+                    .nested_files
+                    .entry(entity_name.into())
+                    .or_default()
+                    .entry(selectable_name.into())
+                    .or_default();
+                for file_entry in fs::read_dir(selectable_entry.path())? {
+                    let file_entry = file_entry?;
+                    let file_type = file_entry.file_type()?;
+                    if !file_type.is_file() {
+                        state.stray_paths.push(if file_type.is_dir() {
+                            StrayPath::Directory(file_entry.path())
+                        } else {
+                            StrayPath::File(file_entry.path())
+                        });
+                        continue;
+                    }
+                    let file_name = file_entry.file_name().to_string_lossy().intern();
+
+                    state
+                        .nested_files
+                        .entry(entity_name.into())
+                        .or_default()
+                        .entry(selectable_name.into())
+                        .or_default()
+                        .insert(file_name.into(), next_value(&file_entry.path())?);
+                }
+            }
+        }
+
+        state.wrap_ok()
+    }
+}
+
 #[expect(clippy::type_complexity)]
 impl From<&[ArtifactPathAndContent]> for FileSystemState {
     fn from(artifacts: &[ArtifactPathAndContent]) -> Self {
@@ -199,6 +327,7 @@ impl From<&[ArtifactPathAndContent]> for FileSystemState {
         FileSystemState {
             root_files,
             nested_files,
+            stray_paths: Vec::new(),
         }
     }
 }
@@ -510,4 +639,98 @@ mod tests {
         assert!(create_dirs >= 1); // Comment/text
         assert_eq!(delete_dirs, 1); // User/email directory
     }
+
+    #[test]
+    fn test_from_disk_matches_written_artifacts() {
+        let artifact_dir = std::env::temp_dir().join(format!(
+            "isograph_file_system_state_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&artifact_dir);
+        fs::create_dir_all(artifact_dir.join("User").join("name")).unwrap();
+        fs::write(artifact_dir.join("root.txt"), "root").unwrap();
+        fs::write(
+            artifact_dir.join("User").join("name").join("query.graphql"),
+            "old query",
+        )
+        .unwrap();
+
+        let old_state = FileSystemState::from_disk(&artifact_dir).unwrap();
+        fs::remove_dir_all(&artifact_dir).unwrap();
+
+        assert_eq!(old_state.root_files.len(), 1);
+        assert_eq!(old_state.nested_files.len(), 1);
+
+        let new_artifacts = [
+            create_artifact(None, None, "root.txt", "root"),
+            create_artifact(Some("User"), Some("name"), "query.graphql", "new query"),
+        ];
+        let new_state = FileSystemState::from(&new_artifacts[..]);
+
+        let ops = FileSystemState::diff(&old_state, &new_state, &artifact_dir);
+        assert_eq!(ops.len(), 1);
+        if let FileSystemOperation::WriteFile(path, _) = &ops[0] {
+            assert!(path.ends_with("User/name/query.graphql"));
+        } else {
+            panic!("Expected WriteFile operation");
+        }
+    }
+
+    #[test]
+    fn test_from_disk_deletes_stray_paths() {
+        let artifact_dir = std::env::temp_dir().join(format!(
+            "isograph_file_system_state_stray_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&artifact_dir);
+        let selectable_dir = artifact_dir.join("User").join("name");
+        fs::create_dir_all(selectable_dir.join("stray_directory")).unwrap();
+        fs::create_dir_all(artifact_dir.join("User").join("empty")).unwrap();
+        fs::create_dir_all(artifact_dir.join("Empty")).unwrap();
+        fs::write(selectable_dir.join("query.graphql"), "query").unwrap();
+        fs::write(artifact_dir.join("User").join("stray.txt"), "stray").unwrap();
+
+        let old_state = FileSystemState::from_disk(&artifact_dir).unwrap();
+        fs::remove_dir_all(&artifact_dir).unwrap();
+
+        let new_artifacts = [create_artifact(
+            Some("User"),
+            Some("name"),
+            "query.graphql",
+            "query",
+        )];
+        let new_state = FileSystemState::from(&new_artifacts[..]);
+
+        let mut deleted_files = vec![];
+        let mut deleted_directories = vec![];
+        for operation in FileSystemState::diff(&old_state, &new_state, &artifact_dir) {
+            match operation {
+                FileSystemOperation::DeleteFile(path) => deleted_files.push(path),
+                FileSystemOperation::DeleteDirectory(path) => deleted_directories.push(path),
+                operation => panic!("Unexpected operation {operation:?}"),
+            }
+        }
+        deleted_directories.sort();
+
+        assert_eq!(
+            deleted_files,
+            vec![artifact_dir.join("User").join("stray.txt")]
+        );
+        assert_eq!(
+            deleted_directories,
+            vec![
+                artifact_dir.join("Empty"),
+                artifact_dir.join("User").join("empty"),
+                selectable_dir.join("stray_directory"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_disk_missing_directory() {
+        let artifact_dir = PathBuf::from("/this/path/does/not/exist/__isograph");
+        let state = FileSystemState::from_disk(&artifact_dir).unwrap();
+        assert!(state.root_files.is_empty());
+        assert!(state.nested_files.is_empty());
+    }
 }
@@ -1,11 +1,12 @@
 use isograph_lang_types::{TypeAnnotationDeclaration, UnionVariant};
-use isograph_schema::{CompilationProfile, IsographDatabase, TargetPlatform};
+use isograph_schema::{CompilationProfile, IsographDatabase, ScalarTypeImports, TargetPlatform};
 use prelude::Postfix;
 
 pub(crate) fn format_parameter_type<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     type_: &TypeAnnotationDeclaration,
     indentation_level: u8,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     match type_ {
         TypeAnnotationDeclaration::Scalar(entity_name_wrapper) => {
@@ -13,6 +14,7 @@ pub(crate) fn format_parameter_type<TCompilationProfile: CompilationProfile>(
                 db,
                 entity_name_wrapper.0,
                 indentation_level,
+                scalar_type_imports,
             )
         }
         TypeAnnotationDeclaration::Union(union_type_annotation) => {
@@ -27,6 +29,7 @@ pub(crate) fn format_parameter_type<TCompilationProfile: CompilationProfile>(
                                 db,
                                 entity_name_wrapper.0,
                                 indentation_level,
+                                scalar_type_imports,
                             ),
                         );
                     }
@@ -36,6 +39,7 @@ pub(crate) fn format_parameter_type<TCompilationProfile: CompilationProfile>(
                             db,
                             p.item.reference(),
                             indentation_level,
+                            scalar_type_imports,
                         ));
                         s.push('>');
                     }
@@ -58,6 +62,7 @@ pub(crate) fn format_parameter_type<TCompilationProfile: CompilationProfile>(
                 db,
                 plural.item.reference(),
                 indentation_level,
+                scalar_type_imports,
             ));
             s.push('>');
             s
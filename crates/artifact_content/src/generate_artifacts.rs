@@ -1,7 +1,7 @@
 use common_lang_types::{
-    ArtifactFileName, ArtifactFilePrefix, ArtifactPathAndContent, DiagnosticVecResult,
-    EmbeddedLocation, ExpectEntityToExist, ExpectSelectableToExist, VariableName,
-    WithLocationPostfix, derive_display,
+    ArtifactFileName, ArtifactFilePrefix, ArtifactPath, ArtifactPathAndContent,
+    DiagnosticVecResult, EmbeddedLocation, ExpectEntityToExist, ExpectSelectableToExist,
+    VariableName, WithLocationPostfix, derive_display,
 };
 use core::panic;
 use intern::string_key::Intern;
@@ -19,6 +19,7 @@ use isograph_schema::{
     selectable_named, validate_entire_schema, validated_entrypoints,
 };
 use lazy_static::lazy_static;
+use pico::Database;
 use prelude::*;
 use std::{
     collections::{BTreeMap, HashSet},
@@ -46,6 +47,8 @@ use crate::{
 lazy_static! {
     pub static ref ENTRYPOINT_FILE_NAME: ArtifactFileName = "entrypoint.ts".intern().into();
     pub static ref ENTRYPOINT: ArtifactFilePrefix = "entrypoint".intern().into();
+    pub static ref ENUMS_FILE_NAME: ArtifactFileName = "enums.ts".intern().into();
+    pub static ref ENUMS: ArtifactFilePrefix = "enums".intern().into();
     pub static ref ISO_TS_FILE_NAME: ArtifactFileName = "iso.ts".intern().into();
     pub static ref ISO_TS: ArtifactFilePrefix = "iso".intern().into();
     pub static ref NORMALIZATION_AST_FILE_NAME: ArtifactFileName =
@@ -344,7 +347,7 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
         }
     }
 
-    for (client_type_name, user_written_client_type) in deprecated_client_selectable_map(db)
+    let user_written_client_types = deprecated_client_selectable_map(db)
         .as_ref()
         .expect("Expected client selectable map to be valid.")
         .iter()
@@ -369,14 +372,21 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
 
             (client_type_name, value).wrap_some()
         })
-    {
-        // For each user-written client types, generate a param type artifact
-        path_and_contents.push(generate_eager_reader_param_type_artifact(
-            db,
-            user_written_client_type.dereference(),
-            config.options.include_file_extensions_in_import_statements,
-        ));
+        .collect::<Vec<_>>();
 
+    // For each user-written client types, generate a param type artifact
+    path_and_contents.extend(db.par_map(
+        &user_written_client_types,
+        |db, &(_, user_written_client_type)| {
+            generate_eager_reader_param_type_artifact(
+                db,
+                user_written_client_type.dereference(),
+                config.options.include_file_extensions_in_import_statements,
+            )
+        },
+    ));
+
+    for (client_type_name, user_written_client_type) in user_written_client_types {
         match encountered_client_type_map.get(&client_type_name.inner()) {
             Some(FieldTraversalResult {
                 traversal_state, ..
@@ -398,7 +408,7 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
         }
     }
 
-    for output_type_id in encountered_output_types {
+    path_and_contents.extend(db.par_map(encountered_output_types, |db, output_type_id| {
         let (parent_object_entity_name, client_selectable_name) = match output_type_id {
             SelectionType::Scalar(s) => (s.0, s.1),
             SelectionType::Object(o) => (o.0, o.1),
@@ -417,7 +427,7 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
                     This is indicative of a bug in Isograph.",
                 );
 
-        let artifact_path_and_content = match client_selectable {
+        match client_selectable {
             SelectionType::Object(client_object_selectable) => {
                 let client_object_selectable = client_object_selectable.lookup(db);
                 generate_eager_reader_output_type_artifact(
@@ -430,13 +440,12 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
                         .unwrap_user_written_variant(),
                     config.options.include_file_extensions_in_import_statements,
                 )
-                .wrap_some()
             }
             SelectionType::Scalar(client_scalar_selectable) => {
                 let client_scalar_selectable = client_scalar_selectable.lookup(db);
                 match client_scalar_selectable.variant.reference() {
                     ClientFieldVariant::Link => {
-                        generate_link_output_type_artifact(db, client_scalar_selectable).wrap_some()
+                        generate_link_output_type_artifact(db, client_scalar_selectable)
                     }
                     ClientFieldVariant::UserWritten(info) => {
                         generate_eager_reader_output_type_artifact(
@@ -446,20 +455,14 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
                             info,
                             config.options.include_file_extensions_in_import_statements,
                         )
-                        .wrap_some()
                     }
                     ClientFieldVariant::ImperativelyLoadedField(_) => {
                         generate_refetch_output_type_artifact(db, client_scalar_selectable)
-                            .wrap_some()
                     }
                 }
             }
-        };
-
-        if let Some(path_and_content) = artifact_path_and_content {
-            path_and_contents.push(path_and_content);
         }
-    }
+    }));
 
     path_and_contents.push(build_iso_overload_artifact(
         db,
@@ -468,6 +471,16 @@ fn get_artifact_path_and_content_impl<TCompilationProfile: CompilationProfile>(
     ));
     path_and_contents.push(generate_ts_config());
 
+    if let Some(enum_types) = TCompilationProfile::TargetPlatform::generate_enum_types(db) {
+        path_and_contents.push(ArtifactPathAndContent {
+            file_content: enum_types.into(),
+            artifact_path: ArtifactPath {
+                file_name: *ENUMS_FILE_NAME,
+                type_and_field: None,
+            },
+        });
+    }
+
     if let Some(persisted_documents) = persisted_documents {
         path_and_contents.push(persisted_documents.path_and_content());
     }
@@ -11,8 +11,8 @@ use isograph_lang_types::{
     SelectionType, TypeAnnotationDeclaration, VariableDeclaration,
 };
 use isograph_schema::{
-    ClientFieldVariant, CompilationProfile, IsographDatabase, LINK_FIELD_NAME, TargetPlatform,
-    flattened_entity_named, selectable_named,
+    ClientFieldVariant, CompilationProfile, IsographDatabase, LINK_FIELD_NAME, ScalarTypeImports,
+    TargetPlatform, flattened_entity_named, selectable_named,
 };
 use prelude::Postfix;
 
@@ -31,6 +31,7 @@ pub(crate) fn generate_client_selectable_parameter_type<TCompilationProfile: Com
     selection_map: &WithEmbeddedLocation<SelectionSet>,
     nested_client_scalar_selectable_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
+    scalar_type_imports: &mut ScalarTypeImports,
     indentation_level: u8,
 ) -> ClientScalarSelectableParameterType {
     // TODO use unwraps
@@ -44,6 +45,7 @@ pub(crate) fn generate_client_selectable_parameter_type<TCompilationProfile: Com
             selection,
             nested_client_scalar_selectable_imports,
             loadable_fields,
+            scalar_type_imports,
             indentation_level + 1,
         );
     }
@@ -53,6 +55,7 @@ pub(crate) fn generate_client_selectable_parameter_type<TCompilationProfile: Com
     ClientScalarSelectableParameterType(client_scalar_selectable_parameter_type)
 }
 
+#[expect(clippy::too_many_arguments)]
 fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     parent_object_entity_name: EntityName,
@@ -60,6 +63,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
     selection: &WithEmbeddedLocation<Selection>,
     nested_client_scalar_selectable_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
+    scalar_type_imports: &mut ScalarTypeImports,
     indentation_level: u8,
 ) {
     let selectable = selectable_named(db, parent_object_entity_name, selection.item.name())
@@ -116,6 +120,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
                             db,
                             server_scalar_selectable.parent_entity_name.item,
                             server_scalar_selectable.name.item,
+                            scalar_type_imports,
                         );
 
                     query_type_declaration.push_str(&format!(
@@ -138,6 +143,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
                     query_type_declaration,
                     nested_client_scalar_selectable_imports,
                     loadable_fields,
+                    scalar_type_imports,
                     indentation_level,
                     scalar_field_selection,
                     parent_object_entity_name,
@@ -239,6 +245,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
                                 &object_selection.selection_set,
                                 nested_client_scalar_selectable_imports,
                                 loadable_fields,
+                                scalar_type_imports,
                                 indentation_level,
                             )
                         );
@@ -252,6 +259,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
                             &object_selection.selection_set,
                             nested_client_scalar_selectable_imports,
                             loadable_fields,
+                            scalar_type_imports,
                             indentation_level,
                         );
                         print_javascript_type_declaration(type_annotation, inner_text)
@@ -262,6 +270,7 @@ fn write_param_type_from_selection<TCompilationProfile: CompilationProfile>(
     }
 }
 
+#[expect(clippy::too_many_arguments)]
 pub(crate) fn generate_client_selectable_updatable_data_type<
     TCompilationProfile: CompilationProfile,
 >(
@@ -270,6 +279,7 @@ pub(crate) fn generate_client_selectable_updatable_data_type<
     selection_map: &WithEmbeddedLocation<SelectionSet>,
     nested_client_scalar_selectable_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
+    scalar_type_imports: &mut ScalarTypeImports,
     indentation_level: u8,
     updatable_fields: &mut UpdatableImports,
 ) -> ClientScalarSelectableUpdatableDataType {
@@ -285,6 +295,7 @@ pub(crate) fn generate_client_selectable_updatable_data_type<
             selection,
             nested_client_scalar_selectable_imports,
             loadable_fields,
+            scalar_type_imports,
             indentation_level + 1,
             updatable_fields,
         );
@@ -304,6 +315,7 @@ fn write_updatable_data_type_from_selection<TCompilationProfile: CompilationProf
     selection: &WithEmbeddedLocation<Selection>,
     nested_client_scalar_selectable_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
+    scalar_type_imports: &mut ScalarTypeImports,
     indentation_level: u8,
     updatable_fields: &mut UpdatableImports,
 ) {
@@ -367,6 +379,7 @@ fn write_updatable_data_type_from_selection<TCompilationProfile: CompilationProf
                             db,
                             server_scalar_selectable.parent_entity_name.item,
                             server_scalar_selectable.name.item,
+                            scalar_type_imports,
                         );
 
                     if selection.item.is_updatable() {
@@ -407,6 +420,7 @@ fn write_updatable_data_type_from_selection<TCompilationProfile: CompilationProf
                         query_type_declaration,
                         nested_client_scalar_selectable_imports,
                         loadable_fields,
+                        scalar_type_imports,
                         indentation_level,
                         scalar_selection,
                         parent_object_entity_name,
@@ -494,6 +508,7 @@ fn write_updatable_data_type_from_selection<TCompilationProfile: CompilationProf
                 &object_selection.selection_set,
                 nested_client_scalar_selectable_imports,
                 loadable_fields,
+                scalar_type_imports,
                 indentation_level,
                 updatable_fields,
             );
@@ -521,7 +536,7 @@ fn write_updatable_data_type_from_selection<TCompilationProfile: CompilationProf
                         inner_text,
                     );
                 }
-                ObjectSelectionDirectiveSet::None(_) => {
+                ObjectSelectionDirectiveSet::Defer(_) | ObjectSelectionDirectiveSet::None(_) => {
                     query_type_declaration.push_str(&format!(
                         "readonly {}: {},\n",
                         name_or_alias,
@@ -568,6 +583,7 @@ fn write_param_type_from_client_scalar_selectable<TCompilationProfile: Compilati
     query_type_declaration: &mut String,
     nested_client_scalar_selectable_imports: &mut BTreeSet<EntityNameAndSelectableName>,
     loadable_fields: &mut BTreeSet<EntityNameAndSelectableName>,
+    scalar_type_imports: &mut ScalarTypeImports,
     indentation_level: u8,
     scalar_selection: &ScalarSelection,
     parent_object_entity_name: EntityName,
@@ -633,7 +649,11 @@ fn write_param_type_from_client_scalar_selectable<TCompilationProfile: Compilati
                             client_scalar_selectable
                                 .entity_name_and_selectable_name()
                                 .underscore_separated(),
-                            get_loadable_field_type_from_arguments(db, provided_arguments)
+                            get_loadable_field_type_from_arguments(
+                                db,
+                                provided_arguments,
+                                scalar_type_imports
+                            )
                         )
                     };
 
@@ -664,6 +684,7 @@ fn write_param_type_from_client_scalar_selectable<TCompilationProfile: Compilati
 fn get_loadable_field_type_from_arguments<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     arguments: Vec<VariableDeclaration>,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     let mut loadable_field_type = "{".to_string();
     let mut is_first = true;
@@ -677,7 +698,7 @@ fn get_loadable_field_type_from_arguments<TCompilationProfile: CompilationProfil
             "readonly {}{}: {}",
             arg.name.item,
             if is_optional { "?" } else { "" },
-            format_parameter_type(db, arg.type_.item.reference(), 1)
+            format_parameter_type(db, arg.type_.item.reference(), 1, scalar_type_imports)
         ));
     }
     loadable_field_type.push('}');
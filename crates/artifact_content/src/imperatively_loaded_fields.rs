@@ -13,8 +13,12 @@ use isograph_schema::{
 use prelude::Postfix;
 
 use crate::{
-    generate_artifacts::QUERY_TEXT, normalization_ast_text::generate_normalization_ast_text,
-    operation_text::generate_operation_text, persisted_documents::PersistedDocuments,
+    generate_artifacts::QUERY_TEXT,
+    normalization_ast_text::{
+        generate_deferred_normalization_ast_text, generate_normalization_ast_text,
+    },
+    operation_text::generate_operation_text,
+    persisted_documents::PersistedDocuments,
 };
 
 #[expect(clippy::too_many_arguments)]
@@ -100,7 +104,7 @@ pub(crate) fn get_paths_and_contents_for_imperatively_loaded_field<
         Format::Pretty,
     );
 
-    let operation_text = generate_operation_text(
+    let (operation_text, operation_id) = generate_operation_text(
         db,
         query_name,
         query_text_selection_map_wrapped.reference(),
@@ -109,11 +113,17 @@ pub(crate) fn get_paths_and_contents_for_imperatively_loaded_field<
         persisted_documents,
         1,
     );
-
-    let normalization_ast_text = generate_normalization_ast_text(
-        normalization_ast_wrapped_selection_map.inner().values(),
-        1,
-    );
+    let strip_query_text = persisted_documents
+        .as_ref()
+        .is_some_and(|persisted_documents| persisted_documents.options.strip_query_text);
+
+    let normalization_ast_selection_map = normalization_ast_wrapped_selection_map.inner();
+    let normalization_ast_text =
+        generate_normalization_ast_text(normalization_ast_selection_map.values(), 1);
+    let deferred_selections_text =
+        generate_deferred_normalization_ast_text(normalization_ast_selection_map.values(), 1)
+            .map(|text| format!("  deferredSelections: {text},\n"))
+            .unwrap_or_default();
 
     let file_name_prefix = format!("{}__{}.ts", *REFETCH_FIELD_NAME, index)
         .intern()
@@ -132,12 +142,22 @@ pub(crate) fn get_paths_and_contents_for_imperatively_loaded_field<
             .intern()
             .into();
 
+    let query_text_import = if strip_query_text {
+        "".to_string()
+    } else {
+        format!("import queryText from './{query_text_file_name}';\n")
+    };
+    let operation_id_export = operation_id
+        .map(|operation_id| format!("export const operationId = \"{operation_id}\";\n"))
+        .unwrap_or_default();
+
     let imperatively_loaded_field_file_contents = format!(
         "import type {{ IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact }} from '@isograph/react';\n\
-        import queryText from './{query_text_file_name}';\n\n\
+        {query_text_import}\n\
         const normalizationAst: NormalizationAst = {{\n\
         {}kind: \"NormalizationAst\",\n\
         {}selections: {normalization_ast_text},\n\
+        {deferred_selections_text}\
         }};\n\
         const artifact: RefetchQueryNormalizationArtifact = {{\n\
         {}kind: \"RefetchQuery\",\n\
@@ -148,12 +168,24 @@ pub(crate) fn get_paths_and_contents_for_imperatively_loaded_field<
         {}}},\n\
         {}concreteType: \"{root_entity}\",\n\
         }};\n\n\
+        {operation_id_export}\
         export default artifact;\n",
         "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ",
     );
 
-    vec![
-        ArtifactPathAndContent {
+    let mut path_and_contents = vec![ArtifactPathAndContent {
+        file_content: imperatively_loaded_field_file_contents.into(),
+        artifact_path: ArtifactPath {
+            file_name: file_name_prefix,
+            type_and_field: EntityNameAndSelectableName {
+                parent_entity_name: root_parent_object,
+                selectable_name: root_fetchable_field,
+            }
+            .wrap_some(),
+        },
+    }];
+    if !strip_query_text {
+        path_and_contents.push(ArtifactPathAndContent {
             file_content: format!("export default '{query_text}';").into(),
             artifact_path: ArtifactPath {
                 file_name: query_text_file_name_with_extension,
@@ -163,19 +195,9 @@ pub(crate) fn get_paths_and_contents_for_imperatively_loaded_field<
                 }
                 .wrap_some(),
             },
-        },
-        ArtifactPathAndContent {
-            file_content: imperatively_loaded_field_file_contents.into(),
-            artifact_path: ArtifactPath {
-                file_name: file_name_prefix,
-                type_and_field: EntityNameAndSelectableName {
-                    parent_entity_name: root_parent_object,
-                    selectable_name: root_fetchable_field,
-                }
-                .wrap_some(),
-            },
-        },
-    ]
+        });
+    }
+    path_and_contents
 }
 
 fn get_used_variable_definitions<TCompilationProfile: CompilationProfile>(
@@ -1,7 +1,13 @@
-use std::collections::BTreeSet;
+use std::{collections::BTreeSet, path::Path};
 
 use common_lang_types::EntityNameAndSelectableName;
-use isograph_config::GenerateFileExtensionsOption;
+use intern::Lookup;
+use isograph_config::{
+    CompilerConfig, CustomScalarType, CustomScalarTypeModule, GenerateFileExtensionsOption,
+};
+use isograph_schema::ScalarTypeImports;
+
+use crate::generate_artifacts::ENUMS;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum ImportedFileCategory {
@@ -76,3 +82,90 @@ pub(crate) fn param_type_imports_to_import_param_statement(
     }
     output
 }
+
+/// Import statements for the custom scalar types (from the `custom_scalar_types`
+/// config option) and the enum types (from the generated enums file) that were used
+/// while generating a type. `type_and_field` is the directory of the artifact, e.g.
+/// `__isograph/Type/field/`, or `None` for files at the root of the artifact directory.
+pub(crate) fn scalar_type_imports_to_import_statement(
+    config: &CompilerConfig,
+    scalar_type_imports: &ScalarTypeImports,
+    type_and_field: Option<EntityNameAndSelectableName>,
+) -> String {
+    let mut artifact_directory = config.artifact_directory.absolute_path.clone();
+    if let Some(type_and_field) = type_and_field {
+        artifact_directory.push(type_and_field.parent_entity_name.lookup());
+        artifact_directory.push(type_and_field.selectable_name.lookup());
+    }
+
+    let imports = scalar_type_imports
+        .scalar_entity_names
+        .iter()
+        .filter_map(|scalar_entity_name| {
+            match config
+                .options
+                .custom_scalar_types
+                .get(scalar_entity_name.lookup())?
+            {
+                CustomScalarType::Inline(_) => None,
+                CustomScalarType::Import { module, name } => Some((
+                    name,
+                    custom_scalar_type_module_specifier(module, &artifact_directory),
+                )),
+            }
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut output = String::new();
+    for (name, module_specifier) in imports {
+        output.push_str(&format!(
+            "import type {{ {name} }} from '{module_specifier}';\n"
+        ));
+    }
+
+    if !scalar_type_imports.enum_type_names.is_empty() {
+        output.push_str(&format!(
+            "import type {{ {} }} from '{}{}{}';\n",
+            scalar_type_imports
+                .enum_type_names
+                .iter()
+                .map(|enum_type_name| enum_type_name.lookup())
+                .collect::<Vec<_>>()
+                .join(", "),
+            if type_and_field.is_some() {
+                "../../"
+            } else {
+                "./"
+            },
+            *ENUMS,
+            config
+                .options
+                .include_file_extensions_in_import_statements
+                .ts()
+        ));
+    }
+    output
+}
+
+fn custom_scalar_type_module_specifier(
+    module: &CustomScalarTypeModule,
+    artifact_directory: &Path,
+) -> String {
+    match module {
+        CustomScalarTypeModule::Package(package) => package.clone(),
+        CustomScalarTypeModule::Path(path) => {
+            let relative_path = pathdiff::diff_paths(path, artifact_directory)
+                .expect(
+                    "Expected path to be able to be made relative to the artifact directory. \
+                    This is indicative of a bug in Isograph.",
+                )
+                .to_string_lossy()
+                .replace('\\', "/");
+            if relative_path.starts_with("../") {
+                relative_path
+            } else {
+                format!("./{relative_path}")
+            }
+        }
+    }
+}
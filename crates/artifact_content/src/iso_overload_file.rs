@@ -13,8 +13,8 @@ use common_lang_types::{
 };
 use isograph_schema::{
     ClientScalarSelectable, CompilationProfile, EntrypointDeclarationInfo, IsographDatabase,
-    LINK_FIELD_NAME, MemoRefClientSelectable, deprecated_client_selectable_map, selectable_named,
-    validated_entrypoints,
+    LINK_FIELD_NAME, MemoRefClientSelectable, NetworkProtocol, deprecated_client_selectable_map,
+    selectable_named, validated_entrypoints,
 };
 
 use crate::generate_artifacts::{ISO_TS_FILE_NAME, print_javascript_type_declaration};
@@ -142,7 +142,26 @@ pub(crate) fn build_iso_overload_artifact<TCompilationProfile: CompilationProfil
     file_extensions: GenerateFileExtensionsOption,
     no_babel_transform: bool,
 ) -> ArtifactPathAndContent {
-    let mut imports = "import type { IsographEntrypoint } from '@isograph/react';\n".to_string();
+    // Only mention subscription entrypoints if there are any, so that projects
+    // without subscriptions are unaffected.
+    let has_subscription_entrypoints = sorted_entrypoints(db).into_iter().any(|(field, _)| {
+        TCompilationProfile::NetworkProtocol::is_subscription_root_entity(
+            db,
+            field.lookup(db).parent_entity_name,
+        )
+    });
+    let (mut imports, subscription_entrypoint_return_type) = if has_subscription_entrypoints {
+        (
+            "import type { IsographEntrypoint, IsographSubscriptionEntrypoint } from '@isograph/react';\n"
+                .to_string(),
+            "\n  | IsographSubscriptionEntrypoint<any, any, any, any>",
+        )
+    } else {
+        (
+            "import type { IsographEntrypoint } from '@isograph/react';\n".to_string(),
+            "",
+        )
+    };
     let mut content = String::from(
         "
 // This is the type given to regular client fields.
@@ -232,14 +251,14 @@ type MatchesWhitespaceAndString<
     }
 
     if !no_babel_transform {
-        content.push_str(
+        content.push_str(&format!(
             "
 export function iso(_isographLiteralText: string):
   | IdentityWithParam<any>
   | IdentityWithParamComponent<any>
-  | IsographEntrypoint<any, any, any, any>
-{\n",
-        );
+  | IsographEntrypoint<any, any, any, any>{subscription_entrypoint_return_type}
+{{\n",
+        ));
         content.push_str("  throw new Error('iso: Unexpected invocation at runtime. Either the Babel transform ' +
       'was not set up, or it failed to identify this call site. Make sure it ' +
       'is being used verbatim as `iso`. If you cannot use the babel transform, ' + 
@@ -260,15 +279,15 @@ export function iso(_isographLiteralText: string):
                     )
                 });
 
-        content.push_str(
+        content.push_str(&format!(
             "
 export function iso(isographLiteralText: string):
   | IdentityWithParam<any>
   | IdentityWithParamComponent<any>
-  | IsographEntrypoint<any, any, any, any>
-{
-  switch (isographLiteralText) {\n",
-        );
+  | IsographEntrypoint<any, any, any, any>{subscription_entrypoint_return_type}
+{{
+  switch (isographLiteralText) {{\n",
+        ));
 
         for switch_case in switch_cases {
             content.push_str(&switch_case);
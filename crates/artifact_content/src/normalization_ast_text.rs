@@ -1,6 +1,9 @@
+use std::collections::BTreeMap;
+
+use common_lang_types::StringLiteralValue;
 use isograph_schema::{
-    MergedInlineFragmentSelection, MergedLinkedFieldSelection, MergedScalarFieldSelection,
-    MergedServerSelection,
+    MergedDeferSelection, MergedInlineFragmentSelection, MergedLinkedFieldSelection,
+    MergedScalarFieldSelection, MergedSelectionMap, MergedServerSelection,
 };
 use prelude::Postfix;
 
@@ -71,6 +74,9 @@ fn generate_normalization_ast_node(item: &MergedServerSelection, indentation_lev
             )
         }
         MergedServerSelection::ClientObjectSelectable(_) => "".to_string(),
+        // Deferred selections are not part of the initial payload. They are normalized
+        // using the ASTs generated by generate_deferred_normalization_ast_text.
+        MergedServerSelection::Defer(_) => "".to_string(),
         MergedServerSelection::InlineFragment(inline_fragment) => {
             let MergedInlineFragmentSelection {
                 type_to_refine_to,
@@ -92,3 +98,62 @@ fn generate_normalization_ast_node(item: &MergedServerSelection, indentation_lev
         }
     }
 }
+
+/// Generates a normalization AST for each `@defer` label, which the runtime uses
+/// to normalize the corresponding incremental payload. Returns None if the selection
+/// map contains no deferred selections.
+pub(crate) fn generate_deferred_normalization_ast_text<'a>(
+    selection_map: impl Iterator<Item = &'a MergedServerSelection> + 'a,
+    indentation_level: u8,
+) -> Option<NormalizationAstText> {
+    let mut deferred_selection_maps = BTreeMap::new();
+    collect_deferred_selection_maps(selection_map, &mut deferred_selection_maps);
+
+    if deferred_selection_maps.is_empty() {
+        return None;
+    }
+
+    let indent = "  ".repeat(indentation_level as usize);
+    let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+    let mut deferred_normalization_ast_text = "{\n".to_string();
+    for (label, selection_map) in deferred_selection_maps {
+        let selections =
+            generate_normalization_ast_text(selection_map.values(), indentation_level + 1);
+        deferred_normalization_ast_text
+            .push_str(&format!("{indent_2}\"{label}\": {selections},\n"));
+    }
+    deferred_normalization_ast_text.push_str(&format!("{indent}}}"));
+    NormalizationAstText(deferred_normalization_ast_text).wrap_some()
+}
+
+fn collect_deferred_selection_maps<'a>(
+    selection_map: impl Iterator<Item = &'a MergedServerSelection> + 'a,
+    deferred_selection_maps: &mut BTreeMap<StringLiteralValue, MergedSelectionMap>,
+) {
+    for item in selection_map {
+        match item {
+            MergedServerSelection::ScalarField(_)
+            | MergedServerSelection::ClientObjectSelectable(_) => {}
+            MergedServerSelection::LinkedField(MergedLinkedFieldSelection {
+                selection_map,
+                ..
+            })
+            | MergedServerSelection::InlineFragment(MergedInlineFragmentSelection {
+                selection_map,
+                ..
+            }) => collect_deferred_selection_maps(selection_map.values(), deferred_selection_maps),
+            MergedServerSelection::Defer(MergedDeferSelection {
+                label,
+                selection_map,
+            }) => {
+                // Each label is validated to be used at a single position, so entries are
+                // only combined if multiple client fields defer selections at that position.
+                deferred_selection_maps
+                    .entry(*label)
+                    .or_default()
+                    .extend(selection_map.clone());
+                collect_deferred_selection_maps(selection_map.values(), deferred_selection_maps);
+            }
+        }
+    }
+}
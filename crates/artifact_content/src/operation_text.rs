@@ -1,4 +1,6 @@
-use common_lang_types::{EntityName, QueryExtraInfo, QueryOperationName, derive_display};
+use common_lang_types::{
+    EntityName, OperationId, QueryExtraInfo, QueryOperationName, derive_display,
+};
 use intern::string_key::Intern;
 use isograph_config::PersistedDocumentsHashAlgorithm;
 use isograph_lang_types::VariableDeclaration;
@@ -6,6 +8,7 @@ use isograph_schema::{
     CompilationProfile, Format, IsographDatabase, NetworkProtocol, WrappedMergedSelectionMap,
 };
 use md5::{Digest, Md5};
+use prelude::Postfix;
 use sha2::Sha256;
 
 use crate::persisted_documents::PersistedDocuments;
@@ -14,6 +17,8 @@ use crate::persisted_documents::PersistedDocuments;
 pub(crate) struct OperationText(pub String);
 derive_display!(OperationText);
 
+/// Returns the operation text, and, if persisted documents are enabled, the id
+/// of the persisted document.
 pub(crate) fn generate_operation_text<'a, TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     query_name: QueryOperationName,
@@ -22,7 +27,7 @@ pub(crate) fn generate_operation_text<'a, TCompilationProfile: CompilationProfil
     root_entity: EntityName,
     persisted_documents: &mut Option<PersistedDocuments>,
     indentation_level: u8,
-) -> OperationText {
+) -> (OperationText, Option<OperationId>) {
     let indent = "  ".repeat((indentation_level + 1) as usize);
     match persisted_documents {
         Some(pd) => {
@@ -34,7 +39,7 @@ pub(crate) fn generate_operation_text<'a, TCompilationProfile: CompilationProfil
                 query_variables,
                 Format::Compact,
             );
-            let operation_id = hash(query_text.0.as_str(), pd.options.algorithm)
+            let operation_id: OperationId = hash(query_text.0.as_str(), pd.options.algorithm)
                 .intern()
                 .into();
             pd.documents.insert(operation_id, query_text);
@@ -47,20 +52,26 @@ pub(crate) fn generate_operation_text<'a, TCompilationProfile: CompilationProfil
             } else {
                 QueryExtraInfo("null".to_string())
             };
+            (
+                OperationText(format!(
+                    "{{\n\
+                    {indent}  kind: \"PersistedOperation\",\n\
+                    {indent}  operationId: \"{operation_id}\",\n\
+                    {indent}  extraInfo: {query_extra_info},\n\
+                    {indent}}}"
+                )),
+                operation_id.wrap_some(),
+            )
+        }
+        None => (
             OperationText(format!(
                 "{{\n\
-                {indent}  kind: \"PersistedOperation\",\n\
-                {indent}  operationId: \"{operation_id}\",\n\
-                {indent}  extraInfo: {query_extra_info},\n\
+                {indent}  kind: \"Operation\",\n\
+                {indent}  text: queryText,\n\
                 {indent}}}"
-            ))
-        }
-        None => OperationText(format!(
-            "{{\n\
-            {indent}  kind: \"Operation\",\n\
-            {indent}  text: queryText,\n\
-            {indent}}}"
-        )),
+            )),
+            None,
+        ),
     }
 }
 
@@ -2,7 +2,7 @@ use common_lang_types::{EntityName, ExpectSelectableToExist};
 use intern::Lookup;
 use isograph_schema::{
     CompilationProfile, IsographDatabase, MergedSelectionMap, MergedServerSelection,
-    TargetPlatform, WrappedMergedSelectionMap, flattened_selectable_named,
+    ScalarTypeImports, TargetPlatform, WrappedMergedSelectionMap, flattened_selectable_named,
 };
 use prelude::Postfix;
 use std::collections::BTreeMap;
@@ -14,6 +14,7 @@ pub fn generate_raw_response_type<TCompilationProfile: CompilationProfile>(
     parent_object_entity_name: EntityName,
     selection_map: WrappedMergedSelectionMap,
     indentation_level: u8,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     let indent = &"  ".repeat(indentation_level as usize).to_string();
 
@@ -25,6 +26,7 @@ pub fn generate_raw_response_type<TCompilationProfile: CompilationProfile>(
         parent_object_entity_name,
         selection_map.inner().reference(),
         indentation_level + 1,
+        scalar_type_imports,
     );
     raw_response_type.push_str(&format!("{}}}\n", indent));
     raw_response_type
@@ -36,6 +38,7 @@ pub fn generate_raw_response_type_inner<TCompilationProfile: CompilationProfile>
     parent_object_entity_name: EntityName,
     selection_map: &MergedSelectionMap,
     indentation_level: u8,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) {
     let indent = &"  ".repeat(indentation_level as usize).to_string();
     let mut inline_fragments = BTreeMap::new();
@@ -76,6 +79,7 @@ pub fn generate_raw_response_type_inner<TCompilationProfile: CompilationProfile>
                             db,
                             server_scalar_selectable.parent_entity_name.item,
                             server_scalar_selectable.name.item,
+                            scalar_type_imports,
                         );
 
                     raw_response_type.push_str(&format!(
@@ -134,6 +138,7 @@ pub fn generate_raw_response_type_inner<TCompilationProfile: CompilationProfile>
                             nested_parent_object_entity_name,
                             &linked_field.selection_map,
                             indentation_level + 1,
+                            scalar_type_imports,
                         );
                         raw_response_type_declaration.push_str(&format!("{indent}}}"));
                         raw_response_type_declaration
@@ -181,6 +186,7 @@ pub fn generate_raw_response_type_inner<TCompilationProfile: CompilationProfile>
             type_to_refine_to,
             &combined_selection_map,
             indentation_level,
+            scalar_type_imports,
         );
         fragments.insert(type_to_refine_to, fragment_raw_response_type);
     }
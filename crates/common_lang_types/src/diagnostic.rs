@@ -3,10 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{EntityNameAndSelectableName, Location, LocationFreeDiagnostic};
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Diagnostic(pub Box<DiagnosticData>);
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct DiagnosticData {
     /// A human-readable message.
     pub message: String,
@@ -131,6 +131,78 @@ pub enum IsographCodeAction {
     CreateNewObjectSelectable(EntityNameAndSelectableName),
 }
 
+/// A file that an [`IsographCodeAction`] offers to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsographCodeActionNewFile {
+    pub title: String,
+    /// The path of the file, relative to the project root
+    pub path: String,
+    pub content: String,
+}
+
+impl IsographCodeAction {
+    /// The files that this code action offers to create. The user chooses one of them.
+    pub fn new_files(&self) -> Vec<IsographCodeActionNewFile> {
+        match self {
+            IsographCodeAction::CreateNewScalarSelectable(entity_name_and_selectable_name) => {
+                vec![
+                    new_selectable_file(*entity_name_and_selectable_name, false, false),
+                    new_selectable_file(*entity_name_and_selectable_name, true, false),
+                ]
+            }
+            IsographCodeAction::CreateNewObjectSelectable(entity_name_and_selectable_name) => {
+                vec![new_selectable_file(
+                    *entity_name_and_selectable_name,
+                    false,
+                    true,
+                )]
+            }
+        }
+    }
+}
+
+fn new_selectable_file(
+    entity_name_and_selectable_name: EntityNameAndSelectableName,
+    // TODO it would be more elegant to make this part of the scalar case of
+    // is_object_selectable, and to make the tsx ending part of that...
+    should_add_component_annotation: bool,
+    is_object_selectable: bool,
+) -> IsographCodeActionNewFile {
+    let EntityNameAndSelectableName {
+        parent_entity_name,
+        selectable_name,
+    } = entity_name_and_selectable_name;
+    let indent = "  ";
+
+    let (component_annotation, extension) = if should_add_component_annotation {
+        ("@component ", "tsx")
+    } else {
+        ("", "ts")
+    };
+    let (keyword, to_section) = if is_object_selectable {
+        ("pointer", " to TYPE")
+    } else {
+        ("field", "")
+    };
+
+    IsographCodeActionNewFile {
+        title: format!(
+            "Create new {component_annotation}{keyword} named `{parent_entity_name}.{selectable_name}`"
+        ),
+        path: format!("{parent_entity_name}/{selectable_name}.{extension}"),
+        content: format!(
+            "import {{ iso }} from '@iso';\n\
+            \n\
+            export const {parent_entity_name}__{selectable_name} = iso(`\n\
+            {indent}{keyword} {parent_entity_name}.{selectable_name}{to_section} {component_annotation}{{\n\
+            {indent}}}\n\
+            `)(({{ data }}) => {{\n\
+            {indent}return null;\n\
+            }})\n",
+        ),
+    }
+}
+
 impl From<LocationFreeDiagnostic> for Diagnostic {
     fn from(value: LocationFreeDiagnostic) -> Self {
         Self(
@@ -1,6 +1,7 @@
 use intern::string_key::{Intern, Lookup};
 use lazy_static::lazy_static;
 use prelude::Postfix;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::{CurrentWorkingDirectory, RelativePathToSourceFile, Span};
@@ -12,7 +13,7 @@ use crate::{CurrentWorkingDirectory, RelativePathToSourceFile, Span};
 /// TODO consider whether to replace the span with an index,
 /// as this will probably mean that sources are more reusable
 /// during watch mode.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct TextSource {
     pub relative_path_to_source_file: RelativePathToSourceFile,
     pub span: Option<Span>,
@@ -26,7 +27,7 @@ lazy_static! {
     };
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct EmbeddedLocation {
     pub text_source: TextSource,
     /// The span is relative to the Source's span, not to the
@@ -48,7 +49,7 @@ impl From<EmbeddedLocation> for Location {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Location {
     Embedded(EmbeddedLocation),
     Generated,
@@ -131,7 +132,7 @@ pub fn relative_path_from_absolute_and_working_directory(
     .into()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, Hash, Serialize, Deserialize)]
 pub struct WithGenericLocation<TItem, TLocation> {
     pub item: TItem,
     pub location: TLocation,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::{fmt, ops::Range};
 
 use prelude::Postfix;
@@ -9,7 +10,7 @@ use crate::{EmbeddedLocation, Location, TextSource, WithEmbeddedLocation, WithLo
 //
 // For schema sources, the first span is missing, and everything is the second type.
 // Invariant: end >= start
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub struct Span {
     pub start: u32,
     pub end: u32,
@@ -84,7 +85,7 @@ impl Span {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub struct WithSpan<T> {
     pub item: T,
     pub span: Span,
@@ -1,4 +1,4 @@
-use pico::{Key, Singleton, Source};
+use pico::{Durability, Key, Singleton, Source};
 use string_key_newtype::{string_key_equality, string_key_newtype, string_key_one_way_conversion};
 
 use crate::SelectableName;
@@ -87,6 +87,10 @@ impl Source for CurrentWorkingDirectory {
     fn get_key(&self) -> Key {
         CurrentWorkingDirectory::get_singleton_key()
     }
+
+    fn get_durability(&self) -> Durability {
+        Durability::High
+    }
 }
 
 string_key_newtype!(RelativePathToSourceFile);
@@ -31,10 +31,64 @@ pub fn text_with_carats(
 }
 
 /// The row number, 1-indexed. Because VSCode!
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OneIndexedRowNumber(pub NonZeroU32);
 /// The col number, 1-indexed. Because VSCode!
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OneIndexedColNumber(pub NonZeroU32);
 
+/// A range of text, in terms of one-indexed rows and columns. Columns count
+/// unicode code points, and the end column is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineAndColumnRange {
+    pub start_row: OneIndexedRowNumber,
+    pub start_col: OneIndexedColNumber,
+    pub end_row: OneIndexedRowNumber,
+    pub end_col: OneIndexedColNumber,
+}
+
+/// For a given string and span, return the rows and columns at which the
+/// span starts and ends. Spans that extend past the end of the text are
+/// clamped to the end of the text.
+pub fn line_and_column_range(
+    file_text: &str,
+    outer_span: Option<Span>,
+    inner_span: Span,
+) -> LineAndColumnRange {
+    let outer_span_start = outer_span.map(|x| x.start).unwrap_or(0);
+    let (start_row, start_col) =
+        row_and_col_at_index(file_text, (outer_span_start + inner_span.start) as usize);
+    let (end_row, end_col) =
+        row_and_col_at_index(file_text, (outer_span_start + inner_span.end) as usize);
+
+    LineAndColumnRange {
+        start_row,
+        start_col,
+        end_row,
+        end_col,
+    }
+}
+
+fn row_and_col_at_index(
+    file_text: &str,
+    index: usize,
+) -> (OneIndexedRowNumber, OneIndexedColNumber) {
+    let mut index = std::cmp::min(index, file_text.len());
+    while !file_text.is_char_boundary(index) {
+        index -= 1;
+    }
+    let text_before = &file_text[..index];
+
+    let row = text_before.matches('\n').count() + 1;
+    let start_of_line = text_before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let col = text_before[start_of_line..].chars().count() + 1;
+
+    (
+        OneIndexedRowNumber((row as u32).try_into().unwrap()),
+        OneIndexedColNumber((col as u32).try_into().unwrap()),
+    )
+}
+
 /// For a given string and span, return a string with
 /// the span underlined with carats and LINE_COUNT_BUFFER previous and following
 /// lines.
@@ -198,7 +252,7 @@ mod test {
     use std::sync::{LazyLock, Mutex};
 
     use crate::{
-        OneIndexedColNumber, OneIndexedRowNumber, Span,
+        OneIndexedColNumber, OneIndexedRowNumber, Span, line_and_column_range,
         text_with_carats::text_with_carats_and_line_count_buffer_and_line_numbers,
     };
 
@@ -538,4 +592,40 @@ mod test {
 
         assert_eq!(output, expected);
     }
+
+    fn row_and_col(range_part: (OneIndexedRowNumber, OneIndexedColNumber)) -> (u32, u32) {
+        (range_part.0.0.get(), range_part.1.0.get())
+    }
+
+    #[test]
+    fn line_and_column_range_single_line() {
+        let range = line_and_column_range(&input_with_lines(10), None, Span::new(31, 33));
+        assert_eq!(row_and_col((range.start_row, range.start_col)), (4, 2));
+        assert_eq!(row_and_col((range.end_row, range.end_col)), (4, 4));
+    }
+
+    #[test]
+    fn line_and_column_range_multi_line_with_outer_span() {
+        let range = line_and_column_range(
+            &input_with_lines(10),
+            Some(Span::new(20, 100)),
+            Span::new(11, 23),
+        );
+        assert_eq!(row_and_col((range.start_row, range.start_col)), (4, 2));
+        assert_eq!(row_and_col((range.end_row, range.end_col)), (5, 4));
+    }
+
+    #[test]
+    fn line_and_column_range_counts_code_points() {
+        let range = line_and_column_range("é\nab😀cd", None, Span::new(9, 11));
+        assert_eq!(row_and_col((range.start_row, range.start_col)), (2, 4));
+        assert_eq!(row_and_col((range.end_row, range.end_col)), (2, 6));
+    }
+
+    #[test]
+    fn line_and_column_range_past_end_of_text() {
+        let range = line_and_column_range(&input_with_lines(1), None, Span::new(5, 50));
+        assert_eq!(row_and_col((range.start_row, range.start_col)), (1, 6));
+        assert_eq!(row_and_col((range.end_row, range.end_col)), (2, 1));
+    }
 }
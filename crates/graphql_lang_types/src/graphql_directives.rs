@@ -9,12 +9,12 @@ use common_lang_types::{
 use intern::Lookup;
 use prelude::Postfix;
 use serde::{
-    Deserialize, Deserializer,
+    Deserialize, Deserializer, Serialize,
     de::{self, IntoDeserializer, MapAccess, SeqAccess, value::SeqDeserializer},
 };
 
 // TODO maybe this should be NameAndArguments and a field should be the same thing...?
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct GraphQLDirective<T> {
     pub name: WithEmbeddedLocation<DirectiveName>,
     pub arguments: Vec<NameValuePair<DirectiveArgumentName, T>>,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 
 use crate::{GraphQLDirective, GraphQLTypeAnnotation};
@@ -7,10 +8,10 @@ use common_lang_types::{
     DescriptionValue, DirectiveName, EntityName, EnumLiteralValue, InputValueName, SelectableName,
     WithEmbeddedLocation,
 };
-use strum::EnumString;
+use strum::{Display, EnumString};
 
 // also Schema
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub enum GraphQLTypeSystemDefinition {
     ObjectTypeDefinition(GraphQLObjectTypeDefinition),
     ScalarTypeDefinition(GraphQLScalarTypeDefinition),
@@ -70,7 +71,7 @@ impl From<GraphQLSchemaDefinition> for GraphQLTypeSystemDefinition {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Hash, Serialize, Deserialize)]
 pub struct GraphQLTypeSystemDocument(pub Vec<WithEmbeddedLocation<GraphQLTypeSystemDefinition>>);
 
 impl Deref for GraphQLTypeSystemDocument {
@@ -81,26 +82,26 @@ impl Deref for GraphQLTypeSystemDocument {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLTypeSystemExtensionDocument(
     pub Vec<WithEmbeddedLocation<GraphQLTypeSystemExtensionOrDefinition>>,
 );
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub enum GraphQLTypeSystemExtensionOrDefinition {
     Definition(GraphQLTypeSystemDefinition),
     Extension(GraphQLTypeSystemExtension),
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub enum GraphQLTypeSystemExtension {
     ObjectTypeExtension(GraphQLObjectTypeExtension),
-    // ScalarTypeExtension
-    // InterfaceTypeExtension
-    // UnionTypeExtension
-    // EnumTypeExtension
-    // InputObjectTypeExtension
-    // SchemaExtension
+    ScalarTypeExtension(GraphQLScalarTypeExtension),
+    InterfaceTypeExtension(GraphQLInterfaceTypeExtension),
+    UnionTypeExtension(GraphQLUnionTypeExtension),
+    EnumTypeExtension(GraphQLEnumTypeExtension),
+    InputObjectTypeExtension(GraphQLInputObjectTypeExtension),
+    SchemaExtension(GraphQLSchemaExtension),
 }
 
 impl From<GraphQLObjectTypeExtension> for GraphQLTypeSystemExtension {
@@ -109,7 +110,43 @@ impl From<GraphQLObjectTypeExtension> for GraphQLTypeSystemExtension {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+impl From<GraphQLScalarTypeExtension> for GraphQLTypeSystemExtension {
+    fn from(scalar_type_extension: GraphQLScalarTypeExtension) -> Self {
+        Self::ScalarTypeExtension(scalar_type_extension)
+    }
+}
+
+impl From<GraphQLInterfaceTypeExtension> for GraphQLTypeSystemExtension {
+    fn from(interface_type_extension: GraphQLInterfaceTypeExtension) -> Self {
+        Self::InterfaceTypeExtension(interface_type_extension)
+    }
+}
+
+impl From<GraphQLUnionTypeExtension> for GraphQLTypeSystemExtension {
+    fn from(union_type_extension: GraphQLUnionTypeExtension) -> Self {
+        Self::UnionTypeExtension(union_type_extension)
+    }
+}
+
+impl From<GraphQLEnumTypeExtension> for GraphQLTypeSystemExtension {
+    fn from(enum_type_extension: GraphQLEnumTypeExtension) -> Self {
+        Self::EnumTypeExtension(enum_type_extension)
+    }
+}
+
+impl From<GraphQLInputObjectTypeExtension> for GraphQLTypeSystemExtension {
+    fn from(input_object_type_extension: GraphQLInputObjectTypeExtension) -> Self {
+        Self::InputObjectTypeExtension(input_object_type_extension)
+    }
+}
+
+impl From<GraphQLSchemaExtension> for GraphQLTypeSystemExtension {
+    fn from(schema_extension: GraphQLSchemaExtension) -> Self {
+        Self::SchemaExtension(schema_extension)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLObjectTypeDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -119,7 +156,7 @@ pub struct GraphQLObjectTypeDefinition {
     pub fields: Vec<WithEmbeddedLocation<GraphQLFieldDefinition>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLObjectTypeExtension {
     pub name: WithEmbeddedLocation<EntityName>,
     pub interfaces: Vec<WithEmbeddedLocation<EntityName>>,
@@ -128,7 +165,7 @@ pub struct GraphQLObjectTypeExtension {
     pub fields: Vec<WithEmbeddedLocation<GraphQLFieldDefinition>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLScalarTypeDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -136,7 +173,14 @@ pub struct GraphQLScalarTypeDefinition {
     pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLScalarTypeExtension {
+    pub name: WithEmbeddedLocation<EntityName>,
+    // These should have locations
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLInterfaceTypeDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -146,7 +190,16 @@ pub struct GraphQLInterfaceTypeDefinition {
     pub fields: Vec<WithEmbeddedLocation<GraphQLFieldDefinition>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLInterfaceTypeExtension {
+    pub name: WithEmbeddedLocation<EntityName>,
+    pub interfaces: Vec<WithEmbeddedLocation<EntityName>>,
+    // These should have locations
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+    pub fields: Vec<WithEmbeddedLocation<GraphQLFieldDefinition>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLInputObjectTypeDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -155,7 +208,15 @@ pub struct GraphQLInputObjectTypeDefinition {
     pub fields: Vec<WithEmbeddedLocation<GraphQLInputValueDefinition>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLInputObjectTypeExtension {
+    pub name: WithEmbeddedLocation<EntityName>,
+    // These should have locations
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+    pub fields: Vec<WithEmbeddedLocation<GraphQLInputValueDefinition>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLSchemaDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub query: Option<WithEmbeddedLocation<EntityName>>,
@@ -165,7 +226,29 @@ pub struct GraphQLSchemaDefinition {
     pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, EnumString, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLSchemaExtension {
+    pub query: Option<WithEmbeddedLocation<EntityName>>,
+    pub subscription: Option<WithEmbeddedLocation<EntityName>>,
+    pub mutation: Option<WithEmbeddedLocation<EntityName>>,
+    // These should have locations
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Debug,
+    Display,
+    EnumString,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DirectiveLocation {
     Query,
@@ -177,6 +260,7 @@ pub enum DirectiveLocation {
     InlineFragment,
     VariableDefinition,
 
+    Schema,
     Scalar,
     Object,
     FieldDefinition,
@@ -189,7 +273,7 @@ pub enum DirectiveLocation {
     InputFieldDefinition,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLDirectiveDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<DirectiveName>,
@@ -198,7 +282,7 @@ pub struct GraphQLDirectiveDefinition {
     pub locations: Vec<WithEmbeddedLocation<DirectiveLocation>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLEnumDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -206,14 +290,21 @@ pub struct GraphQLEnumDefinition {
     pub enum_value_definitions: Vec<WithEmbeddedLocation<GraphQLEnumValueDefinition>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLEnumTypeExtension {
+    pub name: WithEmbeddedLocation<EntityName>,
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+    pub enum_value_definitions: Vec<WithEmbeddedLocation<GraphQLEnumValueDefinition>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLEnumValueDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub value: WithEmbeddedLocation<EnumLiteralValue>,
     pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLUnionTypeDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<EntityName>,
@@ -221,6 +312,13 @@ pub struct GraphQLUnionTypeDefinition {
     pub union_member_types: Vec<WithEmbeddedLocation<EntityName>>,
 }
 
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
+pub struct GraphQLUnionTypeExtension {
+    pub name: WithEmbeddedLocation<EntityName>,
+    pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
+    pub union_member_types: Vec<WithEmbeddedLocation<EntityName>>,
+}
+
 impl From<GraphQLInputValueDefinition> for GraphQLFieldDefinition {
     fn from(value: GraphQLInputValueDefinition) -> Self {
         Self {
@@ -236,7 +334,7 @@ impl From<GraphQLInputValueDefinition> for GraphQLFieldDefinition {
 }
 
 /// A server field definition on an object or interface
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLFieldDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<SelectableName>,
@@ -246,7 +344,7 @@ pub struct GraphQLFieldDefinition {
 }
 
 /// This is an argument definition, but we're using the GraphQL spec lingo here.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct GraphQLInputValueDefinition {
     pub description: Option<WithEmbeddedLocation<DescriptionValue>>,
     pub name: WithEmbeddedLocation<InputValueName>,
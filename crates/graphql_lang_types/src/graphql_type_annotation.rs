@@ -1,9 +1,10 @@
+use serde::{Deserialize, Serialize};
 use std::{fmt, ops::Deref};
 
 use common_lang_types::{EntityName, WithEmbeddedLocation};
 use prelude::Postfix;
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum GraphQLTypeAnnotation {
     Named(GraphQLNamedTypeAnnotation),
     List(Box<GraphQLListTypeAnnotation>),
@@ -38,7 +39,7 @@ impl fmt::Display for GraphQLTypeAnnotation {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum GraphQLNonNullTypeAnnotation {
     Named(GraphQLNamedTypeAnnotation),
     List(GraphQLListTypeAnnotation),
@@ -62,7 +63,7 @@ impl fmt::Display for GraphQLNonNullTypeAnnotation {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct GraphQLNamedTypeAnnotation(pub EntityName);
 
 impl Deref for GraphQLNamedTypeAnnotation {
@@ -80,7 +81,7 @@ impl fmt::Display for GraphQLNamedTypeAnnotation {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct GraphQLListTypeAnnotation(pub WithEmbeddedLocation<GraphQLTypeAnnotation>);
 
 impl Deref for GraphQLListTypeAnnotation {
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use common_lang_types::{
@@ -5,7 +6,7 @@ use common_lang_types::{
     WithEmbeddedLocation, WithGenericLocation, WithSpan,
 };
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum GraphQLConstantValue {
     Int(i64),
     Float(FloatValue),
@@ -76,7 +77,7 @@ impl fmt::Display for GraphQLNonConstantValue {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct FloatValue(u64);
 
 impl FloatValue {
@@ -114,7 +115,7 @@ impl std::convert::From<i64> for FloatValue {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct NameValuePairInner<TName, TValue, TLocation> {
     pub name: WithGenericLocation<TName, TLocation>,
     pub value: WithGenericLocation<TValue, TLocation>,
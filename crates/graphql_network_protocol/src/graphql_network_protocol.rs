@@ -2,9 +2,9 @@ use std::collections::BTreeMap;
 use std::fmt;
 
 use common_lang_types::{
-    Diagnostic, DiagnosticResult, EntityName, ExpectEntityToExist, ExpectSelectableToExist,
-    JavascriptName, QueryExtraInfo, QueryOperationName, QueryText, SelectableName,
-    WithNonFatalDiagnostics,
+    Diagnostic, DiagnosticResult, EntityName, EnumLiteralValue, ExpectEntityToExist,
+    ExpectSelectableToExist, JavascriptName, QueryExtraInfo, QueryOperationName, QueryText,
+    SelectableName, WithNonFatalDiagnostics,
 };
 use intern::string_key::Intern;
 use isograph_lang_types::{
@@ -12,15 +12,15 @@ use isograph_lang_types::{
     VariableDeclaration,
 };
 use isograph_schema::{
-    CompilationProfile, IsographDatabase, MemoRefServerSelectable, TargetPlatform,
-    WrappedMergedSelectionMap, entity_not_defined_diagnostic, flattened_selectables_for_entity,
-    selectable_named,
+    CompilationProfile, IsographDatabase, MemoRefServerSelectable, ScalarTypeImports,
+    TargetPlatform, WrappedMergedSelectionMap, entity_not_defined_diagnostic,
+    flattened_selectables_for_entity, selectable_named,
 };
 use isograph_schema::{
     ConcreteTargetEntityName, DeprecatedParseTypeSystemOutcome, Format, ID_FIELD_NAME,
     ID_VARIABLE_NAME, MergedSelectionMap, NODE_FIELD_NAME, NetworkProtocol, RootOperationName,
-    WrapMergedSelectionMapResult, WrappedSelectionMapSelection, flattened_entity_named,
-    selection_map_wrapped,
+    WrapMergedSelectionMapResult, WrappedSelectionMapSelection, flattened_entities,
+    flattened_entity_named, selection_map_wrapped,
 };
 use lazy_static::lazy_static;
 use pico_macros::memo;
@@ -38,6 +38,10 @@ lazy_static! {
     pub static ref NEVER_JAVASCRIPT_TYPE: JavascriptName = "never".intern().into();
 }
 
+/// The value that output enum types include, so that code handling them keeps
+/// type checking if the server adds a value.
+pub const FUTURE_ADDED_VALUE: &str = "%future added value";
+
 pub(crate) struct GraphQLRootTypes {
     pub query: EntityName,
     pub mutation: EntityName,
@@ -96,6 +100,8 @@ pub struct GraphQLFetchableInfo {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub struct GraphQLNetworkProtocolEntityAssociatedData {
     pub fetchable: Option<GraphQLFetchableInfo>,
+    /// The values of the entity, if it is an enum.
+    pub enum_values: Option<Vec<EnumLiteralValue>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash, Default)]
@@ -125,11 +131,59 @@ impl CompilationProfile for GraphQLAndJavascriptProfile {
     }
 }
 
+/// The Javascript types of a scalar entity. These are the same for every scalar
+/// except enums, whose output type also includes [`FUTURE_ADDED_VALUE`], which
+/// should not be passed as an input.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+pub struct JavascriptScalarType {
+    pub output_type: JavascriptName,
+    pub input_type: JavascriptName,
+    /// If the scalar is an enum, its values. The output and input types are then
+    /// the names of types that are exported from the generated enums file.
+    pub enum_values: Option<Vec<JavascriptEnumValue>>,
+}
+
+impl JavascriptScalarType {
+    /// Records that `javascript_name` (i.e. the input or output type of this scalar
+    /// entity) is used, so that it is imported if necessary.
+    fn import(
+        &self,
+        entity_name: EntityName,
+        javascript_name: JavascriptName,
+        scalar_type_imports: &mut ScalarTypeImports,
+    ) {
+        if self.enum_values.is_some() {
+            scalar_type_imports.enum_type_names.insert(javascript_name);
+        } else {
+            scalar_type_imports.scalar_entity_names.insert(entity_name);
+        }
+    }
+}
+
+impl From<JavascriptName> for JavascriptScalarType {
+    fn from(javascript_name: JavascriptName) -> Self {
+        JavascriptScalarType {
+            output_type: javascript_name,
+            input_type: javascript_name,
+            enum_values: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+pub struct JavascriptEnumValue {
+    pub value: EnumLiteralValue,
+    /// If the value is deprecated, the reason. Deprecated values can still be
+    /// passed as inputs, but are marked as deprecated in the input type.
+    pub deprecation_reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash, Default)]
 pub struct JavascriptTargetPlatform {}
 
 impl TargetPlatform for JavascriptTargetPlatform {
-    type EntityAssociatedData = SelectionType<JavascriptName, GraphQLSchemaObjectAssociatedData>;
+    type EntityAssociatedData =
+        SelectionType<JavascriptScalarType, GraphQLSchemaObjectAssociatedData>;
 
     type SelectableAssociatedData = ();
 
@@ -139,6 +193,7 @@ impl TargetPlatform for JavascriptTargetPlatform {
         db: &IsographDatabase<TCompilationProfile>,
         entity_name: EntityName,
         indentation_level: u8,
+        scalar_type_imports: &mut ScalarTypeImports,
     ) -> String {
         let entity = flattened_entity_named(db, entity_name).expect_entity_to_exist(entity_name);
 
@@ -166,6 +221,7 @@ impl TargetPlatform for JavascriptTargetPlatform {
                         name,
                         server_selectable.dereference(),
                         indentation_level + 1,
+                        scalar_type_imports,
                     );
                     s.push_str(&field_type)
                 }
@@ -173,7 +229,10 @@ impl TargetPlatform for JavascriptTargetPlatform {
                 s.push_str(&format!("{}}}", "  ".repeat(indentation_level as usize)));
                 s
             }
-            SelectionType::Scalar(s) => s.to_string(),
+            SelectionType::Scalar(s) => {
+                s.import(entity_name, s.input_type, scalar_type_imports);
+                s.input_type.to_string()
+            }
         }
     }
 
@@ -183,6 +242,7 @@ impl TargetPlatform for JavascriptTargetPlatform {
         db: &IsographDatabase<TCompilationProfile>,
         parent_object_entity_name: EntityName,
         selectable_name: SelectableName,
+        scalar_type_imports: &mut ScalarTypeImports,
     ) -> JavascriptName {
         let server_scalar_selectable =
             selectable_named(db, parent_object_entity_name, selectable_name)
@@ -200,7 +260,7 @@ impl TargetPlatform for JavascriptTargetPlatform {
             .expect("Expected target entity to be valid.")
             .inner()
             .0;
-        flattened_entity_named(db, target_entity_name)
+        let scalar_type = flattened_entity_named(db, target_entity_name)
             .expect_entity_to_exist(target_entity_name)
             .lookup(db)
             .associated_data
@@ -210,8 +270,37 @@ impl TargetPlatform for JavascriptTargetPlatform {
             .target_platform
             .as_ref()
             .as_scalar()
-            .expect("Expected scalar entity to be scalar")
-            .dereference()
+            .expect("Expected scalar entity to be scalar");
+        scalar_type.import(
+            target_entity_name,
+            scalar_type.output_type,
+            scalar_type_imports,
+        );
+        scalar_type.output_type
+    }
+
+    fn generate_enum_types<TCompilationProfile: CompilationProfile<TargetPlatform = Self>>(
+        db: &IsographDatabase<TCompilationProfile>,
+    ) -> Option<String> {
+        let enum_types = flattened_entities(db)
+            .values()
+            .filter_map(|entity| {
+                let scalar_type = entity
+                    .lookup(db)
+                    .associated_data
+                    .as_ref()
+                    .as_server()?
+                    .target_platform
+                    .as_ref()
+                    .as_scalar()?;
+                format_enum_types(scalar_type, scalar_type.enum_values.as_ref()?).wrap_some()
+            })
+            .collect::<Vec<_>>();
+
+        if enum_types.is_empty() {
+            return None;
+        }
+        enum_types.join("\n").wrap_some()
     }
 
     fn generate_link_type<'a, TCompilationProfile: CompilationProfile<TargetPlatform = Self>>(
@@ -258,6 +347,35 @@ impl TargetPlatform for JavascriptTargetPlatform {
     }
 }
 
+/// Output types include [`FUTURE_ADDED_VALUE`], because the server can return
+/// values that it adds in the future. Input types do not, but mark deprecated
+/// values as such.
+fn format_enum_types(scalar_type: &JavascriptScalarType, values: &[JavascriptEnumValue]) -> String {
+    let mut output_type = format!("export type {} =", scalar_type.output_type);
+    for value in values {
+        output_type.push_str(&format!("\n  | \"{}\"", value.value));
+    }
+    output_type.push_str(&format!("\n  | \"{FUTURE_ADDED_VALUE}\";\n"));
+
+    let mut input_type = format!("export type {} =", scalar_type.input_type);
+    if values.is_empty() {
+        input_type.push_str(&format!(" {};\n", *NEVER_JAVASCRIPT_TYPE));
+    } else {
+        for value in values {
+            if let Some(deprecation_reason) = &value.deprecation_reason {
+                input_type.push_str(&format!(
+                    "\n  /** @deprecated {} */",
+                    deprecation_reason.replace("*/", "*\\/")
+                ));
+            }
+            input_type.push_str(&format!("\n  | \"{}\"", value.value));
+        }
+        input_type.push_str(";\n");
+    }
+
+    format!("{output_type}\n{input_type}")
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash, Default)]
 pub struct GraphQLNetworkProtocol {}
 
@@ -383,6 +501,44 @@ impl NetworkProtocol for GraphQLNetworkProtocol {
         }
     }
 
+    fn is_subscription_root_entity<
+        TCompilationProfile: CompilationProfile<NetworkProtocol = Self>,
+    >(
+        db: &IsographDatabase<TCompilationProfile>,
+        entity_name: EntityName,
+    ) -> bool {
+        flattened_entity_named(db, entity_name)
+            .and_then(|entity| {
+                entity
+                    .lookup(db)
+                    .associated_data
+                    .as_ref()
+                    .as_server()?
+                    .network_protocol
+                    .fetchable
+                    .as_ref()
+                    .map(|fetchable_info| {
+                        fetchable_info.operation_kind == GraphQLOperationKind::Subscription
+                    })
+            })
+            .unwrap_or(false)
+    }
+
+    fn enum_values<TCompilationProfile: CompilationProfile<NetworkProtocol = Self>>(
+        db: &IsographDatabase<TCompilationProfile>,
+        entity_name: EntityName,
+    ) -> Option<&[EnumLiteralValue]> {
+        flattened_entity_named(db, entity_name)
+            .as_ref()?
+            .lookup(db)
+            .associated_data
+            .as_ref()
+            .as_server()?
+            .network_protocol
+            .enum_values
+            .as_deref()
+    }
+
     fn generate_query_extra_info(
         query_name: QueryOperationName,
         operation_name: EntityName,
@@ -409,6 +565,7 @@ fn format_field_definition<TCompilationProfile: CompilationProfile>(
     name: &SelectableName,
     server_selectable: MemoRefServerSelectable<TCompilationProfile>,
     indentation_level: u8,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     let server_selectable = server_selectable.lookup(db);
     let is_optional = is_nullable(
@@ -433,7 +590,8 @@ fn format_field_definition<TCompilationProfile: CompilationProfile>(
                 .as_ref()
                 .expect("Expected target entity to be valid.")
                 .reference(),
-            indentation_level + 1
+            indentation_level + 1,
+            scalar_type_imports,
         ),
     )
 }
@@ -450,6 +608,7 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     type_annotation: &TypeAnnotationDeclaration,
     indentation_level: u8,
+    scalar_type_imports: &mut ScalarTypeImports,
 ) -> String {
     match type_annotation.reference() {
         TypeAnnotationDeclaration::Scalar(scalar) => {
@@ -457,6 +616,7 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                 db,
                 scalar.0,
                 indentation_level + 1,
+                scalar_type_imports,
             )
         }
         TypeAnnotationDeclaration::Union(union_type_annotation) => {
@@ -478,6 +638,7 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                                 db,
                                 scalar.0,
                                 indentation_level + 1,
+                                scalar_type_imports,
                             ));
                         }
                         UnionVariant::Plural(type_annotation) => {
@@ -486,6 +647,7 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                                 db,
                                 type_annotation.item.reference(),
                                 indentation_level + 1,
+                                scalar_type_imports,
                             ));
                             s.push('>');
                         }
@@ -507,6 +669,7 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                             db,
                             scalar.0,
                             indentation_level + 1,
+                            scalar_type_imports,
                         )
                     }
                     UnionVariant::Plural(type_annotation) => {
@@ -515,7 +678,8 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                             TCompilationProfile::TargetPlatform::format_server_field_scalar_type(
                                 db,
                                 type_annotation.item.inner().0,
-                                indentation_level + 1
+                                indentation_level + 1,
+                                scalar_type_imports,
                             )
                         )
                     }
@@ -528,7 +692,8 @@ fn format_type_annotation<TCompilationProfile: CompilationProfile>(
                 TCompilationProfile::TargetPlatform::format_server_field_scalar_type(
                     db,
                     type_annotation.item.inner().0,
-                    indentation_level + 1
+                    indentation_level + 1,
+                    scalar_type_imports,
                 )
             )
         }
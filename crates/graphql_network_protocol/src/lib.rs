@@ -1,9 +1,13 @@
 mod graphql_network_protocol;
+mod merge_type_system_extensions;
 mod nested_server_schema;
 mod parse_type_system_document;
 mod process_type_system_definition;
 mod query_text;
 mod read_schema;
+mod validate_graphql_schema;
 
 pub use graphql_network_protocol::*;
+pub use merge_type_system_extensions::*;
 pub use read_schema::*;
+pub use validate_graphql_schema::*;
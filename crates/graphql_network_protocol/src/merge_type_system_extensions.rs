@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use common_lang_types::{
+    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, Location, WithEmbeddedLocation,
+    WithLocationPostfix, WithNonFatalDiagnostics,
+};
+use graphql_lang_types::{
+    GraphQLSchemaDefinition, GraphQLSchemaExtension, GraphQLTypeSystemDefinition,
+    GraphQLTypeSystemDocument, GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionDocument,
+    GraphQLTypeSystemExtensionOrDefinition,
+};
+use isograph_schema::{CompilationProfile, IsographDatabase};
+use pico_macros::memo;
+use prelude::Postfix;
+
+use crate::parse_graphql_schema;
+
+/// The schema and the definitions in the schema extension files, with every
+/// extension (e.g. `extend enum Status { ARCHIVED }`) merged into the definition
+/// that it extends.
+///
+/// The non-fatal diagnostics (e.g. for extending an undefined type) are reported
+/// by `validate_graphql_schema`.
+#[memo]
+pub fn merged_graphql_schema<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> DiagnosticResult<WithNonFatalDiagnostics<GraphQLTypeSystemDocument>> {
+    let (type_system_document, type_system_extension_documents) =
+        parse_graphql_schema(db).to_owned()?;
+
+    merge_type_system_extensions(
+        type_system_document,
+        type_system_extension_documents
+            .values()
+            .map(|extension_document| extension_document.lookup(db).clone()),
+    )
+    .wrap_ok()
+}
+
+/// Merges the extensions in the extension documents into the definitions in the
+/// schema and the extension documents. Definitions are returned in the order in
+/// which they are defined.
+///
+/// An extension can precede the definition that it extends. If a type is defined
+/// multiple times, it is merged into the first definition; the duplicate definition
+/// is reported when the schema is validated.
+pub fn merge_type_system_extensions(
+    type_system_document: GraphQLTypeSystemDocument,
+    type_system_extension_documents: impl IntoIterator<Item = GraphQLTypeSystemExtensionDocument>,
+) -> WithNonFatalDiagnostics<GraphQLTypeSystemDocument> {
+    let mut definitions = type_system_document.0;
+    let mut extensions = vec![];
+
+    for extension_document in type_system_extension_documents {
+        for extension_or_definition in extension_document.0 {
+            let WithEmbeddedLocation { location, item } = extension_or_definition;
+            match item {
+                GraphQLTypeSystemExtensionOrDefinition::Definition(definition) => {
+                    definitions.push(definition.with_location(location));
+                }
+                GraphQLTypeSystemExtensionOrDefinition::Extension(extension) => {
+                    extensions.push(extension.with_location(location));
+                }
+            }
+        }
+    }
+
+    let mut type_definition_indexes = HashMap::new();
+    let mut schema_definition_index = None;
+    for (index, definition) in definitions.iter().enumerate() {
+        if let Some(name) = type_definition_name(&definition.item) {
+            type_definition_indexes.entry(name).or_insert(index);
+        } else if let GraphQLTypeSystemDefinition::SchemaDefinition(_) = definition.item {
+            schema_definition_index.get_or_insert(index);
+        }
+    }
+
+    let mut non_fatal_diagnostics = vec![];
+    for extension in extensions {
+        let WithEmbeddedLocation { location, item } = extension;
+
+        let item = match item {
+            GraphQLTypeSystemExtension::SchemaExtension(schema_extension) => {
+                merge_schema_extension(
+                    &mut definitions,
+                    &mut schema_definition_index,
+                    schema_extension.with_location(location),
+                    &mut non_fatal_diagnostics,
+                );
+                continue;
+            }
+            item => item,
+        };
+
+        let (name, extension_keyword) = match &item {
+            GraphQLTypeSystemExtension::ObjectTypeExtension(extension) => {
+                (extension.name, "extend type")
+            }
+            GraphQLTypeSystemExtension::ScalarTypeExtension(extension) => {
+                (extension.name, "extend scalar")
+            }
+            GraphQLTypeSystemExtension::InterfaceTypeExtension(extension) => {
+                (extension.name, "extend interface")
+            }
+            GraphQLTypeSystemExtension::UnionTypeExtension(extension) => {
+                (extension.name, "extend union")
+            }
+            GraphQLTypeSystemExtension::EnumTypeExtension(extension) => {
+                (extension.name, "extend enum")
+            }
+            GraphQLTypeSystemExtension::InputObjectTypeExtension(extension) => {
+                (extension.name, "extend input")
+            }
+            GraphQLTypeSystemExtension::SchemaExtension(_) => {
+                panic!(
+                    "Expected schema extensions to have been merged. \
+                    This is indicative of a bug in Isograph."
+                )
+            }
+        };
+
+        let Some(index) = type_definition_indexes.get(&name.item).copied() else {
+            non_fatal_diagnostics.push(Diagnostic::new(
+                format!("Cannot extend `{}`, because it is not defined.", name.item),
+                to_location(name.location),
+            ));
+            continue;
+        };
+
+        match (&mut definitions[index].item, item) {
+            (
+                GraphQLTypeSystemDefinition::ObjectTypeDefinition(definition),
+                GraphQLTypeSystemExtension::ObjectTypeExtension(extension),
+            ) => {
+                definition.interfaces.extend(extension.interfaces);
+                definition.directives.extend(extension.directives);
+                definition.fields.extend(extension.fields);
+            }
+            (
+                GraphQLTypeSystemDefinition::ScalarTypeDefinition(definition),
+                GraphQLTypeSystemExtension::ScalarTypeExtension(extension),
+            ) => {
+                definition.directives.extend(extension.directives);
+            }
+            (
+                GraphQLTypeSystemDefinition::InterfaceTypeDefinition(definition),
+                GraphQLTypeSystemExtension::InterfaceTypeExtension(extension),
+            ) => {
+                definition.interfaces.extend(extension.interfaces);
+                definition.directives.extend(extension.directives);
+                definition.fields.extend(extension.fields);
+            }
+            (
+                GraphQLTypeSystemDefinition::UnionTypeDefinition(definition),
+                GraphQLTypeSystemExtension::UnionTypeExtension(extension),
+            ) => {
+                definition.directives.extend(extension.directives);
+                definition
+                    .union_member_types
+                    .extend(extension.union_member_types);
+            }
+            (
+                GraphQLTypeSystemDefinition::EnumDefinition(definition),
+                GraphQLTypeSystemExtension::EnumTypeExtension(extension),
+            ) => {
+                definition.directives.extend(extension.directives);
+                definition
+                    .enum_value_definitions
+                    .extend(extension.enum_value_definitions);
+            }
+            (
+                GraphQLTypeSystemDefinition::InputObjectTypeDefinition(definition),
+                GraphQLTypeSystemExtension::InputObjectTypeExtension(extension),
+            ) => {
+                definition.directives.extend(extension.directives);
+                definition.fields.extend(extension.fields);
+            }
+            (definition, _) => {
+                non_fatal_diagnostics.push(Diagnostic::new(
+                    format!(
+                        "Cannot extend `{}` with `{extension_keyword}`, because it is {}.",
+                        name.item,
+                        type_definition_description(definition)
+                    ),
+                    to_location(name.location),
+                ));
+            }
+        }
+    }
+
+    WithNonFatalDiagnostics::new(
+        GraphQLTypeSystemDocument(definitions),
+        non_fatal_diagnostics,
+    )
+}
+
+fn merge_schema_extension(
+    definitions: &mut Vec<WithEmbeddedLocation<GraphQLTypeSystemDefinition>>,
+    schema_definition_index: &mut Option<usize>,
+    schema_extension: WithEmbeddedLocation<GraphQLSchemaExtension>,
+    non_fatal_diagnostics: &mut Vec<Diagnostic>,
+) {
+    let WithEmbeddedLocation {
+        location,
+        item: schema_extension,
+    } = schema_extension;
+
+    let Some(index) = *schema_definition_index else {
+        // If there is no schema definition, the default root operation types are used,
+        // so the extension becomes the schema definition.
+        *schema_definition_index = definitions.len().wrap_some();
+        definitions.push(
+            GraphQLSchemaDefinition {
+                description: None,
+                query: schema_extension.query,
+                subscription: schema_extension.subscription,
+                mutation: schema_extension.mutation,
+                directives: schema_extension.directives,
+            }
+            .to::<GraphQLTypeSystemDefinition>()
+            .with_location(location),
+        );
+        return;
+    };
+
+    let GraphQLTypeSystemDefinition::SchemaDefinition(schema_definition) =
+        &mut definitions[index].item
+    else {
+        panic!("Expected a schema definition. This is indicative of a bug in Isograph.");
+    };
+
+    for (root_type, extension_root_type, operation_kind) in [
+        (
+            &mut schema_definition.query,
+            schema_extension.query,
+            "query",
+        ),
+        (
+            &mut schema_definition.subscription,
+            schema_extension.subscription,
+            "subscription",
+        ),
+        (
+            &mut schema_definition.mutation,
+            schema_extension.mutation,
+            "mutation",
+        ),
+    ] {
+        let Some(extension_root_type) = extension_root_type else {
+            continue;
+        };
+        if root_type.is_some() {
+            non_fatal_diagnostics.push(Diagnostic::new(
+                format!(
+                    "Cannot extend the schema with a {operation_kind} type, \
+                    because it already has one."
+                ),
+                to_location(extension_root_type.location),
+            ));
+        } else {
+            *root_type = extension_root_type.wrap_some();
+        }
+    }
+
+    schema_definition
+        .directives
+        .extend(schema_extension.directives);
+}
+
+fn type_definition_name(definition: &GraphQLTypeSystemDefinition) -> Option<EntityName> {
+    match definition {
+        GraphQLTypeSystemDefinition::ObjectTypeDefinition(object) => object.name.item,
+        GraphQLTypeSystemDefinition::ScalarTypeDefinition(scalar) => scalar.name.item,
+        GraphQLTypeSystemDefinition::InterfaceTypeDefinition(interface) => interface.name.item,
+        GraphQLTypeSystemDefinition::InputObjectTypeDefinition(input_object) => {
+            input_object.name.item
+        }
+        GraphQLTypeSystemDefinition::EnumDefinition(enum_definition) => enum_definition.name.item,
+        GraphQLTypeSystemDefinition::UnionTypeDefinition(union) => union.name.item,
+        GraphQLTypeSystemDefinition::DirectiveDefinition(_)
+        | GraphQLTypeSystemDefinition::SchemaDefinition(_) => return None,
+    }
+    .wrap_some()
+}
+
+fn type_definition_description(definition: &GraphQLTypeSystemDefinition) -> &'static str {
+    match definition {
+        GraphQLTypeSystemDefinition::ObjectTypeDefinition(_) => "an object",
+        GraphQLTypeSystemDefinition::ScalarTypeDefinition(_) => "a scalar",
+        GraphQLTypeSystemDefinition::InterfaceTypeDefinition(_) => "an interface",
+        GraphQLTypeSystemDefinition::InputObjectTypeDefinition(_) => "an input object",
+        GraphQLTypeSystemDefinition::EnumDefinition(_) => "an enum",
+        GraphQLTypeSystemDefinition::UnionTypeDefinition(_) => "a union",
+        GraphQLTypeSystemDefinition::DirectiveDefinition(_) => "a directive",
+        GraphQLTypeSystemDefinition::SchemaDefinition(_) => "a schema",
+    }
+}
+
+fn to_location(location: EmbeddedLocation) -> Option<Location> {
+    location.to::<Location>().wrap_some()
+}
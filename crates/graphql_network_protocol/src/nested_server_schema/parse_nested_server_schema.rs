@@ -5,10 +5,11 @@ use common_lang_types::{
     VariableName, WithEmbeddedLocation, WithLocationPostfix, WithNonFatalDiagnostics,
 };
 use graphql_lang_types::{
-    GraphQLFieldDefinition, GraphQLInterfaceTypeDefinition, GraphQLTypeSystemDefinition,
-    GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionOrDefinition,
+    GraphQLEnumDefinition, GraphQLFieldDefinition, GraphQLInterfaceTypeDefinition,
+    GraphQLTypeSystemDefinition,
 };
-use intern::string_key::Intern;
+use intern::{Lookup, string_key::Intern};
+use isograph_config::CustomScalarType;
 use isograph_lang_types::{
     DefinitionLocationPostfix, Description, EntityNameWrapper, SelectionTypePostfix,
     TypeAnnotationDeclaration, UnionTypeAnnotationDeclaration, UnionVariant,
@@ -27,10 +28,11 @@ use prelude::Postfix;
 
 use crate::graphql_network_protocol::GraphQLRootTypes;
 use crate::{
-    BOOLEAN_JAVASCRIPT_TYPE, GraphQLAndJavascriptProfile, GraphQLFetchableInfo,
-    GraphQLNetworkProtocolEntityAssociatedData, GraphQLOperationKind,
-    GraphQLSchemaObjectAssociatedData, GraphQLWrapStrategy, NEVER_JAVASCRIPT_TYPE,
-    NUMBER_JAVASCRIPT_TYPE, STRING_JAVASCRIPT_TYPE, UNKNOWN_JAVASCRIPT_TYPE, parse_graphql_schema,
+    BOOLEAN_JAVASCRIPT_TYPE, DEFAULT_DEPRECATION_REASON, DEPRECATED_DIRECTIVE_NAME,
+    GraphQLAndJavascriptProfile, GraphQLFetchableInfo, GraphQLNetworkProtocolEntityAssociatedData,
+    GraphQLOperationKind, GraphQLSchemaObjectAssociatedData, GraphQLWrapStrategy,
+    JavascriptEnumValue, JavascriptScalarType, NEVER_JAVASCRIPT_TYPE, NUMBER_JAVASCRIPT_TYPE,
+    REASON_ARGUMENT_NAME, STRING_JAVASCRIPT_TYPE, UNKNOWN_JAVASCRIPT_TYPE, merged_graphql_schema,
 };
 
 pub fn parse_nested_schema(
@@ -58,7 +60,9 @@ fn define_default_graphql_data_model_entities(
             selectables: Default::default(),
             associated_data: EntityAssociatedData {
                 network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
-                target_platform: (*STRING_JAVASCRIPT_TYPE).scalar_selected(),
+                target_platform: (*STRING_JAVASCRIPT_TYPE)
+                    .to::<JavascriptScalarType>()
+                    .scalar_selected(),
             }
             .server_defined(),
             selection_info: ().scalar_selected(),
@@ -79,7 +83,9 @@ fn define_default_graphql_data_model_entities(
             selectables: Default::default(),
             associated_data: EntityAssociatedData {
                 network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
-                target_platform: (*STRING_JAVASCRIPT_TYPE).scalar_selected(),
+                target_platform: (*STRING_JAVASCRIPT_TYPE)
+                    .to::<JavascriptScalarType>()
+                    .scalar_selected(),
             }
             .server_defined(),
             selection_info: ().scalar_selected(),
@@ -95,7 +101,9 @@ fn define_default_graphql_data_model_entities(
             selectables: Default::default(),
             associated_data: EntityAssociatedData {
                 network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
-                target_platform: (*NUMBER_JAVASCRIPT_TYPE).scalar_selected(),
+                target_platform: (*NUMBER_JAVASCRIPT_TYPE)
+                    .to::<JavascriptScalarType>()
+                    .scalar_selected(),
             }
             .server_defined(),
             selection_info: ().scalar_selected(),
@@ -111,7 +119,9 @@ fn define_default_graphql_data_model_entities(
             selectables: Default::default(),
             associated_data: EntityAssociatedData {
                 network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
-                target_platform: (*NUMBER_JAVASCRIPT_TYPE).scalar_selected(),
+                target_platform: (*NUMBER_JAVASCRIPT_TYPE)
+                    .to::<JavascriptScalarType>()
+                    .scalar_selected(),
             }
             .server_defined(),
             selection_info: ().scalar_selected(),
@@ -127,7 +137,9 @@ fn define_default_graphql_data_model_entities(
             selectables: Default::default(),
             associated_data: EntityAssociatedData {
                 network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
-                target_platform: (*BOOLEAN_JAVASCRIPT_TYPE).scalar_selected(),
+                target_platform: (*BOOLEAN_JAVASCRIPT_TYPE)
+                    .to::<JavascriptScalarType>()
+                    .scalar_selected(),
             }
             .server_defined(),
             selection_info: ().scalar_selected(),
@@ -136,17 +148,14 @@ fn define_default_graphql_data_model_entities(
     );
 }
 
-fn graphql_root_types_from_documents(
-    documents: &[WithEmbeddedLocation<GraphQLTypeSystemExtensionOrDefinition>],
+fn graphql_root_types_from_definitions(
+    definitions: &[WithEmbeddedLocation<GraphQLTypeSystemDefinition>],
     non_fatal_diagnostics: &mut Vec<Diagnostic>,
 ) -> GraphQLRootTypes {
     let mut graphql_root_types = None;
 
-    for document in documents {
-        if let GraphQLTypeSystemExtensionOrDefinition::Definition(
-            GraphQLTypeSystemDefinition::SchemaDefinition(schema_definition),
-        ) = &document.item
-        {
+    for definition in definitions {
+        if let GraphQLTypeSystemDefinition::SchemaDefinition(schema_definition) = &definition.item {
             if graphql_root_types.is_some() {
                 non_fatal_diagnostics.push(Diagnostic::new(
                     "Duplicate schema definition".to_string(),
@@ -209,43 +218,37 @@ fn graphql_network_protocol_associated_data_for_object(
         None
     };
 
-    GraphQLNetworkProtocolEntityAssociatedData { fetchable }
+    GraphQLNetworkProtocolEntityAssociatedData {
+        fetchable,
+        enum_values: None,
+    }
 }
 
 fn insert_parsed_items_into_schema(
     db: &IsographDatabase<GraphQLAndJavascriptProfile>,
     schema: &mut NestedDataModelSchema<GraphQLAndJavascriptProfile>,
 ) {
-    let (type_system_document, type_system_extension_documents) =
-        match parse_graphql_schema(db).to_owned() {
-            Ok(s) => s,
-            Err(e) => {
-                schema.non_fatal_diagnostics.push(e);
-                return;
-            }
-        };
-
+    // The diagnostics for invalid extensions are reported by validate_graphql_schema,
+    // so we ignore them here.
     // TODO clone less etc
-    let documents = type_system_document
-        .0
-        .into_iter()
-        .map(|with_location| with_location.map(GraphQLTypeSystemExtensionOrDefinition::Definition))
-        .chain(
-            type_system_extension_documents
-                .iter()
-                .flat_map(|(_, val)| val.lookup(db).clone().0.into_iter()),
-        )
-        .collect::<Vec<_>>();
+    let definitions = match merged_graphql_schema(db).to_owned() {
+        Ok(merged_type_system_document) => merged_type_system_document.item.0,
+        Err(e) => {
+            schema.non_fatal_diagnostics.push(e);
+            return;
+        }
+    };
 
     let mut supertype_to_subtype_map = BTreeMap::new();
     let mut interfaces_to_process = vec![];
 
     let graphql_root_types =
-        graphql_root_types_from_documents(&documents, &mut schema.non_fatal_diagnostics);
+        graphql_root_types_from_definitions(&definitions, &mut schema.non_fatal_diagnostics);
 
-    process_graphql_documents(
+    process_graphql_definitions(
         schema,
-        documents,
+        definitions,
+        &db.get_isograph_config().options.custom_scalar_types,
         &mut supertype_to_subtype_map,
         &mut interfaces_to_process,
         &graphql_root_types,
@@ -315,6 +318,7 @@ fn insert_parsed_items_into_schema(
                 associated_data: EntityAssociatedData {
                     network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
                     target_platform: get_js_union_name(&concrete_child_entity_names)
+                        .to::<JavascriptScalarType>()
                         .scalar_selected(),
                 }
                 .server_defined(),
@@ -408,275 +412,264 @@ fn insert_parsed_items_into_schema(
     }
 }
 
-fn process_graphql_documents(
+fn process_graphql_definitions(
     schema: &mut NestedDataModelSchema<GraphQLAndJavascriptProfile>,
-    documents: Vec<WithEmbeddedLocation<GraphQLTypeSystemExtensionOrDefinition>>,
+    definitions: Vec<WithEmbeddedLocation<GraphQLTypeSystemDefinition>>,
+    custom_scalar_types: &BTreeMap<String, CustomScalarType>,
     supertype_to_subtype_map: &mut UnvalidatedTypeRefinementMap,
     interfaces_to_process: &mut Vec<WithEmbeddedLocation<GraphQLInterfaceTypeDefinition>>,
     graphql_root_types: &GraphQLRootTypes,
 ) {
-    for document in documents {
-        match document.item {
-            GraphQLTypeSystemExtensionOrDefinition::Definition(graphql_type_system_definition) => {
-                match graphql_type_system_definition {
-                    GraphQLTypeSystemDefinition::ObjectTypeDefinition(
-                        graphql_object_type_definition,
-                    ) => {
-                        let mut selectables = process_fields(
-                            graphql_object_type_definition.name.item,
-                            graphql_object_type_definition.fields,
-                        );
-                        let entity_name = graphql_object_type_definition.name.item;
-                        let has_id_field = selectables.item.contains_key(&*ID_FIELD_NAME);
-                        let network_protocol_associated_data =
-                            graphql_network_protocol_associated_data_for_object(
-                                graphql_root_types,
-                                entity_name,
-                                has_id_field,
-                            );
-
-                        let typename_entity_name = format!("{}__discriminator", entity_name)
-                            .intern()
-                            .to::<EntityName>()
-                            // And make it not selectable!
-                            .note_todo(
-                                "Come up with a way to not have \
+    for definition in definitions {
+        match definition.item {
+            GraphQLTypeSystemDefinition::ObjectTypeDefinition(graphql_object_type_definition) => {
+                let mut selectables = process_fields(
+                    graphql_object_type_definition.name.item,
+                    graphql_object_type_definition.fields,
+                );
+                let entity_name = graphql_object_type_definition.name.item;
+                let has_id_field = selectables.item.contains_key(&*ID_FIELD_NAME);
+                let network_protocol_associated_data =
+                    graphql_network_protocol_associated_data_for_object(
+                        graphql_root_types,
+                        entity_name,
+                        has_id_field,
+                    );
+
+                let typename_entity_name = format!("{}__discriminator", entity_name)
+                    .intern()
+                    .to::<EntityName>()
+                    // And make it not selectable!
+                    .note_todo(
+                        "Come up with a way to not have \
                                 these be in the same namespace",
-                            );
-
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: typename_entity_name.with_missing_location(),
-                                description: format!("The typename of {}", entity_name)
-                                    .intern()
-                                    .to::<DescriptionValue>()
-                                    .wrap(Description)
-                                    .with_missing_location()
-                                    .wrap_some(),
-                                selectables: Default::default(),
-                                associated_data: EntityAssociatedData {
-                                    network_protocol:
-                                        GraphQLNetworkProtocolEntityAssociatedData::default(),
-                                    target_platform: format!("\"{entity_name}\"")
-                                        .intern()
-                                        .to::<JavascriptName>()
-                                        .scalar_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ().scalar_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
-
-                        selectables.item.insert(
-                            *TYPENAME_FIELD_NAME,
-                            DataModelSelectable {
-                                name: (*TYPENAME_FIELD_NAME).with_missing_location(),
-                                // Missing location because we didn't parse the parent type name as part of the
-                                // selectable
-                                parent_entity_name: graphql_object_type_definition
-                                    .name
-                                    .item
-                                    .with_missing_location(),
-                                description: format!("A discriminant for the {} type", entity_name)
-                                    .intern()
-                                    .to::<DescriptionValue>()
-                                    .wrap(Description)
-                                    .with_missing_location()
-                                    .wrap_some(),
-                                arguments: vec![],
-                                target_entity: TypeAnnotationDeclaration::Scalar(
-                                    typename_entity_name.into(),
-                                )
-                                .wrap_ok()
-                                .with_missing_location(),
-                                associated_data: SelectableAssociatedData {
-                                    network_protocol: (),
-                                    target_platform: (),
-                                }
-                                .server_defined(),
-                                is_inline_fragment: false.into(),
-                            },
-                        );
-
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: graphql_object_type_definition.name.map_location(Some),
-                                description: graphql_object_type_definition
-                                    .description
-                                    .map(|x| x.map_location(Some).map(Description)),
-                                selectables,
-                                associated_data: EntityAssociatedData {
-                                    network_protocol: network_protocol_associated_data,
-                                    target_platform: GraphQLSchemaObjectAssociatedData {
-                                        subtypes: vec![],
-                                    }
-                                    .object_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ServerObjectSelectionInfo {
-                                    is_concrete: IsConcrete(true),
-                                }
-                                .object_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
-
-                        for interface in graphql_object_type_definition.interfaces {
-                            supertype_to_subtype_map
-                                .entry(interface.item)
-                                .or_default()
-                                .push(graphql_object_type_definition.name.item);
-                        }
+                    );
 
-                        // TODO refetch field and refetch field selection set
-                    }
-                    GraphQLTypeSystemDefinition::ScalarTypeDefinition(
-                        graphql_scalar_type_definition,
-                    ) => {
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: graphql_scalar_type_definition.name.map_location(Some),
-                                description: graphql_scalar_type_definition
-                                    .description
-                                    .map(|x| x.map_location(Some).map(Description)),
-                                selectables: Default::default(),
-                                associated_data: EntityAssociatedData {
-                                    network_protocol:
-                                        GraphQLNetworkProtocolEntityAssociatedData::default(),
-                                    target_platform: (*UNKNOWN_JAVASCRIPT_TYPE).scalar_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ().scalar_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
-                    }
-                    GraphQLTypeSystemDefinition::InterfaceTypeDefinition(
-                        graphql_interface_type_definition,
-                    ) => {
-                        supertype_to_subtype_map
-                            .entry(graphql_interface_type_definition.name.item)
-                            .or_default();
-                        interfaces_to_process.push(
-                            graphql_interface_type_definition.with_location(document.location),
-                        );
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: typename_entity_name.with_missing_location(),
+                        description: format!("The typename of {}", entity_name)
+                            .intern()
+                            .to::<DescriptionValue>()
+                            .wrap(Description)
+                            .with_missing_location()
+                            .wrap_some(),
+                        selectables: Default::default(),
+                        associated_data: EntityAssociatedData {
+                            network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
+                            target_platform: format!("\"{entity_name}\"")
+                                .intern()
+                                .to::<JavascriptName>()
+                                .to::<JavascriptScalarType>()
+                                .scalar_selected(),
+                        }
+                        .server_defined(),
+                        selection_info: ().scalar_selected(),
                     }
-                    GraphQLTypeSystemDefinition::InputObjectTypeDefinition(
-                        graphql_input_object_type_definition,
-                    ) => {
-                        let selectables = process_fields(
-                            graphql_input_object_type_definition.name.item,
-                            graphql_input_object_type_definition
-                                .fields
-                                .into_iter()
-                                .map(|x| x.map(From::from))
-                                .collect(),
-                        );
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: graphql_input_object_type_definition.name.map_location(Some),
-                                description: graphql_input_object_type_definition
-                                    .description
-                                    .map(|x| x.map_location(Some).map(Description)),
-                                selectables,
-                                associated_data: EntityAssociatedData {
-                                    network_protocol:
-                                        GraphQLNetworkProtocolEntityAssociatedData::default(),
-                                    target_platform: GraphQLSchemaObjectAssociatedData {
-                                        subtypes: vec![],
-                                    }
-                                    .object_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ServerObjectSelectionInfo {
-                                    is_concrete: IsConcrete(true),
-                                }
+                    .with_some_location(definition.location),
+                );
+
+                selectables.item.insert(
+                    *TYPENAME_FIELD_NAME,
+                    DataModelSelectable {
+                        name: (*TYPENAME_FIELD_NAME).with_missing_location(),
+                        // Missing location because we didn't parse the parent type name as part of the
+                        // selectable
+                        parent_entity_name: graphql_object_type_definition
+                            .name
+                            .item
+                            .with_missing_location(),
+                        description: format!("A discriminant for the {} type", entity_name)
+                            .intern()
+                            .to::<DescriptionValue>()
+                            .wrap(Description)
+                            .with_missing_location()
+                            .wrap_some(),
+                        arguments: vec![],
+                        target_entity: TypeAnnotationDeclaration::Scalar(
+                            typename_entity_name.into(),
+                        )
+                        .wrap_ok()
+                        .with_missing_location(),
+                        associated_data: SelectableAssociatedData {
+                            network_protocol: (),
+                            target_platform: (),
+                        }
+                        .server_defined(),
+                        is_inline_fragment: false.into(),
+                    },
+                );
+
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: graphql_object_type_definition.name.map_location(Some),
+                        description: graphql_object_type_definition
+                            .description
+                            .map(|x| x.map_location(Some).map(Description)),
+                        selectables,
+                        associated_data: EntityAssociatedData {
+                            network_protocol: network_protocol_associated_data,
+                            target_platform: GraphQLSchemaObjectAssociatedData { subtypes: vec![] }
                                 .object_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
+                        }
+                        .server_defined(),
+                        selection_info: ServerObjectSelectionInfo {
+                            is_concrete: IsConcrete(true),
+                        }
+                        .object_selected(),
                     }
-                    GraphQLTypeSystemDefinition::DirectiveDefinition(
-                        _graphql_directive_definition,
-                    ) => {}
-                    GraphQLTypeSystemDefinition::EnumDefinition(graphql_enum_definition) => {
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: graphql_enum_definition.name.map_location(Some),
-                                description: graphql_enum_definition
-                                    .description
-                                    .map(|x| x.map_location(Some).map(Description)),
-                                selectables: Default::default(),
-                                associated_data: EntityAssociatedData {
-                                    network_protocol:
-                                        GraphQLNetworkProtocolEntityAssociatedData::default(),
-                                    target_platform: (*STRING_JAVASCRIPT_TYPE).scalar_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ().scalar_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
+                    .with_some_location(definition.location),
+                );
+
+                for interface in graphql_object_type_definition.interfaces {
+                    supertype_to_subtype_map
+                        .entry(interface.item)
+                        .or_default()
+                        .push(graphql_object_type_definition.name.item);
+                }
+
+                // TODO refetch field and refetch field selection set
+            }
+            GraphQLTypeSystemDefinition::ScalarTypeDefinition(graphql_scalar_type_definition) => {
+                let javascript_name = match custom_scalar_types
+                    .get(graphql_scalar_type_definition.name.item.lookup())
+                {
+                    Some(CustomScalarType::Inline(type_)) => type_.intern().into(),
+                    Some(CustomScalarType::Import { name, .. }) => name.intern().into(),
+                    None => *UNKNOWN_JAVASCRIPT_TYPE,
+                };
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: graphql_scalar_type_definition.name.map_location(Some),
+                        description: graphql_scalar_type_definition
+                            .description
+                            .map(|x| x.map_location(Some).map(Description)),
+                        selectables: Default::default(),
+                        associated_data: EntityAssociatedData {
+                            network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
+                            target_platform: javascript_name
+                                .to::<JavascriptScalarType>()
+                                .scalar_selected(),
+                        }
+                        .server_defined(),
+                        selection_info: ().scalar_selected(),
                     }
-                    GraphQLTypeSystemDefinition::UnionTypeDefinition(
-                        graphql_union_type_definition,
-                    ) => {
-                        insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
-                            schema,
-                            DataModelEntity {
-                                name: graphql_union_type_definition.name.map_location(Some),
-                                description: graphql_union_type_definition
-                                    .description
-                                    .map(|x| x.map_location(Some).map(Description)),
-                                selectables: Default::default(),
-                                associated_data: EntityAssociatedData {
-                                    network_protocol:
-                                        GraphQLNetworkProtocolEntityAssociatedData::default(),
-                                    target_platform: GraphQLSchemaObjectAssociatedData {
-                                        subtypes: graphql_union_type_definition
-                                            .union_member_types
-                                            .iter()
-                                            .map(|x| x.item)
-                                            .collect(),
-                                    }
-                                    .object_selected(),
-                                }
-                                .server_defined(),
-                                selection_info: ServerObjectSelectionInfo {
-                                    is_concrete: IsConcrete(false),
-                                }
+                    .with_some_location(definition.location),
+                );
+            }
+            GraphQLTypeSystemDefinition::InterfaceTypeDefinition(
+                graphql_interface_type_definition,
+            ) => {
+                supertype_to_subtype_map
+                    .entry(graphql_interface_type_definition.name.item)
+                    .or_default();
+                interfaces_to_process
+                    .push(graphql_interface_type_definition.with_location(definition.location));
+            }
+            GraphQLTypeSystemDefinition::InputObjectTypeDefinition(
+                graphql_input_object_type_definition,
+            ) => {
+                let selectables = process_fields(
+                    graphql_input_object_type_definition.name.item,
+                    graphql_input_object_type_definition
+                        .fields
+                        .into_iter()
+                        .map(|x| x.map(From::from))
+                        .collect(),
+                );
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: graphql_input_object_type_definition.name.map_location(Some),
+                        description: graphql_input_object_type_definition
+                            .description
+                            .map(|x| x.map_location(Some).map(Description)),
+                        selectables,
+                        associated_data: EntityAssociatedData {
+                            network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
+                            target_platform: GraphQLSchemaObjectAssociatedData { subtypes: vec![] }
                                 .object_selected(),
-                            }
-                            .with_some_location(document.location),
-                        );
-
-                        *supertype_to_subtype_map
-                            .entry(graphql_union_type_definition.name.item)
-                            .or_default() = graphql_union_type_definition
-                            .union_member_types
-                            .into_iter()
-                            .map(|x| x.item)
-                            .collect();
+                        }
+                        .server_defined(),
+                        selection_info: ServerObjectSelectionInfo {
+                            is_concrete: IsConcrete(true),
+                        }
+                        .object_selected(),
                     }
-                    GraphQLTypeSystemDefinition::SchemaDefinition(_graphql_schema_definition) => {
-                        // TODO schema
+                    .with_some_location(definition.location),
+                );
+            }
+            GraphQLTypeSystemDefinition::DirectiveDefinition(_graphql_directive_definition) => {}
+            GraphQLTypeSystemDefinition::EnumDefinition(graphql_enum_definition) => {
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: graphql_enum_definition.name.map_location(Some),
+                        description: graphql_enum_definition
+                            .description
+                            .map(|x| x.map_location(Some).map(Description)),
+                        selectables: Default::default(),
+                        associated_data: EntityAssociatedData {
+                            network_protocol: GraphQLNetworkProtocolEntityAssociatedData {
+                                fetchable: None,
+                                enum_values: graphql_enum_definition
+                                    .enum_value_definitions
+                                    .iter()
+                                    .map(|enum_value| enum_value.item.value.item)
+                                    .collect::<Vec<_>>()
+                                    .wrap_some(),
+                            },
+                            target_platform: get_js_enum_type(&graphql_enum_definition)
+                                .scalar_selected(),
+                        }
+                        .server_defined(),
+                        selection_info: ().scalar_selected(),
                     }
-                }
+                    .with_some_location(definition.location),
+                );
             }
-            GraphQLTypeSystemExtensionOrDefinition::Extension(graphql_type_system_extension) => {
-                match graphql_type_system_extension {
-                    GraphQLTypeSystemExtension::ObjectTypeExtension(
-                        _graphql_object_type_extension,
-                    ) => {
-                        // TODO extensions
+            GraphQLTypeSystemDefinition::UnionTypeDefinition(graphql_union_type_definition) => {
+                insert_entity_into_schema_or_emit_multiple_definitions_diagnostic(
+                    schema,
+                    DataModelEntity {
+                        name: graphql_union_type_definition.name.map_location(Some),
+                        description: graphql_union_type_definition
+                            .description
+                            .map(|x| x.map_location(Some).map(Description)),
+                        selectables: Default::default(),
+                        associated_data: EntityAssociatedData {
+                            network_protocol: GraphQLNetworkProtocolEntityAssociatedData::default(),
+                            target_platform: GraphQLSchemaObjectAssociatedData {
+                                subtypes: graphql_union_type_definition
+                                    .union_member_types
+                                    .iter()
+                                    .map(|x| x.item)
+                                    .collect(),
+                            }
+                            .object_selected(),
+                        }
+                        .server_defined(),
+                        selection_info: ServerObjectSelectionInfo {
+                            is_concrete: IsConcrete(false),
+                        }
+                        .object_selected(),
                     }
-                }
+                    .with_some_location(definition.location),
+                );
+
+                *supertype_to_subtype_map
+                    .entry(graphql_union_type_definition.name.item)
+                    .or_default() = graphql_union_type_definition
+                    .union_member_types
+                    .into_iter()
+                    .map(|x| x.item)
+                    .collect();
+            }
+            GraphQLTypeSystemDefinition::SchemaDefinition(_graphql_schema_definition) => {
+                // TODO schema
             }
         }
     }
@@ -757,4 +750,38 @@ fn get_js_union_name(members: &[EntityName]) -> JavascriptName {
     }
 }
 
+/// Enums are typed by types that are exported from the generated enums file, e.g.
+/// `Status` and `Status__input`. See [`JavascriptTargetPlatform::generate_enum_types`].
+fn get_js_enum_type(enum_definition: &GraphQLEnumDefinition) -> JavascriptScalarType {
+    let enum_name = enum_definition.name.item;
+
+    JavascriptScalarType {
+        output_type: enum_name.unchecked_conversion(),
+        input_type: format!("{enum_name}__input").intern().into(),
+        enum_values: enum_definition
+            .enum_value_definitions
+            .iter()
+            .map(|enum_value| JavascriptEnumValue {
+                value: enum_value.item.value.item,
+                deprecation_reason: enum_value
+                    .item
+                    .directives
+                    .iter()
+                    .find(|directive| directive.name.item == *DEPRECATED_DIRECTIVE_NAME)
+                    .map(|directive| {
+                        directive
+                            .arguments
+                            .iter()
+                            .find(|argument| argument.name.item == *REASON_ARGUMENT_NAME)
+                            .and_then(|argument| argument.value.item.as_string())
+                            .map_or(DEFAULT_DEPRECATION_REASON.to_string(), |reason| {
+                                reason.to_string()
+                            })
+                    }),
+            })
+            .collect::<Vec<_>>()
+            .wrap_some(),
+    }
+}
+
 type UnvalidatedTypeRefinementMap = BTreeMap<EntityName, Vec<EntityName>>;
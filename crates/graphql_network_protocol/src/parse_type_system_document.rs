@@ -1,7 +1,7 @@
 use std::collections::{BTreeMap, HashMap};
 
 use common_lang_types::{
-    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, SelectableName,
+    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, Location, SelectableName,
     WithLocationPostfix, WithNonFatalDiagnostics,
 };
 use graphql_lang_types::from_graphql_directives;
@@ -22,10 +22,9 @@ use isograph_schema::{
 use prelude::{ErrClone, Postfix};
 
 use crate::{
-    GraphQLAndJavascriptProfile, parse_graphql_schema,
-    process_type_system_definition::{
-        process_graphql_type_system_document, process_graphql_type_system_extension_document,
-    },
+    GraphQLAndJavascriptProfile, merged_graphql_schema,
+    process_type_system_definition::process_graphql_type_system_document,
+    validate_graphql_schema::validate_graphql_schema,
 };
 
 #[expect(clippy::type_complexity)]
@@ -43,8 +42,10 @@ pub(crate) fn parse_type_system_document(
     let mut directives = HashMap::new();
     let mut interfaces_to_process = vec![];
 
-    let (type_system_document, type_system_extension_documents) =
-        parse_graphql_schema(db).to_owned()?;
+    // The diagnostics for invalid extensions are reported by validate_graphql_schema
+    let type_system_document = merged_graphql_schema(db).to_owned()?.item;
+
+    non_fatal_diagnostics.extend(validate_graphql_schema(db).iter().cloned());
 
     process_graphql_type_system_document(
         db,
@@ -56,20 +57,6 @@ pub(crate) fn parse_type_system_document(
         &mut non_fatal_diagnostics,
     );
 
-    for type_system_extension_document in type_system_extension_documents.values() {
-        process_graphql_type_system_extension_document(
-            db,
-            type_system_extension_document
-                .to_owned(db)
-                .note_todo("Don't clone, use a MemoRef"),
-            &mut graphql_root_types,
-            &mut outcome,
-            &mut directives,
-            &mut interfaces_to_process,
-            &mut non_fatal_diagnostics,
-        );
-    }
-
     // We process interfaces later, because we need to know all of the subtypes that an interface
     // implements. In an ideal world, this info would not be part of the ServerEntity struct,
     // and we should make that refactor.
@@ -89,7 +76,16 @@ pub(crate) fn parse_type_system_document(
     // exposeField directives -> fields
     'exposeField: for (parent_object_entity_name, directives) in directives {
         let result = from_graphql_directives::<ServerEntityDirectives>(&directives)?;
-        for expose_field_directive in result.expose_field {
+        // The deserialized directives are in the same order as the directives
+        let expose_field_directive_locations = directives
+            .iter()
+            .filter(|directive| directive.name.item.lookup() == "exposeField")
+            .map(|directive| directive.name.location);
+        for (expose_field_directive, expose_field_directive_location) in result
+            .expose_field
+            .into_iter()
+            .zip(expose_field_directive_locations)
+        {
             // HACK: we're essentially splitting the field arg by . and keeping the same
             // implementation as before. But really, there isn't much a distinction
             // between field and path, and we should clean this up.
@@ -115,7 +111,7 @@ pub(crate) fn parse_type_system_document(
                 None => {
                     non_fatal_diagnostics.push(Diagnostic::new(
                         "Mutation field not found".to_string(),
-                        None,
+                        expose_field_directive_location.to::<Location>().wrap_some(),
                     ));
                     continue 'exposeField;
                 }
@@ -251,7 +247,7 @@ pub(crate) fn parse_type_system_document(
                 mutation_client_scalar_selectable
                     .interned_value(db)
                     .scalar_selected()
-                    .with_generated_location(),
+                    .with_location(expose_field_directive_location.to::<Location>()),
                 &mut non_fatal_diagnostics,
             );
 
@@ -6,8 +6,7 @@ use common_lang_types::{
 };
 use graphql_lang_types::{
     GraphQLConstantValue, GraphQLDirective, GraphQLInterfaceTypeDefinition,
-    GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument, GraphQLTypeSystemExtension,
-    GraphQLTypeSystemExtensionDocument, GraphQLTypeSystemExtensionOrDefinition,
+    GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument,
 };
 use intern::string_key::Intern;
 use isograph_lang_types::{
@@ -229,49 +228,3 @@ fn get_refetch_selectable(
         phantom_data: std::marker::PhantomData,
     }
 }
-
-pub fn process_graphql_type_system_extension_document(
-    db: &IsographDatabase<GraphQLAndJavascriptProfile>,
-    extension_document: GraphQLTypeSystemExtensionDocument,
-    graphql_root_types: &mut Option<GraphQLRootTypes>,
-    outcome: &mut DeprecatedParseTypeSystemOutcome<GraphQLAndJavascriptProfile>,
-    directives: &mut HashMap<EntityName, Vec<GraphQLDirective<GraphQLConstantValue>>>,
-    interfaces_to_process: &mut Vec<WithEmbeddedLocation<GraphQLInterfaceTypeDefinition>>,
-    non_fatal_diagnostics: &mut Vec<Diagnostic>,
-) {
-    let mut definitions = Vec::with_capacity(extension_document.0.len());
-    let mut extensions = Vec::with_capacity(extension_document.0.len());
-
-    for extension_or_definition in extension_document.0 {
-        let WithEmbeddedLocation { location, item } = extension_or_definition;
-        match item {
-            GraphQLTypeSystemExtensionOrDefinition::Definition(definition) => {
-                definitions.push(definition.with_location(location));
-            }
-            GraphQLTypeSystemExtensionOrDefinition::Extension(extension) => {
-                extensions.push(extension.with_location(location))
-            }
-        }
-    }
-
-    process_graphql_type_system_document(
-        db,
-        GraphQLTypeSystemDocument(definitions),
-        graphql_root_types,
-        outcome,
-        directives,
-        interfaces_to_process,
-        non_fatal_diagnostics,
-    );
-
-    for extension in extensions {
-        match extension.item {
-            GraphQLTypeSystemExtension::ObjectTypeExtension(object_type_extension) => {
-                directives
-                    .entry(object_type_extension.name.item.unchecked_conversion())
-                    .or_default()
-                    .extend(object_type_extension.directives);
-            }
-        }
-    }
-}
@@ -127,6 +127,20 @@ fn write_selections_for_query_text(
                     );
                     query_text.push_str(&format!("{indent}}},{new_line}"));
                 }
+                MergedServerSelection::Defer(defer) => {
+                    query_text.push_str(indent);
+                    query_text.push_str(&format!(
+                        "... @defer(label: \"{}\") {{{}",
+                        defer.label, new_line,
+                    ));
+                    write_selections_for_query_text(
+                        query_text,
+                        &defer.selection_map,
+                        indentation_level + 1,
+                        format,
+                    );
+                    query_text.push_str(&format!("{indent}}},{new_line}"));
+                }
             }
         }
     }
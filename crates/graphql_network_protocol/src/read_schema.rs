@@ -2,7 +2,8 @@ use std::collections::BTreeMap;
 
 use common_lang_types::{DiagnosticResult, RelativePathToSourceFile};
 use graphql_lang_types::{GraphQLTypeSystemDocument, GraphQLTypeSystemExtensionDocument};
-use graphql_schema_parser::{parse_schema, parse_schema_extensions};
+use graphql_schema_parser::{parse_introspection_schema, parse_schema, parse_schema_extensions};
+use intern::Lookup;
 use isograph_schema::{CompilationProfile, IsographDatabase, SchemaSource};
 use pico::{MemoRef, SourceId};
 use pico_macros::memo;
@@ -17,21 +18,21 @@ pub fn parse_graphql_schema<TCompilationProfile: CompilationProfile>(
     GraphQLTypeSystemDocument,
     BTreeMap<RelativePathToSourceFile, MemoRef<GraphQLTypeSystemExtensionDocument>>,
 )> {
-    // TODO use db.get_standard_sources()
-    let SchemaSource {
-        content,
-        text_source,
-        ..
-    } = db.get_schema_source();
+    let standard_sources = db.get_standard_sources();
+    let standard_sources = standard_sources.tracked();
 
-    let schema = parse_schema(content, *text_source)?;
+    // The definitions in the schema files are concatenated into a single document
+    let mut schema = GraphQLTypeSystemDocument(vec![]);
+    for schema_source_id in standard_sources.schema_sources.values() {
+        let schema_document = parse_schema_file(db, *schema_source_id)
+            .to_owned()
+            .note_todo("Do not clone. Use a MemoRef.")?;
+        schema.0.extend(schema_document.0);
+    }
 
     let mut schema_extensions = BTreeMap::new();
-    for (relative_path, schema_extension_source_id) in db
-        .get_standard_sources()
-        .tracked()
-        .schema_extension_sources
-        .iter()
+    for (relative_path, schema_extension_source_id) in
+        standard_sources.schema_extension_sources.iter()
     {
         let extensions_document = parse_schema_extensions_file(db, *schema_extension_source_id)
             .to_owned()
@@ -42,17 +43,45 @@ pub fn parse_graphql_schema<TCompilationProfile: CompilationProfile>(
     (schema, schema_extensions).wrap_ok()
 }
 
+#[memo(persist)]
+pub fn parse_schema_file<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    schema_source_id: SourceId<SchemaSource>,
+) -> DiagnosticResult<GraphQLTypeSystemDocument> {
+    let SchemaSource {
+        relative_path,
+        content,
+        text_source,
+    } = db.get(schema_source_id);
+    // The schema is either SDL or the JSON result of the introspection query
+    if relative_path.lookup().ends_with(".json") {
+        parse_introspection_schema(content, *text_source)
+    } else {
+        parse_schema(content, *text_source)
+    }
+}
+
 #[memo]
 pub fn parse_schema_extensions_file<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     schema_extension_source_id: SourceId<SchemaSource>,
 ) -> DiagnosticResult<MemoRef<GraphQLTypeSystemExtensionDocument>> {
+    let schema_extensions = parse_schema_extensions_source(db, schema_extension_source_id)
+        .to_owned()
+        .note_todo("Do not clone. Use a MemoRef.")?;
+
+    schema_extensions.interned_value(db).wrap_ok()
+}
+
+#[memo(persist)]
+fn parse_schema_extensions_source<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    schema_extension_source_id: SourceId<SchemaSource>,
+) -> DiagnosticResult<GraphQLTypeSystemExtensionDocument> {
     let SchemaSource {
         content,
         text_source,
         ..
     } = db.get(schema_extension_source_id);
-    let schema_extensions = parse_schema_extensions(content, *text_source)?;
-
-    schema_extensions.interned_value(db).wrap_ok()
+    parse_schema_extensions(content, *text_source)
 }
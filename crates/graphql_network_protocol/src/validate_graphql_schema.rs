@@ -0,0 +1,831 @@
+use std::collections::{HashMap, HashSet};
+
+use common_lang_types::{
+    Diagnostic, DirectiveArgumentName, DirectiveName, EmbeddedLocation, EntityName, Location,
+    WithEmbeddedLocation,
+};
+use graphql_lang_types::{
+    DirectiveLocation, GraphQLConstantValue, GraphQLDirective, GraphQLDirectiveDefinition,
+    GraphQLEnumDefinition, GraphQLFieldDefinition, GraphQLInputObjectTypeDefinition,
+    GraphQLInputValueDefinition, GraphQLInterfaceTypeDefinition, GraphQLNonNullTypeAnnotation,
+    GraphQLObjectTypeDefinition, GraphQLScalarTypeDefinition, GraphQLTypeAnnotation,
+    GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument, GraphQLTypeSystemExtensionDocument,
+    GraphQLUnionTypeDefinition,
+};
+use intern::string_key::Intern;
+use isograph_schema::{CompilationProfile, IsographDatabase};
+use lazy_static::lazy_static;
+use pico_macros::memo;
+use prelude::Postfix;
+
+use crate::{merge_type_system_extensions, merged_graphql_schema};
+
+lazy_static! {
+    pub(crate) static ref DEPRECATED_DIRECTIVE_NAME: DirectiveName = "deprecated".intern().into();
+    pub(crate) static ref REASON_ARGUMENT_NAME: DirectiveArgumentName = "reason".intern().into();
+    static ref SPECIFIED_BY_DIRECTIVE_NAME: DirectiveName = "specifiedBy".intern().into();
+    static ref ONE_OF_DIRECTIVE_NAME: DirectiveName = "oneOf".intern().into();
+}
+
+/// The reason of a `@deprecated` directive without a `reason` argument, as defined
+/// by the GraphQL spec.
+pub(crate) const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// Validates the GraphQL type system rules that are not otherwise enforced when
+/// the schema is processed, e.g. that objects correctly implement their interfaces.
+/// This includes the diagnostics for invalid extensions, e.g. extensions of undefined
+/// types.
+///
+/// If the schema fails to parse, the parse errors are reported elsewhere, so this
+/// returns no diagnostics.
+#[memo]
+pub fn validate_graphql_schema<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> Vec<Diagnostic> {
+    let Ok(merged_type_system_document) = merged_graphql_schema(db) else {
+        return vec![];
+    };
+
+    let mut diagnostics = merged_type_system_document.non_fatal_diagnostics.clone();
+    diagnostics.extend(validate_merged_type_system_document(
+        &merged_type_system_document.item,
+    ));
+    diagnostics
+}
+
+/// Validates a schema and its extensions against the type system rules of the
+/// GraphQL spec:
+/// - types, fields, arguments, enum values and directives are defined once,
+/// - objects and interfaces correctly implement their interfaces,
+/// - unions only include object types,
+/// - input objects do not reference themselves via non-null fields, and
+/// - directives are only used at the locations where they are allowed.
+///
+/// Extensions are merged into the definitions that they extend before validation.
+pub fn validate_graphql_type_system_documents<'a>(
+    type_system_document: &'a GraphQLTypeSystemDocument,
+    type_system_extension_documents: impl IntoIterator<Item = &'a GraphQLTypeSystemExtensionDocument>,
+) -> Vec<Diagnostic> {
+    let merged_type_system_document = merge_type_system_extensions(
+        type_system_document.clone(),
+        type_system_extension_documents.into_iter().cloned(),
+    );
+
+    let mut diagnostics = merged_type_system_document.non_fatal_diagnostics;
+    diagnostics.extend(validate_merged_type_system_document(
+        &merged_type_system_document.item,
+    ));
+    diagnostics
+}
+
+fn validate_merged_type_system_document(
+    type_system_document: &GraphQLTypeSystemDocument,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut type_system = TypeSystem::default();
+
+    for definition in type_system_document.iter() {
+        type_system.insert_definition(&definition.item, &mut diagnostics);
+    }
+
+    type_system.validate_unique_names(&mut diagnostics);
+    type_system.validate_interface_implementations(&mut diagnostics);
+    type_system.validate_union_members(&mut diagnostics);
+    type_system.validate_input_object_cycles(&mut diagnostics);
+    type_system.validate_directive_usages(&mut diagnostics);
+
+    diagnostics
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TypeDefinition<'a> {
+    Object(&'a GraphQLObjectTypeDefinition),
+    Interface(&'a GraphQLInterfaceTypeDefinition),
+    Scalar(&'a GraphQLScalarTypeDefinition),
+    Union(&'a GraphQLUnionTypeDefinition),
+    Enum(&'a GraphQLEnumDefinition),
+    InputObject(&'a GraphQLInputObjectTypeDefinition),
+}
+
+impl<'a> TypeDefinition<'a> {
+    fn name(&self) -> WithEmbeddedLocation<EntityName> {
+        match self {
+            TypeDefinition::Object(object) => object.name,
+            TypeDefinition::Interface(interface) => interface.name,
+            TypeDefinition::Scalar(scalar) => scalar.name,
+            TypeDefinition::Union(union) => union.name,
+            TypeDefinition::Enum(enum_definition) => enum_definition.name,
+            TypeDefinition::InputObject(input_object) => input_object.name,
+        }
+    }
+
+    /// The fields of an object or interface.
+    fn fields(self) -> Vec<&'a WithEmbeddedLocation<GraphQLFieldDefinition>> {
+        let fields = match self {
+            TypeDefinition::Object(object) => object.fields.as_slice(),
+            TypeDefinition::Interface(interface) => interface.fields.as_slice(),
+            _ => &[],
+        };
+        fields.iter().collect()
+    }
+
+    /// The interfaces that an object or interface implements.
+    fn interfaces(self) -> Vec<&'a WithEmbeddedLocation<EntityName>> {
+        let interfaces = match self {
+            TypeDefinition::Object(object) => object.interfaces.as_slice(),
+            TypeDefinition::Interface(interface) => interface.interfaces.as_slice(),
+            _ => &[],
+        };
+        interfaces.iter().collect()
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            TypeDefinition::Object(_) => "an object",
+            TypeDefinition::Interface(_) => "an interface",
+            TypeDefinition::Scalar(_) => "a scalar",
+            TypeDefinition::Union(_) => "a union",
+            TypeDefinition::Enum(_) => "an enum",
+            TypeDefinition::InputObject(_) => "an input object",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TypeSystem<'a> {
+    /// In the order in which they are defined, so that diagnostics are deterministic.
+    definitions: Vec<TypeDefinition<'a>>,
+    types: HashMap<EntityName, TypeDefinition<'a>>,
+    directive_definitions: Vec<&'a GraphQLDirectiveDefinition>,
+    directive_definitions_by_name: HashMap<DirectiveName, &'a GraphQLDirectiveDefinition>,
+    directive_usages: Vec<(
+        &'a [GraphQLDirective<GraphQLConstantValue>],
+        DirectiveLocation,
+    )>,
+}
+
+impl<'a> TypeSystem<'a> {
+    fn insert_definition(
+        &mut self,
+        definition: &'a GraphQLTypeSystemDefinition,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let type_definition = match definition {
+            GraphQLTypeSystemDefinition::ObjectTypeDefinition(object) => {
+                TypeDefinition::Object(object)
+            }
+            GraphQLTypeSystemDefinition::InterfaceTypeDefinition(interface) => {
+                TypeDefinition::Interface(interface)
+            }
+            GraphQLTypeSystemDefinition::ScalarTypeDefinition(scalar) => {
+                TypeDefinition::Scalar(scalar)
+            }
+            GraphQLTypeSystemDefinition::UnionTypeDefinition(union) => TypeDefinition::Union(union),
+            GraphQLTypeSystemDefinition::EnumDefinition(enum_definition) => {
+                TypeDefinition::Enum(enum_definition)
+            }
+            GraphQLTypeSystemDefinition::InputObjectTypeDefinition(input_object) => {
+                TypeDefinition::InputObject(input_object)
+            }
+            GraphQLTypeSystemDefinition::DirectiveDefinition(directive_definition) => {
+                if self
+                    .directive_definitions_by_name
+                    .insert(directive_definition.name.item, directive_definition)
+                    .is_some()
+                {
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Multiple definitions of directive `@{}` were found.",
+                            directive_definition.name.item
+                        ),
+                        to_location(directive_definition.name.location),
+                    ));
+                }
+                self.directive_definitions.push(directive_definition);
+                return;
+            }
+            GraphQLTypeSystemDefinition::SchemaDefinition(schema_definition) => {
+                self.directive_usages
+                    .push((&schema_definition.directives, DirectiveLocation::Schema));
+                return;
+            }
+        };
+
+        let name = type_definition.name();
+        if self.types.insert(name.item, type_definition).is_some() {
+            diagnostics.push(Diagnostic::new(
+                format!("Multiple definitions of `{}` were found.", name.item),
+                to_location(name.location),
+            ));
+            return;
+        }
+        self.definitions.push(type_definition);
+    }
+
+    fn validate_unique_names(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for type_definition in self.definitions.iter().copied() {
+            let type_name = type_definition.name().item;
+            match type_definition {
+                TypeDefinition::Object(_) | TypeDefinition::Interface(_) => {
+                    let fields = type_definition.fields();
+                    validate_unique(
+                        fields.iter().map(|field| field.item.name),
+                        |field_name| {
+                            format!(
+                                "Multiple definitions of `{type_name}.{field_name}` were found."
+                            )
+                        },
+                        diagnostics,
+                    );
+                    for field in fields {
+                        validate_unique_arguments(
+                            &field.item.arguments,
+                            &format!("{type_name}.{}", field.item.name.item),
+                            diagnostics,
+                        );
+                    }
+                    validate_unique(
+                        type_definition.interfaces().into_iter().copied(),
+                        |interface_name| {
+                            format!("`{type_name}` implements `{interface_name}` more than once.")
+                        },
+                        diagnostics,
+                    );
+                }
+                TypeDefinition::InputObject(input_object) => {
+                    validate_unique(
+                        input_object.fields.iter().map(|field| field.item.name),
+                        |field_name| {
+                            format!(
+                                "Multiple definitions of `{type_name}.{field_name}` were found."
+                            )
+                        },
+                        diagnostics,
+                    );
+                }
+                TypeDefinition::Enum(enum_definition) => {
+                    validate_unique(
+                        enum_definition
+                            .enum_value_definitions
+                            .iter()
+                            .map(|enum_value| enum_value.item.value),
+                        |enum_value| {
+                            format!(
+                                "Multiple definitions of `{type_name}.{enum_value}` were found."
+                            )
+                        },
+                        diagnostics,
+                    );
+                }
+                TypeDefinition::Union(union) => {
+                    validate_unique(
+                        union.union_member_types.iter().copied(),
+                        |member_name| {
+                            format!("Union `{type_name}` includes `{member_name}` more than once.")
+                        },
+                        diagnostics,
+                    );
+                }
+                TypeDefinition::Scalar(_) => {}
+            }
+        }
+
+        for directive_definition in &self.directive_definitions {
+            validate_unique_arguments(
+                &directive_definition.arguments,
+                &format!("@{}", directive_definition.name.item),
+                diagnostics,
+            );
+        }
+    }
+
+    fn validate_interface_implementations(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for type_definition in self.definitions.iter().copied() {
+            if !matches!(
+                type_definition,
+                TypeDefinition::Object(_) | TypeDefinition::Interface(_)
+            ) {
+                continue;
+            }
+            let type_name = type_definition.name().item;
+            let interfaces = type_definition.interfaces();
+            for interface_name in interfaces.iter().copied() {
+                if interface_name.item == type_name {
+                    diagnostics.push(Diagnostic::new(
+                        format!("`{type_name}` cannot implement itself."),
+                        to_location(interface_name.location),
+                    ));
+                    continue;
+                }
+                match self.types.get(&interface_name.item).copied() {
+                    Some(interface @ TypeDefinition::Interface(_)) => {
+                        self.validate_interface_implementation(
+                            type_definition,
+                            &interfaces,
+                            interface,
+                            interface_name.location,
+                            diagnostics,
+                        );
+                    }
+                    Some(other) => diagnostics.push(Diagnostic::new(
+                        format!(
+                            "`{type_name}` can only implement interfaces, but `{}` is {}.",
+                            interface_name.item,
+                            other.description()
+                        ),
+                        to_location(interface_name.location),
+                    )),
+                    None => diagnostics.push(Diagnostic::new(
+                        format!(
+                            "`{type_name}` implements `{}`, which is not defined.",
+                            interface_name.item
+                        ),
+                        to_location(interface_name.location),
+                    )),
+                }
+            }
+        }
+    }
+
+    fn validate_interface_implementation(
+        &self,
+        type_definition: TypeDefinition<'a>,
+        interfaces: &[&'a WithEmbeddedLocation<EntityName>],
+        interface: TypeDefinition<'a>,
+        interface_location: EmbeddedLocation,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let type_name = type_definition.name().item;
+        let interface_name = interface.name().item;
+        let fields = type_definition.fields();
+
+        for interface_field in interface.fields() {
+            let field_name = interface_field.item.name.item;
+            let Some(field) = fields
+                .iter()
+                .find(|field| field.item.name.item == field_name)
+            else {
+                diagnostics.push(Diagnostic::new(
+                    format!(
+                        "`{type_name}` must define `{field_name}`, because it implements \
+                        `{interface_name}`."
+                    ),
+                    to_location(interface_location),
+                ));
+                continue;
+            };
+
+            if !self.is_valid_implementation_type(
+                &field.item.type_.item,
+                &interface_field.item.type_.item,
+            ) {
+                diagnostics.push(Diagnostic::new(
+                    format!(
+                        "`{type_name}.{field_name}` has type `{}`, which is not compatible \
+                        with the type `{}` of `{interface_name}.{field_name}`.",
+                        field.item.type_.item, interface_field.item.type_.item
+                    ),
+                    to_location(field.item.type_.location),
+                ));
+            }
+
+            for interface_argument in &interface_field.item.arguments {
+                let argument_name = interface_argument.item.name.item;
+                match field
+                    .item
+                    .arguments
+                    .iter()
+                    .find(|argument| argument.item.name.item == argument_name)
+                {
+                    Some(argument) => {
+                        if !is_equal_type(
+                            &argument.item.type_.item,
+                            &interface_argument.item.type_.item,
+                        ) {
+                            diagnostics.push(Diagnostic::new(
+                                format!(
+                                    "Argument `{argument_name}` of `{type_name}.{field_name}` \
+                                    has type `{}`, but it has type `{}` on \
+                                    `{interface_name}.{field_name}`.",
+                                    argument.item.type_.item, interface_argument.item.type_.item
+                                ),
+                                to_location(argument.item.type_.location),
+                            ));
+                        }
+                    }
+                    None => diagnostics.push(Diagnostic::new(
+                        format!(
+                            "`{type_name}.{field_name}` must accept argument `{argument_name}`, \
+                            because `{interface_name}.{field_name}` does."
+                        ),
+                        to_location(field.item.name.location),
+                    )),
+                }
+            }
+
+            for argument in &field.item.arguments {
+                let argument_name = argument.item.name.item;
+                let is_interface_argument = interface_field
+                    .item
+                    .arguments
+                    .iter()
+                    .any(|interface_argument| interface_argument.item.name.item == argument_name);
+                if !is_interface_argument && is_required(&argument.item) {
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Argument `{argument_name}` of `{type_name}.{field_name}` cannot be \
+                            required, because `{interface_name}.{field_name}` does not accept it."
+                        ),
+                        to_location(argument.item.name.location),
+                    ));
+                }
+            }
+        }
+
+        for transitive_interface in interface.interfaces() {
+            if transitive_interface.item != type_name
+                && !interfaces
+                    .iter()
+                    .any(|interface| interface.item == transitive_interface.item)
+            {
+                diagnostics.push(Diagnostic::new(
+                    format!(
+                        "`{type_name}` must implement `{}`, because it implements \
+                        `{interface_name}`, which implements `{}`.",
+                        transitive_interface.item, transitive_interface.item
+                    ),
+                    to_location(interface_location),
+                ));
+            }
+        }
+    }
+
+    /// Whether a field with type `field_type` can implement an interface field with
+    /// type `interface_field_type`, i.e. whether `field_type` is a subtype of it.
+    fn is_valid_implementation_type(
+        &self,
+        field_type: &GraphQLTypeAnnotation,
+        interface_field_type: &GraphQLTypeAnnotation,
+    ) -> bool {
+        let (field_type_is_non_null, field_type) = nullable_type(field_type);
+        let (interface_field_type_is_non_null, interface_field_type) =
+            nullable_type(interface_field_type);
+        if interface_field_type_is_non_null && !field_type_is_non_null {
+            return false;
+        }
+        match (field_type, interface_field_type) {
+            (NullableType::List(item_type), NullableType::List(interface_item_type)) => {
+                self.is_valid_implementation_type(item_type, interface_item_type)
+            }
+            (NullableType::Named(type_name), NullableType::Named(interface_type_name)) => {
+                type_name == interface_type_name
+                    || self.is_possible_type(interface_type_name, type_name)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `type_name` is a member of the union or implements the interface
+    /// named `abstract_type_name`.
+    fn is_possible_type(&self, abstract_type_name: EntityName, type_name: EntityName) -> bool {
+        match self.types.get(&abstract_type_name) {
+            Some(TypeDefinition::Interface(_)) => {
+                self.types.get(&type_name).is_some_and(|type_definition| {
+                    type_definition
+                        .interfaces()
+                        .iter()
+                        .any(|interface| interface.item == abstract_type_name)
+                })
+            }
+            Some(TypeDefinition::Union(union)) => union
+                .union_member_types
+                .iter()
+                .any(|member| member.item == type_name),
+            _ => false,
+        }
+    }
+
+    fn validate_union_members(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for type_definition in &self.definitions {
+            let TypeDefinition::Union(union) = type_definition else {
+                continue;
+            };
+            for member in &union.union_member_types {
+                match self.types.get(&member.item) {
+                    Some(TypeDefinition::Object(_)) => {}
+                    Some(other) => diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Union `{}` can only include object types, but `{}` is {}.",
+                            union.name.item,
+                            member.item,
+                            other.description()
+                        ),
+                        to_location(member.location),
+                    )),
+                    None => diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Union `{}` includes `{}`, which is not defined.",
+                            union.name.item, member.item
+                        ),
+                        to_location(member.location),
+                    )),
+                }
+            }
+        }
+    }
+
+    /// An input object cannot reference itself via a chain of non-null, non-list
+    /// fields, because it would then be impossible to provide a value for it.
+    fn validate_input_object_cycles(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut visited = HashSet::new();
+        for type_definition in &self.definitions {
+            if let TypeDefinition::InputObject(input_object) = type_definition {
+                let mut path = vec![];
+                self.find_input_object_cycles(input_object, &mut visited, &mut path, diagnostics);
+            }
+        }
+    }
+
+    fn find_input_object_cycles(
+        &self,
+        input_object: &'a GraphQLInputObjectTypeDefinition,
+        visited: &mut HashSet<EntityName>,
+        path: &mut Vec<(
+            EntityName,
+            &'a WithEmbeddedLocation<GraphQLInputValueDefinition>,
+        )>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if !visited.insert(input_object.name.item) {
+            return;
+        }
+
+        for field in &input_object.fields {
+            let GraphQLTypeAnnotation::NonNull(non_null) = &field.item.type_.item else {
+                continue;
+            };
+            let GraphQLNonNullTypeAnnotation::Named(field_type_name) = non_null.as_ref() else {
+                continue;
+            };
+            let Some(TypeDefinition::InputObject(field_type)) = self.types.get(&field_type_name.0)
+            else {
+                continue;
+            };
+
+            path.push((input_object.name.item, field));
+            match path
+                .iter()
+                .position(|(type_name, _)| *type_name == field_type_name.0)
+            {
+                Some(cycle_start) => {
+                    let cycle = path[cycle_start..]
+                        .iter()
+                        .map(|(type_name, field)| format!("{type_name}.{}", field.item.name.item))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "`{}` cannot reference itself via non-null fields. \
+                            Make one of the following fields nullable: {cycle}.",
+                            field_type_name.0
+                        ),
+                        to_location(path[cycle_start].1.item.name.location),
+                    ));
+                }
+                None => self.find_input_object_cycles(field_type, visited, path, diagnostics),
+            }
+            path.pop();
+        }
+    }
+
+    fn validate_directive_usages(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let built_in_directive_definitions = built_in_directive_definitions();
+        let directive_definition = |directive_name: DirectiveName| {
+            self.directive_definitions_by_name
+                .get(&directive_name)
+                .map(|directive_definition| {
+                    (
+                        directive_definition
+                            .locations
+                            .iter()
+                            .map(|location| location.item)
+                            .collect::<Vec<_>>(),
+                        directive_definition.repeatable.is_some(),
+                    )
+                })
+                .or_else(|| {
+                    built_in_directive_definitions
+                        .get(&directive_name)
+                        .map(|locations| (locations.to_vec(), false))
+                })
+        };
+
+        for (directives, directive_location) in self.directive_usages() {
+            let mut used_directive_names = HashSet::new();
+            for directive in directives {
+                let directive_name = directive.name.item;
+                // Isograph uses directives (e.g. @exposeField) that schemas need not define.
+                let Some((locations, repeatable)) = directive_definition(directive_name) else {
+                    continue;
+                };
+                if !locations.contains(&directive_location) {
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Directive `@{directive_name}` cannot be used on {directive_location}. \
+                            It can only be used on {}.",
+                            locations
+                                .iter()
+                                .map(|location| location.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        to_location(directive.name.location),
+                    ));
+                }
+                if !repeatable && !used_directive_names.insert(directive_name) {
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "Directive `@{directive_name}` is not repeatable, \
+                            but was used more than once."
+                        ),
+                        to_location(directive.name.location),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Every list of directives in the type system, along with where they are used.
+    fn directive_usages(
+        &self,
+    ) -> Vec<(
+        &'a [GraphQLDirective<GraphQLConstantValue>],
+        DirectiveLocation,
+    )> {
+        let mut usages = self.directive_usages.clone();
+
+        let push_field_usages =
+            |usages: &mut Vec<_>, fields: &'a [WithEmbeddedLocation<GraphQLFieldDefinition>]| {
+                for field in fields {
+                    usages.push((
+                        field.item.directives.as_slice(),
+                        DirectiveLocation::FieldDefinition,
+                    ));
+                    for argument in &field.item.arguments {
+                        usages.push((
+                            argument.item.directives.as_slice(),
+                            DirectiveLocation::ArgumentDefinition,
+                        ));
+                    }
+                }
+            };
+
+        for type_definition in &self.definitions {
+            match type_definition {
+                TypeDefinition::Object(object) => {
+                    usages.push((object.directives.as_slice(), DirectiveLocation::Object));
+                    push_field_usages(&mut usages, &object.fields);
+                }
+                TypeDefinition::Interface(interface) => {
+                    usages.push((
+                        interface.directives.as_slice(),
+                        DirectiveLocation::Interface,
+                    ));
+                    push_field_usages(&mut usages, &interface.fields);
+                }
+                TypeDefinition::Scalar(scalar) => {
+                    usages.push((scalar.directives.as_slice(), DirectiveLocation::Scalar));
+                }
+                TypeDefinition::Union(union) => {
+                    usages.push((union.directives.as_slice(), DirectiveLocation::Union));
+                }
+                TypeDefinition::Enum(enum_definition) => {
+                    usages.push((
+                        enum_definition.directives.as_slice(),
+                        DirectiveLocation::Enum,
+                    ));
+                    for enum_value in &enum_definition.enum_value_definitions {
+                        usages.push((
+                            enum_value.item.directives.as_slice(),
+                            DirectiveLocation::EnumValue,
+                        ));
+                    }
+                }
+                TypeDefinition::InputObject(input_object) => {
+                    usages.push((
+                        input_object.directives.as_slice(),
+                        DirectiveLocation::InputObject,
+                    ));
+                    for field in &input_object.fields {
+                        usages.push((
+                            field.item.directives.as_slice(),
+                            DirectiveLocation::InputFieldDefinition,
+                        ));
+                    }
+                }
+            }
+        }
+
+        for directive_definition in &self.directive_definitions {
+            for argument in &directive_definition.arguments {
+                usages.push((
+                    argument.item.directives.as_slice(),
+                    DirectiveLocation::ArgumentDefinition,
+                ));
+            }
+        }
+
+        usages
+    }
+}
+
+/// The directives defined by the GraphQL spec, which schemas need not define.
+fn built_in_directive_definitions() -> HashMap<DirectiveName, &'static [DirectiveLocation]> {
+    HashMap::from([
+        (
+            *DEPRECATED_DIRECTIVE_NAME,
+            [
+                DirectiveLocation::FieldDefinition,
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
+                DirectiveLocation::EnumValue,
+            ]
+            .as_slice(),
+        ),
+        (
+            *SPECIFIED_BY_DIRECTIVE_NAME,
+            [DirectiveLocation::Scalar].as_slice(),
+        ),
+        (
+            *ONE_OF_DIRECTIVE_NAME,
+            [DirectiveLocation::InputObject].as_slice(),
+        ),
+    ])
+}
+
+fn validate_unique<T: Copy + Eq + std::hash::Hash>(
+    names: impl IntoIterator<Item = WithEmbeddedLocation<T>>,
+    message: impl Fn(T) -> String,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name.item) {
+            diagnostics.push(Diagnostic::new(
+                message(name.item),
+                to_location(name.location),
+            ));
+        }
+    }
+}
+
+fn validate_unique_arguments(
+    arguments: &[WithEmbeddedLocation<GraphQLInputValueDefinition>],
+    parent: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    validate_unique(
+        arguments.iter().map(|argument| argument.item.name),
+        |argument_name| {
+            format!("Multiple definitions of argument `{argument_name}` of `{parent}` were found.")
+        },
+        diagnostics,
+    );
+}
+
+fn is_required(argument: &GraphQLInputValueDefinition) -> bool {
+    !argument.type_.item.is_nullable() && argument.default_value.is_none()
+}
+
+enum NullableType<'a> {
+    Named(EntityName),
+    List(&'a GraphQLTypeAnnotation),
+}
+
+/// Whether the type is non-null, and the type without the non-null wrapper.
+fn nullable_type(type_: &GraphQLTypeAnnotation) -> (bool, NullableType<'_>) {
+    match type_ {
+        GraphQLTypeAnnotation::Named(named) => (false, NullableType::Named(named.0)),
+        GraphQLTypeAnnotation::List(list) => (false, NullableType::List(&list.0.item)),
+        GraphQLTypeAnnotation::NonNull(non_null) => match non_null.as_ref() {
+            GraphQLNonNullTypeAnnotation::Named(named) => (true, NullableType::Named(named.0)),
+            GraphQLNonNullTypeAnnotation::List(list) => (true, NullableType::List(&list.0.item)),
+        },
+    }
+}
+
+/// Whether two types are the same, ignoring their locations.
+fn is_equal_type(left: &GraphQLTypeAnnotation, right: &GraphQLTypeAnnotation) -> bool {
+    let (left_is_non_null, left) = nullable_type(left);
+    let (right_is_non_null, right) = nullable_type(right);
+    left_is_non_null == right_is_non_null
+        && match (left, right) {
+            (NullableType::Named(left), NullableType::Named(right)) => left == right,
+            (NullableType::List(left), NullableType::List(right)) => is_equal_type(left, right),
+            _ => false,
+        }
+}
+
+fn to_location(location: EmbeddedLocation) -> Option<Location> {
+    location.to::<Location>().wrap_some()
+}
@@ -0,0 +1,681 @@
+use common_lang_types::{
+    DescriptionValue, Diagnostic, DiagnosticResult, DirectiveArgumentName, DirectiveName,
+    EmbeddedLocation, EntityName, EnumLiteralValue, InputValueName, Location, SelectableName, Span,
+    StringLiteralValue, TextSource, WithEmbeddedLocation, WithLocationPostfix,
+};
+use graphql_lang_types::{
+    DirectiveLocation, GraphQLConstantValue, GraphQLDirective, GraphQLDirectiveDefinition,
+    GraphQLEnumDefinition, GraphQLEnumValueDefinition, GraphQLFieldDefinition,
+    GraphQLInputObjectTypeDefinition, GraphQLInputValueDefinition, GraphQLInterfaceTypeDefinition,
+    GraphQLListTypeAnnotation, GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation,
+    GraphQLObjectTypeDefinition, GraphQLScalarTypeDefinition, GraphQLSchemaDefinition,
+    GraphQLTypeAnnotation, GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument,
+    GraphQLUnionTypeDefinition, NameValuePair,
+};
+use intern::string_key::Intern;
+use prelude::Postfix;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+use crate::{parse_schema::parse_constant_value, peekable_lexer::PeekableLexer};
+
+/// Types that every GraphQL schema has, and which are not printed in SDL
+const BUILT_IN_SCALARS: [&str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+
+/// Directives that every GraphQL schema has, and which are not printed in SDL
+const BUILT_IN_DIRECTIVES: [&str; 5] = ["skip", "include", "deprecated", "specifiedBy", "oneOf"];
+
+/// Converts the result of the standard introspection query (either `{ "data": {
+/// "__schema": ... } }` or `{ "__schema": ... }`) into the document that would be
+/// parsed from the equivalent SDL.
+///
+/// Introspection does not expose the directives used in the schema, except for
+/// deprecation, `@specifiedBy` and `@oneOf`, so these are the only directives that
+/// the document contains.
+pub fn parse_introspection_schema(
+    source: &str,
+    text_source: TextSource,
+) -> DiagnosticResult<GraphQLTypeSystemDocument> {
+    let parser = IntrospectionParser {
+        source,
+        text_source,
+    };
+
+    let (result, result_location) = parser.parse::<IntrospectionResult>(parser.source_value()?)?;
+    let schema = match (result.data, result.schema) {
+        (Some(data), _) => data.schema,
+        (None, Some(schema)) => schema,
+        (None, None) => {
+            return Diagnostic::new(
+                "Expected the introspection result to contain `data.__schema` or `__schema`."
+                    .to_string(),
+                result_location.to::<Location>().wrap_some(),
+            )
+            .wrap_err();
+        }
+    };
+
+    parser.schema_document(schema)
+}
+
+#[derive(Deserialize)]
+struct IntrospectionResult<'a> {
+    #[serde(borrow)]
+    data: Option<IntrospectionData<'a>>,
+    #[serde(rename = "__schema", borrow)]
+    schema: Option<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData<'a> {
+    #[serde(rename = "__schema", borrow)]
+    schema: &'a RawValue,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema<'a> {
+    description: Option<String>,
+    query_type: Option<IntrospectionRootType>,
+    mutation_type: Option<IntrospectionRootType>,
+    subscription_type: Option<IntrospectionRootType>,
+    #[serde(borrow)]
+    types: Vec<&'a RawValue>,
+    #[serde(default, borrow)]
+    directives: Vec<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionRootType {
+    name: String,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum IntrospectionTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+impl IntrospectionTypeKind {
+    fn name(self) -> &'static str {
+        match self {
+            IntrospectionTypeKind::Scalar => "SCALAR",
+            IntrospectionTypeKind::Object => "OBJECT",
+            IntrospectionTypeKind::Interface => "INTERFACE",
+            IntrospectionTypeKind::Union => "UNION",
+            IntrospectionTypeKind::Enum => "ENUM",
+            IntrospectionTypeKind::InputObject => "INPUT_OBJECT",
+            IntrospectionTypeKind::List => "LIST",
+            IntrospectionTypeKind::NonNull => "NON_NULL",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType<'a> {
+    kind: IntrospectionTypeKind,
+    name: String,
+    description: Option<String>,
+    #[serde(borrow)]
+    fields: Option<Vec<&'a RawValue>>,
+    #[serde(borrow)]
+    input_fields: Option<Vec<&'a RawValue>>,
+    interfaces: Option<Vec<IntrospectionTypeRef>>,
+    #[serde(borrow)]
+    enum_values: Option<Vec<&'a RawValue>>,
+    possible_types: Option<Vec<IntrospectionTypeRef>>,
+    #[serde(rename = "specifiedByURL")]
+    specified_by_url: Option<String>,
+    is_one_of: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeRef {
+    kind: IntrospectionTypeKind,
+    name: Option<String>,
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField<'a> {
+    name: String,
+    description: Option<String>,
+    #[serde(default, borrow)]
+    args: Vec<&'a RawValue>,
+    #[serde(rename = "type")]
+    type_: IntrospectionTypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue<'a> {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    type_: IntrospectionTypeRef,
+    /// The default value, printed as a GraphQL literal (e.g. `"\"foo\""` or `"[1, 2]"`)
+    #[serde(borrow)]
+    default_value: Option<&'a RawValue>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionDirective<'a> {
+    name: String,
+    description: Option<String>,
+    locations: Vec<String>,
+    #[serde(default, borrow)]
+    args: Vec<&'a RawValue>,
+    #[serde(default)]
+    is_repeatable: bool,
+}
+
+struct IntrospectionParser<'a> {
+    source: &'a str,
+    text_source: TextSource,
+}
+
+impl<'a> IntrospectionParser<'a> {
+    /// The entire source as a JSON value, so that it can be parsed like any other
+    /// value.
+    fn source_value(&self) -> DiagnosticResult<&'a RawValue> {
+        serde_json::from_str::<&RawValue>(self.source)
+            .map_err(|error| self.json_error_diagnostic(self.source, error))
+    }
+
+    /// Deserializes a JSON value, which must be borrowed from the source. Returns the
+    /// value and the location of the JSON value in the source.
+    fn parse<T: Deserialize<'a>>(
+        &self,
+        raw_value: &'a RawValue,
+    ) -> DiagnosticResult<(T, EmbeddedLocation)> {
+        let json = raw_value.get();
+        let value = serde_json::from_str::<T>(json)
+            .map_err(|error| self.json_error_diagnostic(json, error))?;
+        (value, self.location(json)).wrap_ok()
+    }
+
+    fn offset(&self, json: &str) -> u32 {
+        (json.as_ptr() as usize - self.source.as_ptr() as usize) as u32
+    }
+
+    fn location(&self, json: &str) -> EmbeddedLocation {
+        let start = self.offset(json);
+        EmbeddedLocation::new(
+            self.text_source,
+            Span::new(start, start + json.len() as u32),
+        )
+    }
+
+    /// serde_json reports the line and column at which `json` is invalid, so we convert
+    /// these to an offset in the source.
+    fn json_error_diagnostic(&self, json: &str, error: serde_json::Error) -> Diagnostic {
+        let offset_in_json = json
+            .split_inclusive('\n')
+            .take(error.line().saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>()
+            + error.column().saturating_sub(1);
+        let start = (self.offset(json) as usize + offset_in_json).min(self.source.len()) as u32;
+        let end = (start + 1).min(self.source.len() as u32);
+
+        let message = error.to_string();
+        let position_suffix = format!(" at line {} column {}", error.line(), error.column());
+        Diagnostic::new(
+            format!(
+                "Invalid introspection result: {}",
+                message.strip_suffix(&position_suffix).unwrap_or(&message)
+            ),
+            Location::new(self.text_source, Span::new(start, end)).wrap_some(),
+        )
+    }
+
+    fn schema_document(&self, schema: &'a RawValue) -> DiagnosticResult<GraphQLTypeSystemDocument> {
+        let (schema, location) = self.parse::<IntrospectionSchema>(schema)?;
+
+        let mut definitions = vec![];
+        for raw_type in schema.types {
+            if let Some(definition) = self.type_definition(raw_type)? {
+                definitions.push(definition);
+            }
+        }
+        for raw_directive in schema.directives {
+            if let Some(definition) = self.directive_definition(raw_directive)? {
+                definitions.push(definition);
+            }
+        }
+
+        let root_type = |root_type: Option<IntrospectionRootType>| {
+            root_type.map(|root_type| entity_name(&root_type.name).with_location(location))
+        };
+        definitions.push(
+            GraphQLSchemaDefinition {
+                description: description(schema.description, location),
+                query: root_type(schema.query_type),
+                subscription: root_type(schema.subscription_type),
+                mutation: root_type(schema.mutation_type),
+                directives: vec![],
+            }
+            .to::<GraphQLTypeSystemDefinition>()
+            .with_location(location),
+        );
+
+        GraphQLTypeSystemDocument(definitions).wrap_ok()
+    }
+
+    fn type_definition(
+        &self,
+        raw_type: &'a RawValue,
+    ) -> DiagnosticResult<Option<WithEmbeddedLocation<GraphQLTypeSystemDefinition>>> {
+        let (type_, location) = self.parse::<IntrospectionType>(raw_type)?;
+        if type_.name.starts_with("__") || BUILT_IN_SCALARS.contains(&type_.name.as_str()) {
+            return None.wrap_ok();
+        }
+
+        let description = description(type_.description, location);
+        let name = entity_name(&type_.name).with_location(location);
+        let named_types = |type_refs: Option<Vec<IntrospectionTypeRef>>| {
+            type_refs
+                .unwrap_or_default()
+                .into_iter()
+                .map(|type_ref| named_type(type_ref, location))
+                .collect::<DiagnosticResult<Vec<_>>>()
+        };
+
+        let definition: GraphQLTypeSystemDefinition = match type_.kind {
+            IntrospectionTypeKind::Scalar => {
+                let mut directives = vec![];
+                if let Some(url) = type_.specified_by_url {
+                    directives.push(directive_with_string_argument(
+                        "specifiedBy",
+                        "url",
+                        &url,
+                        location,
+                    ));
+                }
+                GraphQLScalarTypeDefinition {
+                    description,
+                    name,
+                    directives,
+                }
+                .into()
+            }
+            IntrospectionTypeKind::Object => GraphQLObjectTypeDefinition {
+                description,
+                name,
+                interfaces: named_types(type_.interfaces)?,
+                directives: vec![],
+                fields: self.fields(type_.fields)?,
+            }
+            .into(),
+            IntrospectionTypeKind::Interface => GraphQLInterfaceTypeDefinition {
+                description,
+                name,
+                interfaces: named_types(type_.interfaces)?,
+                directives: vec![],
+                fields: self.fields(type_.fields)?,
+            }
+            .into(),
+            IntrospectionTypeKind::Union => GraphQLUnionTypeDefinition {
+                description,
+                name,
+                directives: vec![],
+                union_member_types: named_types(type_.possible_types)?,
+            }
+            .into(),
+            IntrospectionTypeKind::Enum => GraphQLEnumDefinition {
+                description,
+                name,
+                directives: vec![],
+                enum_value_definitions: self.enum_values(type_.enum_values)?,
+            }
+            .into(),
+            IntrospectionTypeKind::InputObject => {
+                let mut directives = vec![];
+                if type_.is_one_of == Some(true) {
+                    directives.push(GraphQLDirective {
+                        name: directive_name("oneOf").with_location(location),
+                        arguments: vec![],
+                    });
+                }
+                GraphQLInputObjectTypeDefinition {
+                    description,
+                    name,
+                    directives,
+                    fields: self.input_values(type_.input_fields.unwrap_or_default())?,
+                }
+                .into()
+            }
+            kind @ (IntrospectionTypeKind::List | IntrospectionTypeKind::NonNull) => {
+                return Diagnostic::new(
+                    format!(
+                        "Expected a named type, but `{}` is a {} type.",
+                        type_.name,
+                        kind.name()
+                    ),
+                    location.to::<Location>().wrap_some(),
+                )
+                .wrap_err();
+            }
+        };
+
+        definition.with_location(location).wrap_some().wrap_ok()
+    }
+
+    fn fields(
+        &self,
+        raw_fields: Option<Vec<&'a RawValue>>,
+    ) -> DiagnosticResult<Vec<WithEmbeddedLocation<GraphQLFieldDefinition>>> {
+        raw_fields
+            .unwrap_or_default()
+            .into_iter()
+            .map(|raw_field| {
+                let (field, location) = self.parse::<IntrospectionField>(raw_field)?;
+                GraphQLFieldDefinition {
+                    description: description(field.description, location),
+                    name: field
+                        .name
+                        .intern()
+                        .to::<SelectableName>()
+                        .with_location(location),
+                    type_: type_annotation(field.type_, location)?.with_location(location),
+                    arguments: self.input_values(field.args)?,
+                    directives: deprecated_directive(
+                        field.is_deprecated,
+                        field.deprecation_reason,
+                        location,
+                    ),
+                }
+                .with_location(location)
+                .wrap_ok()
+            })
+            .collect()
+    }
+
+    fn input_values(
+        &self,
+        raw_input_values: Vec<&'a RawValue>,
+    ) -> DiagnosticResult<Vec<WithEmbeddedLocation<GraphQLInputValueDefinition>>> {
+        raw_input_values
+            .into_iter()
+            .map(|raw_input_value| {
+                let (input_value, location) =
+                    self.parse::<IntrospectionInputValue>(raw_input_value)?;
+                GraphQLInputValueDefinition {
+                    description: description(input_value.description, location),
+                    name: input_value
+                        .name
+                        .intern()
+                        .to::<InputValueName>()
+                        .with_location(location),
+                    type_: type_annotation(input_value.type_, location)?.with_location(location),
+                    default_value: input_value
+                        .default_value
+                        .map(|default_value| self.default_value(default_value))
+                        .transpose()?
+                        .flatten(),
+                    directives: deprecated_directive(
+                        input_value.is_deprecated,
+                        input_value.deprecation_reason,
+                        location,
+                    ),
+                }
+                .with_location(location)
+                .wrap_ok()
+            })
+            .collect()
+    }
+
+    /// Default values are GraphQL literals in JSON strings, so we parse them like
+    /// default values in SDL.
+    fn default_value(
+        &self,
+        raw_default_value: &'a RawValue,
+    ) -> DiagnosticResult<Option<WithEmbeddedLocation<GraphQLConstantValue>>> {
+        let (default_value, location) = self.parse::<Option<String>>(raw_default_value)?;
+        let Some(default_value) = default_value else {
+            return None.wrap_ok();
+        };
+
+        // Skip the opening quote. If the string contains escape sequences, locations
+        // after the first escape sequence are approximate.
+        let mut tokens = PeekableLexer::new_with_offset(
+            &default_value,
+            self.text_source,
+            location.span.start + 1,
+        );
+        let value = parse_constant_value(&mut tokens)?;
+        if !tokens.reached_eof() {
+            return Diagnostic::new(
+                format!("Expected the default value `{default_value}` to be a single value."),
+                location.to::<Location>().wrap_some(),
+            )
+            .wrap_err();
+        }
+        value.wrap_some().wrap_ok()
+    }
+
+    fn enum_values(
+        &self,
+        raw_enum_values: Option<Vec<&'a RawValue>>,
+    ) -> DiagnosticResult<Vec<WithEmbeddedLocation<GraphQLEnumValueDefinition>>> {
+        raw_enum_values
+            .unwrap_or_default()
+            .into_iter()
+            .map(|raw_enum_value| {
+                let (enum_value, location) =
+                    self.parse::<IntrospectionEnumValue>(raw_enum_value)?;
+                GraphQLEnumValueDefinition {
+                    description: description(enum_value.description, location),
+                    value: enum_value
+                        .name
+                        .intern()
+                        .to::<EnumLiteralValue>()
+                        .with_location(location),
+                    directives: deprecated_directive(
+                        enum_value.is_deprecated,
+                        enum_value.deprecation_reason,
+                        location,
+                    ),
+                }
+                .with_location(location)
+                .wrap_ok()
+            })
+            .collect()
+    }
+
+    fn directive_definition(
+        &self,
+        raw_directive: &'a RawValue,
+    ) -> DiagnosticResult<Option<WithEmbeddedLocation<GraphQLTypeSystemDefinition>>> {
+        let (directive, location) = self.parse::<IntrospectionDirective>(raw_directive)?;
+        if BUILT_IN_DIRECTIVES.contains(&directive.name.as_str()) {
+            return None.wrap_ok();
+        }
+
+        let locations = directive
+            .locations
+            .iter()
+            .map(|directive_location| {
+                directive_location
+                    .parse::<DirectiveLocation>()
+                    .map(|directive_location| directive_location.with_location(location))
+                    .map_err(|_| {
+                        Diagnostic::new(
+                            format!("Expected directive location, found {directive_location}"),
+                            location.to::<Location>().wrap_some(),
+                        )
+                    })
+            })
+            .collect::<DiagnosticResult<Vec<_>>>()?;
+
+        GraphQLDirectiveDefinition {
+            description: description(directive.description, location),
+            name: directive_name(&directive.name).with_location(location),
+            arguments: self.input_values(directive.args)?,
+            repeatable: directive.is_repeatable.then(|| ().with_location(location)),
+            locations,
+        }
+        .to::<GraphQLTypeSystemDefinition>()
+        .with_location(location)
+        .wrap_some()
+        .wrap_ok()
+    }
+}
+
+fn type_annotation(
+    type_ref: IntrospectionTypeRef,
+    location: EmbeddedLocation,
+) -> DiagnosticResult<GraphQLTypeAnnotation> {
+    match type_ref.kind {
+        IntrospectionTypeKind::NonNull => {
+            match type_annotation(of_type(type_ref, location)?, location)? {
+                GraphQLTypeAnnotation::Named(named) => {
+                    GraphQLNonNullTypeAnnotation::Named(named).wrap_ok()
+                }
+                GraphQLTypeAnnotation::List(list) => {
+                    GraphQLNonNullTypeAnnotation::List(*list).wrap_ok()
+                }
+                GraphQLTypeAnnotation::NonNull(_) => Diagnostic::new(
+                    "Expected the type of a NON_NULL type to be nullable.".to_string(),
+                    location.to::<Location>().wrap_some(),
+                )
+                .wrap_err(),
+            }
+            .map(|non_null| GraphQLTypeAnnotation::NonNull(non_null.boxed()))
+        }
+        IntrospectionTypeKind::List => GraphQLTypeAnnotation::List(
+            GraphQLListTypeAnnotation(
+                type_annotation(of_type(type_ref, location)?, location)?.with_location(location),
+            )
+            .boxed(),
+        )
+        .wrap_ok(),
+        _ => GraphQLTypeAnnotation::Named(GraphQLNamedTypeAnnotation(
+            named_type(type_ref, location)?.item,
+        ))
+        .wrap_ok(),
+    }
+}
+
+fn of_type(
+    type_ref: IntrospectionTypeRef,
+    location: EmbeddedLocation,
+) -> DiagnosticResult<IntrospectionTypeRef> {
+    match type_ref.of_type {
+        Some(of_type) => (*of_type).wrap_ok(),
+        None => Diagnostic::new(
+            format!(
+                "Expected the {} type to have an `ofType`.",
+                type_ref.kind.name()
+            ),
+            location.to::<Location>().wrap_some(),
+        )
+        .wrap_err(),
+    }
+}
+
+fn named_type(
+    type_ref: IntrospectionTypeRef,
+    location: EmbeddedLocation,
+) -> DiagnosticResult<WithEmbeddedLocation<EntityName>> {
+    match (type_ref.kind, type_ref.name) {
+        (IntrospectionTypeKind::List | IntrospectionTypeKind::NonNull, _) | (_, None) => {
+            Diagnostic::new(
+                "Expected a named type.".to_string(),
+                location.to::<Location>().wrap_some(),
+            )
+            .wrap_err()
+        }
+        (_, Some(name)) => entity_name(&name).with_location(location).wrap_ok(),
+    }
+}
+
+fn deprecated_directive(
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+    location: EmbeddedLocation,
+) -> Vec<GraphQLDirective<GraphQLConstantValue>> {
+    if !is_deprecated {
+        return vec![];
+    }
+    match deprecation_reason {
+        Some(reason) => {
+            vec![directive_with_string_argument(
+                "deprecated",
+                "reason",
+                &reason,
+                location,
+            )]
+        }
+        None => vec![GraphQLDirective {
+            name: directive_name("deprecated").with_location(location),
+            arguments: vec![],
+        }],
+    }
+}
+
+fn directive_with_string_argument(
+    name: &str,
+    argument_name: &str,
+    value: &str,
+    location: EmbeddedLocation,
+) -> GraphQLDirective<GraphQLConstantValue> {
+    GraphQLDirective {
+        name: directive_name(name).with_location(location),
+        arguments: vec![NameValuePair {
+            name: argument_name
+                .intern()
+                .to::<DirectiveArgumentName>()
+                .with_location(location),
+            value: GraphQLConstantValue::String(value.intern().to::<StringLiteralValue>())
+                .with_location(location),
+        }],
+    }
+}
+
+fn description(
+    description: Option<String>,
+    location: EmbeddedLocation,
+) -> Option<WithEmbeddedLocation<DescriptionValue>> {
+    description.map(|description| {
+        description
+            .intern()
+            .to::<DescriptionValue>()
+            .with_location(location)
+    })
+}
+
+fn entity_name(name: &str) -> EntityName {
+    name.intern().into()
+}
+
+fn directive_name(name: &str) -> DirectiveName {
+    name.intern().into()
+}
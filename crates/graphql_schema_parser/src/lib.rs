@@ -1,5 +1,7 @@
 pub mod description;
+mod introspection;
 mod parse_schema;
 mod peekable_lexer;
 
+pub use introspection::*;
 pub use parse_schema::*;
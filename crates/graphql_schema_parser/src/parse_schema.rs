@@ -12,14 +12,16 @@ use intern::{
 
 use graphql_lang_types::{
     DirectiveLocation, GraphQLConstantValue, GraphQLDirective, GraphQLDirectiveDefinition,
-    GraphQLEnumDefinition, GraphQLEnumValueDefinition, GraphQLFieldDefinition,
-    GraphQLInputObjectTypeDefinition, GraphQLInputValueDefinition, GraphQLInterfaceTypeDefinition,
+    GraphQLEnumDefinition, GraphQLEnumTypeExtension, GraphQLEnumValueDefinition,
+    GraphQLFieldDefinition, GraphQLInputObjectTypeDefinition, GraphQLInputObjectTypeExtension,
+    GraphQLInputValueDefinition, GraphQLInterfaceTypeDefinition, GraphQLInterfaceTypeExtension,
     GraphQLListTypeAnnotation, GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation,
     GraphQLObjectTypeDefinition, GraphQLObjectTypeExtension, GraphQLScalarTypeDefinition,
-    GraphQLSchemaDefinition, GraphQLTypeAnnotation, GraphQLTypeSystemDefinition,
-    GraphQLTypeSystemDocument, GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionDocument,
-    GraphQLTypeSystemExtensionOrDefinition, GraphQLUnionTypeDefinition, NameValuePair,
-    RootOperationKind,
+    GraphQLScalarTypeExtension, GraphQLSchemaDefinition, GraphQLSchemaExtension,
+    GraphQLTypeAnnotation, GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument,
+    GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionDocument,
+    GraphQLTypeSystemExtensionOrDefinition, GraphQLUnionTypeDefinition, GraphQLUnionTypeExtension,
+    NameValuePair, RootOperationKind,
 };
 use prelude::Postfix;
 
@@ -105,10 +107,22 @@ fn parse_type_system_extension(
         match identifier.item {
             "type" => parse_object_type_extension(tokens)
                 .map(GraphQLTypeSystemExtension::from),
+            "scalar" => parse_scalar_type_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
+            "interface" => parse_interface_type_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
+            "union" => parse_union_type_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
+            "enum" => parse_enum_type_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
+            "input" => parse_input_object_type_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
+            "schema" => parse_schema_extension(tokens)
+                .map(GraphQLTypeSystemExtension::from),
             _ => {
                 let found_text = identifier.item;
                 Diagnostic::new(
-                    format!("Expected scalar, type, interface, union, enum, input object, schema or directive, found \"{found_text}\""),
+                    format!("Expected scalar, type, interface, union, enum, input or schema, found \"{found_text}\""),
                     identifier.location.to::<Location>().wrap_some()
                 )
                 .wrap_err()
@@ -200,6 +214,25 @@ fn parse_object_type_extension(
     .wrap_ok()
 }
 
+/// The state of the PeekableLexer is that it has processed the "interface" keyword
+fn parse_interface_type_extension(
+    tokens: &mut PeekableLexer,
+) -> DiagnosticResult<GraphQLInterfaceTypeExtension> {
+    let name = tokens.parse_string_key_type(TokenKind::Identifier)?;
+
+    let interfaces = parse_implements_interfaces_if_present(tokens)?;
+    let directives = parse_constant_directives(tokens)?;
+    let fields = parse_optional_fields(tokens)?;
+
+    GraphQLInterfaceTypeExtension {
+        name,
+        interfaces,
+        directives,
+        fields,
+    }
+    .wrap_ok()
+}
+
 /// The state of the PeekableLexer is that it has processed the "interface" keyword
 fn parse_interface_type_definition(
     tokens: &mut PeekableLexer,
@@ -244,6 +277,27 @@ fn parse_input_object_type_definition(
     .wrap_ok()
 }
 
+fn parse_input_object_type_extension(
+    tokens: &mut PeekableLexer,
+) -> DiagnosticResult<GraphQLInputObjectTypeExtension> {
+    let name = tokens.parse_string_key_type(TokenKind::Identifier)?;
+
+    let directives = parse_constant_directives(tokens)?;
+    let fields = parse_optional_enclosed_items(
+        tokens,
+        TokenKind::OpenBrace,
+        TokenKind::CloseBrace,
+        parse_argument_definition,
+    )?;
+
+    GraphQLInputObjectTypeExtension {
+        name,
+        directives,
+        fields,
+    }
+    .wrap_ok()
+}
+
 /// The state of the PeekableLexer is that it has processed the "directive" keyword
 fn parse_directive_definition(
     tokens: &mut PeekableLexer,
@@ -336,6 +390,23 @@ fn parse_enum_definition(
     .wrap_ok()
 }
 
+fn parse_enum_type_extension(
+    tokens: &mut PeekableLexer,
+) -> DiagnosticResult<GraphQLEnumTypeExtension> {
+    let name = tokens.parse_string_key_type(TokenKind::Identifier)?;
+
+    let directives = parse_constant_directives(tokens)?;
+
+    let enum_value_definitions = parse_enum_value_definitions(tokens)?;
+
+    GraphQLEnumTypeExtension {
+        name,
+        directives,
+        enum_value_definitions,
+    }
+    .wrap_ok()
+}
+
 fn parse_enum_value_definitions(
     tokens: &mut PeekableLexer,
 ) -> DiagnosticResult<Vec<WithEmbeddedLocation<GraphQLEnumValueDefinition>>> {
@@ -402,6 +473,29 @@ fn parse_union_definition(
     .wrap_ok()
 }
 
+fn parse_union_type_extension(
+    tokens: &mut PeekableLexer,
+) -> DiagnosticResult<GraphQLUnionTypeExtension> {
+    let name = tokens.parse_string_key_type(TokenKind::Identifier)?;
+
+    let directives = parse_constant_directives(tokens)?;
+
+    // Unlike in a union definition, the member types are optional, since
+    // an extension can add only directives
+    let union_member_types = if tokens.parse_token_of_kind(TokenKind::Equals).is_ok() {
+        parse_union_member_types(tokens)?
+    } else {
+        vec![]
+    };
+
+    GraphQLUnionTypeExtension {
+        name,
+        directives,
+        union_member_types,
+    }
+    .wrap_ok()
+}
+
 fn parse_union_member_types(
     tokens: &mut PeekableLexer,
 ) -> DiagnosticResult<Vec<WithEmbeddedLocation<EntityName>>> {
@@ -427,6 +521,48 @@ fn parse_schema_definition(
 
     let _open_curly = tokens.parse_token_of_kind(TokenKind::OpenBrace)?;
 
+    let (query_type, subscription_type, mutation_type) = parse_root_operation_types(tokens)?;
+
+    GraphQLSchemaDefinition {
+        description,
+        query: query_type,
+        subscription: subscription_type,
+        mutation: mutation_type,
+        directives,
+    }
+    .wrap_ok()
+}
+
+fn parse_schema_extension(tokens: &mut PeekableLexer) -> DiagnosticResult<GraphQLSchemaExtension> {
+    let directives = parse_constant_directives(tokens)?;
+
+    // Unlike in a schema definition, the root operation types are optional, since
+    // an extension can add only directives
+    let (query_type, subscription_type, mutation_type) =
+        if tokens.parse_token_of_kind(TokenKind::OpenBrace).is_ok() {
+            parse_root_operation_types(tokens)?
+        } else {
+            (None, None, None)
+        };
+
+    GraphQLSchemaExtension {
+        query: query_type,
+        subscription: subscription_type,
+        mutation: mutation_type,
+        directives,
+    }
+    .wrap_ok()
+}
+
+type RootOperationTypes = (
+    Option<WithEmbeddedLocation<EntityName>>,
+    Option<WithEmbeddedLocation<EntityName>>,
+    Option<WithEmbeddedLocation<EntityName>>,
+);
+
+/// The state of the PeekableLexer is that it has processed the opening brace.
+/// Returns the query, subscription and mutation types, in that order.
+fn parse_root_operation_types(tokens: &mut PeekableLexer) -> DiagnosticResult<RootOperationTypes> {
     let mut query_type = None;
     let mut mutation_type = None;
     let mut subscription_type = None;
@@ -456,14 +592,7 @@ fn parse_schema_definition(
         }
     }
 
-    GraphQLSchemaDefinition {
-        description,
-        query: query_type,
-        subscription: subscription_type,
-        mutation: mutation_type,
-        directives,
-    }
-    .wrap_ok()
+    (query_type, subscription_type, mutation_type).wrap_ok()
 }
 
 fn reassign_or_error(
@@ -515,6 +644,17 @@ fn parse_root_operation_type(
     (root_operation_type, object_name).wrap_ok()
 }
 
+/// The state of the PeekableLexer is that it has processed the "scalar" keyword
+fn parse_scalar_type_extension(
+    tokens: &mut PeekableLexer,
+) -> DiagnosticResult<GraphQLScalarTypeExtension> {
+    let name = tokens.parse_string_key_type(TokenKind::Identifier)?;
+
+    let directives = parse_constant_directives(tokens)?;
+
+    GraphQLScalarTypeExtension { name, directives }.wrap_ok()
+}
+
 /// The state of the PeekableLexer is that it has processed the "scalar" keyword
 fn parse_scalar_type_definition(
     tokens: &mut PeekableLexer,
@@ -614,7 +754,7 @@ fn parse_constant_name_value_pair<T: From<StringKey>, TValue>(
     NameValuePair { name, value }.wrap_ok()
 }
 
-fn parse_constant_value(
+pub(crate) fn parse_constant_value(
     tokens: &mut PeekableLexer,
 ) -> DiagnosticResult<WithEmbeddedLocation<GraphQLConstantValue>> {
     from_control_flow(|| {
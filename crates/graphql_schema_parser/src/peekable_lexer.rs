@@ -21,6 +21,16 @@ pub(crate) struct PeekableLexer<'source> {
 
 impl<'source> PeekableLexer<'source> {
     pub fn new(source_content: &'source str, text_source: TextSource) -> Self {
+        Self::new_with_offset(source_content, text_source, 0)
+    }
+
+    /// Creates a lexer for source content that starts `offset` bytes into the
+    /// file, so that locations point into the file.
+    pub fn new_with_offset(
+        source_content: &'source str,
+        text_source: TextSource,
+        offset: u32,
+    ) -> Self {
         // To enable fast lookahead the parser needs to store at least the 'kind' (TokenKind)
         // of the next token: the simplest option is to store the full current token, but
         // the Parser requires an initial value. Rather than incur runtime/code overhead
@@ -35,7 +45,7 @@ impl<'source> PeekableLexer<'source> {
             lexer,
             source: source_content,
             end_index_of_last_parsed_token: 0,
-            offset: 0,
+            offset,
 
             text_source,
         };
@@ -4,8 +4,8 @@ use clap::Parser;
 use common_lang_types::CurrentWorkingDirectory;
 use graphql_network_protocol::GraphQLAndJavascriptProfile;
 use intern::string_key::Intern;
-use isograph_compiler::{compile_and_print, handle_watch_command};
-use isograph_config::{CompilerConfig, create_config};
+use isograph_compiler::{check_and_print, compile_and_print, handle_watch_command};
+use isograph_config::{CompilerConfig, create_configs};
 use opentelemetry::{KeyValue, sdk::Resource};
 use opentelemetry_otlp::WithExportConfig;
 use opt::{Command, CompileCommand, LspCommand, Opt};
@@ -36,12 +36,17 @@ async fn start_compiler(
         .config
         .unwrap_or("./isograph.config.json".into());
 
-    let config = create_config(&config_location, current_working_directory);
+    let configs = create_configs(&config_location, current_working_directory);
 
-    configure_logger(compile_command.log_level, &config);
+    configure_logger(compile_command.log_level, &configs);
     if compile_command.watch {
-        match handle_watch_command::<GraphQLAndJavascriptProfile>(config, current_working_directory)
-            .await
+        match handle_watch_command::<GraphQLAndJavascriptProfile>(
+            configs,
+            current_working_directory,
+            compile_command.diagnostics_format,
+            compile_command.profile.as_deref(),
+        )
+        .await
         {
             Ok(_) => {
                 info!("{}", "Successfully watched. Exiting.\n")
@@ -54,8 +59,23 @@ async fn start_compiler(
                 std::process::exit(1);
             }
         };
-    } else if compile_and_print::<GraphQLAndJavascriptProfile>(config, current_working_directory)
+    } else if compile_command.check {
+        if check_and_print::<GraphQLAndJavascriptProfile>(
+            configs,
+            current_working_directory,
+            compile_command.diagnostics_format,
+        )
         .is_err()
+        {
+            std::process::exit(1);
+        }
+    } else if compile_and_print::<GraphQLAndJavascriptProfile>(
+        configs,
+        current_working_directory,
+        compile_command.diagnostics_format,
+        compile_command.profile.as_deref(),
+    )
+    .is_err()
     {
         std::process::exit(1);
     }
@@ -69,11 +89,11 @@ async fn start_language_server(
         .config
         .unwrap_or("./isograph.config.json".into());
 
-    let config = create_config(&config_location, current_working_directory);
+    let configs = create_configs(&config_location, current_working_directory);
 
-    configure_logger(lsp_command.log_level, &config);
+    configure_logger(lsp_command.log_level, &configs);
     if let Err(e) = isograph_lsp::start_language_server::<GraphQLAndJavascriptProfile>(
-        config,
+        configs,
         current_working_directory,
     )
     .await
@@ -88,7 +108,7 @@ async fn start_language_server(
     }
 }
 
-fn configure_logger(log_level: LevelFilter, config: &CompilerConfig) {
+fn configure_logger(log_level: LevelFilter, configs: &[CompilerConfig]) {
     let mut fmt_layer = tracing_subscriber::fmt::layer()
         .pretty()
         .without_time()
@@ -104,8 +124,12 @@ fn configure_logger(log_level: LevelFilter, config: &CompilerConfig) {
     let fmt_layer =
         fmt_layer.with_filter(EnvFilter::from_default_env().add_directive(log_level.into()));
 
-    if let Some(options) = &config.options.open_telemetry
-        && options.enable_tracing
+    // Tracing is process-wide, so we use the OpenTelemetry options of the first
+    // project that enables tracing.
+    if let Some(options) = configs
+        .iter()
+        .filter_map(|config| config.options.open_telemetry.as_ref())
+        .find(|options| options.enable_tracing)
     {
         let tracer = opentelemetry_otlp::new_pipeline()
             .tracing()
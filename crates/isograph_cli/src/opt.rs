@@ -1,4 +1,5 @@
 use clap::{Args, Parser, Subcommand};
+use isograph_compiler::DiagnosticsFormat;
 use std::path::PathBuf;
 use tracing::level_filters::LevelFilter;
 
@@ -23,11 +24,28 @@ pub(crate) struct CompileCommand {
     #[arg(long)]
     pub watch: bool,
 
+    /// Do not write artifacts. Instead, exit with a non-zero status if any artifact
+    /// would be created, modified or deleted.
+    #[arg(long, conflicts_with = "watch")]
+    pub check: bool,
+
+    /// In addition to logging diagnostics, print them to stdout in a machine-readable
+    /// format (one line per compilation). One of `human`, `json` or `sarif`.
+    #[arg(long, default_value = "human")]
+    pub diagnostics_format: DiagnosticsFormat,
+
     /// Compile using this config file. If not provided, searches for a config in
     /// package.json under the `isograph` key.
     #[arg(long)]
     pub config: Option<PathBuf>,
 
+    /// Write the memoized function calls made during the last compilation, along with
+    /// whether they were reused, their duration and their dependencies, to this file.
+    /// It is written as a Graphviz DOT file if the extension is `.dot` or `.gv`, and
+    /// as a Chrome trace otherwise.
+    #[arg(long, conflicts_with = "check")]
+    pub profile: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value = "info")]
     pub log_level: LevelFilter,
 }
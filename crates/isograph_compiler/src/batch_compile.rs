@@ -1,15 +1,29 @@
-use std::time::Duration;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use crate::{
-    compiler_state::CompilerState,
+    compiler_state::{CompilerState, profile_file_for_project},
+    diagnostics_format::{
+        DiagnosticsFormat, print_machine_readable_diagnostics, relative_project_root,
+    },
     with_duration::WithDuration,
-    write_artifacts::{apply_file_system_operations, get_file_system_operations},
+    write_artifacts::{
+        OutOfDateArtifacts, apply_file_system_operations, get_file_system_operations,
+        get_out_of_date_artifacts,
+    },
 };
 use artifact_content::get_artifact_path_and_content;
 use colored::Colorize;
-use common_lang_types::{CurrentWorkingDirectory, Diagnostic, DiagnosticVecResult};
+use common_lang_types::{
+    CurrentWorkingDirectory, Diagnostic, DiagnosticVecResult,
+    relative_path_from_absolute_and_working_directory,
+};
+use intern::Lookup;
 use isograph_config::CompilerConfig;
 use isograph_schema::{CompilationProfile, IsographDatabase};
+use pico::Database;
 use prelude::Postfix;
 use pretty_duration::pretty_duration;
 use tracing::{error, info};
@@ -21,34 +35,97 @@ pub struct CompilationStats {
     pub total_artifacts_written: usize,
 }
 
+/// Compiles each project, and prints the results. Returns an error if the compilation
+/// of any project fails.
+///
+/// If `profile_file` is provided, the memoized function calls made while compiling
+/// are written to it (see [`CompilerState::write_profile`]).
 #[expect(clippy::result_unit_err)]
 pub fn compile_and_print<TCompilationProfile: CompilationProfile>(
+    configs: Vec<CompilerConfig>,
+    current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+    profile_file: Option<&Path>,
+) -> Result<(), ()> {
+    let is_multi_project = configs.len() > 1;
+    let mut result = ().wrap_ok();
+    for (project_index, config) in configs.into_iter().enumerate() {
+        if is_multi_project {
+            log_project(&config, current_working_directory);
+        }
+        if compile_project_and_print::<TCompilationProfile>(
+            config,
+            current_working_directory,
+            diagnostics_format,
+            profile_file.map(|profile_file| {
+                profile_file_for_project(profile_file, project_index, is_multi_project)
+            }),
+        )
+        .is_err()
+        {
+            result = ().wrap_err();
+        }
+    }
+    result
+}
+
+fn compile_project_and_print<TCompilationProfile: CompilationProfile>(
     config: CompilerConfig,
     current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+    profile_file: Option<PathBuf>,
 ) -> Result<(), ()> {
     info!("{}", "Starting to compile.".green());
     let mut state = match CompilerState::new(config, current_working_directory) {
         Ok(s) => s,
         Err(e) => {
             error!("{}", e);
+            print_machine_readable_diagnostics(
+                diagnostics_format,
+                &[e.into()],
+                &|_| None,
+                Path::new(""),
+            );
             return ().wrap_err();
         }
     };
+    if profile_file.is_some() {
+        state.db.get_storage().enable_profiling();
+    }
     let result = WithDuration::new(|| compile::<TCompilationProfile>(&mut state));
-    print_result(&state.db, result)
+    state.save_cache();
+    if let Some(profile_file) = &profile_file {
+        state.write_profile(profile_file);
+    }
+    print_result(&state.db, result, diagnostics_format)
 }
 
 #[expect(clippy::result_unit_err)]
 pub fn print_result<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     result: WithDuration<DiagnosticVecResult<CompilationStats>>,
+    diagnostics_format: DiagnosticsFormat,
 ) -> Result<(), ()> {
+    let line_and_column_range = |location| db.line_and_column_range(location);
+    let project_root = relative_project_root(db);
     match result.item {
         Ok(stats) => {
+            print_machine_readable_diagnostics(
+                diagnostics_format,
+                &[],
+                &line_and_column_range,
+                &project_root,
+            );
             print_stats(result.elapsed_time, stats);
             ().wrap_ok()
         }
         Err(err) => {
+            print_machine_readable_diagnostics(
+                diagnostics_format,
+                &err,
+                &line_and_column_range,
+                &project_root,
+            );
             error!(
                 "{}\n{}\n{}",
                 "Error when compiling.\n".bright_red(),
@@ -68,6 +145,140 @@ pub fn print_result<TCompilationProfile: CompilationProfile>(
     }
 }
 
+/// Compiles each project, and verifies that the artifacts on disk match the generated
+/// artifacts, without writing anything. Returns an error if compilation fails or if any
+/// artifact would be created, modified or deleted.
+#[expect(clippy::result_unit_err)]
+pub fn check_and_print<TCompilationProfile: CompilationProfile>(
+    configs: Vec<CompilerConfig>,
+    current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+) -> Result<(), ()> {
+    let is_multi_project = configs.len() > 1;
+    let mut result = ().wrap_ok();
+    for config in configs {
+        if is_multi_project {
+            log_project(&config, current_working_directory);
+        }
+        if check_project_and_print::<TCompilationProfile>(
+            config,
+            current_working_directory,
+            diagnostics_format,
+        )
+        .is_err()
+        {
+            result = ().wrap_err();
+        }
+    }
+    result
+}
+
+fn check_project_and_print<TCompilationProfile: CompilationProfile>(
+    config: CompilerConfig,
+    current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+) -> Result<(), ()> {
+    info!("{}", "Starting to check artifacts.".green());
+    let state = match CompilerState::new(config, current_working_directory) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}", e);
+            print_machine_readable_diagnostics(
+                diagnostics_format,
+                &[e.into()],
+                &|_| None,
+                Path::new(""),
+            );
+            return ().wrap_err();
+        }
+    };
+    let result = WithDuration::new(|| check::<TCompilationProfile>(&state));
+    let elapsed_time = result.elapsed_time;
+    let out_of_date_artifacts = match result.item {
+        Ok(out_of_date_artifacts) => out_of_date_artifacts,
+        Err(err) => {
+            return print_result(
+                &state.db,
+                WithDuration {
+                    elapsed_time,
+                    item: Err(err),
+                },
+                diagnostics_format,
+            );
+        }
+    };
+    print_machine_readable_diagnostics(
+        diagnostics_format,
+        &[],
+        &|location| state.db.line_and_column_range(location),
+        &relative_project_root(&state.db),
+    );
+
+    if out_of_date_artifacts.is_empty() {
+        info!(
+            "Success! Artifacts are up to date. Checked in {}.",
+            pretty_duration(&elapsed_time, None)
+        );
+        return ().wrap_ok();
+    }
+
+    let artifact_directory = &state.db.get_isograph_config().artifact_directory;
+    let display_path = |path: &Path| {
+        Path::new(artifact_directory.relative_path.lookup())
+            .join(
+                path.strip_prefix(&artifact_directory.absolute_path)
+                    .unwrap_or(path),
+            )
+            .display()
+            .to_string()
+    };
+    let OutOfDateArtifacts {
+        created,
+        modified,
+        deleted,
+    } = out_of_date_artifacts;
+
+    let mut summary = String::new();
+    for (verb, paths) in [
+        ("created", created),
+        ("modified", modified),
+        ("deleted", deleted),
+    ] {
+        if paths.is_empty() {
+            continue;
+        }
+        summary.push_str(&format!("\n{} file(s) would be {verb}:\n", paths.len()));
+        for path in paths {
+            summary.push_str(&format!("  {}\n", display_path(&path)));
+        }
+    }
+
+    error!(
+        "{}\n{}\n{}",
+        "Artifacts are out of date. Run the Isograph compiler to update them.".bright_red(),
+        summary,
+        format!("Checking took {}.", pretty_duration(&elapsed_time, None)).bright_red()
+    );
+    ().wrap_err()
+}
+
+pub(crate) fn log_project(
+    config: &CompilerConfig,
+    current_working_directory: CurrentWorkingDirectory,
+) {
+    info!(
+        "{}",
+        format!(
+            "Project {}:",
+            relative_path_from_absolute_and_working_directory(
+                current_working_directory,
+                &config.project_root
+            )
+        )
+        .bold()
+    );
+}
+
 fn print_stats(elapsed_time: Duration, stats: CompilationStats) {
     let s_if_plural = |count: usize| {
         if count == 1 { "" } else { "s" }
@@ -117,3 +328,18 @@ pub fn compile<TCompilationProfile: CompilationProfile>(
     }
     .wrap_ok()
 }
+
+/// Like [`compile`], but instead of writing artifacts, returns the changes
+/// that writing them would make.
+#[tracing::instrument(skip_all)]
+pub fn check<TCompilationProfile: CompilationProfile>(
+    state: &CompilerState<TCompilationProfile>,
+) -> DiagnosticVecResult<OutOfDateArtifacts> {
+    let db = &state.db;
+    let config = db.get_isograph_config();
+    let (artifacts, _) = get_artifact_path_and_content(db)?;
+
+    get_out_of_date_artifacts(&artifacts, &config.artifact_directory.absolute_path)
+        .map_err(Diagnostic::from)?
+        .wrap_ok()
+}
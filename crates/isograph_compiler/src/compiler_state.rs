@@ -1,10 +1,14 @@
-use std::time::{Duration, Instant};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant, UNIX_EPOCH},
+};
 
 use common_lang_types::{CurrentWorkingDirectory, LocationFreeDiagnosticResult};
 use isograph_config::CompilerConfig;
 use isograph_schema::{CompilationProfile, IsographDatabase};
 use pico::Database;
 use prelude::Postfix;
+use tracing::{info, warn};
 
 use crate::source_files::initialize_sources;
 use artifact_content::FileSystemState;
@@ -28,6 +32,11 @@ impl<TCompilationProfile: CompilationProfile> CompilerState<TCompilationProfile>
         db.set(current_working_directory);
         db.set(config);
         initialize_sources(&mut db)?;
+        if let Some(cache_file) = &db.get_isograph_config().options.cache_file {
+            load_cache(&db, cache_file);
+        }
+        db.get_storage()
+            .set_max_derived_node_count(db.get_isograph_config().options.max_memoized_value_count);
         Self {
             db,
             last_gc_run: Instant::now(),
@@ -38,8 +47,116 @@ impl<TCompilationProfile: CompilationProfile> CompilerState<TCompilationProfile>
 
     pub fn run_garbage_collection(&mut self) {
         if self.last_gc_run.elapsed() >= Duration::from_secs(GC_DURATION_SECONDS) {
+            let previous_eviction_metrics = self.db.get_storage().eviction_metrics();
             self.db.run_garbage_collection();
             self.last_gc_run = Instant::now();
+
+            let eviction_metrics = self.db.get_storage().eviction_metrics();
+            if eviction_metrics != previous_eviction_metrics {
+                info!(
+                    "Discarded {} memoized values, since more than `max_memoized_value_count` \
+                    were kept. {} memoized values have been discarded in total.",
+                    eviction_metrics.evicted_derived_node_count
+                        - previous_eviction_metrics.evicted_derived_node_count,
+                    eviction_metrics.evicted_derived_node_count
+                );
+            }
+        }
+    }
+
+    /// Writes the parsed schema and iso literals to the cache file (if the config
+    /// specifies one), so that the next compiler or language server process need
+    /// not parse unchanged files.
+    pub fn save_cache(&self) {
+        let Some(cache_file) = &self.db.get_isograph_config().options.cache_file else {
+            return;
+        };
+        let bytes = self
+            .db
+            .get_storage()
+            .serialize_persisted_values(&cache_version());
+        let result = match cache_file.parent() {
+            Some(cache_directory) => std::fs::create_dir_all(cache_directory),
+            None => Ok(()),
+        }
+        .and_then(|_| std::fs::write(cache_file, bytes));
+        if let Err(e) = result {
+            warn!(
+                "Unable to write the cache file {}. {e}",
+                cache_file.display()
+            );
+        }
+    }
+
+    /// Writes the memoized function calls made since the sources last changed to
+    /// `profile_file`. It is written as a Graphviz DOT file if its extension is
+    /// `dot` or `gv`, and as a Chrome trace otherwise.
+    pub fn write_profile(&self, profile_file: &Path) {
+        let profile = self.db.get_storage().profile();
+        let contents = match profile_file
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("dot" | "gv") => profile.to_dot(),
+            _ => profile.to_chrome_trace(),
+        };
+        if let Err(e) = std::fs::write(profile_file, contents) {
+            warn!(
+                "Unable to write the profile {}. {e}",
+                profile_file.display()
+            );
         }
     }
 }
+
+/// If the config contains multiple projects, each project's profile is written
+/// to its own file, e.g. `profile.json` becomes `profile.0.json`, `profile.1.json`,
+/// etc.
+pub fn profile_file_for_project(
+    profile_file: &Path,
+    project_index: usize,
+    is_multi_project: bool,
+) -> PathBuf {
+    if !is_multi_project {
+        return profile_file.to_path_buf();
+    }
+    let mut file_name = profile_file.file_stem().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{project_index}"));
+    if let Some(extension) = profile_file.extension() {
+        file_name.push(".");
+        file_name.push(extension);
+    }
+    profile_file.with_file_name(file_name)
+}
+
+fn load_cache<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    cache_file: &Path,
+) {
+    // The cache file does not exist before the first compilation
+    let Ok(bytes) = std::fs::read(cache_file) else {
+        return;
+    };
+    if let Err(e) = db
+        .get_storage()
+        .load_persisted_values(&bytes, &cache_version())
+    {
+        warn!(
+            "Unable to reuse the cache file {}. {e}",
+            cache_file.display()
+        );
+    }
+}
+
+/// Cached values can only be reused by the same build of the compiler, since
+/// a different build may parse files differently.
+fn cache_version() -> String {
+    let build_time = std::env::current_exe()
+        .and_then(|executable| executable.metadata())
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    format!("{}-{build_time}", env!("CARGO_PKG_VERSION"))
+}
@@ -0,0 +1,554 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use common_lang_types::{
+    Diagnostic, EmbeddedLocation, IsographCodeAction, LineAndColumnRange, Location,
+};
+use intern::Lookup;
+use isograph_schema::{CompilationProfile, IsographDatabase};
+use prelude::Postfix;
+use serde::Serialize;
+use tracing::error;
+
+/// How diagnostics are reported by the compiler.
+///
+/// Human-readable diagnostics are always logged. If the format is `Json` or `Sarif`,
+/// the diagnostics from each compilation are additionally printed to stdout as a
+/// single line of JSON, so that tools (e.g. code review bots) can consume them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+    #[default]
+    Human,
+    Json,
+    Sarif,
+}
+
+impl FromStr for DiagnosticsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => DiagnosticsFormat::Human.wrap_ok(),
+            "json" => DiagnosticsFormat::Json.wrap_ok(),
+            "sarif" => DiagnosticsFormat::Sarif.wrap_ok(),
+            _ => format!("Invalid diagnostics format {s:?}. Expected one of human, json or sarif.")
+                .wrap_err(),
+        }
+    }
+}
+
+/// Resolves the rows and columns of a location, e.g. via
+/// [`isograph_schema::IsographDatabase::line_and_column_range`].
+pub(crate) type LineAndColumnRangeFn<'a> =
+    &'a dyn Fn(EmbeddedLocation) -> Option<LineAndColumnRange>;
+
+/// The project root, relative to the current working directory (like the paths of
+/// locations). The files that code actions create are in it.
+pub(crate) fn relative_project_root<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> PathBuf {
+    let project_root = &db.get_isograph_config().project_root;
+    pathdiff::diff_paths(
+        project_root,
+        PathBuf::from(db.get_current_working_directory().lookup()),
+    )
+    .unwrap_or_else(|| project_root.clone())
+}
+
+pub(crate) fn print_machine_readable_diagnostics(
+    diagnostics_format: DiagnosticsFormat,
+    diagnostics: &[Diagnostic],
+    line_and_column_range: LineAndColumnRangeFn,
+    project_root: &Path,
+) {
+    let Some(serialized) = serialize_diagnostics(
+        diagnostics_format,
+        diagnostics,
+        line_and_column_range,
+        project_root,
+    ) else {
+        return;
+    };
+
+    if let Err(e) = writeln!(std::io::stdout().lock(), "{serialized}") {
+        error!("Unable to write diagnostics to stdout.\nReason: {e}");
+    }
+}
+
+fn serialize_diagnostics(
+    diagnostics_format: DiagnosticsFormat,
+    diagnostics: &[Diagnostic],
+    line_and_column_range: LineAndColumnRangeFn,
+    project_root: &Path,
+) -> Option<String> {
+    match diagnostics_format {
+        DiagnosticsFormat::Human => return None,
+        DiagnosticsFormat::Json => serde_json::to_string(&JsonOutput {
+            diagnostics: diagnostics
+                .iter()
+                .map(|diagnostic| json_diagnostic(diagnostic, line_and_column_range))
+                .collect(),
+        }),
+        DiagnosticsFormat::Sarif => {
+            serde_json::to_string(&sarif_log(diagnostics, line_and_column_range, project_root))
+        }
+    }
+    .expect("Expected serialization to work. This is indicative of a bug in Isograph.")
+    .wrap_some()
+}
+
+/// The location of a diagnostic. The range is missing if the file could not be found.
+fn resolve_location(
+    location: Option<Location>,
+    line_and_column_range: LineAndColumnRangeFn,
+) -> Option<(&'static str, Option<LineAndColumnRange>)> {
+    let location = location?.as_embedded_location()?;
+    (
+        location.text_source.relative_path_to_source_file.lookup(),
+        line_and_column_range(location),
+    )
+        .wrap_some()
+}
+
+#[derive(Serialize)]
+struct JsonOutput {
+    diagnostics: Vec<JsonDiagnostic>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDiagnostic {
+    message: String,
+    severity: &'static str,
+    location: Option<JsonLocation>,
+    code_actions: Vec<IsographCodeAction>,
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+    path: &'static str,
+    range: Option<JsonRange>,
+}
+
+#[derive(Serialize)]
+struct JsonRange {
+    start: JsonPosition,
+    end: JsonPosition,
+}
+
+/// One-indexed. The column counts unicode code points.
+#[derive(Serialize)]
+struct JsonPosition {
+    line: u32,
+    column: u32,
+}
+
+fn json_diagnostic(
+    diagnostic: &Diagnostic,
+    line_and_column_range: LineAndColumnRangeFn,
+) -> JsonDiagnostic {
+    JsonDiagnostic {
+        message: diagnostic.0.message.clone(),
+        severity: "error",
+        location: resolve_location(diagnostic.location(), line_and_column_range).map(
+            |(path, range)| JsonLocation {
+                path,
+                range: range.map(|range| JsonRange {
+                    start: JsonPosition {
+                        line: range.start_row.0.get(),
+                        column: range.start_col.0.get(),
+                    },
+                    end: JsonPosition {
+                        line: range.end_row.0.get(),
+                        column: range.end_col.0.get(),
+                    },
+                }),
+            },
+        ),
+        code_actions: diagnostic.0.code_actions.clone(),
+    }
+}
+
+// SARIF 2.1.0. See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifResult {
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage,
+}
+
+/// Each file that a code action offers to create is a fix, which inserts the
+/// content of the file at the start of the (new, empty) file.
+fn sarif_fixes(code_action: &IsographCodeAction, project_root: &Path) -> Vec<SarifFix> {
+    code_action
+        .new_files()
+        .into_iter()
+        .map(|new_file| SarifFix {
+            description: SarifMessage {
+                text: new_file.title,
+            },
+            artifact_changes: [SarifArtifactChange {
+                artifact_location: SarifArtifactLocation {
+                    uri: project_root
+                        .join(&new_file.path)
+                        .to_string_lossy()
+                        .replace('\\', "/"),
+                },
+                replacements: [SarifReplacement {
+                    deleted_region: SarifRegion {
+                        start_line: 1,
+                        start_column: 1,
+                        end_line: 1,
+                        end_column: 1,
+                    },
+                    inserted_content: SarifMessage {
+                        text: new_file.content,
+                    },
+                }],
+            }],
+        })
+        .collect()
+}
+
+fn sarif_log(
+    diagnostics: &[Diagnostic],
+    line_and_column_range: LineAndColumnRangeFn,
+    project_root: &Path,
+) -> SarifLog {
+    SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "isograph",
+                    information_uri: "https://isograph.dev",
+                    version: env!("CARGO_PKG_VERSION"),
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results: diagnostics
+                .iter()
+                .map(|diagnostic| sarif_result(diagnostic, line_and_column_range, project_root))
+                .collect(),
+        }],
+    }
+}
+
+fn sarif_result(
+    diagnostic: &Diagnostic,
+    line_and_column_range: LineAndColumnRangeFn,
+    project_root: &Path,
+) -> SarifResult {
+    SarifResult {
+        level: "error",
+        message: SarifMessage {
+            text: diagnostic.0.message.clone(),
+        },
+        locations: resolve_location(diagnostic.location(), line_and_column_range)
+            .map(|(path, range)| SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: path.to_string(),
+                    },
+                    region: range.map(|range| SarifRegion {
+                        start_line: range.start_row.0.get(),
+                        start_column: range.start_col.0.get(),
+                        end_line: range.end_row.0.get(),
+                        end_column: range.end_col.0.get(),
+                    }),
+                },
+            })
+            .into_iter()
+            .collect(),
+        fixes: diagnostic
+            .0
+            .code_actions
+            .iter()
+            .flat_map(|code_action| sarif_fixes(code_action, project_root))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lang_types::{EntityNameAndSelectableName, Span, TextSource, line_and_column_range};
+    use intern::string_key::Intern;
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    const FILE_TEXT: &str = "import { iso } from '@iso';\n\
+        export const A = iso(`\n  field Pet.Avatar {\n    nme\n  }\n`);\n";
+
+    /// The location of `nme`, which is on the fourth line, in the fifth to seventh column
+    fn located_diagnostic() -> Diagnostic {
+        Diagnostic::new_with_code_actions(
+            "The field `Pet.nme` does not exist.".to_string(),
+            Location::new(
+                TextSource {
+                    relative_path_to_source_file: "src/Pet/Avatar.ts".intern().into(),
+                    span: Span::new(50, 84).wrap_some(),
+                },
+                Span::new(26, 29),
+            )
+            .wrap_some(),
+            vec![IsographCodeAction::CreateNewScalarSelectable(
+                EntityNameAndSelectableName {
+                    parent_entity_name: "Pet".intern().into(),
+                    selectable_name: "nme".intern().into(),
+                },
+            )],
+        )
+    }
+
+    fn unlocated_diagnostic() -> Diagnostic {
+        Diagnostic::new("Unable to read the schema.".to_string(), None)
+    }
+
+    fn serialize(diagnostics_format: DiagnosticsFormat) -> Value {
+        let serialized = serialize_diagnostics(
+            diagnostics_format,
+            &[located_diagnostic(), unlocated_diagnostic()],
+            &|location| {
+                line_and_column_range(FILE_TEXT, location.text_source.span, location.span)
+                    .wrap_some()
+            },
+            Path::new("src"),
+        )
+        .expect("Expected diagnostics to be serialized");
+        serde_json::from_str(&serialized).expect("Expected diagnostics to be valid JSON")
+    }
+
+    fn new_file_content(component_annotation: &str) -> String {
+        format!(
+            "import {{ iso }} from '@iso';\n\nexport const Pet__nme = iso(`\n  \
+            field Pet.nme {component_annotation}{{\n  }}\n`)(({{ data }}) => {{\n  \
+            return null;\n}})\n"
+        )
+    }
+
+    #[test]
+    fn test_human_diagnostics_are_not_serialized() {
+        assert_eq!(
+            serialize_diagnostics(
+                DiagnosticsFormat::Human,
+                &[unlocated_diagnostic()],
+                &|_| None,
+                Path::new("src"),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_json_diagnostics() {
+        assert_eq!(
+            serialize(DiagnosticsFormat::Json),
+            json!({
+                "diagnostics": [
+                    {
+                        "message": "The field `Pet.nme` does not exist.",
+                        "severity": "error",
+                        "location": {
+                            "path": "src/Pet/Avatar.ts",
+                            "range": {
+                                "start": { "line": 4, "column": 5 },
+                                "end": { "line": 4, "column": 8 },
+                            },
+                        },
+                        "codeActions": [
+                            {
+                                "CreateNewScalarSelectable": {
+                                    "parent_entity_name": "Pet",
+                                    "selectable_name": "nme",
+                                },
+                            },
+                        ],
+                    },
+                    {
+                        "message": "Unable to read the schema.",
+                        "severity": "error",
+                        "location": null,
+                        "codeActions": [],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_diagnostic_without_range() {
+        let serialized = serialize_diagnostics(
+            DiagnosticsFormat::Json,
+            &[located_diagnostic()],
+            &|_| None,
+            Path::new("src"),
+        )
+        .expect("Expected diagnostics to be serialized");
+        let serialized: Value = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(
+            serialized["diagnostics"][0]["location"],
+            json!({ "path": "src/Pet/Avatar.ts", "range": null })
+        );
+    }
+
+    #[test]
+    fn test_sarif_diagnostics() {
+        let serialized = serialize(DiagnosticsFormat::Sarif);
+        let run = &serialized["runs"][0];
+
+        assert_eq!(serialized["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["name"], "isograph");
+        assert_eq!(run["columnKind"], "unicodeCodePoints");
+
+        let fix = |title: &str, path: &str, component_annotation: &str| {
+            json!({
+                "description": { "text": title },
+                "artifactChanges": [
+                    {
+                        "artifactLocation": { "uri": path },
+                        "replacements": [
+                            {
+                                "deletedRegion": {
+                                    "startLine": 1,
+                                    "startColumn": 1,
+                                    "endLine": 1,
+                                    "endColumn": 1,
+                                },
+                                "insertedContent": {
+                                    "text": new_file_content(component_annotation),
+                                },
+                            },
+                        ],
+                    },
+                ],
+            })
+        };
+        assert_eq!(
+            run["results"],
+            json!([
+                {
+                    "level": "error",
+                    "message": { "text": "The field `Pet.nme` does not exist." },
+                    "locations": [
+                        {
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "src/Pet/Avatar.ts" },
+                                "region": {
+                                    "startLine": 4,
+                                    "startColumn": 5,
+                                    "endLine": 4,
+                                    "endColumn": 8,
+                                },
+                            },
+                        },
+                    ],
+                    "fixes": [
+                        fix("Create new field named `Pet.nme`", "src/Pet/nme.ts", ""),
+                        fix(
+                            "Create new @component field named `Pet.nme`",
+                            "src/Pet/nme.tsx",
+                            "@component ",
+                        ),
+                    ],
+                },
+                {
+                    "level": "error",
+                    "message": { "text": "Unable to read the schema." },
+                    "locations": [],
+                },
+            ])
+        );
+    }
+}
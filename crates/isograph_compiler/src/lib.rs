@@ -1,13 +1,16 @@
 pub mod batch_compile;
 mod compiler_state;
+mod diagnostics_format;
 mod read_files;
 mod source_files;
 pub mod watch;
 mod with_duration;
 mod write_artifacts;
 
-pub use batch_compile::compile_and_print;
+pub use batch_compile::{check_and_print, compile_and_print};
 pub use compiler_state::*;
+pub use diagnostics_format::DiagnosticsFormat;
 pub use source_files::*;
 pub use watch::handle_watch_command;
 pub use with_duration::*;
+pub use write_artifacts::OutOfDateArtifacts;
@@ -9,6 +9,7 @@ use common_lang_types::{
     relative_path_from_absolute_and_working_directory,
 };
 use isograph_config::ISOGRAPH_FOLDER;
+use isograph_schema::is_iso_literal_file;
 use prelude::Postfix;
 
 use crate::write_artifacts::unable_to_do_something_at_path_diagnostic;
@@ -19,14 +20,7 @@ pub fn read_files_in_folder(
 ) -> LocationFreeDiagnosticResult<Vec<(RelativePathToSourceFile, String)>> {
     read_dir_recursive(folder)?
         .into_iter()
-        .filter(|p| {
-            let extension = p.extension().and_then(|x| x.to_str());
-
-            matches!(
-                extension,
-                Some("ts") | Some("tsx") | Some("js") | Some("jsx")
-            )
-        })
+        .filter(|p| is_iso_literal_file(p))
         .filter(|p| {
             !p.to_str()
                 .expect("Expected path to be stringable")
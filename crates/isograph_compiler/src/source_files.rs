@@ -9,7 +9,7 @@ use common_lang_types::{
     relative_path_from_absolute_and_working_directory,
 };
 use intern::Lookup;
-use isograph_config::absolute_and_relative_paths;
+use isograph_config::{SchemaPattern, absolute_and_relative_paths};
 use isograph_schema::{CompilationProfile, IsographDatabase, SchemaSource, StandardSources};
 use pico::{Database, SourceId};
 use prelude::Postfix;
@@ -23,11 +23,10 @@ use crate::{
 pub fn initialize_sources<TCompilationProfile: CompilationProfile>(
     db: &mut IsographDatabase<TCompilationProfile>,
 ) -> LocationFreeDiagnosticResult<()> {
-    let schema = db.get_isograph_config().schema.clone();
-    let schema_source_id = read_schema(db, &schema)?;
+    let schema_sources = read_schemas(db)?;
     let schema_extension_sources = read_schema_extensions(db)?;
     *db.get_standard_sources_mut().tracked() = StandardSources {
-        schema_source_id,
+        schema_sources,
         schema_extension_sources,
     };
     read_iso_literals_from_project_root(db)
@@ -44,6 +43,7 @@ pub fn update_sources<TCompilationProfile: CompilationProfile>(
                 panic!("Unexpected config file change. This is indicative of a bug in Isograph.");
             }
             ChangedFileKind::Schema => handle_update_schema(db, event).err(),
+            ChangedFileKind::SchemaFolder => handle_update_schema_folder(db, event).err(),
             ChangedFileKind::SchemaExtension => handle_update_schema_extensions(db, event).err(),
             ChangedFileKind::JavaScriptSourceFile => handle_update_source_file(db, event).err(),
             ChangedFileKind::JavaScriptSourceFolder => handle_update_source_folder(db, event).err(),
@@ -60,29 +60,95 @@ fn handle_update_schema<TCompilationProfile: CompilationProfile>(
     db: &mut IsographDatabase<TCompilationProfile>,
     event_kind: &SourceEventKind,
 ) -> LocationFreeDiagnosticResult<()> {
-    let schema = db.get_isograph_config().schema.clone();
     match event_kind {
-        SourceEventKind::CreateOrModify(_) => {
-            db.get_standard_sources_mut().tracked().schema_source_id = read_schema(db, &schema)?;
+        SourceEventKind::CreateOrModify(path) => {
+            create_or_update_schema(db, path)?;
         }
-        SourceEventKind::Rename((_, target_path)) => {
-            if schema.absolute_path != *target_path {
-                db.remove(db.get_standard_sources().untracked().schema_source_id);
-                db.get_standard_sources_mut().tracked().schema_source_id = SourceId::default();
-
-                return schema_not_found_diagnostic().wrap_err();
+        SourceEventKind::Rename((source_path, target_path)) => {
+            let interned_file_path = relative_path_from_absolute_and_working_directory(
+                db.get_current_working_directory(),
+                source_path,
+            );
+            db.remove_schema(interned_file_path);
+            if db
+                .get_isograph_config()
+                .schema
+                .iter()
+                .any(|schema| schema.matches(target_path))
+            {
+                create_or_update_schema(db, target_path)?;
             }
         }
-        SourceEventKind::Remove(_) => {
-            db.remove(db.get_standard_sources().untracked().schema_source_id);
-            db.get_standard_sources_mut().tracked().schema_source_id = SourceId::default();
+        SourceEventKind::Remove(path) => {
+            let interned_file_path = relative_path_from_absolute_and_working_directory(
+                db.get_current_working_directory(),
+                path,
+            );
+            db.remove_schema(interned_file_path);
+        }
+    }
+
+    validate_schema_is_not_empty(db)
+}
+
+fn handle_update_schema_folder<TCompilationProfile: CompilationProfile>(
+    db: &mut IsographDatabase<TCompilationProfile>,
+    event_kind: &SourceEventKind,
+) -> LocationFreeDiagnosticResult<()> {
+    match event_kind {
+        SourceEventKind::CreateOrModify(folder) => {
+            read_schemas_from_folder(db, folder)?;
+        }
+        SourceEventKind::Rename((source_path, target_path)) => {
+            remove_schemas_from_folder(db, source_path);
+            read_schemas_from_folder(db, target_path)?;
+        }
+        SourceEventKind::Remove(path) => {
+            remove_schemas_from_folder(db, path);
+        }
+    }
+
+    validate_schema_is_not_empty(db)
+}
 
-            return schema_not_found_diagnostic().wrap_err();
+fn read_schemas_from_folder<TCompilationProfile: CompilationProfile>(
+    db: &mut IsographDatabase<TCompilationProfile>,
+    folder: &Path,
+) -> LocationFreeDiagnosticResult<()> {
+    let config_schema = db.get_isograph_config().schema.clone();
+    for schema_path in config_schema.iter().flat_map(SchemaPattern::matching_files) {
+        if schema_path.starts_with(folder) {
+            create_or_update_schema(db, &schema_path)?;
         }
     }
     Ok(())
 }
 
+fn remove_schemas_from_folder<TCompilationProfile: CompilationProfile>(
+    db: &mut IsographDatabase<TCompilationProfile>,
+    folder: &PathBuf,
+) {
+    let relative_path = relative_path_from_absolute_and_working_directory(
+        db.get_current_working_directory(),
+        folder,
+    );
+    db.remove_schemas_from_path(Path::new(relative_path.lookup()));
+}
+
+fn create_or_update_schema<TCompilationProfile: CompilationProfile>(
+    db: &mut IsographDatabase<TCompilationProfile>,
+    path: &Path,
+) -> LocationFreeDiagnosticResult<()> {
+    let absolute_and_relative =
+        absolute_and_relative_paths(db.get_current_working_directory(), path.to_path_buf());
+    let schema_id = read_schema(db, &absolute_and_relative)?;
+    db.get_standard_sources_mut()
+        .tracked()
+        .schema_sources
+        .insert(absolute_and_relative.relative_path, schema_id);
+    Ok(())
+}
+
 fn handle_update_schema_extensions<TCompilationProfile: CompilationProfile>(
     db: &mut IsographDatabase<TCompilationProfile>,
     event_kind: &SourceEventKind,
@@ -256,6 +322,24 @@ fn read_schema_file(path: &PathBuf) -> LocationFreeDiagnosticResult<String> {
         .wrap_ok()
 }
 
+/// Reads every schema file that matches a schema path or glob pattern in the config.
+fn read_schemas<TCompilationProfile: CompilationProfile>(
+    db: &mut IsographDatabase<TCompilationProfile>,
+) -> LocationFreeDiagnosticResult<BTreeMap<RelativePathToSourceFile, SourceId<SchemaSource>>> {
+    let config_schema = db.get_isograph_config().schema.clone();
+    let mut schemas = BTreeMap::new();
+    for schema_path in config_schema.iter().flat_map(SchemaPattern::matching_files) {
+        let schema_path =
+            absolute_and_relative_paths(db.get_current_working_directory(), schema_path);
+        let schema = read_schema(db, &schema_path)?;
+        schemas.insert(schema_path.relative_path, schema);
+    }
+    if schemas.is_empty() {
+        return schema_not_found_diagnostic().wrap_err();
+    }
+    schemas.wrap_ok()
+}
+
 fn read_schema_extensions<TCompilationProfile: CompilationProfile>(
     db: &mut IsographDatabase<TCompilationProfile>,
 ) -> LocationFreeDiagnosticResult<BTreeMap<RelativePathToSourceFile, SourceId<SchemaSource>>> {
@@ -288,6 +372,22 @@ fn read_iso_literals_from_folder<TCompilationProfile: CompilationProfile>(
     Ok(())
 }
 
+/// Removing the last schema file (e.g. the only file that matches a glob pattern)
+/// leaves us without a schema.
+fn validate_schema_is_not_empty<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> LocationFreeDiagnosticResult<()> {
+    if db
+        .get_standard_sources()
+        .untracked()
+        .schema_sources
+        .is_empty()
+    {
+        return schema_not_found_diagnostic().wrap_err();
+    }
+    Ok(())
+}
+
 fn schema_not_found_diagnostic() -> LocationFreeDiagnostic {
     "Schema not found. Cannot proceed without a schema"
         .to_string()
@@ -2,8 +2,8 @@ use colored::Colorize;
 use common_lang_types::{
     CurrentWorkingDirectory, LocationFreeDiagnostic, LocationFreeDiagnosticVecResult,
 };
-use isograph_config::{CompilerConfig, create_config};
-use isograph_schema::CompilationProfile;
+use isograph_config::{CompilerConfig, SchemaPattern, create_configs};
+use isograph_schema::{CompilationProfile, is_iso_literal_file};
 use notify::{
     Error, EventKind, RecommendedWatcher, RecursiveMode,
     event::{CreateKind, ModifyKind, RemoveKind, RenameMode},
@@ -11,33 +11,53 @@ use notify::{
 use notify_debouncer_full::{
     DebounceEventResult, DebouncedEvent, Debouncer, RecommendedCache, new_debouncer,
 };
+use pico::Database;
 use prelude::Postfix;
-use std::{path::PathBuf, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use tokio::{runtime::Handle, sync::mpsc::Receiver};
 use tracing::{info, warn};
 
 use crate::{
-    batch_compile::{compile, print_result},
-    compiler_state::CompilerState,
+    batch_compile::{compile, log_project, print_result},
+    compiler_state::{CompilerState, profile_file_for_project},
+    diagnostics_format::DiagnosticsFormat,
     source_files::update_sources,
     with_duration::WithDuration,
 };
 
+/// If `profile_file` is provided, the memoized function calls made during each
+/// compilation are written to it (see [`CompilerState::write_profile`]).
 pub async fn handle_watch_command<TCompilationProfile: CompilationProfile>(
-    config: CompilerConfig,
+    configs: Vec<CompilerConfig>,
     current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+    profile_file: Option<&Path>,
 ) -> LocationFreeDiagnosticVecResult<()> {
-    let mut state =
-        CompilerState::new(config, current_working_directory).map_err(|e| e.wrap_vec())?;
-
-    let config = state.db.get_isograph_config().clone();
+    let config_location = configs
+        .first()
+        .expect("Expected at least one project. This is indicative of a bug in Isograph.")
+        .config_location
+        .clone();
+    let mut states = create_compiler_states(configs.clone(), current_working_directory)?;
 
     info!("{}", "Starting to compile.".green());
-    let result = WithDuration::new(|| compile::<TCompilationProfile>(&mut state));
-    let _ = print_result(&state.db, result);
+    let is_multi_project = states.len() > 1;
+    for (project_index, state) in states.iter_mut().enumerate() {
+        compile_and_print_project::<TCompilationProfile>(
+            state,
+            project_index,
+            is_multi_project,
+            current_working_directory,
+            diagnostics_format,
+            profile_file,
+        );
+    }
 
     let (mut file_system_receiver, mut file_system_watcher) =
-        create_debounced_file_watcher(&config);
+        create_debounced_file_watcher(&configs);
     while let Some(res) = file_system_receiver.recv().await {
         match res {
             Ok(changes) => {
@@ -46,20 +66,46 @@ pub async fn handle_watch_command<TCompilationProfile: CompilationProfile>(
                         "{}",
                         "Config change detected. Starting a full compilation.".cyan()
                     );
-                    let config = create_config(&config.config_location, current_working_directory);
-                    state = CompilerState::new(config.clone(), current_working_directory)
-                        .map_err(|e| e.wrap_vec())?;
+                    let configs = create_configs(&config_location, current_working_directory);
+                    states = create_compiler_states(configs.clone(), current_working_directory)?;
                     file_system_watcher.stop();
                     // TODO is this a bug? Will we continue to watch the old folders? I think so.
                     (file_system_receiver, file_system_watcher) =
-                        create_debounced_file_watcher(&config);
+                        create_debounced_file_watcher(&configs);
+                    let is_multi_project = states.len() > 1;
+                    for (project_index, state) in states.iter_mut().enumerate() {
+                        compile_and_print_project::<TCompilationProfile>(
+                            state,
+                            project_index,
+                            is_multi_project,
+                            current_working_directory,
+                            diagnostics_format,
+                            profile_file,
+                        );
+                        state.run_garbage_collection();
+                    }
                 } else {
                     info!("{}", "File changes detected. Starting to compile.".cyan());
-                    update_sources(&mut state.db, &changes)?;
+                    let is_multi_project = states.len() > 1;
+                    for (project_index, (state, changes)) in
+                        states.iter_mut().zip(changes.iter()).enumerate()
+                    {
+                        // Only recompile the projects that contain changed files
+                        if changes.is_empty() {
+                            continue;
+                        }
+                        update_sources(&mut state.db, changes)?;
+                        compile_and_print_project::<TCompilationProfile>(
+                            state,
+                            project_index,
+                            is_multi_project,
+                            current_working_directory,
+                            diagnostics_format,
+                            profile_file,
+                        );
+                        state.run_garbage_collection();
+                    }
                 };
-                let result = WithDuration::new(|| compile::<TCompilationProfile>(&mut state));
-                let _ = print_result(&state.db, result);
-                state.run_garbage_collection();
             }
             Err(errors) => {
                 return errors
@@ -73,16 +119,71 @@ pub async fn handle_watch_command<TCompilationProfile: CompilationProfile>(
     Ok(())
 }
 
-pub fn has_config_changes(changes: &[SourceFileEvent]) -> bool {
+pub fn create_compiler_states<TCompilationProfile: CompilationProfile>(
+    configs: Vec<CompilerConfig>,
+    current_working_directory: CurrentWorkingDirectory,
+) -> LocationFreeDiagnosticVecResult<Vec<CompilerState<TCompilationProfile>>> {
+    configs
+        .into_iter()
+        .map(|config| {
+            CompilerState::new(config, current_working_directory).map_err(|e| e.wrap_vec())
+        })
+        .collect()
+}
+
+fn compile_and_print_project<TCompilationProfile: CompilationProfile>(
+    state: &mut CompilerState<TCompilationProfile>,
+    project_index: usize,
+    is_multi_project: bool,
+    current_working_directory: CurrentWorkingDirectory,
+    diagnostics_format: DiagnosticsFormat,
+    profile_file: Option<&Path>,
+) {
+    if is_multi_project {
+        log_project(state.db.get_isograph_config(), current_working_directory);
+    }
+    if profile_file.is_some() {
+        state.db.get_storage().enable_profiling();
+    }
+    let result = WithDuration::new(|| compile::<TCompilationProfile>(state));
+    state.save_cache();
+    if let Some(profile_file) = profile_file {
+        state.write_profile(&profile_file_for_project(
+            profile_file,
+            project_index,
+            is_multi_project,
+        ));
+    }
+    let _ = print_result(&state.db, result, diagnostics_format);
+}
+
+/// Whether any of the changes, in any project, is a change to the config file.
+pub fn has_config_changes(changes: &SourceFileEventsByProject) -> bool {
     changes
         .iter()
+        .flatten()
         .any(|(_, changed_file_kind)| matches!(changed_file_kind, ChangedFileKind::Config))
 }
 
 fn categorize_and_filter_events(
+    events: &[DebouncedEvent],
+    configs: &[CompilerConfig],
+) -> Option<SourceFileEventsByProject> {
+    let source_file_events_by_project = configs
+        .iter()
+        .map(|config| categorize_and_filter_events_for_project(events, config))
+        .collect::<Vec<_>>();
+    if source_file_events_by_project.iter().all(Vec::is_empty) {
+        None
+    } else {
+        source_file_events_by_project.wrap_some()
+    }
+}
+
+fn categorize_and_filter_events_for_project(
     events: &[DebouncedEvent],
     config: &CompilerConfig,
-) -> Option<Vec<SourceFileEvent>> {
+) -> Vec<SourceFileEvent> {
     let mut source_file_events = vec![];
     for event in events {
         if let Some(source_file_event) = match event.kind {
@@ -100,11 +201,7 @@ fn categorize_and_filter_events(
             source_file_events.push(source_file_event);
         }
     }
-    if source_file_events.is_empty() {
-        None
-    } else {
-        source_file_events.wrap_some()
-    }
+    source_file_events
 }
 
 fn process_create_event(
@@ -225,12 +322,22 @@ fn categorize_changed_file_and_filter_changes_in_artifact_directory(
     if !path.starts_with(&config.artifact_directory.absolute_path) {
         if path.starts_with(&config.project_root) {
             if path.is_file() {
+                if !is_iso_literal_file(path) {
+                    return None;
+                }
                 return ChangedFileKind::JavaScriptSourceFile.wrap_some();
             } else {
                 return ChangedFileKind::JavaScriptSourceFolder.wrap_some();
             }
-        } else if path == &config.schema.absolute_path {
+        } else if config.schema.iter().any(|schema| schema.matches(path)) {
             return ChangedFileKind::Schema.wrap_some();
+        } else if !path.is_file()
+            && config
+                .schema
+                .iter()
+                .any(|schema| schema.may_contain_folder(path))
+        {
+            return ChangedFileKind::SchemaFolder.wrap_some();
         } else if config
             .schema_extensions
             .iter()
@@ -247,14 +354,14 @@ fn categorize_changed_file_and_filter_changes_in_artifact_directory(
 // TODO reimplement this as create_debounced_file_watcher.map(...)
 #[expect(clippy::complexity)]
 pub fn create_debounced_file_watcher(
-    config: &CompilerConfig,
+    configs: &[CompilerConfig],
 ) -> (
-    Receiver<Result<Vec<(SourceEventKind, ChangedFileKind)>, Vec<Error>>>,
+    Receiver<Result<SourceFileEventsByProject, Vec<Error>>>,
     Debouncer<RecommendedWatcher, RecommendedCache>,
 ) {
     let (sender, receiver) = tokio::sync::mpsc::channel(1);
     let current_runtime = Handle::current();
-    let config_for_watcher = config.clone();
+    let configs_for_watcher = configs.to_vec();
 
     let mut watcher = new_debouncer(
         // TODO control this with config
@@ -262,7 +369,7 @@ pub fn create_debounced_file_watcher(
         None,
         move |result: DebounceEventResult| {
             let events = result
-                .map(|events| categorize_and_filter_events(&events, &config_for_watcher))
+                .map(|events| categorize_and_filter_events(&events, &configs_for_watcher))
                 .transpose();
 
             if let Some(events) = events {
@@ -277,19 +384,32 @@ pub fn create_debounced_file_watcher(
     )
     .expect("Expected to be able to create debouncer");
 
-    watcher
-        .watch(&config.config_location, RecursiveMode::NonRecursive)
-        .expect("Failure when watching project root");
-    watcher
-        .watch(&config.project_root, RecursiveMode::Recursive)
-        .expect("Failure when watching project root");
-    watcher
-        .watch(&config.schema.absolute_path, RecursiveMode::NonRecursive)
-        .expect("Failing when watching schema");
-    for extension in &config.schema_extensions {
+    // All projects share a config file
+    if let Some(config) = configs.first() {
         watcher
-            .watch(&extension.absolute_path, RecursiveMode::NonRecursive)
-            .expect("Failing when watching schema extension");
+            .watch(&config.config_location, RecursiveMode::NonRecursive)
+            .expect("Failure when watching config");
+    }
+    for config in configs {
+        watcher
+            .watch(&config.project_root, RecursiveMode::Recursive)
+            .expect("Failure when watching project root");
+        for schema in &config.schema {
+            match schema {
+                SchemaPattern::File(file) => watcher
+                    .watch(&file.absolute_path, RecursiveMode::NonRecursive)
+                    .expect("Failing when watching schema"),
+                // Schema files that are added to the directory later are picked up, too
+                SchemaPattern::Glob { directory, .. } => watcher
+                    .watch(directory, RecursiveMode::Recursive)
+                    .expect("Failing when watching schema directory"),
+            }
+        }
+        for extension in &config.schema_extensions {
+            watcher
+                .watch(&extension.absolute_path, RecursiveMode::NonRecursive)
+                .expect("Failing when watching schema extension");
+        }
     }
 
     (receiver, watcher)
@@ -305,9 +425,152 @@ pub enum SourceEventKind {
 pub enum ChangedFileKind {
     Config,
     Schema,
+    /// A folder within the directory of a schema glob pattern
+    SchemaFolder,
     SchemaExtension,
     JavaScriptSourceFile,
     JavaScriptSourceFolder,
 }
 
 pub type SourceFileEvent = (SourceEventKind, ChangedFileKind);
+
+/// The source file events of each project, in the order in which the projects
+/// are listed in the config. A change to a file that is shared by multiple projects
+/// (e.g. the config, or a schema) is included for each of them.
+pub type SourceFileEventsByProject = Vec<Vec<SourceFileEvent>>;
+
+#[cfg(test)]
+mod test {
+    use std::time::Instant;
+
+    use intern::string_key::Intern;
+    use notify::Event;
+
+    use super::*;
+
+    /// A config with two projects, `a` and `b`, each with a single iso literal file.
+    fn configs(name: &str) -> (PathBuf, Vec<CompilerConfig>) {
+        let config_dir =
+            std::env::temp_dir().join(format!("isograph_watch_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&config_dir);
+        for file in [
+            "a/schema.graphql",
+            "a/src/HomePage.ts",
+            "b/schema.graphql",
+            "b/src/HomePage.ts",
+            "README.md",
+            "other/src/HomePage.ts",
+        ] {
+            let path = config_dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        std::fs::write(
+            config_dir.join("isograph.config.json"),
+            r#"{
+                "projects": [
+                    { "project_root": "./a/src", "schema": "./a/schema.graphql" },
+                    { "project_root": "./b/src", "schema": "./b/schema.graphql" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let config_dir = config_dir.canonicalize().unwrap();
+
+        let configs = create_configs(
+            &config_dir.join("isograph.config.json"),
+            CurrentWorkingDirectory::from(config_dir.to_string_lossy().intern()),
+        );
+        (config_dir, configs)
+    }
+
+    fn modify_event(path: PathBuf) -> DebouncedEvent {
+        DebouncedEvent::new(
+            Event::new(EventKind::Modify(ModifyKind::Data(
+                notify::event::DataChange::Content,
+            )))
+            .add_path(path),
+            Instant::now(),
+        )
+    }
+
+    /// The paths and kinds of the events of each project
+    fn summarize(events: SourceFileEventsByProject) -> Vec<Vec<(PathBuf, &'static str)>> {
+        events
+            .into_iter()
+            .map(|events| {
+                events
+                    .into_iter()
+                    .map(|(source_event_kind, changed_file_kind)| {
+                        let SourceEventKind::CreateOrModify(path) = source_event_kind else {
+                            panic!("Expected a create or modify event");
+                        };
+                        let changed_file_kind = match changed_file_kind {
+                            ChangedFileKind::Config => "config",
+                            ChangedFileKind::Schema => "schema",
+                            ChangedFileKind::JavaScriptSourceFile => "source file",
+                            _ => panic!("Unexpected changed file kind"),
+                        };
+                        (path, changed_file_kind)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_events_are_routed_to_the_project_that_contains_the_file() {
+        let (config_dir, configs) = configs("routed");
+
+        let events = categorize_and_filter_events(
+            &[
+                modify_event(config_dir.join("b/src/HomePage.ts")),
+                modify_event(config_dir.join("a/schema.graphql")),
+            ],
+            &configs,
+        )
+        .expect("Expected events to be handled");
+        assert_eq!(
+            summarize(events),
+            vec![
+                vec![(config_dir.join("a/schema.graphql"), "schema")],
+                vec![(config_dir.join("b/src/HomePage.ts"), "source file")],
+            ]
+        );
+
+        // The config is shared by every project
+        let events = categorize_and_filter_events(
+            &[modify_event(config_dir.join("isograph.config.json"))],
+            &configs,
+        )
+        .expect("Expected events to be handled");
+        assert!(has_config_changes(&events));
+        assert_eq!(
+            summarize(events),
+            vec![
+                vec![(config_dir.join("isograph.config.json"), "config")],
+                vec![(config_dir.join("isograph.config.json"), "config")],
+            ]
+        );
+
+        std::fs::remove_dir_all(config_dir).unwrap();
+    }
+
+    #[test]
+    fn test_events_outside_of_every_project_are_ignored() {
+        let (config_dir, configs) = configs("ignored");
+
+        assert!(
+            categorize_and_filter_events(
+                &[
+                    modify_event(config_dir.join("other/src/HomePage.ts")),
+                    modify_event(config_dir.join("README.md")),
+                ],
+                &configs,
+            )
+            .is_none()
+        );
+
+        std::fs::remove_dir_all(config_dir).unwrap();
+    }
+}
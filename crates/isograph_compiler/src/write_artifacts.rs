@@ -30,6 +30,65 @@ pub(crate) fn get_file_system_operations(
     operations
 }
 
+/// The changes that compiling would make to the artifact directory, without making them.
+#[derive(Debug, Default)]
+pub struct OutOfDateArtifacts {
+    pub created: Vec<PathBuf>,
+    pub modified: Vec<PathBuf>,
+    pub deleted: Vec<PathBuf>,
+}
+
+impl OutOfDateArtifacts {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// Diffs the artifacts currently on disk against the freshly generated artifacts.
+/// Unlike [`get_file_system_operations`], this never touches disk.
+#[tracing::instrument(skip_all)]
+pub(crate) fn get_out_of_date_artifacts(
+    paths_and_contents: &[ArtifactPathAndContent],
+    artifact_directory: &Path,
+) -> LocationFreeDiagnosticResult<OutOfDateArtifacts> {
+    let on_disk_file_system_state =
+        FileSystemState::from_disk(artifact_directory).map_err(|e| {
+            unable_to_do_something_at_path_diagnostic(
+                &artifact_directory.to_path_buf(),
+                &e.to_string(),
+                "read artifacts",
+            )
+        })?;
+    let new_file_system_state = paths_and_contents.into();
+
+    let mut out_of_date_artifacts = OutOfDateArtifacts::default();
+    for operation in FileSystemState::diff(
+        &on_disk_file_system_state,
+        &new_file_system_state,
+        artifact_directory,
+    ) {
+        match operation {
+            // Files in a created directory are reported individually
+            FileSystemOperation::CreateDirectory(_) => {}
+            FileSystemOperation::WriteFile(path, _) => {
+                if path.exists() {
+                    out_of_date_artifacts.modified.push(path);
+                } else {
+                    out_of_date_artifacts.created.push(path);
+                }
+            }
+            FileSystemOperation::DeleteFile(path) | FileSystemOperation::DeleteDirectory(path) => {
+                out_of_date_artifacts.deleted.push(path);
+            }
+        }
+    }
+
+    out_of_date_artifacts.created.sort();
+    out_of_date_artifacts.modified.sort();
+    out_of_date_artifacts.deleted.sort();
+    out_of_date_artifacts.wrap_ok()
+}
+
 #[tracing::instrument(skip_all)]
 pub(crate) fn apply_file_system_operations(
     operations: &[FileSystemOperation],
@@ -2,11 +2,16 @@ use common_lang_types::{
     AbsolutePathAndRelativePath, CurrentWorkingDirectory, Diagnostic, GeneratedFileHeader,
     PrintLocationFn, relative_path_from_absolute_and_working_directory,
 };
+use glob::{MatchOptions, Pattern, glob_with};
 use intern::string_key::Intern;
 use pico_macros::Singleton;
 use schemars::JsonSchema;
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    path::{Component, Path, PathBuf},
+};
 use tracing::warn;
 
 pub static ISOGRAPH_FOLDER: &str = "__isograph";
@@ -14,6 +19,7 @@ pub static ISOGRAPH_FOLDER: &str = "__isograph";
 /// This struct is the internal representation of the config. It
 /// is a transformed version of IsographProjectConfig.
 #[derive(Debug, Clone, Singleton, Eq, PartialEq)]
+#[durability(high)]
 pub struct CompilerConfig {
     // The absolute path to the config file
     pub config_location: PathBuf,
@@ -21,8 +27,8 @@ pub struct CompilerConfig {
     pub project_root: PathBuf,
     /// The folder where the compiler should create artifacts
     pub artifact_directory: AbsolutePathAndRelativePath,
-    /// The absolute path to the GraphQL schema
-    pub schema: AbsolutePathAndRelativePath,
+    /// The GraphQL schema files and the glob patterns that match schema files
+    pub schema: Vec<SchemaPattern>,
     /// The absolute path to the schema extensions
     pub schema_extensions: Vec<AbsolutePathAndRelativePath>,
 
@@ -30,6 +36,59 @@ pub struct CompilerConfig {
     pub options: CompilerConfigOptions,
 }
 
+/// A schema file, or a glob pattern that matches schema files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaPattern {
+    File(AbsolutePathAndRelativePath),
+    Glob {
+        /// The absolute path to the directory that contains every matching file,
+        /// i.e. the part of the pattern before the first wildcard.
+        directory: PathBuf,
+        /// The absolute glob pattern
+        pattern: Pattern,
+    },
+}
+
+const SCHEMA_GLOB_MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+impl SchemaPattern {
+    pub fn matches(&self, path: &Path) -> bool {
+        match self {
+            SchemaPattern::File(file) => file.absolute_path == path,
+            SchemaPattern::Glob { pattern, .. } => {
+                pattern.matches_path_with(path, SCHEMA_GLOB_MATCH_OPTIONS)
+            }
+        }
+    }
+
+    /// Whether schema files that match might be added to or removed from `folder`.
+    pub fn may_contain_folder(&self, folder: &Path) -> bool {
+        match self {
+            SchemaPattern::File(_) => false,
+            SchemaPattern::Glob { directory, .. } => folder.starts_with(directory),
+        }
+    }
+
+    /// The absolute paths to the schema files that currently exist and match.
+    pub fn matching_files(&self) -> Vec<PathBuf> {
+        match self {
+            SchemaPattern::File(file) => vec![file.absolute_path.clone()],
+            SchemaPattern::Glob { pattern, .. } => glob_with(
+                pattern.as_str(),
+                SCHEMA_GLOB_MATCH_OPTIONS,
+            )
+            .expect("Expected glob pattern to be valid. This is indicative of a bug in Isograph.")
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect(),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct CompilerConfigOptions {
     pub on_invalid_id_type: OptionalValidationLevel,
@@ -39,6 +98,33 @@ pub struct CompilerConfigOptions {
     pub generated_file_header: Option<GeneratedFileHeader>,
     pub persisted_documents: Option<PersistedDocumentsOptions>,
     pub open_telemetry: Option<OpenTelemetryOptions>,
+    pub custom_scalar_types: BTreeMap<String, CustomScalarType>,
+    /// The absolute path to the file in which parsed files are cached
+    pub cache_file: Option<PathBuf>,
+    /// The maximum number of memoized values that long-lived processes keep after
+    /// garbage collection. This does not bound memory usage: it is a count, not a
+    /// size in bytes, and more values are kept between garbage collections.
+    pub max_memoized_value_count: Option<NonZeroUsize>,
+}
+
+/// The TypeScript type that is generated for a custom scalar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomScalarType {
+    /// A type that needs no import, e.g. `string` or `Date`.
+    Inline(String),
+    /// A named type, imported from a module.
+    Import {
+        module: CustomScalarTypeModule,
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomScalarTypeModule {
+    /// The absolute path to a module in the project, without an extension.
+    Path(PathBuf),
+    /// A package, e.g. `decimal.js`.
+    Package(String),
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -98,6 +184,7 @@ pub struct PersistedDocumentsOptions {
     pub file: Option<PathBuf>,
     pub algorithm: PersistedDocumentsHashAlgorithm,
     pub include_extra_info: bool,
+    pub strip_query_text: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -114,7 +201,8 @@ pub enum PersistedDocumentsHashAlgorithm {
     Sha256,
 }
 
-/// This struct is deserialized from an isograph.config.json file.
+/// This struct is deserialized from an isograph.config.json file, or from an
+/// entry in its `projects` list.
 #[derive(Deserialize, JsonSchema, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct IsographProjectConfig {
@@ -126,8 +214,11 @@ pub struct IsographProjectConfig {
     /// The relative path to the folder where the compiler should create artifacts
     /// Defaults to the project_root directory.
     pub artifact_directory: Option<PathBuf>,
-    /// The relative path to the GraphQL schema
-    pub schema: PathBuf,
+    /// The relative path to the GraphQL schema, either as SDL or as the JSON result
+    /// of the introspection query (if the path ends in .json). A schema that is split
+    /// across multiple files can be given as a list of relative paths and glob patterns,
+    /// e.g. `["./schema/**/*.graphql"]`.
+    pub schema: ConfigFileSchema,
     /// The relative path to schema extensions
     #[serde(default)]
     pub schema_extensions: Vec<PathBuf>,
@@ -137,10 +228,50 @@ pub struct IsographProjectConfig {
     pub options: ConfigFileOptions,
 }
 
-pub fn create_config(
+#[derive(Deserialize, JsonSchema, Debug)]
+#[serde(untagged)]
+pub enum ConfigFileSchema {
+    /// A relative path or glob pattern, e.g. `"./schema.graphql"`.
+    Single(PathBuf),
+    /// A list of relative paths and glob patterns.
+    Multiple(Vec<PathBuf>),
+}
+
+impl ConfigFileSchema {
+    fn into_vec(self) -> Vec<PathBuf> {
+        match self {
+            ConfigFileSchema::Single(path) => vec![path],
+            ConfigFileSchema::Multiple(paths) => paths,
+        }
+    }
+}
+
+/// This enum is deserialized from an isograph.config.json file. The file
+/// either describes a single project, or contains a list of projects.
+#[derive(Deserialize, JsonSchema, Debug)]
+#[serde(untagged)]
+pub enum IsographConfigFile {
+    MultiProject(IsographMultiProjectConfig),
+    SingleProject(Box<IsographProjectConfig>),
+}
+
+#[derive(Deserialize, JsonSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct IsographMultiProjectConfig {
+    /// The user may hard-code the JSON Schema for their version of the config.
+    #[serde(rename = "$schema")]
+    pub json_schema: Option<String>,
+    /// The projects, each of which has its own schema, project root and artifact
+    /// directory. Project roots must not overlap. All relative paths are relative
+    /// to the config file.
+    pub projects: Vec<IsographProjectConfig>,
+}
+
+/// Creates one config per project described by the config file.
+pub fn create_configs(
     config_location: &PathBuf,
     current_working_directory: CurrentWorkingDirectory,
-) -> CompilerConfig {
+) -> Vec<CompilerConfig> {
     let config_contents = match std::fs::read_to_string(config_location) {
         Ok(contents) => contents,
         Err(_) => match config_location.to_str() {
@@ -153,9 +284,64 @@ pub fn create_config(
         },
     };
 
-    let config_parsed: IsographProjectConfig = serde_json::from_str(&config_contents)
+    let config_value: serde_json::Value = serde_json::from_str(&config_contents)
         .unwrap_or_else(|e| panic!("Error parsing config. Error: {e}"));
 
+    // We do not deserialize into IsographConfigFile directly, because the errors
+    // for untagged enums do not say what is wrong.
+    let project_configs = if config_value.get("projects").is_some() {
+        let config_parsed: IsographMultiProjectConfig = serde_json::from_value(config_value)
+            .unwrap_or_else(|e| panic!("Error parsing config. Error: {e}"));
+        if config_parsed.projects.is_empty() {
+            panic!("Error parsing config. Error: `projects` must not be empty.");
+        }
+        config_parsed.projects
+    } else {
+        let config_parsed: IsographProjectConfig = serde_json::from_value(config_value)
+            .unwrap_or_else(|e| panic!("Error parsing config. Error: {e}"));
+        vec![config_parsed]
+    };
+
+    let configs = project_configs
+        .into_iter()
+        .map(|config_parsed| {
+            create_project_config(config_location, config_parsed, current_working_directory)
+        })
+        .collect::<Vec<_>>();
+
+    validate_projects_do_not_overlap(&configs);
+
+    configs
+}
+
+fn validate_projects_do_not_overlap(configs: &[CompilerConfig]) {
+    for (index, config) in configs.iter().enumerate() {
+        for other_config in &configs[index + 1..] {
+            if config.project_root.starts_with(&other_config.project_root)
+                || other_config.project_root.starts_with(&config.project_root)
+            {
+                panic!(
+                    "Error parsing config. Error: the project roots {:?} and {:?} overlap.",
+                    config.project_root, other_config.project_root
+                );
+            }
+            if config.artifact_directory.absolute_path
+                == other_config.artifact_directory.absolute_path
+            {
+                panic!(
+                    "Error parsing config. Error: multiple projects use the artifact directory {:?}.",
+                    config.artifact_directory.absolute_path
+                );
+            }
+        }
+    }
+}
+
+fn create_project_config(
+    config_location: &PathBuf,
+    config_parsed: IsographProjectConfig,
+    current_working_directory: CurrentWorkingDirectory,
+) -> CompilerConfig {
     let mut config_dir = config_location.clone();
     config_dir.pop();
 
@@ -172,10 +358,16 @@ pub fn create_config(
     let project_root_dir = config_dir.join(&config_parsed.project_root);
     std::fs::create_dir_all(&project_root_dir).expect("Unable to create project root directory");
 
+    let canonicalized_config_location = config_location
+        .canonicalize()
+        .unwrap_or_else(|_| panic!("Unable to canonicalize config_file at {config_location:?}."));
+    let canonicalized_config_dir = canonicalized_config_location
+        .parent()
+        .expect("Expected config file to have a parent directory.")
+        .to_path_buf();
+
     CompilerConfig {
-        config_location: config_location.canonicalize().unwrap_or_else(|_| {
-            panic!("Unable to canonicalize config_file at {config_location:?}.")
-        }),
+        config_location: canonicalized_config_location,
         project_root: project_root_dir.canonicalize().unwrap_or_else(|_| {
             panic!(
                 "Unable to canonicalize project root at {:?}.",
@@ -191,18 +383,12 @@ pub fn create_config(
                 )
             }),
         ),
-        schema: absolute_and_relative_paths(
-            current_working_directory,
-            config_dir
-                .join(&config_parsed.schema)
-                .canonicalize()
-                .unwrap_or_else(|_| {
-                    panic!(
-                        "Unable to canonicalize schema path. Does {:?} exist?",
-                        config_parsed.schema
-                    )
-                }),
-        ),
+        schema: config_parsed
+            .schema
+            .into_vec()
+            .into_iter()
+            .map(|schema| create_schema_pattern(&config_dir, schema, current_working_directory))
+            .collect(),
         schema_extensions: config_parsed
             .schema_extensions
             .into_iter()
@@ -221,10 +407,62 @@ pub fn create_config(
                 )
             })
             .collect(),
-        options: create_options(config_parsed.options),
+        options: create_options(config_parsed.options, &canonicalized_config_dir),
     }
 }
 
+fn create_schema_pattern(
+    config_dir: &Path,
+    schema: PathBuf,
+    current_working_directory: CurrentWorkingDirectory,
+) -> SchemaPattern {
+    let is_glob = |component: &Component| {
+        component
+            .as_os_str()
+            .to_string_lossy()
+            .contains(['*', '?', '['])
+    };
+
+    let Some(wildcard_index) = schema
+        .components()
+        .position(|component| is_glob(&component))
+    else {
+        return SchemaPattern::File(absolute_and_relative_paths(
+            current_working_directory,
+            config_dir.join(&schema).canonicalize().unwrap_or_else(|_| {
+                panic!("Unable to canonicalize schema path. Does {schema:?} exist?")
+            }),
+        ));
+    };
+
+    let directory = config_dir
+        .join(
+            schema
+                .components()
+                .take(wildcard_index)
+                .collect::<PathBuf>(),
+        )
+        .canonicalize()
+        .unwrap_or_else(|_| {
+            panic!(
+                "Unable to canonicalize the directory of the schema glob pattern. \
+                Does {schema:?} exist?"
+            )
+        });
+    // The directory is escaped, so that e.g. a directory named [schema] is matched literally.
+    let pattern = Path::new(&Pattern::escape(&directory.to_string_lossy())).join(
+        schema
+            .components()
+            .skip(wildcard_index)
+            .collect::<PathBuf>(),
+    );
+    let pattern = Pattern::new(&pattern.to_string_lossy()).unwrap_or_else(|e| {
+        panic!("Error parsing config. Error: invalid schema glob pattern {schema:?}: {e}")
+    });
+
+    SchemaPattern::Glob { directory, pattern }
+}
+
 #[derive(Deserialize, Default, JsonSchema, Debug)]
 #[serde(default, deny_unknown_fields)]
 pub struct ConfigFileOptions {
@@ -246,6 +484,46 @@ pub struct ConfigFileOptions {
     persisted_documents: Option<ConfigFilePersistedDocumentsOptions>,
     /// OpenTelemetry tracing configuration
     open_telemetry: Option<ConfigFileOpenTelemetryOptions>,
+    /// The TypeScript types to generate for custom scalars, keyed by the
+    /// name of the scalar. Custom scalars that are not listed here are
+    /// typed as `unknown`.
+    custom_scalar_types: BTreeMap<String, ConfigFileCustomScalarType>,
+    /// A file, relative to the config file, in which the compiler caches the
+    /// parsed schema and iso literals. If set, the compiler and language server
+    /// reuse the cached results for files that have not changed when they start,
+    /// instead of parsing every file again. This file should not be checked in.
+    cache_file: Option<PathBuf>,
+    /// The maximum number of memoized values that the language server and the
+    /// compiler in watch mode keep in memory. When they periodically collect
+    /// garbage, if more are kept, the least recently used are discarded (and
+    /// recalculated if they are needed again). If not set, there is no maximum.
+    ///
+    /// This does NOT bound memory usage. It limits the number of values, not
+    /// their size in bytes, and values vary greatly in size (from a single
+    /// boolean to an entire parsed schema). Also, the number of values may
+    /// exceed the maximum between garbage collections, and the contents of the
+    /// files that are read are not memoized values, so they are always kept.
+    max_memoized_value_count: Option<NonZeroUsize>,
+}
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(untagged)]
+pub enum ConfigFileCustomScalarType {
+    /// A TypeScript type that needs no import, e.g. `"string"`.
+    Inline(String),
+    /// A named type that is imported, e.g. `{ "from": "./types", "name": "Decimal" }`.
+    Import(ConfigFileCustomScalarTypeImport),
+}
+
+#[derive(Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFileCustomScalarTypeImport {
+    /// The module from which to import the type. Paths starting with `.`
+    /// are relative to the config file. Anything else is treated as a
+    /// package name.
+    pub from: String,
+    /// The name of the exported type.
+    pub name: String,
 }
 
 #[derive(Deserialize, Debug, Clone, Copy, JsonSchema)]
@@ -279,6 +557,10 @@ pub struct ConfigFilePersistedDocumentsOptions {
     pub algorithm: ConfigFilePersistedDocumentsHashAlgorithm,
     /// Include extra info to the operation text
     pub include_extra_info: bool,
+    /// Do not generate query_text artifacts, and do not import them from
+    /// entrypoints. Only the operation id is sent to the server, so this
+    /// requires a server that accepts persisted documents.
+    pub strip_query_text: bool,
 }
 
 #[derive(Deserialize, Default, JsonSchema, Debug)]
@@ -300,7 +582,7 @@ pub enum ConfigFilePersistedDocumentsHashAlgorithm {
     Sha256,
 }
 
-fn create_options(options: ConfigFileOptions) -> CompilerConfigOptions {
+fn create_options(options: ConfigFileOptions, config_dir: &Path) -> CompilerConfigOptions {
     if let Some(header) = options.generated_file_header.as_ref() {
         let line_count = header.lines().count();
         if line_count > 1 {
@@ -320,6 +602,11 @@ fn create_options(options: ConfigFileOptions) -> CompilerConfigOptions {
         generated_file_header,
         persisted_documents: create_persisted_documents(options.persisted_documents),
         open_telemetry: create_open_telemetry(options.open_telemetry),
+        custom_scalar_types: create_custom_scalar_types(options.custom_scalar_types, config_dir),
+        cache_file: options
+            .cache_file
+            .map(|cache_file| config_dir.join(cache_file)),
+        max_memoized_value_count: options.max_memoized_value_count,
     }
 }
 
@@ -364,6 +651,7 @@ fn create_persisted_documents(
             file: options.file,
             algorithm,
             include_extra_info: options.include_extra_info,
+            strip_query_text: options.strip_query_text,
         }
     })
 }
@@ -380,6 +668,32 @@ fn create_open_telemetry(
     })
 }
 
+fn create_custom_scalar_types(
+    custom_scalar_types: BTreeMap<String, ConfigFileCustomScalarType>,
+    config_dir: &Path,
+) -> BTreeMap<String, CustomScalarType> {
+    custom_scalar_types
+        .into_iter()
+        .map(|(scalar_name, custom_scalar_type)| {
+            let custom_scalar_type = match custom_scalar_type {
+                ConfigFileCustomScalarType::Inline(type_) => CustomScalarType::Inline(type_),
+                ConfigFileCustomScalarType::Import(import) => {
+                    let module = if import.from.starts_with('.') {
+                        CustomScalarTypeModule::Path(config_dir.join(&import.from))
+                    } else {
+                        CustomScalarTypeModule::Package(import.from)
+                    };
+                    CustomScalarType::Import {
+                        module,
+                        name: import.name,
+                    }
+                }
+            };
+            (scalar_name, custom_scalar_type)
+        })
+        .collect()
+}
+
 pub fn absolute_and_relative_paths(
     current_working_directory: CurrentWorkingDirectory,
     absolute_path: PathBuf,
@@ -394,3 +708,200 @@ pub fn absolute_and_relative_paths(
         relative_path,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_files(directory: &Path, files: &[&str]) {
+        for file in files {
+            let path = directory.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+    }
+
+    fn schema_pattern(config_dir: &Path, schema: &str) -> SchemaPattern {
+        let current_working_directory =
+            CurrentWorkingDirectory::from(config_dir.to_string_lossy().intern());
+        create_schema_pattern(config_dir, PathBuf::from(schema), current_working_directory)
+    }
+
+    #[test]
+    fn test_schema_glob_patterns() {
+        let config_dir = std::env::temp_dir()
+            .join(format!("isograph_schema_glob_{}", std::process::id()))
+            .join("[config]");
+        create_files(
+            &config_dir,
+            &[
+                "schema/users.graphql",
+                "schema/pets/pets.graphql",
+                "schema/pets/README.md",
+            ],
+        );
+        let config_dir = config_dir.canonicalize().unwrap();
+        let schema_dir = config_dir.join("schema");
+
+        let file = schema_pattern(&config_dir, "./schema/users.graphql");
+        assert_eq!(
+            file.matching_files(),
+            vec![schema_dir.join("users.graphql")]
+        );
+
+        let shallow = schema_pattern(&config_dir, "./schema/*.graphql");
+        assert_eq!(
+            shallow.matching_files(),
+            vec![schema_dir.join("users.graphql")]
+        );
+
+        let recursive = schema_pattern(&config_dir, "./schema/**/*.graphql");
+        assert_eq!(
+            recursive.matching_files(),
+            vec![
+                schema_dir.join("pets/pets.graphql"),
+                schema_dir.join("users.graphql"),
+            ]
+        );
+        let SchemaPattern::Glob { directory, .. } = &recursive else {
+            panic!("Expected a glob pattern");
+        };
+        assert_eq!(directory, &schema_dir);
+
+        // Files that are added later, e.g. while watching, match as well
+        assert!(recursive.matches(&schema_dir.join("new/new.graphql")));
+        assert!(!recursive.matches(&schema_dir.join("new/README.md")));
+        assert!(!shallow.matches(&schema_dir.join("new/new.graphql")));
+
+        std::fs::remove_dir_all(config_dir.parent().unwrap()).unwrap();
+    }
+
+    /// Writes the config to a new directory that contains the given (empty) files,
+    /// and creates the configs of its projects.
+    fn configs_for(name: &str, config: &str, files: &[&str]) -> (PathBuf, Vec<CompilerConfig>) {
+        let config_dir =
+            std::env::temp_dir().join(format!("isograph_config_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&config_dir);
+        std::fs::create_dir_all(&config_dir).unwrap();
+        create_files(&config_dir, files);
+        std::fs::write(config_dir.join("isograph.config.json"), config).unwrap();
+        let config_dir = config_dir.canonicalize().unwrap();
+
+        let configs = create_configs(
+            &config_dir.join("isograph.config.json"),
+            CurrentWorkingDirectory::from(config_dir.to_string_lossy().intern()),
+        );
+        (config_dir, configs)
+    }
+
+    #[test]
+    fn test_single_project_config() {
+        let (config_dir, configs) = configs_for(
+            "single_project",
+            r#"{ "project_root": "./src", "schema": "./schema.graphql" }"#,
+            &["schema.graphql"],
+        );
+
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].project_root, config_dir.join("src"));
+        assert_eq!(
+            configs[0].artifact_directory.absolute_path,
+            config_dir.join("src").join(ISOGRAPH_FOLDER)
+        );
+
+        std::fs::remove_dir_all(config_dir).unwrap();
+    }
+
+    #[test]
+    fn test_projects_config() {
+        let (config_dir, configs) = configs_for(
+            "projects",
+            r#"{
+                "projects": [
+                    { "project_root": "./a/src", "schema": "./a/schema.graphql" },
+                    {
+                        "project_root": "./b/src",
+                        "schema": "./b/schema.graphql",
+                        "artifact_directory": "./b/generated",
+                        "options": { "no_babel_transform": true }
+                    }
+                ]
+            }"#,
+            &["a/schema.graphql", "b/schema.graphql"],
+        );
+
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].project_root, config_dir.join("a/src"));
+        assert_eq!(
+            configs[0].artifact_directory.absolute_path,
+            config_dir.join("a/src").join(ISOGRAPH_FOLDER)
+        );
+        assert_eq!(
+            configs[0].schema,
+            vec![SchemaPattern::File(absolute_and_relative_paths(
+                CurrentWorkingDirectory::from(config_dir.to_string_lossy().intern()),
+                config_dir.join("a/schema.graphql"),
+            ))]
+        );
+        assert!(!configs[0].options.no_babel_transform);
+
+        assert_eq!(configs[1].project_root, config_dir.join("b/src"));
+        assert_eq!(
+            configs[1].artifact_directory.absolute_path,
+            config_dir.join("b/generated").join(ISOGRAPH_FOLDER)
+        );
+        assert!(configs[1].options.no_babel_transform);
+        // Both projects share the config file
+        assert_eq!(configs[0].config_location, configs[1].config_location);
+
+        std::fs::remove_dir_all(config_dir).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "overlap")]
+    fn test_overlapping_project_roots_are_rejected() {
+        configs_for(
+            "overlapping_project_roots",
+            r#"{
+                "projects": [
+                    { "project_root": "./src", "schema": "./schema.graphql" },
+                    {
+                        "project_root": "./src/nested",
+                        "schema": "./schema.graphql",
+                        "artifact_directory": "./generated"
+                    }
+                ]
+            }"#,
+            &["schema.graphql"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple projects use the artifact directory")]
+    fn test_shared_artifact_directories_are_rejected() {
+        configs_for(
+            "shared_artifact_directory",
+            r#"{
+                "projects": [
+                    {
+                        "project_root": "./a",
+                        "schema": "./schema.graphql",
+                        "artifact_directory": "./generated"
+                    },
+                    {
+                        "project_root": "./b",
+                        "schema": "./schema.graphql",
+                        "artifact_directory": "./generated"
+                    }
+                ]
+            }"#,
+            &["schema.graphql"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`projects` must not be empty")]
+    fn test_empty_projects_are_rejected() {
+        configs_for("empty_projects", r#"{ "projects": [] }"#, &[]);
+    }
+}
@@ -53,7 +53,8 @@ struct FixtureOpt {
 }
 
 lazy_static! {
-    static ref INPUT_SUFFIX: Regex = Regex::new(r"(.+)\.input\.js$").unwrap();
+    static ref INPUT_SUFFIX: Regex =
+        Regex::new(r"(.+)\.input\.(js|ts|tsx|vue|svelte|astro|mdx)$").unwrap();
 }
 const OUTPUT_SUFFIX: &str = r"output";
 
@@ -0,0 +1,7 @@
+const documentation = `Declare fields with iso(\`field Type.NotAField { id }\`)();`;
+
+export const BasicField = iso(`
+  field Type.BasicField {
+    id
+  }
+`)();
@@ -0,0 +1,12 @@
+/*
+export const CommentedOut = iso(`
+  field Type.CommentedOut {
+    id
+  }
+`)();
+*/
+export const BasicField = iso(`
+  field Type.BasicField {
+    id
+  }
+`)();
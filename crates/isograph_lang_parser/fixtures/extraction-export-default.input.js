@@ -0,0 +1,5 @@
+export default iso(`
+  field Type.DefaultExport {
+    id
+  }
+`)(function DefaultExport() {});
@@ -0,0 +1,9 @@
+export const MultiLineCall = iso(
+  `
+  field Type.MultiLineCall {
+    id
+  }
+`,
+)(
+  function MultiLineCall() {},
+);
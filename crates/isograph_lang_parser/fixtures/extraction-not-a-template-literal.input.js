@@ -0,0 +1 @@
+export const BasicField = iso('field Type.BasicField { id }')();
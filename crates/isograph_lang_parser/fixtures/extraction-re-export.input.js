@@ -0,0 +1,7 @@
+const LocalName = iso(`
+  field Type.ReExported {
+    id
+  }
+`)(function ReExported() {});
+
+export { LocalName as ExportedName };
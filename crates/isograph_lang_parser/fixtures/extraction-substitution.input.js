@@ -0,0 +1,6 @@
+const name = 'BasicField';
+export const BasicField = iso(`
+  field Type.${name} {
+    id
+  }
+`)();
@@ -0,0 +1,5 @@
+// This file cannot be parsed, but it does not call the iso function
+// (it only mentions iso and isolate), so no diagnostic is reported.
+import { isolate } from './isolate';
+
+export const result = isolate(;
@@ -0,0 +1,5 @@
+export const BasicField = iso(`
+  field Type.BasicField {
+    id
+  }
+`)(;
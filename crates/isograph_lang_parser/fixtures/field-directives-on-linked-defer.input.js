@@ -0,0 +1,7 @@
+export const deferred = iso(`
+  field Type.Name {
+    linked @defer(label: "Type__Name__linked") {
+      scalar
+    }
+  }
+`)();
@@ -18,11 +18,12 @@ use isograph_lang_types::{
 };
 use prelude::Postfix;
 use resolve_position_macros::ResolvePosition;
+use serde::{Deserialize, Serialize};
 use std::ops::ControlFlow;
 
 use crate::{IsographLangTokenKind, parse_optional_description, peekable_lexer::PeekableLexer};
 
-#[derive(Debug, Clone, PartialEq, Eq, ResolvePosition)]
+#[derive(Debug, Clone, PartialEq, Eq, ResolvePosition, Serialize, Deserialize)]
 #[resolve_position(parent_type=(), resolved_node=IsographResolvedNode<'a>)]
 pub enum IsoLiteralExtractionResult {
     ClientPointerDeclaration(WithEmbeddedLocation<ClientPointerDeclaration>),
@@ -658,29 +659,24 @@ fn parse_non_constant_value(
                 .wrap_ok()
         })?;
 
-        to_control_flow(|| {
-            let bool_or_null = tokens.parse_source_of_kind(
+        to_control_flow::<_, Diagnostic>(|| {
+            let identifier = tokens.parse_source_of_kind(
                 IsographLangTokenKind::Identifier,
                 semantic_token_legend::ST_BOOL_OR_NULL,
             )?;
 
-            let embedded_location = bool_or_null.location;
-
-            bool_or_null.and_then(|bool_or_null| match bool_or_null {
-                "null" => NonConstantValue::Null.wrap_ok(),
-                bool => match bool.parse::<bool>() {
-                    Ok(b) => NonConstantValue::Boolean(b).wrap_ok(),
-                    Err(_) => Diagnostic::new(
-                        "Expected null or a boolean value (true or false)".to_string(),
-                        embedded_location.to::<Location>().wrap_some(),
-                    )
-                    .wrap_err(),
-                },
-            })
+            identifier
+                .map(|identifier| match identifier {
+                    "null" => NonConstantValue::Null,
+                    "true" => NonConstantValue::Boolean(true),
+                    "false" => NonConstantValue::Boolean(false),
+                    enum_literal => NonConstantValue::Enum(enum_literal.intern().into()),
+                })
+                .wrap_ok()
         })?;
 
         ControlFlow::Continue(Diagnostic::new(
-            "Expected a valid value, like $foo, 42, \"bar\", true or false".to_string(),
+            "Expected a valid value, like $foo, 42, \"bar\", true, false or ENUM_VALUE".to_string(),
             // TODO get location
             Location::Generated.wrap_some(),
         ))
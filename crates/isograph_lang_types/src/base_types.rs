@@ -1,5 +1,6 @@
 // These must be kept in-sync with `impl_base_types` or things will not compile!
 
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use common_lang_types::{EntityName, Location, SelectableName, WithLocation};
@@ -151,7 +152,7 @@ impl<TServerObject, TServerScalar, TClientObject, TClientScalar>
 /// - scalar field selections (i.e. those without selection sets) vs
 ///   linked field selections.
 /// - schema scalars vs schema objects
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum SelectionType<TScalar, TObject> {
     Scalar(TScalar),
     Object(TObject),
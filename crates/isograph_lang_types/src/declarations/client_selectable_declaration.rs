@@ -1,6 +1,7 @@
 use common_lang_types::{ConstExportName, RelativePathToSourceFile, WithEmbeddedLocation};
 use resolve_position::PositionResolutionPath;
 use resolve_position_macros::ResolvePosition;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 use crate::{
@@ -10,7 +11,9 @@ use crate::{
     string_key_wrappers::Description,
 };
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=(), resolved_node=IsographResolvedNode<'a>)]
 pub struct ClientFieldDeclaration {
     pub const_export_name: ConstExportName,
@@ -32,7 +35,9 @@ pub struct ClientFieldDeclaration {
 
 pub type ClientFieldDeclarationPath<'a> = PositionResolutionPath<&'a ClientFieldDeclaration, ()>;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=(), resolved_node=IsographResolvedNode<'a>)]
 pub struct ClientPointerDeclaration {
     pub const_export_name: ConstExportName,
@@ -57,7 +62,9 @@ pub struct ClientPointerDeclaration {
 pub type ClientPointerDeclarationPath<'a> =
     PositionResolutionPath<&'a ClientPointerDeclaration, ()>;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=SelectionSetParentType<'a>, resolved_node=IsographResolvedNode<'a>)]
 pub struct SelectionSet {
     #[resolve_field]
@@ -1,6 +1,7 @@
 use common_lang_types::{IsoLiteralText, WithEmbeddedLocation};
 use resolve_position::PositionResolutionPath;
 use resolve_position_macros::ResolvePosition;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ClientScalarSelectableNameWrapper, EntityNameWrapper, IsographFieldDirective,
@@ -8,7 +9,7 @@ use crate::{
 };
 
 // TODO should this be ObjectTypeAndFieldNames?
-#[derive(Debug, Clone, Eq, PartialEq, Hash, ResolvePosition)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, ResolvePosition, Serialize, Deserialize)]
 #[resolve_position(parent_type=(), resolved_node=IsographResolvedNode<'a>)]
 pub struct EntrypointDeclaration {
     #[resolve_field]
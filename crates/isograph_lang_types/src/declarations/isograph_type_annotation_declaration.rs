@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeSet, btree_set::Union},
     fmt::Debug,
@@ -22,7 +23,7 @@ use crate::{
 
 /// This is annoying! We should find a better way to model lists.
 /// This gets us closer to a good solution, so it's fine.
-#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Debug, Hash)]
+#[derive(PartialEq, PartialOrd, Ord, Eq, Clone, Debug, Hash, Serialize, Deserialize)]
 pub enum TypeAnnotationDeclaration {
     Scalar(EntityNameWrapper),
     Union(UnionTypeAnnotationDeclaration),
@@ -143,7 +144,7 @@ impl TypeAnnotationDeclaration {
     }
 }
 
-#[derive(Default, Ord, PartialEq, PartialOrd, Eq, Clone, Debug, Hash)]
+#[derive(Default, Ord, PartialEq, PartialOrd, Eq, Clone, Debug, Hash, Serialize, Deserialize)]
 pub struct UnionTypeAnnotationDeclaration {
     pub variants: BTreeSet<UnionVariant>,
     // TODO this is incredibly hacky. null should be in the variants set, but
@@ -191,7 +192,7 @@ impl UnionTypeAnnotationDeclaration {
     }
 }
 
-#[derive(Ord, PartialEq, PartialOrd, Eq, Clone, Debug, Hash)]
+#[derive(Ord, PartialEq, PartialOrd, Eq, Clone, Debug, Hash, Serialize, Deserialize)]
 pub enum UnionVariant {
     Scalar(EntityNameWrapper),
     Plural(WithEmbeddedLocation<TypeAnnotationDeclaration>),
@@ -5,10 +5,11 @@ use common_lang_types::{
 use graphql_lang_types::{FloatValue, NameValuePair};
 use intern::string_key::Lookup;
 use prelude::Postfix;
+use serde::{Deserialize, Serialize};
 
 use crate::VariableNameWrapper;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct SelectionFieldArgument {
     pub name: WithEmbeddedLocation<FieldArgumentName>,
     pub value: WithEmbeddedLocation<NonConstantValue>,
@@ -45,7 +46,7 @@ impl ArgumentKeyAndValue {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub enum NonConstantValueInner<TLocation> {
     Variable(VariableNameWrapper),
     Integer(i64),
@@ -154,7 +155,7 @@ impl<TLocation: Copy> From<ConstantValueInner<TLocation>> for NonConstantValueIn
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub enum ConstantValueInner<TLocation> {
     Integer(i64),
     Boolean(bool),
@@ -3,6 +3,7 @@ use common_lang_types::{
 };
 use resolve_position::PositionResolutionPath;
 use resolve_position_macros::ResolvePosition;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     IsographResolvedNode, NonConstantValue, ObjectSelectionDirectiveSet,
@@ -57,12 +58,15 @@ impl Selection {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=SelectionParentType<'a>, resolved_node=IsographResolvedNode<'a>)]
 pub struct ScalarSelection {
     pub name: WithEmbeddedLocation<SelectableName>,
     pub reader_alias: Option<WithEmbeddedLocation<SelectableAlias>>,
     pub arguments: Vec<WithEmbeddedLocation<SelectionFieldArgument>>,
+    #[serde(with = "crate::selection_directive_set::tagged_scalar_selection_directive_set")]
     pub scalar_selection_directive_set: ScalarSelectionDirectiveSet,
 }
 
@@ -77,7 +81,9 @@ impl ScalarSelection {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=SelectionParentType<'a>, resolved_node=IsographResolvedNode<'a>)]
 // TODO remove the type parameters
 pub struct ObjectSelection {
@@ -86,6 +92,7 @@ pub struct ObjectSelection {
     #[resolve_field]
     pub selection_set: WithEmbeddedLocation<SelectionSet>,
     pub arguments: Vec<WithEmbeddedLocation<SelectionFieldArgument>>,
+    #[serde(with = "crate::selection_directive_set::tagged_object_selection_directive_set")]
     pub object_selection_directive_set: ObjectSelectionDirectiveSet,
 }
 
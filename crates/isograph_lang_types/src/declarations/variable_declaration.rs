@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 use common_lang_types::{EmbeddedLocation, WithGenericLocation};
@@ -9,7 +10,9 @@ use crate::{
     IsographResolvedNode, TypeAnnotationDeclaration, VariableNameWrapper,
 };
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, ResolvePosition, Serialize, Deserialize,
+)]
 #[resolve_position(parent_type=VariableDeclarationParentType<'a>, resolved_node=IsographResolvedNode<'a>, self_type_generics=<EmbeddedLocation>)]
 pub struct VariableDeclarationInner<TLocation> {
     #[resolve_field]
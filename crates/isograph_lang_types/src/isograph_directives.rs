@@ -4,13 +4,13 @@ use common_lang_types::{
 use intern::Lookup;
 use prelude::Postfix;
 use serde::{
-    Deserialize, Deserializer,
-    de::{self, IntoDeserializer, MapAccess},
+    Deserialize, Deserializer, Serialize,
+    de::{self, IntoDeserializer, MapAccess, value::SeqDeserializer},
 };
 
 use crate::{NonConstantValue, SelectionFieldArgument};
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Serialize, Deserialize)]
 pub struct IsographFieldDirective {
     pub name: WithEmbeddedLocation<IsographDirectiveName>,
     pub arguments: Vec<WithEmbeddedLocation<SelectionFieldArgument>>,
@@ -153,17 +153,23 @@ impl<'de> Deserializer<'de> for NonConstantValueDeserializer<'de> {
         V: de::Visitor<'de>,
     {
         match self.value {
-            NonConstantValue::Variable(_variable) => todo!("Variable?"),
+            NonConstantValue::Variable(variable) => DeserializationError::Custom(format!(
+                "Variables are not supported here, but ${variable} was passed."
+            ))
+            .wrap_err(),
             NonConstantValue::Integer(i_64) => visitor.visit_i64(*i_64),
             NonConstantValue::Boolean(bool) => visitor.visit_bool(*bool),
             NonConstantValue::String(s) => visitor.visit_str(s.lookup()),
             NonConstantValue::Float(f) => visitor.visit_f64(f.as_float()),
             NonConstantValue::Null => visitor.visit_none(),
-            NonConstantValue::Enum(_) => panic!("Enums not supported when deserializing"),
-            NonConstantValue::List(_) => {
-                panic!("Deserializing from lists is not yet supported here.")
+            NonConstantValue::Enum(enum_literal) => visitor.visit_str(enum_literal.lookup()),
+            NonConstantValue::List(list) => {
+                visitor.visit_seq(SeqDeserializer::new(list.iter().map(|item| &item.item)))
+            }
+            NonConstantValue::Object(_) => {
+                DeserializationError::Custom("Objects are not supported here.".to_string())
+                    .wrap_err()
             }
-            NonConstantValue::Object(_) => panic!("Deserializing objects not yet supported here."),
         }
     }
 
@@ -174,10 +180,28 @@ impl<'de> Deserializer<'de> for NonConstantValueDeserializer<'de> {
         visitor.visit_some(self)
     }
 
+    /// Enum literals (e.g. `CAT`) deserialize into unit variants.
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.value {
+            NonConstantValue::Enum(enum_literal) => {
+                visitor.visit_enum(enum_literal.lookup().into_deserializer())
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     serde::forward_to_deserialize_any! {
         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
         bytes byte_buf unit unit_struct newtype_struct seq tuple
-        tuple_struct map struct enum ignored_any identifier
+        tuple_struct map struct ignored_any identifier
     }
 }
 
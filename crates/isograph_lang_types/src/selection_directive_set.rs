@@ -1,6 +1,9 @@
-use serde::Deserialize;
+use common_lang_types::StringLiteralValue;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(
+    Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash,
+)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct UpdatableDirectiveParameters {}
 
@@ -16,29 +19,150 @@ pub enum ScalarSelectionDirectiveSet {
 #[serde(rename_all = "camelCase", untagged)]
 pub enum ObjectSelectionDirectiveSet {
     Updatable(UpdatableDirectiveSet),
+    Defer(DeferDirectiveSet),
     None(EmptyDirectiveSet),
 }
 
-#[derive(Deserialize, Debug, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash)]
+#[derive(
+    Deserialize, Serialize, Debug, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash,
+)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct UpdatableDirectiveSet {
     pub updatable: UpdatableDirectiveParameters,
 }
 
-#[derive(Deserialize, Debug, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DeferDirectiveSet {
+    pub defer: DeferDirectiveParameters,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash,
+)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LoadableDirectiveSet {
     pub loadable: LoadableDirectiveParameters,
 }
 
 // No directives -> an EmptyStruct is parsed!
-#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(
+    Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash,
+)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct EmptyDirectiveSet {}
 
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default, Hash)]
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default, Hash,
+)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct LoadableDirectiveParameters {
     #[serde(default)]
     pub lazy_load_artifact: bool,
 }
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Hash)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeferDirectiveParameters {
+    /// Identifies the incremental payload in which the deferred selections
+    /// are delivered. The generated normalization ASTs are keyed by this label.
+    pub label: StringLiteralValue,
+}
+
+/// Directive sets are deserialized from directives, so the enums are untagged.
+/// But untagged enums cannot be deserialized from formats that are not self-describing,
+/// such as the format in which parsed iso literals are persisted. So, selections
+/// serialize their directive sets as these (tagged) enums instead.
+#[derive(Serialize, Deserialize)]
+enum TaggedScalarSelectionDirectiveSet {
+    Loadable(LoadableDirectiveSet),
+    Updatable(UpdatableDirectiveSet),
+    None(EmptyDirectiveSet),
+}
+
+#[derive(Serialize, Deserialize)]
+enum TaggedObjectSelectionDirectiveSet {
+    Updatable(UpdatableDirectiveSet),
+    Defer(DeferDirectiveSet),
+    None(EmptyDirectiveSet),
+}
+
+pub(crate) mod tagged_scalar_selection_directive_set {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        directive_set: &ScalarSelectionDirectiveSet,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match *directive_set {
+            ScalarSelectionDirectiveSet::Loadable(directive_set) => {
+                TaggedScalarSelectionDirectiveSet::Loadable(directive_set)
+            }
+            ScalarSelectionDirectiveSet::Updatable(directive_set) => {
+                TaggedScalarSelectionDirectiveSet::Updatable(directive_set)
+            }
+            ScalarSelectionDirectiveSet::None(directive_set) => {
+                TaggedScalarSelectionDirectiveSet::None(directive_set)
+            }
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ScalarSelectionDirectiveSet, D::Error> {
+        Ok(
+            match TaggedScalarSelectionDirectiveSet::deserialize(deserializer)? {
+                TaggedScalarSelectionDirectiveSet::Loadable(directive_set) => {
+                    ScalarSelectionDirectiveSet::Loadable(directive_set)
+                }
+                TaggedScalarSelectionDirectiveSet::Updatable(directive_set) => {
+                    ScalarSelectionDirectiveSet::Updatable(directive_set)
+                }
+                TaggedScalarSelectionDirectiveSet::None(directive_set) => {
+                    ScalarSelectionDirectiveSet::None(directive_set)
+                }
+            },
+        )
+    }
+}
+
+pub(crate) mod tagged_object_selection_directive_set {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        directive_set: &ObjectSelectionDirectiveSet,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match *directive_set {
+            ObjectSelectionDirectiveSet::Updatable(directive_set) => {
+                TaggedObjectSelectionDirectiveSet::Updatable(directive_set)
+            }
+            ObjectSelectionDirectiveSet::Defer(directive_set) => {
+                TaggedObjectSelectionDirectiveSet::Defer(directive_set)
+            }
+            ObjectSelectionDirectiveSet::None(directive_set) => {
+                TaggedObjectSelectionDirectiveSet::None(directive_set)
+            }
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ObjectSelectionDirectiveSet, D::Error> {
+        Ok(
+            match TaggedObjectSelectionDirectiveSet::deserialize(deserializer)? {
+                TaggedObjectSelectionDirectiveSet::Updatable(directive_set) => {
+                    ObjectSelectionDirectiveSet::Updatable(directive_set)
+                }
+                TaggedObjectSelectionDirectiveSet::Defer(directive_set) => {
+                    ObjectSelectionDirectiveSet::Defer(directive_set)
+                }
+                TaggedObjectSelectionDirectiveSet::None(directive_set) => {
+                    ObjectSelectionDirectiveSet::None(directive_set)
+                }
+            },
+        )
+    }
+}
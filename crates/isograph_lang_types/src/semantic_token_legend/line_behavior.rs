@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::ops::{Deref, Not};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum LineBehavior {
     StartsNewLine(StartsNewLineBehavior),
     EndsLine(EndsLineBehavior),
@@ -48,23 +49,23 @@ impl LineBehavior {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct StartsNewLineBehavior {
     pub space_after: SpaceAfter,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct EndsLineBehavior {
     pub space_before: SpaceBefore,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct InlineBehavior {
     pub space_before: SpaceBefore,
     pub space_after: SpaceAfter,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct SpaceBefore(pub bool);
 
 impl Deref for SpaceBefore {
@@ -75,7 +76,7 @@ impl Deref for SpaceBefore {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct SpaceAfter(pub bool);
 
 impl Deref for SpaceAfter {
@@ -1,6 +1,7 @@
 pub mod line_behavior;
 
 use lsp_types::{SemanticTokenModifier, SemanticTokenType, SemanticTokensLegend};
+use serde::{Deserialize, Serialize};
 
 use crate::semantic_token_legend::line_behavior::{
     EndsLineBehavior, InlineBehavior, LineBehavior, SpaceAfter, SpaceBefore, StartsNewLineBehavior,
@@ -45,7 +46,7 @@ pub fn semantic_token_legend() -> SemanticTokensLegend {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct LspSemanticToken(pub u32);
 
 #[expect(unused)]
@@ -83,7 +84,7 @@ const LSP_ST_REGEXP: LspSemanticToken = LspSemanticToken(20);
 const LSP_ST_OPERATOR: LspSemanticToken = LspSemanticToken(21);
 const LSP_ST_DECORATOR: LspSemanticToken = LspSemanticToken(22);
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct IsographSemanticToken {
     pub lsp_semantic_token: LspSemanticToken,
     pub line_behavior: LineBehavior,
@@ -324,7 +325,7 @@ pub const ST_COMMENT: IsographSemanticToken = IsographSemanticToken {
     indent_change: IndentChange::Same,
 };
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum IndentChange {
     Indent,
     Dedent,
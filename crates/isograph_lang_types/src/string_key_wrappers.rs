@@ -15,6 +15,8 @@ macro_rules! define_wrapper {
             PartialOrd,
             Ord,
             ::resolve_position_macros::ResolvePosition,
+            ::serde::Serialize,
+            ::serde::Deserialize,
         )]
         #[resolve_position(parent_type=$parent_type, resolved_node=IsographResolvedNode<'a>)]
         pub struct $struct_name(pub $inner);
@@ -0,0 +1,11 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage {
+    pets {
+      name
+    }
+  }
+`)(function HomePage({ data }) {
+  return data.pets;
+});
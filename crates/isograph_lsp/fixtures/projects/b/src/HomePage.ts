@@ -0,0 +1,11 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage {
+    users {
+      email
+    }
+  }
+`)(function HomePage({ data }) {
+  return data.users;
+});
@@ -0,0 +1,22 @@
+import { iso } from '@iso';
+
+export const PetName = iso(`
+  field Pet.PetName {
+    name
+  }
+`)(function PetName({ data }) {
+  return data.name;
+});
+
+export const PetPage = iso(`
+  field Query.PetPage($id: ID!) {
+    pet(id: $id) {
+      PetName
+      set_name
+    }
+  }
+`)(function PetPage({ data }) {
+  return data.pet?.PetName;
+});
+
+export const PetPageEntrypoint = iso(`entrypoint Query.PetPage`);
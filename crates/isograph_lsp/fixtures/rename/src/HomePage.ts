@@ -0,0 +1,12 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage {
+    me {
+      Avatar
+      welcome: Greeting
+    }
+  }
+`)(function HomePage({ data }) {
+  return [data.me?.Avatar, data.me?.welcome];
+});
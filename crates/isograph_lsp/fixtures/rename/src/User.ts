@@ -0,0 +1,17 @@
+import { iso } from '@iso';
+
+export const Avatar = iso(`
+  field User.Avatar {
+    name
+  }
+`)(function Avatar({ data }) {
+  return data.name;
+});
+
+export const Greeting = iso(`
+  field User.Greeting {
+    name
+  }
+`)(function Greeting({ data }) {
+  return `Hello, ${data.name}`;
+});
@@ -0,0 +1,16 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage {
+    firstPet {
+      PetName
+    }
+  }
+`)(function HomePage({ data }) {
+  return data.firstPet?.PetName;
+});
+
+export const HomePageEntrypoint = iso(`entrypoint Query.HomePage`);
+
+// This entrypoint is not valid, so it is not a symbol.
+export const MissingEntrypoint = iso(`entrypoint Query.Missing`);
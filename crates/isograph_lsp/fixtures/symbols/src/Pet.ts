@@ -0,0 +1,17 @@
+import { iso } from '@iso';
+
+export const PetName = iso(`
+  field Pet.PetName {
+    name
+  }
+`)(function PetName({ data }) {
+  return data.name;
+});
+
+export const FirstPet = iso(`
+  pointer Query.firstPet to Pet {
+    pets {
+      __link
+    }
+  }
+`)(({ data }) => data.pets[0]?.__link);
@@ -1,8 +1,7 @@
 use std::str::FromStr;
 
-use common_lang_types::{EntityName, IsographCodeAction, SelectableName};
+use common_lang_types::{IsographCodeAction, IsographCodeActionNewFile};
 use intern::Lookup;
-use isograph_lang_types::SelectionType;
 use isograph_schema::{CompilationProfile, IsographDatabase};
 use lsp_types::{
     CodeAction, CodeActionOrCommand, CreateFile, DocumentChangeOperation, DocumentChanges, OneOf,
@@ -22,6 +21,9 @@ pub fn on_code_action<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <CodeActionRequest as Request>::Params,
 ) -> LSPRuntimeResult<<CodeActionRequest as Request>::Result> {
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document.uri)?
+        .db;
     for diagnostic in params.context.diagnostics {
         if let Some(data) = diagnostic.data {
             let code_actions = serde_json::from_value::<Vec<IsographCodeAction>>(data).expect(
@@ -31,12 +33,7 @@ pub fn on_code_action<TCompilationProfile: CompilationProfile>(
 
             return code_actions
                 .into_iter()
-                .flat_map(|code_action| {
-                    isograph_code_action_to_lsp_code_actions(
-                        &lsp_state.compiler_state.db,
-                        code_action,
-                    )
-                })
+                .flat_map(|code_action| isograph_code_action_to_lsp_code_actions(db, code_action))
                 .collect::<Vec<_>>()
                 .wrap_some()
                 .wrap_ok();
@@ -54,141 +51,67 @@ fn isograph_code_action_to_lsp_code_actions<TCompilationProfile: CompilationProf
 ) -> Vec<CodeActionOrCommand> {
     let config = db.get_isograph_config();
 
-    match action {
-        IsographCodeAction::CreateNewScalarSelectable(
-            parent_object_entity_name_and_selectable_name,
-        ) => {
-            let parent_entity_name =
-                parent_object_entity_name_and_selectable_name.parent_entity_name;
-            let selectable_name = parent_object_entity_name_and_selectable_name.selectable_name;
-            let new_file_path_string = format!(
-                "{}/{}/{}.ts",
-                config.project_root.to_str().expect(
-                    "Expected project root to be able to be turned into a string. \
-                    This is indicative of a bug in Isograph."
-                ),
-                parent_entity_name,
-                selectable_name
-            );
-            let new_file_path_string_tsx = format!(
-                "{}/{}/{}.tsx",
-                config.project_root.to_str().expect(
-                    "Expected project root to be able to be turned into a string. \
-                    This is indicative of a bug in Isograph."
-                ),
-                parent_entity_name,
-                selectable_name
-            );
-            let new_file_path = Uri::from_str(&new_file_path_string).expect(
-                "Expected uri to be valid. \
-                This is indicative of a bug in Isograph.",
-            );
-            let new_file_path_tsx = Uri::from_str(&new_file_path_string_tsx).expect(
-                "Expected uri to be valid. \
-                This is indicative of a bug in Isograph.",
-            );
-
-            vec![
-                CodeActionOrCommand::CodeAction(create_new_selectable_code_action(
-                    parent_entity_name,
-                    selectable_name,
-                    new_file_path_string,
-                    new_file_path,
-                    false,
-                    SelectionType::Scalar(()),
-                )),
-                CodeActionOrCommand::CodeAction(create_new_selectable_code_action(
-                    parent_entity_name,
-                    selectable_name,
-                    new_file_path_string_tsx,
-                    new_file_path_tsx,
-                    true,
-                    SelectionType::Scalar(()),
-                )),
-            ]
+    // Corresponds to TYPE in the generated pointer
+    let target_range = match action {
+        IsographCodeAction::CreateNewScalarSelectable(_) => None,
+        IsographCodeAction::CreateNewObjectSelectable(entity_name_and_selectable_name) => {
+            let left_char = (15
+                + entity_name_and_selectable_name
+                    .parent_entity_name
+                    .lookup()
+                    .len()
+                + entity_name_and_selectable_name
+                    .selectable_name
+                    .lookup()
+                    .len()) as u32;
+            Range::new(
+                Position {
+                    line: 3,
+                    character: left_char,
+                },
+                Position {
+                    line: 3,
+                    character: left_char + 4,
+                },
+            )
+            .wrap_some()
         }
-        IsographCodeAction::CreateNewObjectSelectable(
-            parent_object_entity_name_and_selectable_name,
-        ) => {
-            let parent_entity_name =
-                parent_object_entity_name_and_selectable_name.parent_entity_name;
-            let selectable_name = parent_object_entity_name_and_selectable_name.selectable_name;
+    };
+
+    action
+        .new_files()
+        .into_iter()
+        .map(|new_file| {
             let new_file_path_string = format!(
-                "{}/{}/{}.ts",
+                "{}/{}",
                 config.project_root.to_str().expect(
                     "Expected project root to be able to be turned into a string. \
                     This is indicative of a bug in Isograph."
                 ),
-                parent_entity_name,
-                selectable_name
+                new_file.path
             );
             let new_file_path = Uri::from_str(&new_file_path_string).expect(
                 "Expected uri to be valid. \
                 This is indicative of a bug in Isograph.",
             );
-
-            vec![CodeActionOrCommand::CodeAction(
-                create_new_selectable_code_action(
-                    parent_entity_name,
-                    selectable_name,
-                    new_file_path_string.clone(),
-                    new_file_path.clone(),
-                    false,
-                    SelectionType::Object(()),
-                ),
-            )]
-        }
-    }
+            CodeActionOrCommand::CodeAction(create_new_file_code_action(
+                new_file,
+                new_file_path_string,
+                new_file_path,
+                target_range,
+            ))
+        })
+        .collect()
 }
 
-fn create_new_selectable_code_action(
-    parent_entity_name: EntityName,
-    selectable_name: SelectableName,
+fn create_new_file_code_action(
+    new_file: IsographCodeActionNewFile,
     new_file_path_string: String,
     new_file_path: Uri,
-    // TODO it would be more elegant to make should_add_component_annotation
-    // in the SelectionType::Scalar variant of selectable_type
-    // And to make the tsx ending part of that...
-    should_add_component_annotation: bool,
-    selectable_type: SelectionType<(), ()>,
+    target_range: Option<Range>,
 ) -> CodeAction {
-    let indent = "  ";
-
-    let component_annotation = if should_add_component_annotation {
-        "@component "
-    } else {
-        ""
-    };
-
-    let (keyword, to_section, target_range) = match selectable_type {
-        SelectionType::Scalar(_) => ("field", "", None),
-        SelectionType::Object(_) => {
-            let left_char =
-                (15 + parent_entity_name.lookup().len() + selectable_name.lookup().len()) as u32;
-            (
-                "pointer",
-                " to TYPE",
-                // Corresponds to TYPE in the generated output...
-                Range::new(
-                    Position {
-                        line: 3,
-                        character: left_char,
-                    },
-                    Position {
-                        line: 3,
-                        character: left_char + 4,
-                    },
-                )
-                .wrap_some(),
-            )
-        }
-    };
-
     CodeAction {
-        title: format!(
-            "Create new {component_annotation}{keyword} named `{}.{}`",
-            parent_entity_name, selectable_name
-        ),
+        title: new_file.title,
         edit: WorkspaceEdit {
             document_changes: DocumentChanges::Operations(vec![
                 DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
@@ -212,16 +135,7 @@ fn create_new_selectable_code_action(
                                 character: 0,
                             },
                         },
-                        new_text: format!(
-                            "import {{ iso }} from '@iso';\n\
-                            \n\
-                            export const {parent_entity_name}__{selectable_name} = iso(`\n\
-                            {indent}{keyword} {parent_entity_name}.{selectable_name}{to_section} {component_annotation}{{\n\
-                            {indent}}}\n\
-                            `)(({{ data }}) => {{\n\
-                            {indent}return null;\n\
-                            }})\n",
-                        ),
+                        new_text: new_file.content,
                     })],
                 }),
             ])
@@ -232,7 +146,8 @@ fn create_new_selectable_code_action(
         command: OpenFileIsographLspCommand::command(OpenFileIsographLspCommandParams {
             uri_string: new_file_path_string,
             target_range,
-        }).wrap_some(),
+        })
+        .wrap_some(),
         ..Default::default()
     }
 }
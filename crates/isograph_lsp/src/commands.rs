@@ -1,11 +1,13 @@
-use std::{ops::ControlFlow, str::FromStr};
+use std::{ops::ControlFlow, path::Path, str::FromStr};
 
+use isograph_compiler::profile_file_for_project;
 use isograph_schema::CompilationProfile;
 use lsp_server::{Message, RequestId};
 use lsp_types::{
     Command, Range, ShowDocumentParams, Uri,
     request::{ExecuteCommand, Request, ShowDocument},
 };
+use pico::Database;
 use prelude::Postfix;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
@@ -17,7 +19,11 @@ use crate::{
 };
 
 pub fn all_commands() -> Vec<String> {
-    vec![OpenFileIsographLspCommand::METHOD.to_string()]
+    vec![
+        OpenFileIsographLspCommand::METHOD.to_string(),
+        StartProfileIsographLspCommand::METHOD.to_string(),
+        WriteProfileIsographLspCommand::METHOD.to_string(),
+    ]
 }
 
 pub(crate) trait IsographLspCommand {
@@ -88,6 +94,74 @@ impl IsographLspCommand for OpenFileIsographLspCommand {
     }
 }
 
+pub(crate) struct StartProfileIsographLspCommand {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StartProfileIsographLspCommandParams {}
+
+impl IsographLspCommand for StartProfileIsographLspCommand {
+    const METHOD: &'static str = "iso_start_profile";
+    const TITLE: &'static str = "Isograph: Start profiling";
+    type Params = StartProfileIsographLspCommandParams;
+
+    /// Records the memoized function calls made from now on, for each project,
+    /// until the profile is written via `iso_write_profile`. Profiling is not
+    /// enabled by default, because every call is recorded until a source changes.
+    fn handler<TCompilationProfile: CompilationProfile>(
+        state: &LspState<TCompilationProfile>,
+        _lsp_command_params: <StartProfileIsographLspCommand as IsographLspCommand>::Params,
+    ) -> LSPRuntimeResult<Option<Value>> {
+        for compiler_state in &state.compiler_states {
+            compiler_state.db.get_storage().enable_profiling();
+        }
+
+        None.wrap_ok()
+    }
+}
+
+pub(crate) struct WriteProfileIsographLspCommand {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WriteProfileIsographLspCommandParams {
+    /// Written as a Graphviz DOT file if the extension is `.dot` or `.gv`, and
+    /// as a Chrome trace otherwise.
+    pub path: String,
+}
+
+impl IsographLspCommand for WriteProfileIsographLspCommand {
+    const METHOD: &'static str = "iso_write_profile";
+    const TITLE: &'static str = "Isograph: Write profile";
+    type Params = WriteProfileIsographLspCommandParams;
+
+    /// Writes the memoized function calls made since profiling was started via
+    /// `iso_start_profile` (or since the sources last changed, if later), for
+    /// each project, and stops profiling.
+    fn handler<TCompilationProfile: CompilationProfile>(
+        state: &LspState<TCompilationProfile>,
+        lsp_command_params: <WriteProfileIsographLspCommand as IsographLspCommand>::Params,
+    ) -> LSPRuntimeResult<Option<Value>> {
+        if !state.is_profiling_enabled() {
+            return LSPRuntimeError::UnexpectedError(format!(
+                "Profiling has not been started. Run `{}` first.",
+                StartProfileIsographLspCommand::TITLE
+            ))
+            .wrap_err();
+        }
+
+        let is_multi_project = state.compiler_states.len() > 1;
+        for (project_index, compiler_state) in state.compiler_states.iter().enumerate() {
+            compiler_state.write_profile(&profile_file_for_project(
+                Path::new(&lsp_command_params.path),
+                project_index,
+                is_multi_project,
+            ));
+            compiler_state.db.get_storage().disable_profiling();
+        }
+
+        None.wrap_ok()
+    }
+}
+
 pub fn on_command<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <ExecuteCommand as Request>::Params,
@@ -95,6 +169,8 @@ pub fn on_command<TCompilationProfile: CompilationProfile>(
     let get_response = || {
         let retrieved_params = LspIsographCommandDispatch::new(params, lsp_state)
             .on_command_sync::<OpenFileIsographLspCommand>()?
+            .on_command_sync::<StartProfileIsographLspCommand>()?
+            .on_command_sync::<WriteProfileIsographLspCommand>()?
             .params();
 
         ControlFlow::Continue(retrieved_params)
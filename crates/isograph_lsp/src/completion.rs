@@ -26,7 +26,7 @@ pub fn on_completion<TCompilationProfile: CompilationProfile>(
     let url = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
 
-    let db = &lsp_state.compiler_state.db;
+    let db = &lsp_state.compiler_state_for_uri(&url)?.db;
 
     let current_working_directory = db.get_current_working_directory();
 
@@ -26,7 +26,9 @@ pub fn on_document_highlight<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <DocumentHighlightRequest as Request>::Params,
 ) -> LSPRuntimeResult<<DocumentHighlightRequest as Request>::Result> {
-    let db = &lsp_state.compiler_state.db;
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document_position_params.text_document.uri)?
+        .db;
     on_document_highlight_impl(
         db,
         params.text_document_position_params.text_document.uri,
@@ -23,8 +23,8 @@ pub fn on_format<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <Formatting as Request>::Params,
 ) -> LSPRuntimeResult<<Formatting as Request>::Result> {
-    let db = &lsp_state.compiler_state.db;
     let url = params.text_document.uri;
+    let db = &lsp_state.compiler_state_for_uri(&url)?.db;
 
     let current_working_directory = db.get_current_working_directory();
 
@@ -44,6 +44,7 @@ pub fn on_format<TCompilationProfile: CompilationProfile>(
 
     extracted_items
         .iter()
+        .filter_map(|extraction| extraction.as_ref().ok())
         .flat_map(|extraction| {
             TextEdit {
                 range: get_range_of_extraction(extraction, content),
@@ -1,6 +1,6 @@
 use crate::{
     hover::get_iso_literal_extraction_from_text_position_params,
-    location_utils::isograph_location_to_lsp_location,
+    location_utils::{isograph_location_to_lsp_location, schema_location_to_lsp_location},
     lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
     lsp_state::LspState,
     uri_file_path_ext::UriFilePathExt,
@@ -29,7 +29,9 @@ pub fn on_goto_definition<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <GotoDefinition as Request>::Params,
 ) -> LSPRuntimeResult<Option<GotoDefinitionResponse>> {
-    let db = &lsp_state.compiler_state.db;
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document_position_params.text_document.uri)?
+        .db;
     on_goto_definition_impl(
         db,
         params.text_document_position_params.text_document.uri,
@@ -82,13 +84,7 @@ pub fn on_goto_definition_impl<TCompilationProfile: CompilationProfile>(
                                 parent.name.item,
                                 server_selectable.lookup(db).name.item,
                             )
-                            .and_then(|location| {
-                                isograph_location_to_lsp_location(
-                                    db,
-                                    location,
-                                    db.get_schema_source().content.reference(),
-                                )
-                            })
+                            .and_then(|location| schema_location_to_lsp_location(db, location))
                             .map(lsp_location_to_scalar_response)
                         }
                         DefinitionLocation::Client(client_selectable) => {
@@ -129,13 +125,7 @@ pub fn on_goto_definition_impl<TCompilationProfile: CompilationProfile>(
                                 parent.name.item,
                                 server_selectable.lookup(db).name.item,
                             )
-                            .and_then(|location| {
-                                isograph_location_to_lsp_location(
-                                    db,
-                                    location,
-                                    db.get_schema_source().content.reference(),
-                                )
-                            })
+                            .and_then(|location| schema_location_to_lsp_location(db, location))
                             .map(lsp_location_to_scalar_response)
                         }
                         DefinitionLocation::Client(client_selectable) => {
@@ -179,13 +169,7 @@ pub fn on_goto_definition_impl<TCompilationProfile: CompilationProfile>(
                 };
 
                 selectable_definition_location(db, parent_type_name.0, wrapper.inner.0)
-                    .and_then(|location| {
-                        isograph_location_to_lsp_location(
-                            db,
-                            location,
-                            &db.get_schema_source().content,
-                        )
-                    })
+                    .and_then(|location| schema_location_to_lsp_location(db, location))
                     .map(lsp_location_to_scalar_response)
             }
             IsographResolvedNode::ClientObjectSelectableNameWrapper(object_wrapper_path) => {
@@ -198,13 +182,7 @@ pub fn on_goto_definition_impl<TCompilationProfile: CompilationProfile>(
                 };
 
                 selectable_definition_location(db, parent_type_name.0, object_wrapper_path.inner.0)
-                    .and_then(|location| {
-                        isograph_location_to_lsp_location(
-                            db,
-                            location,
-                            &db.get_schema_source().content,
-                        )
-                    })
+                    .and_then(|location| schema_location_to_lsp_location(db, location))
                     .map(lsp_location_to_scalar_response)
             }
             IsographResolvedNode::SelectionSet(_) => None,
@@ -235,8 +213,7 @@ fn goto_entity_definition<TCompilationProfile: CompilationProfile>(
         .ok_or(LSPRuntimeError::ExpectedError)?;
 
     GotoDefinitionResponse::Scalar(
-        isograph_location_to_lsp_location(db, location, &db.get_schema_source().content)
-            .ok_or(LSPRuntimeError::ExpectedError)?,
+        schema_location_to_lsp_location(db, location).ok_or(LSPRuntimeError::ExpectedError)?,
     )
     .wrap_some()
     .wrap_ok()
@@ -33,7 +33,9 @@ pub fn on_hover<TCompilationProfile: CompilationProfile>(
     lsp_state: &LspState<TCompilationProfile>,
     params: <HoverRequest as Request>::Params,
 ) -> LSPRuntimeResult<<HoverRequest as Request>::Result> {
-    let db = &lsp_state.compiler_state.db;
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document_position_params.text_document.uri)?
+        .db;
     on_hover_impl(
         db,
         params.text_document_position_params.text_document.uri,
@@ -197,7 +199,13 @@ pub fn get_iso_literal_extraction_from_text_position_params<
         };
 
     let extracted_items = extract_iso_literals_from_file_content(db, relative_path_to_source_file);
-    find_iso_literal_extraction_under_cursor(line_char, content, extracted_items)
+    find_iso_literal_extraction_under_cursor(
+        line_char,
+        content,
+        extracted_items
+            .iter()
+            .filter_map(|extraction| extraction.as_ref().ok()),
+    )
 }
 
 fn find_iso_literal_extraction_under_cursor<'a>(
@@ -20,19 +20,25 @@ pub mod lsp_notification_dispatch;
 mod lsp_request_dispatch;
 pub mod lsp_runtime_error;
 mod lsp_state;
+mod references;
+mod rename;
+mod selectable_references;
 mod semantic_tokens;
 pub mod server;
+mod symbols;
+#[cfg(test)]
+mod test_utils;
 pub mod text_document;
 mod uri_file_path_ext;
 
 pub async fn start_language_server<TCompilationProfile: CompilationProfile>(
-    config: CompilerConfig,
+    configs: Vec<CompilerConfig>,
     current_working_directory: CurrentWorkingDirectory,
 ) -> LocationFreeDiagnosticVecResult<()> {
     eprintln!("Starting language server");
     let (connection, io_handles) = Connection::stdio();
     let params = server::initialize(&connection).map_err(|e| e.wrap_vec())?;
-    server::run::<TCompilationProfile>(connection, config, params, current_working_directory)
+    server::run::<TCompilationProfile>(connection, configs, params, current_working_directory)
         .await?;
     io_handles
         .join()
@@ -2,8 +2,11 @@ use std::{borrow::Cow, path::PathBuf, str::FromStr};
 
 use common_lang_types::EmbeddedLocation;
 use intern::string_key::Lookup;
-use isograph_schema::{CompilationProfile, IsographDatabase};
+use isograph_schema::{
+    CompilationProfile, IsographDatabase, read_iso_literals_source_from_relative_path,
+};
 use lsp_types::{Range, Uri};
+use prelude::Postfix;
 
 use crate::{format::char_index_to_position, uri_file_path_ext::UriFilePathExt};
 
@@ -66,3 +69,34 @@ pub fn isograph_location_to_lsp_location<TCompilationProfile: CompilationProfile
         },
     })
 }
+
+/// Converts a location within an iso literal into an LSP location. Open files are respected.
+pub(crate) fn iso_literal_location_to_lsp_location<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    location: EmbeddedLocation,
+) -> Option<lsp_types::Location> {
+    isograph_location_to_lsp_location(
+        db,
+        location,
+        read_iso_literals_source_from_relative_path(
+            db,
+            location.text_source.relative_path_to_source_file,
+        )
+        .as_ref()?
+        .content
+        .reference(),
+    )
+}
+
+/// Converts a location within a schema file or schema extension into an LSP location.
+pub(crate) fn schema_location_to_lsp_location<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    location: EmbeddedLocation,
+) -> Option<lsp_types::Location> {
+    isograph_location_to_lsp_location(
+        db,
+        location,
+        &db.get_schema_source(location.text_source.relative_path_to_source_file)?
+            .content,
+    )
+}
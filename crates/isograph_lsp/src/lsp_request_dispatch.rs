@@ -4,6 +4,7 @@ use lsp_server::{
     ErrorCode, Request as ServerRequest, RequestId as ServerRequestId, Response, ResponseError,
 };
 use lsp_types::request::Request;
+use pico::Cancelled;
 use serde_json::Value;
 
 use crate::lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult};
@@ -30,13 +31,17 @@ impl<'state, TState> LSPRequestDispatch<'state, TState> {
         if self.request.method == TRequest::METHOD {
             match extract_request_params::<TRequest>(self.request) {
                 Ok((request_id, params)) => {
-                    let response = handler(self.state, params).and_then(|handler_result| {
-                        serde_json::to_value(handler_result).map_err(|_err| {
-                            LSPRuntimeError::UnexpectedError(
-                                "Unable to serialize request response".to_string(),
-                            )
-                        })
-                    });
+                    // If a document changes while the handler is executing, the memoized
+                    // functions it calls unwind, and the (stale) request is abandoned.
+                    let response = Cancelled::catch(|| handler(self.state, params))
+                        .unwrap_or(Err(LSPRuntimeError::Cancelled))
+                        .and_then(|handler_result| {
+                            serde_json::to_value(handler_result).map_err(|_err| {
+                                LSPRuntimeError::UnexpectedError(
+                                    "Unable to serialize request response".to_string(),
+                                )
+                            })
+                        });
                     let server_response = convert_to_lsp_response(request_id, response);
 
                     return ControlFlow::Break(server_response);
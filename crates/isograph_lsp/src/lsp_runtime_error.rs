@@ -7,6 +7,9 @@ pub type LSPRuntimeResult<T> = std::result::Result<T, LSPRuntimeError>;
 pub enum LSPRuntimeError {
     ExpectedError,
     UnexpectedError(String),
+    /// The request was cancelled, because a document changed while it was
+    /// being handled.
+    Cancelled,
 }
 
 impl From<LSPRuntimeError> for Option<ResponseError> {
@@ -18,6 +21,11 @@ impl From<LSPRuntimeError> for Option<ResponseError> {
                 message,
                 data: None,
             }),
+            LSPRuntimeError::Cancelled => Some(ResponseError {
+                code: ErrorCode::ContentModified as i32,
+                message: "The request was cancelled, because a document changed.".to_string(),
+                data: None,
+            }),
         }
     }
 }
@@ -1,20 +1,258 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
 use crossbeam::channel::Sender;
 use isograph_compiler::CompilerState;
 use isograph_schema::CompilationProfile;
+use lsp_types::Uri;
+use pico::{CancellationHandle, Database};
+use prelude::Postfix;
+
+use crate::{
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    uri_file_path_ext::UriFilePathExt,
+};
 
 pub struct LspState<'a, TCompilationProfile: CompilationProfile> {
-    pub compiler_state: CompilerState<TCompilationProfile>,
+    /// One compiler state per project in the config.
+    pub compiler_states: Vec<CompilerState<TCompilationProfile>>,
     pub sender: &'a Sender<lsp_server::Message>,
+    cancellation_handles: SharedCancellationHandles,
 }
 
 impl<'a, TCompilationProfile: CompilationProfile> LspState<'a, TCompilationProfile> {
     pub fn new(
-        compiler_state: CompilerState<TCompilationProfile>,
+        compiler_states: Vec<CompilerState<TCompilationProfile>>,
         sender: &'a Sender<lsp_server::Message>,
     ) -> Self {
-        LspState {
-            compiler_state,
+        let mut lsp_state = LspState {
+            compiler_states: vec![],
             sender,
+            cancellation_handles: SharedCancellationHandles::default(),
+        };
+        lsp_state.replace_compiler_states(compiler_states);
+        lsp_state
+    }
+
+    /// Replaces the compiler states, e.g. when the config changes. The
+    /// [cancellation handles][LspState::cancellation_handles] cancel the new
+    /// compiler states from then on.
+    pub fn replace_compiler_states(
+        &mut self,
+        compiler_states: Vec<CompilerState<TCompilationProfile>>,
+    ) {
+        // Profiling, once started via the `iso_start_profile` command, continues
+        // until the profile is written.
+        if self.is_profiling_enabled() {
+            for compiler_state in &compiler_states {
+                compiler_state.db.get_storage().enable_profiling();
+            }
         }
+        self.cancellation_handles.replace(
+            compiler_states
+                .iter()
+                .map(|compiler_state| compiler_state.db.get_storage().cancellation_handle())
+                .collect(),
+        );
+        self.compiler_states = compiler_states;
+    }
+
+    pub fn is_profiling_enabled(&self) -> bool {
+        self.compiler_states
+            .iter()
+            .any(|compiler_state| compiler_state.db.get_storage().is_profiling_enabled())
+    }
+
+    /// Handles that cancel the memoized functions that are executing in any
+    /// project. They can be used from another thread, and remain valid when the
+    /// compiler states are replaced.
+    pub fn cancellation_handles(&self) -> SharedCancellationHandles {
+        self.cancellation_handles.clone()
+    }
+
+    pub fn clear_cancellation(&self) {
+        for compiler_state in &self.compiler_states {
+            compiler_state.db.get_storage().clear_cancellation();
+        }
+    }
+
+    /// The compiler state of the project whose project root contains the file at `uri`.
+    /// Files outside of every project root are not handled.
+    pub fn compiler_state_for_uri(
+        &self,
+        uri: &Uri,
+    ) -> LSPRuntimeResult<&CompilerState<TCompilationProfile>> {
+        let index = self.project_index_for_uri(uri)?;
+        self.compiler_states[index].reference().wrap_ok()
+    }
+
+    pub fn compiler_state_for_uri_mut(
+        &mut self,
+        uri: &Uri,
+    ) -> LSPRuntimeResult<&mut CompilerState<TCompilationProfile>> {
+        let index = self.project_index_for_uri(uri)?;
+        (&mut self.compiler_states[index]).wrap_ok()
+    }
+
+    fn project_index_for_uri(&self, uri: &Uri) -> LSPRuntimeResult<usize> {
+        let file_path = uri
+            .to_file_path()
+            .map_err(|_| LSPRuntimeError::ExpectedError)?;
+        self.compiler_states
+            .iter()
+            .position(|compiler_state| {
+                file_path.starts_with(&compiler_state.db.get_isograph_config().project_root)
+            })
+            .ok_or(LSPRuntimeError::ExpectedError)
+    }
+}
+
+/// The cancellation handles of the current compiler states, which are shared with
+/// the thread that receives messages.
+#[derive(Debug, Clone, Default)]
+pub struct SharedCancellationHandles(Arc<Mutex<Vec<CancellationHandle>>>);
+
+impl SharedCancellationHandles {
+    pub fn cancel(&self) {
+        for cancellation_handle in self.lock().iter() {
+            cancellation_handle.cancel();
+        }
+    }
+
+    fn replace(&self, cancellation_handles: Vec<CancellationHandle>) {
+        *self.lock() = cancellation_handles;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<CancellationHandle>> {
+        self.0
+            .lock()
+            .expect("Expected cancellation handles not to be poisoned. This is indicative of a bug in Isograph.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::CurrentWorkingDirectory;
+    use graphql_network_protocol::GraphQLAndJavascriptProfile;
+    use intern::string_key::Intern;
+    use isograph_compiler::{CompilerState, watch::create_compiler_states};
+    use isograph_config::create_configs;
+    use isograph_schema::{CompilationProfile, validate_entire_schema};
+    use lsp_types::ExecuteCommandParams;
+    use pico::Cancelled;
+    use serde_json::json;
+    use tests::TestProject;
+
+    use super::LspState;
+    use crate::{
+        commands::on_command,
+        lsp_runtime_error::LSPRuntimeError,
+        test_utils::{test_project, uri},
+    };
+
+    /// Loads the compiler states like the language server does when it starts, or
+    /// when the config changes.
+    fn compiler_states(project: &TestProject) -> Vec<CompilerState<GraphQLAndJavascriptProfile>> {
+        let current_working_directory: CurrentWorkingDirectory =
+            project.directory.to_string_lossy().intern().into();
+        create_compiler_states(
+            create_configs(
+                &project.path("isograph.config.json"),
+                current_working_directory,
+            ),
+            current_working_directory,
+        )
+        .unwrap_or_else(|_| panic!("Expected project to be loaded"))
+    }
+
+    #[test]
+    fn cancellation_handles_cancel_compiler_states_created_after_config_reload() {
+        let project = test_project("symbols");
+        let (sender, _receiver) = crossbeam::channel::unbounded();
+        let mut lsp_state = LspState::new(compiler_states(&project), &sender);
+        let cancellation_handles = lsp_state.cancellation_handles();
+
+        lsp_state.replace_compiler_states(compiler_states(&project));
+        cancellation_handles.cancel();
+
+        for compiler_state in &lsp_state.compiler_states {
+            assert!(
+                Cancelled::catch(|| validate_entire_schema(&compiler_state.db).to_owned()).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn uris_are_routed_to_the_project_that_contains_them() {
+        let project = test_project("projects");
+        let (sender, _receiver) = crossbeam::channel::unbounded();
+        let lsp_state = LspState::new(compiler_states(&project), &sender);
+        assert_eq!(lsp_state.compiler_states.len(), 2);
+
+        for project_name in ["a", "b"] {
+            let compiler_state = lsp_state
+                .compiler_state_for_uri(&uri(&project, &format!("{project_name}/src/HomePage.ts")))
+                .unwrap_or_else(|_| panic!("Expected a project to contain the file"));
+            assert_eq!(
+                compiler_state.db.get_isograph_config().project_root,
+                project.path(&format!("{project_name}/src"))
+            );
+        }
+
+        for path in ["a/schema.graphql", "HomePage.ts"] {
+            assert_eq!(
+                lsp_state
+                    .compiler_state_for_uri(&uri(&project, path))
+                    .map(|_| ()),
+                Err(LSPRuntimeError::ExpectedError)
+            );
+        }
+    }
+
+    fn execute_command<TCompilationProfile: CompilationProfile>(
+        lsp_state: &LspState<TCompilationProfile>,
+        command: &str,
+        argument: serde_json::Value,
+    ) -> Result<(), LSPRuntimeError> {
+        on_command(
+            lsp_state,
+            ExecuteCommandParams {
+                command: command.to_string(),
+                arguments: vec![argument],
+                work_done_progress_params: Default::default(),
+            },
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn profiling_is_only_enabled_until_the_profile_is_written() {
+        let project = test_project("symbols");
+        let (sender, _receiver) = crossbeam::channel::unbounded();
+        let mut lsp_state = LspState::new(compiler_states(&project), &sender);
+        let profile_file = project.path("profile.json");
+        let write_profile_argument = json!({ "path": profile_file });
+        assert!(!lsp_state.is_profiling_enabled());
+        assert!(
+            execute_command(
+                &lsp_state,
+                "iso_write_profile",
+                write_profile_argument.clone()
+            )
+            .is_err()
+        );
+        assert!(!profile_file.exists());
+
+        execute_command(&lsp_state, "iso_start_profile", json!({}))
+            .unwrap_or_else(|_| panic!("Expected profiling to start"));
+        assert!(lsp_state.is_profiling_enabled());
+
+        // Profiling continues when the config changes
+        lsp_state.replace_compiler_states(compiler_states(&project));
+        assert!(lsp_state.is_profiling_enabled());
+
+        execute_command(&lsp_state, "iso_write_profile", write_profile_argument)
+            .unwrap_or_else(|_| panic!("Expected profile to be written"));
+        assert!(profile_file.exists());
+        assert!(!lsp_state.is_profiling_enabled());
     }
 }
@@ -0,0 +1,277 @@
+use common_lang_types::{
+    EmbeddedLocation, EntityName, SelectableName, Span,
+    relative_path_from_absolute_and_working_directory,
+};
+use isograph_lang_types::{
+    ClientObjectSelectableNameWrapperParent, ClientScalarSelectableNameWrapperParent,
+    DefinitionLocation, IsographResolvedNode, SelectionType,
+};
+use isograph_schema::{
+    CompilationProfile, IsographDatabase, client_selectable_declaration,
+    client_selectable_defined_by_network_protocol_location,
+    get_parent_and_selectable_for_object_path, get_parent_and_selectable_for_scalar_path,
+    process_iso_literal_extraction, selectable_definition_location, selectable_named,
+};
+use lsp_types::{
+    Position, Uri,
+    request::{References, Request},
+};
+use pico_macros::memo;
+use prelude::Postfix;
+use resolve_position::ResolvePosition;
+
+use crate::{
+    hover::get_iso_literal_extraction_from_text_position_params,
+    location_utils::{iso_literal_location_to_lsp_location, schema_location_to_lsp_location},
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LspState,
+    selectable_references::selectable_reference_locations,
+    uri_file_path_ext::UriFilePathExt,
+};
+
+pub fn on_references<TCompilationProfile: CompilationProfile>(
+    lsp_state: &LspState<TCompilationProfile>,
+    params: <References as Request>::Params,
+) -> LSPRuntimeResult<<References as Request>::Result> {
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document_position.text_document.uri)?
+        .db;
+    on_references_impl(
+        db,
+        params.text_document_position.text_document.uri,
+        params.text_document_position.position,
+        params.context.include_declaration,
+    )
+    .to_owned()
+}
+
+#[memo]
+fn on_references_impl<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+    position: Position,
+    include_declaration: bool,
+) -> LSPRuntimeResult<<References as Request>::Result> {
+    let (parent_entity_name, selectable_name, _location) =
+        match selectable_at_position(db, url, position)? {
+            Some(selectable) => selectable,
+            None => return Ok(None),
+        };
+
+    let mut locations = vec![];
+
+    if include_declaration {
+        let declaration_location = match selectable_named(db, parent_entity_name, selectable_name) {
+            Ok(Some(DefinitionLocation::Server(_))) => {
+                selectable_definition_location(db, parent_entity_name, selectable_name)
+                    .and_then(|location| schema_location_to_lsp_location(db, location))
+            }
+            _ => match client_selectable_declaration(db, parent_entity_name, selectable_name) {
+                Some(declaration) => iso_literal_location_to_lsp_location(
+                    db,
+                    match declaration {
+                        SelectionType::Scalar(s) => s.lookup(db).client_field_name.location,
+                        SelectionType::Object(o) => o.lookup(db).client_pointer_name.location,
+                    },
+                ),
+                // Fields defined via `@exposeField` are declared by that directive in the
+                // schema. Other client selectables that are not declared in an iso literal
+                // (e.g. `__refetch` and `__link`) have no declaration.
+                None => client_selectable_defined_by_network_protocol_location(
+                    db,
+                    parent_entity_name,
+                    selectable_name,
+                )
+                .to_owned()
+                .and_then(|location| schema_location_to_lsp_location(db, location)),
+            },
+        };
+        locations.extend(declaration_location);
+    }
+
+    locations.extend(
+        selectable_reference_locations(db, parent_entity_name, selectable_name)
+            .iter()
+            .filter_map(|reference| iso_literal_location_to_lsp_location(db, reference.location)),
+    );
+
+    locations.wrap_some().wrap_ok()
+}
+
+/// If the cursor is on the name of a selectable (either in a selection, or in the declaration
+/// of a client field, client pointer or entrypoint), returns the parent entity name, the
+/// selectable name and the location of the name under the cursor.
+pub(crate) fn selectable_at_position<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+    position: Position,
+) -> LSPRuntimeResult<Option<(EntityName, SelectableName, EmbeddedLocation)>> {
+    let current_working_directory = db.get_current_working_directory();
+
+    let relative_path_to_source_file = relative_path_from_absolute_and_working_directory(
+        current_working_directory,
+        &url.to_file_path().expect("Expected file path to be valid."),
+    );
+
+    let extraction_option =
+        get_iso_literal_extraction_from_text_position_params(db, url, position.into()).to_owned();
+    let (extraction, offset) = match extraction_option {
+        Some(e) => e,
+        None => return Ok(None),
+    };
+
+    let (result, _text_source) =
+        match process_iso_literal_extraction(db, &extraction, relative_path_to_source_file) {
+            Ok(result) => result,
+            Err(_) => return Ok(None),
+        };
+
+    let (parent_entity_name, selectable_name, location) = match result
+        .resolve((), Span::new(offset, offset))
+    {
+        IsographResolvedNode::ScalarSelection(scalar_path) => {
+            match get_parent_and_selectable_for_scalar_path(db, &scalar_path) {
+                Ok((parent, _selectable)) => (
+                    parent.lookup(db).name.item,
+                    scalar_path.inner.name.item,
+                    scalar_path.inner.name.location,
+                ),
+                _ => return Ok(None),
+            }
+        }
+        IsographResolvedNode::ObjectSelection(object_path) => {
+            match get_parent_and_selectable_for_object_path(db, &object_path) {
+                Ok((parent, _selectable)) => (
+                    parent.lookup(db).name.item,
+                    object_path.inner.name.item,
+                    object_path.inner.name.location,
+                ),
+                _ => return Ok(None),
+            }
+        }
+        IsographResolvedNode::ClientScalarSelectableNameWrapper(wrapper) => {
+            let (parent_type, client_field_name) = match wrapper.parent {
+                ClientScalarSelectableNameWrapperParent::EntrypointDeclaration(entrypoint) => (
+                    entrypoint.inner.parent_type.item,
+                    entrypoint.inner.client_field_name,
+                ),
+                ClientScalarSelectableNameWrapperParent::ClientFieldDeclaration(field) => {
+                    (field.inner.parent_type.item, field.inner.client_field_name)
+                }
+            };
+            (
+                parent_type.0,
+                client_field_name.item.0,
+                client_field_name.location,
+            )
+        }
+        IsographResolvedNode::ClientObjectSelectableNameWrapper(wrapper) => match wrapper.parent {
+            ClientObjectSelectableNameWrapperParent::ClientPointerDeclaration(pointer) => (
+                pointer.inner.parent_type.item.0,
+                pointer.inner.client_pointer_name.item.0,
+                pointer.inner.client_pointer_name.location,
+            ),
+        },
+        IsographResolvedNode::ClientFieldDeclaration(_)
+        | IsographResolvedNode::ClientPointerDeclaration(_)
+        | IsographResolvedNode::EntrypointDeclaration(_)
+        | IsographResolvedNode::EntityNameWrapper(_)
+        | IsographResolvedNode::Description(_)
+        | IsographResolvedNode::SelectionSet(_)
+        | IsographResolvedNode::TypeAnnotation(_)
+        | IsographResolvedNode::VariableNameWrapper(_)
+        | IsographResolvedNode::VariableDeclarationInner(_) => return Ok(None),
+    };
+
+    (parent_entity_name, selectable_name, location)
+        .wrap_some()
+        .wrap_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{Location, Position, Range};
+
+    use super::on_references_impl;
+    use crate::test_utils::{position, test_project, uri};
+
+    fn location(uri: lsp_types::Uri, start: Position, length: u32) -> Location {
+        Location {
+            uri,
+            range: Range {
+                start,
+                end: Position {
+                    line: start.line,
+                    character: start.character + length,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn references_of_client_field_include_declaration_and_selections() {
+        let project = test_project("references");
+        let pet = uri(&project, "src/Pet.ts");
+
+        let locations = on_references_impl(
+            project.db(),
+            pet.clone(),
+            position(&project, "src/Pet.ts", "PetName\n"),
+            true,
+        )
+        .to_owned()
+        .expect("Expected references to succeed")
+        .expect("Expected references");
+
+        assert_eq!(
+            locations,
+            vec![
+                location(
+                    pet.clone(),
+                    position(&project, "src/Pet.ts", "PetName {"),
+                    7
+                ),
+                location(pet, position(&project, "src/Pet.ts", "PetName\n"), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn references_of_exposed_field_include_expose_field_directive() {
+        let project = test_project("references");
+        let pet = uri(&project, "src/Pet.ts");
+        let selection = location(pet.clone(), position(&project, "src/Pet.ts", "set_name"), 8);
+
+        let locations = on_references_impl(
+            project.db(),
+            pet.clone(),
+            position(&project, "src/Pet.ts", "set_name"),
+            true,
+        )
+        .to_owned()
+        .expect("Expected references to succeed")
+        .expect("Expected references");
+        assert_eq!(
+            locations,
+            vec![
+                location(
+                    uri(&project, "schema-extension.graphql"),
+                    position(&project, "schema-extension.graphql", "exposeField"),
+                    11
+                ),
+                selection.clone(),
+            ]
+        );
+
+        let locations = on_references_impl(
+            project.db(),
+            pet,
+            position(&project, "src/Pet.ts", "set_name"),
+            false,
+        )
+        .to_owned()
+        .expect("Expected references to succeed")
+        .expect("Expected references");
+        assert_eq!(locations, vec![selection]);
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use common_lang_types::{EmbeddedLocation, EntityName, SelectableName};
+use intern::string_key::Intern;
+use isograph_lang_types::SelectionType;
+use isograph_schema::{
+    CompilationProfile, IsographDatabase, client_selectable_declaration, selectable_named,
+};
+use lsp_types::{
+    Position, PrepareRenameResponse, TextEdit, Uri, WorkspaceEdit,
+    request::{PrepareRenameRequest, Rename, Request},
+};
+use pico_macros::memo;
+use prelude::Postfix;
+
+use crate::{
+    location_utils::iso_literal_location_to_lsp_location,
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LspState,
+    references::selectable_at_position,
+    selectable_references::selectable_reference_locations,
+};
+
+pub fn on_prepare_rename<TCompilationProfile: CompilationProfile>(
+    lsp_state: &LspState<TCompilationProfile>,
+    params: <PrepareRenameRequest as Request>::Params,
+) -> LSPRuntimeResult<<PrepareRenameRequest as Request>::Result> {
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document.uri)?
+        .db;
+    on_prepare_rename_impl(db, params.text_document.uri, params.position).to_owned()
+}
+
+#[memo]
+fn on_prepare_rename_impl<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+    position: Position,
+) -> LSPRuntimeResult<<PrepareRenameRequest as Request>::Result> {
+    let (_parent_entity_name, selectable_name, location) =
+        match renameable_client_selectable_at_position(db, url, position)? {
+            Some(selectable) => selectable,
+            None => return Ok(None),
+        };
+
+    PrepareRenameResponse::RangeWithPlaceholder {
+        range: iso_literal_location_to_lsp_location(db, location)
+            .ok_or(LSPRuntimeError::ExpectedError)?
+            .range,
+        placeholder: selectable_name.to_string(),
+    }
+    .wrap_some()
+    .wrap_ok()
+}
+
+pub fn on_rename<TCompilationProfile: CompilationProfile>(
+    lsp_state: &LspState<TCompilationProfile>,
+    params: <Rename as Request>::Params,
+) -> LSPRuntimeResult<<Rename as Request>::Result> {
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document_position.text_document.uri)?
+        .db;
+    on_rename_impl(
+        db,
+        params.text_document_position.text_document.uri,
+        params.text_document_position.position,
+        params.new_name,
+    )
+    .to_owned()
+}
+
+#[memo]
+fn on_rename_impl<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+    position: Position,
+    new_name: String,
+) -> LSPRuntimeResult<<Rename as Request>::Result> {
+    let (parent_entity_name, selectable_name, _location) =
+        match renameable_client_selectable_at_position(db, url, position)? {
+            Some(selectable) => selectable,
+            None => return Ok(None),
+        };
+
+    if !is_valid_selectable_name(&new_name) {
+        return LSPRuntimeError::UnexpectedError(format!(
+            "`{new_name}` is not a valid field name."
+        ))
+        .wrap_err();
+    }
+    let new_selectable_name: SelectableName = new_name.as_str().intern().into();
+    if new_selectable_name == selectable_name {
+        return Ok(None);
+    }
+    if let Ok(Some(_)) = selectable_named(db, parent_entity_name, new_selectable_name) {
+        return LSPRuntimeError::UnexpectedError(format!(
+            "`{parent_entity_name}.{new_selectable_name}` already exists."
+        ))
+        .wrap_err();
+    }
+
+    let declaration_location =
+        match client_selectable_declaration(db, parent_entity_name, selectable_name)
+            .ok_or(LSPRuntimeError::ExpectedError)?
+        {
+            SelectionType::Scalar(s) => s.lookup(db).client_field_name.location,
+            SelectionType::Object(o) => o.lookup(db).client_pointer_name.location,
+        };
+
+    // The data of unaliased selections is read using the name of the selectable (e.g.
+    // `data.selectable_name`). So that the shape of that data does not change, these
+    // selections are aliased to the old name, e.g. `OldName` becomes `OldName: NewName`.
+    let aliased_new_name = format!("{selectable_name}: {new_name}");
+
+    #[allow(clippy::mutable_key_type)]
+    let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+    for (location, new_text) in std::iter::once((&declaration_location, &new_name)).chain(
+        selectable_reference_locations(db, parent_entity_name, selectable_name)
+            .iter()
+            .map(|reference| {
+                (
+                    &reference.location,
+                    if reference.is_unaliased_selection {
+                        &aliased_new_name
+                    } else {
+                        &new_name
+                    },
+                )
+            }),
+    ) {
+        let lsp_location = iso_literal_location_to_lsp_location(db, *location)
+            .ok_or(LSPRuntimeError::ExpectedError)?;
+        changes.entry(lsp_location.uri).or_default().push(TextEdit {
+            range: lsp_location.range,
+            new_text: new_text.clone(),
+        });
+    }
+
+    WorkspaceEdit {
+        changes: changes.wrap_some(),
+        ..Default::default()
+    }
+    .wrap_some()
+    .wrap_ok()
+}
+
+/// Client fields and client pointers that are declared in an iso literal can be renamed.
+/// Server fields, and client fields that are not declared in iso literals (e.g. `__refetch`),
+/// cannot be renamed.
+fn renameable_client_selectable_at_position<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+    position: Position,
+) -> LSPRuntimeResult<Option<(EntityName, SelectableName, EmbeddedLocation)>> {
+    let selectable = match selectable_at_position(db, url, position)? {
+        Some(selectable) => selectable,
+        None => return Ok(None),
+    };
+    let (parent_entity_name, selectable_name, _location) = selectable;
+
+    if client_selectable_declaration(db, parent_entity_name, selectable_name).is_none() {
+        return Ok(None);
+    }
+
+    selectable.wrap_some().wrap_ok()
+}
+
+/// Matches `/[_A-Za-z][_0-9A-Za-z]*/`, i.e. GraphQL names.
+fn is_valid_selectable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first == '_' || first.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{Position, PrepareRenameResponse, Range, TextEdit};
+
+    use super::{on_prepare_rename_impl, on_rename_impl};
+    use crate::{
+        lsp_runtime_error::LSPRuntimeError,
+        test_utils::{position, test_project, uri},
+    };
+
+    fn range(start: Position, length: u32) -> Range {
+        Range {
+            start,
+            end: Position {
+                line: start.line,
+                character: start.character + length,
+            },
+        }
+    }
+
+    #[test]
+    fn renames_declaration_and_aliased_selections() {
+        let project = test_project("rename");
+        let db = project.db();
+
+        let edit = on_rename_impl(
+            db,
+            uri(&project, "src/HomePage.ts"),
+            position(&project, "src/HomePage.ts", "Greeting"),
+            "Salutation".to_string(),
+        )
+        .to_owned()
+        .expect("Expected rename to succeed")
+        .expect("Expected rename to edit files");
+
+        #[allow(clippy::mutable_key_type)]
+        let changes = edit.changes.expect("Expected changes");
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[&uri(&project, "src/User.ts")],
+            vec![TextEdit {
+                range: range(position(&project, "src/User.ts", "Greeting {"), 8),
+                new_text: "Salutation".to_string(),
+            }]
+        );
+        assert_eq!(
+            changes[&uri(&project, "src/HomePage.ts")],
+            vec![TextEdit {
+                range: range(position(&project, "src/HomePage.ts", "Greeting"), 8),
+                new_text: "Salutation".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn renames_unaliased_selections_by_aliasing_them_to_the_old_name() {
+        let project = test_project("rename");
+        let db = project.db();
+
+        assert_eq!(
+            on_prepare_rename_impl(
+                db,
+                uri(&project, "src/User.ts"),
+                position(&project, "src/User.ts", "Avatar {"),
+            )
+            .to_owned(),
+            Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+                range: range(position(&project, "src/User.ts", "Avatar {"), 6),
+                placeholder: "Avatar".to_string(),
+            }))
+        );
+
+        let edit = on_rename_impl(
+            db,
+            uri(&project, "src/User.ts"),
+            position(&project, "src/User.ts", "Avatar {"),
+            "ProfilePicture".to_string(),
+        )
+        .to_owned()
+        .expect("Expected rename to succeed")
+        .expect("Expected rename to edit files");
+
+        #[allow(clippy::mutable_key_type)]
+        let changes = edit.changes.expect("Expected changes");
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[&uri(&project, "src/User.ts")],
+            vec![TextEdit {
+                range: range(position(&project, "src/User.ts", "Avatar {"), 6),
+                new_text: "ProfilePicture".to_string(),
+            }]
+        );
+        assert_eq!(
+            changes[&uri(&project, "src/HomePage.ts")],
+            vec![TextEdit {
+                range: range(position(&project, "src/HomePage.ts", "Avatar"), 6),
+                new_text: "Avatar: ProfilePicture".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn refuses_to_rename_to_an_existing_selectable() {
+        let project = test_project("rename");
+        let db = project.db();
+
+        assert_eq!(
+            on_rename_impl(
+                db,
+                uri(&project, "src/User.ts"),
+                position(&project, "src/User.ts", "Greeting {"),
+                "Avatar".to_string(),
+            )
+            .to_owned(),
+            Err(LSPRuntimeError::UnexpectedError(
+                "`User.Avatar` already exists.".to_string()
+            ))
+        );
+    }
+}
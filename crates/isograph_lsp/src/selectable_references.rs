@@ -0,0 +1,134 @@
+use common_lang_types::{EmbeddedLocation, EntityName, SelectableName, WithEmbeddedLocation};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{DefinitionLocation, Selection, SelectionType};
+use isograph_schema::{
+    CompilationProfile, IsographDatabase, parse_iso_literal_in_source, selectable_named,
+};
+use pico_macros::memo;
+use prelude::Postfix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct SelectableReference {
+    pub location: EmbeddedLocation,
+    /// Whether this is a selection without an alias. The data of such selections is
+    /// read using the name of the selectable, so renaming the selectable must add an
+    /// alias in order to preserve the shape of the data that the client field receives.
+    pub is_unaliased_selection: bool,
+}
+
+/// The locations of every selection of `parent_entity_name.selectable_name` in iso
+/// literals, as well as of entrypoint declarations of it (i.e. the `HomeRoute` in
+/// `entrypoint Query.HomeRoute`).
+///
+/// The location of the declaration of the selectable is not included. The iso literals
+/// are not validated, so iso literals that fail to parse and selections that cannot be
+/// resolved (e.g. of selectables that do not exist) are skipped.
+#[memo]
+pub(crate) fn selectable_reference_locations<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_entity_name: EntityName,
+    selectable_name: SelectableName,
+) -> Vec<SelectableReference> {
+    let mut locations = vec![];
+
+    for (_relative_path, iso_literals_source_id) in db.get_iso_literal_map().tracked().0.iter() {
+        for extraction in parse_iso_literal_in_source(db, *iso_literals_source_id) {
+            let (extraction_result, _text_source) = match extraction {
+                Ok(extraction) => extraction,
+                Err(_) => continue,
+            };
+
+            match extraction_result {
+                IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+                    push_selection_locations(
+                        db,
+                        &client_field_declaration.item.selection_set.item.selections,
+                        client_field_declaration.item.parent_type.item.0,
+                        (parent_entity_name, selectable_name),
+                        &mut locations,
+                    );
+                }
+                IsoLiteralExtractionResult::ClientPointerDeclaration(
+                    client_pointer_declaration,
+                ) => {
+                    push_selection_locations(
+                        db,
+                        &client_pointer_declaration
+                            .item
+                            .selection_set
+                            .item
+                            .selections,
+                        client_pointer_declaration.item.parent_type.item.0,
+                        (parent_entity_name, selectable_name),
+                        &mut locations,
+                    );
+                }
+                IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+                    let entrypoint_declaration = &entrypoint_declaration.item;
+                    if entrypoint_declaration.parent_type.item.0 == parent_entity_name
+                        && entrypoint_declaration.client_field_name.item.0 == selectable_name
+                    {
+                        locations.push(SelectableReference {
+                            location: entrypoint_declaration.client_field_name.location,
+                            is_unaliased_selection: false,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    locations
+}
+
+fn push_selection_locations<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    selections: &[WithEmbeddedLocation<Selection>],
+    selections_parent_entity_name: EntityName,
+    (parent_entity_name, selectable_name): (EntityName, SelectableName),
+    locations: &mut Vec<SelectableReference>,
+) {
+    for selection in selections {
+        let (name, reader_alias) = match selection.item.reference() {
+            SelectionType::Scalar(scalar_selection) => {
+                (scalar_selection.name, scalar_selection.reader_alias)
+            }
+            SelectionType::Object(object_selection) => {
+                (object_selection.name, object_selection.reader_alias)
+            }
+        };
+        if selections_parent_entity_name == parent_entity_name && name.item == selectable_name {
+            locations.push(SelectableReference {
+                location: name.location,
+                is_unaliased_selection: reader_alias.is_none(),
+            });
+        }
+
+        let SelectionType::Object(object_selection) = selection.item.reference() else {
+            continue;
+        };
+        let target_entity_name =
+            match selectable_named(db, selections_parent_entity_name, name.item) {
+                Ok(Some(DefinitionLocation::Server(server_selectable))) => {
+                    match server_selectable.lookup(db).target_entity.item.as_ref() {
+                        Ok(annotation) => annotation.inner().0,
+                        Err(_) => continue,
+                    }
+                }
+                Ok(Some(DefinitionLocation::Client(SelectionType::Object(client_pointer)))) => {
+                    client_pointer.lookup(db).target_entity.inner().0
+                }
+                Ok(Some(DefinitionLocation::Client(SelectionType::Scalar(_))))
+                | Ok(None)
+                | Err(_) => continue,
+            };
+
+        push_selection_locations(
+            db,
+            &object_selection.selection_set.item.selections,
+            target_entity_name,
+            (parent_entity_name, selectable_name),
+            locations,
+        );
+    }
+}
@@ -26,7 +26,7 @@ pub fn on_semantic_token_full_request<TCompilationProfile: CompilationProfile>(
     params: <SemanticTokensFullRequest as Request>::Params,
 ) -> LSPRuntimeResult<<SemanticTokensFullRequest as Request>::Result> {
     let uri = params.text_document.uri;
-    let db = &lsp_state.compiler_state.db;
+    let db = &lsp_state.compiler_state_for_uri(&uri)?.db;
 
     get_semantic_tokens(db, uri).to_owned()
 }
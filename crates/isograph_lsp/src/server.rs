@@ -13,7 +13,10 @@ use crate::{
     lsp_request_dispatch::LSPRequestDispatch,
     lsp_runtime_error::LSPRuntimeError,
     lsp_state::LspState,
+    references::on_references,
+    rename::{on_prepare_rename, on_rename},
     semantic_tokens::on_semantic_token_full_request,
+    symbols::{on_document_symbol, on_workspace_symbol},
     text_document::{
         on_did_change_text_document, on_did_close_text_document, on_did_open_text_document,
     },
@@ -23,10 +26,10 @@ use common_lang_types::{
     LocationFreeDiagnosticVecResult,
 };
 use isograph_compiler::{
-    CompilerState, WithDuration, update_sources,
-    watch::{create_debounced_file_watcher, has_config_changes},
+    WithDuration, update_sources,
+    watch::{create_compiler_states, create_debounced_file_watcher, has_config_changes},
 };
-use isograph_config::{CompilerConfig, create_config};
+use isograph_config::{CompilerConfig, create_configs};
 use isograph_lang_types::semantic_token_legend::semantic_token_legend;
 use isograph_schema::{CompilationProfile, validate_entire_schema};
 use lsp_server::{Connection, ErrorCode, Response, ResponseError};
@@ -34,17 +37,21 @@ use lsp_types::{
     CodeActionProviderCapability, CompletionOptions, ExecuteCommandOptions,
     HoverProviderCapability,
     request::{
-        CodeActionRequest, Completion, DocumentHighlightRequest, ExecuteCommand, HoverRequest,
-        Request, SemanticTokensFullRequest,
+        CodeActionRequest, Completion, DocumentHighlightRequest, DocumentSymbolRequest,
+        ExecuteCommand, HoverRequest, PrepareRenameRequest, References, Rename, Request,
+        SemanticTokensFullRequest, WorkspaceSymbolRequest,
     },
 };
 use lsp_types::{
-    InitializeParams, OneOf, SemanticTokensFullOptions, SemanticTokensOptions,
+    InitializeParams, OneOf, RenameOptions, SemanticTokensFullOptions, SemanticTokensOptions,
     SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncCapability,
     TextDocumentSyncKind, WorkDoneProgressOptions,
-    notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument},
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification,
+    },
     request::{Formatting, GotoDefinition},
 };
+use pico::Cancelled;
 use prelude::{ErrClone, Postfix};
 use std::{
     collections::BTreeSet,
@@ -79,6 +86,14 @@ pub fn initialize(connection: &Connection) -> LocationFreeDiagnosticResult<Initi
         .wrap_some(),
         document_highlight_provider: Some(OneOf::Left(true)),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        references_provider: OneOf::Left(true).wrap_some(),
+        document_symbol_provider: OneOf::Left(true).wrap_some(),
+        workspace_symbol_provider: OneOf::Left(true).wrap_some(),
+        rename_provider: OneOf::Right(RenameOptions {
+            prepare_provider: true.wrap_some(),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        })
+        .wrap_some(),
         execute_command_provider: ExecuteCommandOptions {
             commands: all_commands(),
             ..Default::default()
@@ -103,29 +118,43 @@ const LONG_DEBOUNCE_TIME: Duration = Duration::from_hours(24 * 365);
 /// Run the main server loop
 pub async fn run<TCompilationProfile: CompilationProfile>(
     connection: Connection,
-    config: CompilerConfig,
+    configs: Vec<CompilerConfig>,
     _params: InitializeParams,
     current_working_directory: CurrentWorkingDirectory,
 ) -> LocationFreeDiagnosticVecResult<()> {
-    let config_location = config.config_location.clone();
+    // All projects share a single config file.
+    let config_location = configs[0].config_location.clone();
 
     let (mut file_system_receiver, mut file_system_watcher) =
-        create_debounced_file_watcher(&config);
+        create_debounced_file_watcher(&configs);
 
-    let compiler_state: CompilerState<TCompilationProfile> =
-        CompilerState::new(config, current_working_directory).map_err(|e| e.wrap_vec())?;
-    let mut lsp_state = LspState::new(compiler_state, &connection.sender);
+    let compiler_states =
+        create_compiler_states::<TCompilationProfile>(configs, current_working_directory)?;
+    let mut lsp_state = LspState::new(compiler_states, &connection.sender);
 
+    // One set of uris per project
     #[allow(clippy::mutable_key_type)]
-    let mut uris_with_diagnostics = BTreeSet::new().note_todo(
-        "When we panic, we should clear all diagnostics. \
-        Add a panic_unwind handler for that.",
-    );
+    let mut uris_with_diagnostics = vec![BTreeSet::new(); lsp_state.compiler_states.len()]
+        .note_todo(
+            "When we panic, we should clear all diagnostics. \
+            Add a panic_unwind handler for that.",
+        );
+
+    // The cache is written after the first compilation, and when the server shuts down.
+    let mut has_saved_cache = false;
 
     eprintln!("Running server loop");
 
     let (tokio_sender, mut lsp_message_receiver) = tokio::sync::mpsc::channel(100);
-    bridge_crossbeam_to_tokio(connection.receiver, tokio_sender);
+    // When a document changes, the requests that are being handled are stale. So,
+    // we cancel them as soon as the change is received, instead of when the main
+    // loop gets to it.
+    let cancellation_handles = lsp_state.cancellation_handles();
+    bridge_crossbeam_to_tokio(connection.receiver, tokio_sender, move |message| {
+        if is_did_change_text_document(message) {
+            cancellation_handles.cancel();
+        }
+    });
 
     // After 100ms of inactivity, we compile the codebase and emit diagnostics.
     // Note that in response to events, we delay the debounce timer.
@@ -151,6 +180,10 @@ pub async fn run<TCompilationProfile: CompilationProfile>(
                             }
                             lsp_server::Message::Notification(notification) => {
                                 eprintln!("\nReceived notification: {}", notification.method);
+                                if notification.method == DidChangeTextDocument::METHOD {
+                                    // Every request received before this notification was cancelled.
+                                    lsp_state.clear_cancellation();
+                                }
                                 let _ = dispatch_notification(notification, &mut lsp_state);
 
                                 // NOTE: we attempt to be judicious, i.e. only trigger the debounce timer
@@ -173,13 +206,13 @@ pub async fn run<TCompilationProfile: CompilationProfile>(
                 if let Some(Ok(changes)) = message {
                     if has_config_changes(&changes) {
                         eprintln!("Config change detected.");
-                        let config = create_config(&config_location, current_working_directory);
+                        let configs = create_configs(&config_location, current_working_directory);
                         file_system_watcher.stop();
                         // TODO is this a bug? Will we continue to watch the old folders? I think so.
                         (file_system_receiver, file_system_watcher) =
-                            create_debounced_file_watcher(&config);
-                        let compiler_state = CompilerState::new(config, current_working_directory).map_err(|e| e.wrap_vec())?;
-                        lsp_state = LspState::new(compiler_state, &connection.sender);
+                            create_debounced_file_watcher(&configs);
+                        let compiler_states = create_compiler_states(configs, current_working_directory)?;
+                        lsp_state.replace_compiler_states(compiler_states);
 
                         // TODO this is a temporary expedient. We need a good way to copy the old DB state to the
                         // new DB. Namely, there's an open files hash map that needs to be transferred over.
@@ -194,9 +227,16 @@ pub async fn run<TCompilationProfile: CompilationProfile>(
                         }
                     } else {
                         eprintln!("File changes detected. Starting to compile.");
-                        update_sources(&mut lsp_state.compiler_state.db, &changes)?;
+                        for (compiler_state, changes) in
+                            lsp_state.compiler_states.iter_mut().zip(changes)
+                        {
+                            if changes.is_empty() {
+                                continue;
+                            }
+                            update_sources(&mut compiler_state.db, &changes)?;
 
-                        lsp_state.compiler_state.run_garbage_collection();
+                            compiler_state.run_garbage_collection();
+                        }
                     };
 
                     debounce_timer.as_mut().reset(Instant::now() + SHORT_DEBOUNCE_TIME);
@@ -206,25 +246,50 @@ pub async fn run<TCompilationProfile: CompilationProfile>(
                 }
             }
             _ = &mut debounce_timer => {
-                let diagnostics = validate_entire_schema(&lsp_state.compiler_state.db)
-                    .clone_err()
-                    .err()
-                    .unwrap_or_default();
+                uris_with_diagnostics = lsp_state
+                    .compiler_states
+                    .iter()
+                    .zip(std::mem::take(&mut uris_with_diagnostics))
+                    .map(|(compiler_state, uris_with_diagnostics)| {
+                        // If a document changed during validation, the diagnostics are stale.
+                        // Handling the change resets the debounce timer, so we validate again soon.
+                        let Ok(diagnostics) = Cancelled::catch(|| {
+                            validate_entire_schema(&compiler_state.db)
+                                .clone_err()
+                                .err()
+                                .unwrap_or_default()
+                        }) else {
+                            eprintln!("Validation was cancelled");
+                            return uris_with_diagnostics;
+                        };
+
+                        eprintln!("Publishing diagnostics {:?}", diagnostics);
 
-                eprintln!("Publishing diagnostics {:?}", diagnostics);
+                        publish_new_diagnostics_and_clear_old_diagnostics(
+                            &compiler_state.db,
+                            &diagnostics,
+                            &connection.sender,
+                            uris_with_diagnostics,
+                        )
+                    })
+                    .collect();
 
-                uris_with_diagnostics = publish_new_diagnostics_and_clear_old_diagnostics(
-                    &lsp_state.compiler_state.db,
-                    &diagnostics,
-                    &connection.sender,
-                    uris_with_diagnostics
-                );
+                if !has_saved_cache {
+                    for compiler_state in lsp_state.compiler_states.iter() {
+                        compiler_state.save_cache();
+                    }
+                    has_saved_cache = true;
+                }
 
                 debounce_timer.as_mut().reset(Instant::now() + LONG_DEBOUNCE_TIME);
             }
         };
     }
 
+    for compiler_state in lsp_state.compiler_states.iter() {
+        compiler_state.save_cache();
+    }
+
     ().wrap_ok()
 }
 
@@ -255,6 +320,11 @@ fn dispatch_request<TCompilationProfile: CompilationProfile>(
             .on_request_sync::<Completion>(on_completion)?
             .on_request_sync::<DocumentHighlightRequest>(on_document_highlight)?
             .on_request_sync::<CodeActionRequest>(on_code_action)?
+            .on_request_sync::<References>(on_references)?
+            .on_request_sync::<DocumentSymbolRequest>(on_document_symbol)?
+            .on_request_sync::<WorkspaceSymbolRequest>(on_workspace_symbol)?
+            .on_request_sync::<PrepareRenameRequest>(on_prepare_rename)?
+            .on_request_sync::<Rename>(on_rename)?
             .on_request_sync::<ExecuteCommand>(on_command)?
             .request();
 
@@ -277,12 +347,16 @@ fn dispatch_request<TCompilationProfile: CompilationProfile>(
     }
 }
 
+/// Forwards messages to the main loop. `on_receive` is called on the bridge
+/// thread as soon as each message is received, even if the main loop is busy.
 fn bridge_crossbeam_to_tokio<T: Send + 'static>(
     crossbeam_receiver: crossbeam::channel::Receiver<T>,
     tokio_sender: tokio::sync::mpsc::Sender<T>,
+    on_receive: impl Fn(&T) + Send + 'static,
 ) {
     std::thread::spawn(move || {
         while let Ok(msg) = crossbeam_receiver.recv() {
+            on_receive(&msg);
             // Use blocking_send since we're in a std::thread, not tokio task
             if tokio_sender.blocking_send(msg).is_err() {
                 break;
@@ -291,6 +365,14 @@ fn bridge_crossbeam_to_tokio<T: Send + 'static>(
     });
 }
 
+fn is_did_change_text_document(message: &lsp_server::Message) -> bool {
+    matches!(
+        message,
+        lsp_server::Message::Notification(notification)
+            if notification.method == DidChangeTextDocument::METHOD
+    )
+}
+
 /// Certain methods have really large responses, and they're not useful! We don't want to print
 /// the full response for those.
 static NON_PRINTABLE_METHODS: &[&str] = &[SemanticTokensFullRequest::METHOD];
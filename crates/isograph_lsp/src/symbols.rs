@@ -0,0 +1,367 @@
+use common_lang_types::{
+    EmbeddedLocation, EntityName, SelectableName, Span,
+    relative_path_from_absolute_and_working_directory,
+};
+use isograph_lang_types::SelectionType;
+use isograph_schema::{
+    CompilationProfile, IsographDatabase, client_selectable_declaration_map_from_iso_literals,
+    entrypoint_declarations, validated_entrypoints,
+};
+use lsp_types::{
+    DocumentSymbol, DocumentSymbolResponse, OneOf, SymbolKind, Uri, WorkspaceSymbol,
+    WorkspaceSymbolResponse,
+    request::{DocumentSymbolRequest, Request, WorkspaceSymbolRequest},
+};
+use pico_macros::memo;
+use prelude::Postfix;
+
+use crate::{
+    location_utils::iso_literal_location_to_lsp_location, lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LspState, uri_file_path_ext::UriFilePathExt,
+};
+
+pub fn on_workspace_symbol<TCompilationProfile: CompilationProfile>(
+    lsp_state: &LspState<TCompilationProfile>,
+    params: <WorkspaceSymbolRequest as Request>::Params,
+) -> LSPRuntimeResult<<WorkspaceSymbolRequest as Request>::Result> {
+    let mut symbols = vec![];
+    for compiler_state in &lsp_state.compiler_states {
+        if let Some(WorkspaceSymbolResponse::Nested(project_symbols)) =
+            on_workspace_symbol_impl(&compiler_state.db, params.query.clone()).to_owned()?
+        {
+            symbols.extend(project_symbols);
+        }
+    }
+    WorkspaceSymbolResponse::Nested(symbols)
+        .wrap_some()
+        .wrap_ok()
+}
+
+#[memo]
+fn on_workspace_symbol_impl<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    query: String,
+) -> LSPRuntimeResult<<WorkspaceSymbolRequest as Request>::Result> {
+    // Clients are expected to do further (fuzzy) filtering, so we only check whether
+    // the symbol name contains the query.
+    let query = query.to_lowercase();
+
+    WorkspaceSymbolResponse::Nested(
+        iso_literal_symbols(db)
+            .into_iter()
+            .filter(|symbol| symbol.name().to_lowercase().contains(&query))
+            .filter_map(|symbol| {
+                WorkspaceSymbol {
+                    name: symbol.name(),
+                    kind: symbol.kind.symbol_kind(),
+                    tags: None,
+                    container_name: None,
+                    location: OneOf::Left(iso_literal_location_to_lsp_location(
+                        db,
+                        symbol.location,
+                    )?),
+                    data: None,
+                }
+                .wrap_some()
+            })
+            .collect(),
+    )
+    .wrap_some()
+    .wrap_ok()
+}
+
+pub fn on_document_symbol<TCompilationProfile: CompilationProfile>(
+    lsp_state: &LspState<TCompilationProfile>,
+    params: <DocumentSymbolRequest as Request>::Params,
+) -> LSPRuntimeResult<<DocumentSymbolRequest as Request>::Result> {
+    let db = &lsp_state
+        .compiler_state_for_uri(&params.text_document.uri)?
+        .db;
+    on_document_symbol_impl(db, params.text_document.uri).to_owned()
+}
+
+#[memo]
+fn on_document_symbol_impl<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    url: Uri,
+) -> LSPRuntimeResult<<DocumentSymbolRequest as Request>::Result> {
+    let relative_path_to_source_file = relative_path_from_absolute_and_working_directory(
+        db.get_current_working_directory(),
+        &url.to_file_path().expect("Expected file path to be valid."),
+    );
+
+    let mut symbols = iso_literal_symbols(db)
+        .into_iter()
+        .filter(|symbol| {
+            symbol.location.text_source.relative_path_to_source_file == relative_path_to_source_file
+        })
+        .collect::<Vec<_>>();
+    symbols.sort_by_key(|symbol| symbol.location.text_source.span.map(|span| span.start));
+
+    DocumentSymbolResponse::Nested(
+        symbols
+            .into_iter()
+            .filter_map(|symbol| {
+                #[allow(deprecated)]
+                DocumentSymbol {
+                    name: symbol.name(),
+                    detail: symbol.kind.detail().to_string().wrap_some(),
+                    kind: symbol.kind.symbol_kind(),
+                    tags: None,
+                    deprecated: None,
+                    range: iso_literal_location_to_lsp_location(db, symbol.location)?.range,
+                    selection_range: iso_literal_location_to_lsp_location(
+                        db,
+                        symbol.name_location,
+                    )?
+                    .range,
+                    children: None,
+                }
+                .wrap_some()
+            })
+            .collect(),
+    )
+    .wrap_some()
+    .wrap_ok()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum IsoLiteralSymbolKind {
+    ClientField,
+    ClientPointer,
+    Entrypoint,
+}
+
+impl IsoLiteralSymbolKind {
+    fn symbol_kind(self) -> SymbolKind {
+        match self {
+            IsoLiteralSymbolKind::ClientField => SymbolKind::FIELD,
+            IsoLiteralSymbolKind::ClientPointer => SymbolKind::PROPERTY,
+            IsoLiteralSymbolKind::Entrypoint => SymbolKind::FUNCTION,
+        }
+    }
+
+    fn detail(self) -> &'static str {
+        match self {
+            IsoLiteralSymbolKind::ClientField => "field",
+            IsoLiteralSymbolKind::ClientPointer => "pointer",
+            IsoLiteralSymbolKind::Entrypoint => "entrypoint",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IsoLiteralSymbol {
+    parent_entity_name: EntityName,
+    selectable_name: SelectableName,
+    kind: IsoLiteralSymbolKind,
+    /// The location of the iso literal declaration
+    location: EmbeddedLocation,
+    /// The location of the selectable name within the declaration
+    name_location: EmbeddedLocation,
+}
+
+impl IsoLiteralSymbol {
+    fn name(&self) -> String {
+        format!("{}.{}", self.parent_entity_name, self.selectable_name)
+    }
+}
+
+/// Client fields and client pointers declared in iso literals, as well as entrypoints
+/// that are valid.
+fn iso_literal_symbols<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> Vec<IsoLiteralSymbol> {
+    let mut symbols = vec![];
+
+    for ((parent_entity_name, selectable_name), declaration) in
+        client_selectable_declaration_map_from_iso_literals(db)
+            .item
+            .iter()
+    {
+        let (kind, name_location) = match declaration.item {
+            SelectionType::Scalar(s) => (
+                IsoLiteralSymbolKind::ClientField,
+                s.lookup(db).client_field_name.location,
+            ),
+            SelectionType::Object(o) => (
+                IsoLiteralSymbolKind::ClientPointer,
+                o.lookup(db).client_pointer_name.location,
+            ),
+        };
+        symbols.push(IsoLiteralSymbol {
+            parent_entity_name: *parent_entity_name,
+            selectable_name: *selectable_name,
+            kind,
+            location: declaration.location,
+            name_location,
+        });
+    }
+
+    let validated_entrypoints = validated_entrypoints(db);
+    for entrypoint_declaration in entrypoint_declarations(db) {
+        let key = (
+            entrypoint_declaration.parent_type.item.0,
+            entrypoint_declaration.client_field_name.item.0,
+        );
+        if !matches!(validated_entrypoints.get(&key), Some(Ok(_))) {
+            continue;
+        }
+
+        let name_location = entrypoint_declaration.client_field_name.location;
+        symbols.push(IsoLiteralSymbol {
+            parent_entity_name: key.0,
+            selectable_name: key.1,
+            kind: IsoLiteralSymbolKind::Entrypoint,
+            // i.e. `entrypoint Query.HomeRoute`
+            location: EmbeddedLocation {
+                text_source: name_location.text_source,
+                span: Span::new(
+                    entrypoint_declaration
+                        .entrypoint_keyword
+                        .location
+                        .span
+                        .start,
+                    name_location.span.end,
+                ),
+            },
+            name_location,
+        });
+    }
+
+    symbols
+}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{
+        DocumentSymbol, DocumentSymbolResponse, Location, OneOf, Position, Range, SymbolKind,
+        WorkspaceSymbol, WorkspaceSymbolResponse,
+    };
+
+    use super::{on_document_symbol_impl, on_workspace_symbol_impl};
+    use crate::test_utils::{position, test_project, uri};
+
+    fn range(start: Position, length: u32) -> Range {
+        Range {
+            start,
+            end: Position {
+                line: start.line,
+                character: start.character + length,
+            },
+        }
+    }
+
+    #[test]
+    fn document_symbols_are_sorted_and_exclude_invalid_entrypoints() {
+        let project = test_project("symbols");
+        let home_page = "src/HomePage.ts";
+
+        let Some(DocumentSymbolResponse::Nested(symbols)) =
+            on_document_symbol_impl(project.db(), uri(&project, home_page))
+                .to_owned()
+                .expect("Expected document symbols to succeed")
+        else {
+            panic!("Expected nested document symbols");
+        };
+
+        #[allow(deprecated)]
+        let expected_symbols = vec![
+            DocumentSymbol {
+                name: "Query.HomePage".to_string(),
+                detail: Some("field".to_string()),
+                kind: SymbolKind::FIELD,
+                tags: None,
+                deprecated: None,
+                range: Range {
+                    start: position(&project, home_page, "Query.HomePage {"),
+                    end: range(position(&project, home_page, "}\n`)"), 1).end,
+                },
+                selection_range: range(position(&project, home_page, "HomePage {"), 8),
+                children: None,
+            },
+            DocumentSymbol {
+                name: "Query.HomePage".to_string(),
+                detail: Some("entrypoint".to_string()),
+                kind: SymbolKind::FUNCTION,
+                tags: None,
+                deprecated: None,
+                range: range(
+                    position(&project, home_page, "entrypoint Query.HomePage"),
+                    25,
+                ),
+                selection_range: range(position(&project, home_page, "HomePage`"), 8),
+                children: None,
+            },
+        ];
+        assert_eq!(symbols, expected_symbols);
+    }
+
+    #[test]
+    fn workspace_symbols_are_filtered_by_query() {
+        let project = test_project("symbols");
+        let workspace_symbol_names = |query: &str| {
+            let Some(WorkspaceSymbolResponse::Nested(symbols)) =
+                on_workspace_symbol_impl(project.db(), query.to_string())
+                    .to_owned()
+                    .expect("Expected workspace symbols to succeed")
+            else {
+                panic!("Expected nested workspace symbols");
+            };
+            let mut names = symbols
+                .into_iter()
+                .map(|symbol| (symbol.name, symbol.kind))
+                .collect::<Vec<_>>();
+            names.sort_by(|a, b| a.0.cmp(&b.0));
+            names
+        };
+
+        assert_eq!(
+            workspace_symbol_names("PET"),
+            vec![
+                ("Pet.PetName".to_string(), SymbolKind::FIELD),
+                ("Query.firstPet".to_string(), SymbolKind::PROPERTY),
+            ]
+        );
+        assert_eq!(
+            workspace_symbol_names("home"),
+            vec![
+                ("Query.HomePage".to_string(), SymbolKind::FIELD),
+                ("Query.HomePage".to_string(), SymbolKind::FUNCTION),
+            ]
+        );
+        assert_eq!(workspace_symbol_names("Missing"), vec![]);
+    }
+
+    #[test]
+    fn workspace_symbol_locations() {
+        let project = test_project("symbols");
+        let pet = "src/Pet.ts";
+
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) =
+            on_workspace_symbol_impl(project.db(), "firstPet".to_string())
+                .to_owned()
+                .expect("Expected workspace symbols to succeed")
+        else {
+            panic!("Expected nested workspace symbols");
+        };
+
+        assert_eq!(
+            symbols,
+            vec![WorkspaceSymbol {
+                name: "Query.firstPet".to_string(),
+                kind: SymbolKind::PROPERTY,
+                tags: None,
+                container_name: None,
+                location: OneOf::Left(Location {
+                    uri: uri(&project, pet),
+                    range: Range {
+                        start: position(&project, pet, "Query.firstPet"),
+                        end: range(position(&project, pet, "}\n`)(("), 1).end,
+                    },
+                }),
+                data: None,
+            }]
+        );
+    }
+}
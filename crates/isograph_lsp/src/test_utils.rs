@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use lsp_types::{Position, Uri};
+use tests::TestProject;
+
+/// Loads a project from `crates/isograph_lsp/fixtures/{fixture_name}`.
+pub(crate) fn test_project(fixture_name: &str) -> TestProject {
+    TestProject::new(&format!(
+        "{}/fixtures/{fixture_name}",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+}
+
+pub(crate) fn uri(project: &TestProject, relative_path: &str) -> Uri {
+    let url = url::Url::from_file_path(project.path(relative_path))
+        .expect("Expected path to be absolute");
+    Uri::from_str(url.as_str()).expect("Expected file URL to be a valid URI")
+}
+
+/// The position of the first occurrence of `needle` in the file
+pub(crate) fn position(project: &TestProject, relative_path: &str, needle: &str) -> Position {
+    let (line, character) = project.line_and_character(relative_path, needle);
+    Position { line, character }
+}
@@ -18,7 +18,7 @@ pub fn on_did_open_text_document<TCompilationProfile: CompilationProfile>(
     let DidOpenTextDocumentParams { text_document } = params;
     let TextDocumentItem { text, uri, .. } = text_document;
 
-    let db = &mut lsp_state.compiler_state.db;
+    let db = &mut lsp_state.compiler_state_for_uri_mut(&uri)?.db;
     let current_working_directory = db.get_current_working_directory();
 
     let relative_path_to_source_file = relative_path_from_absolute_and_working_directory(
@@ -35,7 +35,7 @@ pub fn on_did_close_text_document<TCompilationProfile: CompilationProfile>(
     params: <DidCloseTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
     let uri = params.text_document.uri;
-    let db = &mut lsp_state.compiler_state.db;
+    let db = &mut lsp_state.compiler_state_for_uri_mut(&uri)?.db;
 
     let current_working_directory = db.get_current_working_directory();
 
@@ -58,7 +58,7 @@ pub fn on_did_change_text_document<TCompilationProfile: CompilationProfile>(
         text_document,
     } = params;
     let uri = text_document.uri;
-    let db = &mut lsp_state.compiler_state.db;
+    let db = &mut lsp_state.compiler_state_for_uri_mut(&uri)?.db;
 
     // We do full text document syncing, so the new text will be in the first content change event.
     let content_changed = content_changes
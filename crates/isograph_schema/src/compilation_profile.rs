@@ -8,7 +8,19 @@ use crate::{
 };
 
 pub trait CompilationProfile:
-    Debug + Clone + Copy + Eq + PartialEq + Ord + PartialOrd + Hash + Default + Sized + 'static
+    Debug
+    + Clone
+    + Copy
+    + Eq
+    + PartialEq
+    + Ord
+    + PartialOrd
+    + Hash
+    + Default
+    + Sized
+    + Send
+    + Sync
+    + 'static
 {
     type NetworkProtocol: NetworkProtocol;
     type TargetPlatform: TargetPlatform;
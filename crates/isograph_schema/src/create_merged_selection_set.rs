@@ -5,14 +5,14 @@ use std::{
 
 use common_lang_types::{
     EmbeddedLocation, EntityName, ExpectEntityToExist, ExpectSelectableToExist, SelectableName,
-    VariableName, WithEmbeddedLocation, WithLocationPostfix,
+    StringLiteralValue, VariableName, WithEmbeddedLocation, WithLocationPostfix,
 };
 use intern::string_key::Intern;
 use isograph_lang_types::{
-    ArgumentKeyAndValue, DefinitionLocation, EmptyDirectiveSet, NonConstantValue, ObjectSelection,
-    ObjectSelectionDirectiveSet, ScalarSelection, ScalarSelectionDirectiveSet,
-    SelectionFieldArgument, SelectionSet, SelectionType, SelectionTypePostfix,
-    TypeAnnotationDeclaration, VariableDeclaration, VariableNameWrapper,
+    ArgumentKeyAndValue, DeferDirectiveSet, DefinitionLocation, EmptyDirectiveSet,
+    NonConstantValue, ObjectSelection, ObjectSelectionDirectiveSet, ScalarSelection,
+    ScalarSelectionDirectiveSet, SelectionFieldArgument, SelectionSet, SelectionType,
+    SelectionTypePostfix, TypeAnnotationDeclaration, VariableDeclaration, VariableNameWrapper,
 };
 use lazy_static::lazy_static;
 use prelude::Postfix;
@@ -79,6 +79,7 @@ pub enum MergedServerSelection {
     ClientObjectSelectable(MergedLinkedFieldSelection),
     // TODO does this belong? This is very GraphQL specific.
     InlineFragment(MergedInlineFragmentSelection),
+    Defer(MergedDeferSelection),
 }
 
 impl MergedServerSelection {
@@ -99,6 +100,11 @@ impl MergedServerSelection {
                 .values()
                 .flat_map(|selection| selection.reachable_variables())
                 .collect(),
+            MergedServerSelection::Defer(defer) => defer
+                .selection_map
+                .values()
+                .flat_map(|selection| selection.reachable_variables())
+                .collect(),
         }
     }
 }
@@ -173,6 +179,17 @@ pub struct MergedInlineFragmentSelection {
     pub selection_map: MergedSelectionMap,
 }
 
+/// Selections that are delivered in a subsequent (incremental) payload, i.e.
+/// selections that were marked with `@defer(label: "...")`.
+///
+/// Note that a deferred selection does not introduce a new level of nesting in
+/// the response. Hence, it never appears in a traversal path.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct MergedDeferSelection {
+    pub label: StringLiteralValue,
+    pub selection_map: MergedSelectionMap,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Ord, Hash)]
 pub enum NormalizationKey {
     Discriminator, // AKA typename
@@ -181,6 +198,7 @@ pub enum NormalizationKey {
     ServerField(NameAndArguments),
     ClientPointer(NameAndArguments),
     InlineFragment(EntityName),
+    Defer(StringLiteralValue),
 }
 
 impl NormalizationKey {
@@ -205,6 +223,7 @@ impl NormalizationKey {
                 ),
             ),
             NormalizationKey::InlineFragment(o) => NormalizationKey::InlineFragment(*o),
+            NormalizationKey::Defer(label) => NormalizationKey::Defer(*label),
         }
     }
 }
@@ -295,8 +314,7 @@ pub fn current_target_merged_selections<'a>(
     mut parent_selection_map: &'a MergedSelectionMap,
 ) -> &'a MergedSelectionMap {
     for normalization_key in traversal_path {
-        match parent_selection_map
-            .get(normalization_key)
+        match get_selection_including_deferred(parent_selection_map, normalization_key)
             .expect("Expected linked field to exist by now. This is indicate of a bug in Isograph.")
         {
             MergedServerSelection::ScalarField(_) => {
@@ -313,11 +331,35 @@ pub fn current_target_merged_selections<'a>(
             MergedServerSelection::InlineFragment(inline_fragment) => {
                 parent_selection_map = &inline_fragment.selection_map;
             }
+            MergedServerSelection::Defer(_) => {
+                panic!(
+                    "Expected a linked field, found deferred selections. \
+                    This is indicative of a bug in Isograph."
+                )
+            }
         }
     }
     parent_selection_map
 }
 
+/// Deferred selections are not part of traversal paths, so if a selection is not found
+/// directly in the selection map, we must also look in the deferred selections.
+fn get_selection_including_deferred<'a>(
+    selection_map: &'a MergedSelectionMap,
+    normalization_key: &NormalizationKey,
+) -> Option<&'a MergedServerSelection> {
+    selection_map.get(normalization_key).or_else(|| {
+        selection_map
+            .values()
+            .find_map(|selection| match selection {
+                MergedServerSelection::Defer(defer) => {
+                    get_selection_including_deferred(&defer.selection_map, normalization_key)
+                }
+                _ => None,
+            })
+    })
+}
+
 fn transform_and_merge_child_selection_map_into_parent_map(
     parent_map: &mut MergedSelectionMap,
     untransformed_child_map: &MergedSelectionMap,
@@ -382,6 +424,15 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             ),
                         })
                     }
+                    MergedServerSelection::Defer(defer_selection) => {
+                        MergedServerSelection::Defer(MergedDeferSelection {
+                            label: defer_selection.label,
+                            selection_map: transform_child_map_with_parent_context(
+                                &defer_selection.selection_map,
+                                parent_variable_context,
+                            ),
+                        })
+                    }
                 };
                 vacant.insert(transformed);
             }
@@ -447,6 +498,22 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             )
                         }
                     }
+                    MergedServerSelection::Defer(target_defer) => {
+                        if let MergedServerSelection::Defer(child_defer) =
+                            new_server_field_selection
+                        {
+                            transform_and_merge_child_selection_map_into_parent_map(
+                                &mut target_defer.selection_map,
+                                &child_defer.selection_map,
+                                parent_variable_context,
+                            )
+                        } else {
+                            panic!(
+                                "Error: tried to merge non-deferred selections into deferred \
+                                selections. This is indicative of a bug in Isograph."
+                            )
+                        }
+                    }
                 }
             }
         };
@@ -691,11 +758,16 @@ fn merge_server_object_field<TCompilationProfile: CompilationProfile>(
     field_parent_object_entity_name: EntityName,
     field_server_object_selectable_name: SelectableName,
 ) {
-    if let ObjectSelectionDirectiveSet::Updatable(_) =
-        object_selection.object_selection_directive_set
-    {
-        merge_traversal_state.has_updatable = true;
-    }
+    let parent_map = match object_selection.object_selection_directive_set {
+        ObjectSelectionDirectiveSet::Updatable(_) => {
+            merge_traversal_state.has_updatable = true;
+            parent_map
+        }
+        ObjectSelectionDirectiveSet::Defer(DeferDirectiveSet { defer }) => {
+            deferred_selection_map(parent_map, defer.label)
+        }
+        ObjectSelectionDirectiveSet::None(_) => parent_map,
+    };
 
     let server_object_selectable = flattened_selectable_named(
         db,
@@ -726,7 +798,8 @@ fn merge_server_object_field<TCompilationProfile: CompilationProfile>(
         match inline_fragment {
             MergedServerSelection::ScalarField(_)
             | MergedServerSelection::ClientObjectSelectable(_)
-            | MergedServerSelection::LinkedField(_) => {
+            | MergedServerSelection::LinkedField(_)
+            | MergedServerSelection::Defer(_) => {
                 panic!(
                     "Expected inline fragment. \
                         This is indicative of a bug in Isograph."
@@ -838,7 +911,8 @@ fn merge_server_object_field<TCompilationProfile: CompilationProfile>(
             }
             MergedServerSelection::ClientObjectSelectable(_)
             | MergedServerSelection::ScalarField(_)
-            | MergedServerSelection::InlineFragment(_) => {
+            | MergedServerSelection::InlineFragment(_)
+            | MergedServerSelection::Defer(_) => {
                 panic!(
                     "Expected linked field. \
                     This is indicative of a bug in Isograph."
@@ -848,6 +922,31 @@ fn merge_server_object_field<TCompilationProfile: CompilationProfile>(
     }
 }
 
+fn deferred_selection_map(
+    parent_map: &mut MergedSelectionMap,
+    label: StringLiteralValue,
+) -> &mut MergedSelectionMap {
+    match parent_map
+        .entry(NormalizationKey::Defer(label))
+        .or_insert_with(|| {
+            MergedServerSelection::Defer(MergedDeferSelection {
+                label,
+                selection_map: BTreeMap::new(),
+            })
+        }) {
+        MergedServerSelection::Defer(existing_defer) => &mut existing_defer.selection_map,
+        MergedServerSelection::ScalarField(_)
+        | MergedServerSelection::LinkedField(_)
+        | MergedServerSelection::ClientObjectSelectable(_)
+        | MergedServerSelection::InlineFragment(_) => {
+            panic!(
+                "Expected deferred selections. \
+                This is indicative of a bug in Isograph."
+            )
+        }
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 fn merge_client_object_field<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
@@ -1278,7 +1377,8 @@ fn insert_client_object_selectable_into_refetch_paths<TCompilationProfile: Compi
         }
         MergedServerSelection::LinkedField(_)
         | MergedServerSelection::ScalarField(_)
-        | MergedServerSelection::InlineFragment(_) => {
+        | MergedServerSelection::InlineFragment(_)
+        | MergedServerSelection::Defer(_) => {
             panic!(
                 "Expected client pointer. \
                 This is indicative of a bug in Isograph."
@@ -1391,6 +1491,9 @@ fn merge_server_scalar_field(
                 MergedServerSelection::InlineFragment(_) => {
                     panic!("Unexpected inline fragment, probably a bug in Isograph");
                 }
+                MergedServerSelection::Defer(_) => {
+                    panic!("Unexpected deferred selections, probably a bug in Isograph");
+                }
             };
         }
         Entry::Vacant(vacant_entry) => {
@@ -1448,6 +1551,9 @@ fn select_typename_and_id_fields_in_merged_selection<TCompilationProfile: Compil
                     MergedServerSelection::InlineFragment(_) => {
                         panic!("Unexpected inline fragment, probably a bug in Isograph");
                     }
+                    MergedServerSelection::Defer(_) => {
+                        panic!("Unexpected deferred selections, probably a bug in Isograph");
+                    }
                 };
             }
             Entry::Vacant(vacant_entry) => {
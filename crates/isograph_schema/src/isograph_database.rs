@@ -1,18 +1,20 @@
 use std::{
     collections::{BTreeMap, HashMap},
     marker::PhantomData,
+    path::Path,
 };
 
 use colored::Colorize;
 use common_lang_types::{
-    CurrentWorkingDirectory, Location, PrintLocationFn, RelativePathToSourceFile, Span, TextSource,
-    text_with_carats,
+    CurrentWorkingDirectory, EmbeddedLocation, LineAndColumnRange, Location, PrintLocationFn,
+    RelativePathToSourceFile, Span, TextSource, line_and_column_range, text_with_carats,
 };
 use intern::Lookup;
 use isograph_config::CompilerConfig;
 use pico::{Database, SourceId, Storage};
 use pico_macros::{Db, Source, memo};
 use prelude::Postfix;
+use serde::Serialize;
 
 use crate::CompilationProfile;
 
@@ -21,7 +23,9 @@ pub struct IsographDatabase<TCompilationProfile: CompilationProfile> {
     storage: Storage<Self>,
     #[tracked]
     iso_literal_map: IsoLiteralMap,
-    #[tracked]
+    // Schema files change rarely, so memoized functions that only depend on
+    // them need not be re-verified when an iso literal or open file changes.
+    #[tracked(durability = high)]
     standard_sources: StandardSources,
     #[tracked]
     open_file_map: OpenFileMap,
@@ -31,7 +35,9 @@ pub struct IsographDatabase<TCompilationProfile: CompilationProfile> {
 #[derive(Debug, Default)]
 pub struct IsoLiteralMap(pub HashMap<RelativePathToSourceFile, SourceId<IsoLiteralsSource>>);
 
-#[derive(Debug, Clone, PartialEq, Eq, Source)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Source)]
+#[durability(high)]
+#[source(persist)]
 pub struct SchemaSource {
     #[key]
     pub relative_path: RelativePathToSourceFile,
@@ -64,9 +70,7 @@ pub struct OpenFileSource {
 // thus we can thread this further back, but that is not yet implemented.
 #[derive(Debug, Clone, Default)]
 pub struct StandardSources {
-    // TODO we should just store this as a singleton, and not have it in standard sources.
-    // Or perhaps, we store the schema source directly here.
-    pub schema_source_id: SourceId<SchemaSource>,
+    pub schema_sources: BTreeMap<RelativePathToSourceFile, SourceId<SchemaSource>>,
     pub schema_extension_sources: BTreeMap<RelativePathToSourceFile, SourceId<SchemaSource>>,
 }
 
@@ -82,8 +86,22 @@ impl<TCompilationProfile: CompilationProfile> IsographDatabase<TCompilationProfi
             .expect("Expected CompilerConfig to have been set")
     }
 
-    pub fn get_schema_source(&self) -> &SchemaSource {
-        self.get(self.standard_sources.schema_source_id)
+    /// The schema file or schema extension at `relative_path`, if there is one.
+    pub fn get_schema_source(
+        &self,
+        relative_path: RelativePathToSourceFile,
+    ) -> Option<&SchemaSource> {
+        let standard_sources = self.get_standard_sources();
+        let standard_sources = standard_sources.tracked();
+        standard_sources
+            .schema_sources
+            .get(&relative_path)
+            .or_else(|| {
+                standard_sources
+                    .schema_extension_sources
+                    .get(&relative_path)
+            })
+            .map(|&source_id| self.get(source_id))
     }
 
     pub fn get_open_file(
@@ -96,6 +114,31 @@ impl<TCompilationProfile: CompilationProfile> IsographDatabase<TCompilationProfi
         self.get_open_file_map().tracked().0.get(&file).cloned()
     }
 
+    pub fn remove_schema(
+        &mut self,
+        relative_path: RelativePathToSourceFile,
+    ) -> Option<SourceId<SchemaSource>> {
+        self.get_standard_sources_mut()
+            .tracked()
+            .schema_sources
+            .remove(&relative_path)
+            .inspect(|&source_id| self.remove(source_id))
+    }
+
+    pub fn remove_schemas_from_path(&mut self, relative_path: &Path) {
+        let removed_source_ids = self
+            .get_standard_sources_mut()
+            .tracked()
+            .schema_sources
+            .extract_if(.., |k, _| Path::new(k.lookup()).starts_with(relative_path))
+            .map(|(_, v)| v)
+            .collect::<Vec<_>>();
+
+        for source_id in removed_source_ids {
+            self.remove(source_id);
+        }
+    }
+
     pub fn remove_schema_extension(
         &mut self,
         relative_path: RelativePathToSourceFile,
@@ -228,6 +271,15 @@ impl<TCompilationProfile: CompilationProfile> IsographDatabase<TCompilationProfi
         })
         .boxed()
     }
+
+    /// The rows and columns (within the entire file) of an embedded location, or None
+    /// if the file cannot be found.
+    pub fn line_and_column_range(&self, location: EmbeddedLocation) -> Option<LineAndColumnRange> {
+        let file_text =
+            file_text_at_location(self, location.text_source.relative_path_to_source_file)
+                .as_ref()?;
+        line_and_column_range(file_text, location.text_source.span, location.span).wrap_some()
+    }
 }
 
 // TODO use this in hover, etc
@@ -272,23 +324,7 @@ fn file_text_at_location<TCompilationProfile: CompilationProfile>(
             .wrap_some();
     }
 
-    let standard_sources = db.get_standard_sources();
-    let standard_sources = standard_sources.tracked();
-    let config = db.get_isograph_config();
-
-    // Then schema source
-    if relative_path == config.schema.relative_path {
-        return db
-            .get(standard_sources.schema_source_id)
-            .content
-            .clone()
-            .wrap_some();
-    }
-
-    // Is it a schema extension? Maybe! Try that.
-    return db
-        .standard_sources
-        .schema_extension_sources
-        .get(&relative_path)
-        .map(|extension_source| db.get(extension_source.dereference()).content.clone());
+    // Then schema sources and schema extensions
+    db.get_schema_source(relative_path)
+        .map(|schema_source| schema_source.content.clone())
 }
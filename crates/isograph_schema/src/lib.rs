@@ -20,6 +20,7 @@ mod selection_set_for_parent_query;
 mod target_platform;
 mod validate;
 mod validate_argument_types;
+mod validate_client_selectable_cycles;
 mod validate_entrypoint;
 mod validate_selection_sets;
 mod validate_use_of_arguments;
@@ -48,6 +49,7 @@ pub use selectable::*;
 pub use selection_set_for_parent_query::*;
 pub use target_platform::*;
 pub use validate::*;
+pub use validate_client_selectable_cycles::*;
 pub use validate_entrypoint::*;
 pub use validate_use_of_arguments::*;
 pub use validated_isograph_schema::*;
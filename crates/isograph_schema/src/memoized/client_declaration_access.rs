@@ -98,6 +98,21 @@ pub fn client_selectable_declaration<TCompilationProfile: CompilationProfile>(
         .map(|x| x.item)
 }
 
+/// The location of the name of the client selectable in its declaration, if it is
+/// declared in an iso literal.
+pub fn client_selectable_declaration_location<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_object_entity_name: EntityName,
+    client_selectable_name: SelectableName,
+) -> Option<Location> {
+    client_selectable_declaration(db, parent_object_entity_name, client_selectable_name).map(
+        |declaration| match declaration {
+            SelectionType::Scalar(s) => s.lookup(db).client_field_name.location.into(),
+            SelectionType::Object(o) => o.lookup(db).client_pointer_name.location.into(),
+        },
+    )
+}
+
 #[memo]
 pub fn client_field_declaration<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
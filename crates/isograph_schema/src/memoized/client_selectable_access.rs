@@ -3,11 +3,13 @@ use std::collections::{BTreeMap, HashMap, btree_map::Entry};
 use crate::{
     ClientObjectSelectable, ClientScalarSelectable, CompilationProfile, IsographDatabase,
     MemoRefClientSelectable, add_client_scalar_selectable_to_entity, client_field_declaration,
-    client_pointer_declaration, client_selectable_declaration_map_from_iso_literals,
-    get_link_fields_map, process_client_pointer_declaration_inner,
+    client_pointer_declaration, client_selectable_declaration_location,
+    client_selectable_declaration_map_from_iso_literals, get_link_fields_map,
+    process_client_pointer_declaration_inner,
 };
 use common_lang_types::{
-    Diagnostic, DiagnosticResult, EntityName, Location, SelectableName, WithLocation,
+    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, Location, SelectableName,
+    WithLocation,
 };
 use isograph_lang_types::{
     ClientFieldDeclaration, ClientPointerDeclaration, SelectionType, SelectionTypePostfix,
@@ -88,7 +90,7 @@ fn deprecated_client_object_selectable_named<TCompilationProfile: CompilationPro
     object_selectable.dereference().wrap_some().wrap_ok()
 }
 
-#[memo]
+#[memo(cycle_result = deprecated_client_selectable_named_cycle_result)]
 pub fn deprecated_client_selectable_named<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     parent_object_entity_name: EntityName,
@@ -140,6 +142,23 @@ pub fn deprecated_client_selectable_named<TCompilationProfile: CompilationProfil
     }
 }
 
+fn deprecated_client_selectable_named_cycle_result<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_object_entity_name: EntityName,
+    client_selectable_name: SelectableName,
+) -> DiagnosticResult<Option<MemoRefClientSelectable<TCompilationProfile>>> {
+    selectable_cyclic_dependency_diagnostic(
+        parent_object_entity_name,
+        client_selectable_name,
+        client_selectable_declaration_location(
+            db,
+            parent_object_entity_name,
+            client_selectable_name,
+        ),
+    )
+    .wrap_err()
+}
+
 #[memo]
 // TODO this function seems quite useless!
 pub fn deprecated_client_selectables_defined_by_network_protocol<
@@ -160,6 +179,28 @@ pub fn deprecated_client_selectables_defined_by_network_protocol<
         .wrap_ok()
 }
 
+/// The location of the definition of a client selectable that is defined by the
+/// network protocol, e.g. of the `@exposeField` directive that defines it. This is
+/// a location in the schema.
+#[memo]
+pub fn client_selectable_defined_by_network_protocol_location<
+    TCompilationProfile: CompilationProfile,
+>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_entity_name: EntityName,
+    selectable_name: SelectableName,
+) -> Option<EmbeddedLocation> {
+    TCompilationProfile::deprecated_parse_type_system_documents(db)
+        .as_ref()
+        .ok()?
+        .0
+        .item
+        .selectables
+        .get(&(parent_entity_name, selectable_name))?
+        .location
+        .as_embedded_location()
+}
+
 // TODO use this as a source for the other functions, especially for
 // client_scalar_selectable_named
 #[expect(clippy::type_complexity)]
@@ -258,6 +299,20 @@ pub fn selectable_is_not_defined_diagnostic(
     )
 }
 
+pub fn selectable_cyclic_dependency_diagnostic(
+    parent_object_entity_name: EntityName,
+    selectable_name: SelectableName,
+    location: Option<Location>,
+) -> Diagnostic {
+    Diagnostic::new(
+        format!(
+            "`{parent_object_entity_name}.{selectable_name}` could not be processed, \
+            because it (transitively) depends on itself."
+        ),
+        location,
+    )
+}
+
 pub fn entity_not_defined_diagnostic(
     entity_name: EntityName,
     location: Option<Location>,
@@ -1,17 +1,22 @@
-use std::collections::{HashMap, hash_map::Entry};
+use std::collections::{BTreeSet, HashMap, HashSet, hash_map::Entry};
 
-use common_lang_types::{Diagnostic, DiagnosticResult, EntityName, Location, SelectableName};
+use common_lang_types::{
+    Diagnostic, DiagnosticResult, EntityName, ExpectEntityToExist, Location, SelectableName,
+    StringLiteralValue, WithEmbeddedLocation,
+};
 use isograph_lang_parser::IsoLiteralExtractionResult;
 use isograph_lang_types::{
-    DefinitionLocation, EntrypointDeclaration, SelectionType, from_isograph_field_directives,
+    DeferDirectiveSet, DefinitionLocation, EntrypointDeclaration, ObjectSelectionDirectiveSet,
+    ScalarSelectionDirectiveSet, Selection, SelectionType, from_isograph_field_directives,
 };
 use pico_macros::memo;
 use prelude::{ErrClone, Postfix};
 
 use crate::{
-    CompilationProfile, EntrypointDeclarationInfo, IsographDatabase, flattened_entity_named,
-    parse_iso_literal_in_source, selectable_is_not_defined_diagnostic,
-    selectable_is_wrong_type_diagnostic, selectable_named,
+    CompilationProfile, EntrypointDeclarationInfo, IsographDatabase, NetworkProtocol,
+    TYPENAME_FIELD_NAME, flattened_entity_named, parse_iso_literal_in_source,
+    selectable_is_not_defined_diagnostic, selectable_is_wrong_type_diagnostic, selectable_named,
+    selectable_reader_selection_set, visit_selection_set::visit_selection_set,
 };
 
 #[memo]
@@ -42,7 +47,8 @@ pub fn validated_entrypoints<TCompilationProfile: CompilationProfile>(
     // To validate an entrypoint, we confirm that its parent type exists and the client field is defined,
     // which we can validate by ensuring that the client scalar selectable exists.
     //
-    // We also validate that it is a fetchable type.
+    // We also validate that it is a fetchable type, and if it is a subscription, that it
+    // selects exactly one root field and does not select any fields loadably.
     for entrypoint_declaration_info in entrypoints {
         let value = (|| {
             let selectable = selectable_named(
@@ -63,6 +69,27 @@ pub fn validated_entrypoints<TCompilationProfile: CompilationProfile>(
                 )
                 .wrap_err(),
                 Some(DefinitionLocation::Client(SelectionType::Scalar(_))) => {
+                    validate_defer_labels_are_unique(
+                        db,
+                        entrypoint_declaration_info.parent_type.item.0,
+                        entrypoint_declaration_info.client_field_name.item.0,
+                    )?;
+
+                    if TCompilationProfile::NetworkProtocol::is_subscription_root_entity(
+                        db,
+                        entrypoint_declaration_info.parent_type.item.0,
+                    ) {
+                        validate_subscription_entrypoint(
+                            db,
+                            entrypoint_declaration_info.parent_type.item.0,
+                            entrypoint_declaration_info.client_field_name.item.0,
+                            entrypoint_declaration_info
+                                .client_field_name
+                                .location
+                                .into(),
+                        )?;
+                    }
+
                     Ok(EntrypointDeclarationInfo {
                         iso_literal_text: entrypoint_declaration_info.iso_literal_text,
                         directive_set: from_isograph_field_directives(
@@ -151,3 +178,232 @@ pub fn validated_entrypoints<TCompilationProfile: CompilationProfile>(
 
     out
 }
+
+/// The runtime normalizes each incremental payload using the normalization AST for its
+/// label, so each `@defer` label can only be used at a single position in the response
+/// of the entrypoint's query (including within the client fields that it selects
+/// non-loadably, since those are part of the same query).
+fn validate_defer_labels_are_unique<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    entrypoint_parent_entity_name: EntityName,
+    entrypoint_name: SelectableName,
+) -> DiagnosticResult<()> {
+    let selection_set =
+        selectable_reader_selection_set(db, entrypoint_parent_entity_name, entrypoint_name)
+            .clone_err()?
+            .lookup(db);
+
+    validate_defer_labels_in_selection_set(
+        db,
+        &selection_set.item.selections,
+        entrypoint_parent_entity_name,
+        &mut DeferLabelValidationState {
+            entrypoint: (entrypoint_parent_entity_name, entrypoint_name),
+            path: vec![],
+            defer_label_paths: HashMap::new(),
+            client_scalar_selectable_stack: vec![(entrypoint_parent_entity_name, entrypoint_name)],
+        },
+    )
+}
+
+struct DeferLabelValidationState {
+    entrypoint: (EntityName, SelectableName),
+    /// The object selections from the root of the query to the current selection set
+    path: Vec<SelectableName>,
+    defer_label_paths: HashMap<StringLiteralValue, Vec<SelectableName>>,
+    /// The client fields that are being visited, which we do not visit again if they
+    /// are selected recursively. Cycles are reported elsewhere.
+    client_scalar_selectable_stack: Vec<(EntityName, SelectableName)>,
+}
+
+fn validate_defer_labels_in_selection_set<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    selections: &[WithEmbeddedLocation<Selection>],
+    parent_entity_name: EntityName,
+    state: &mut DeferLabelValidationState,
+) -> DiagnosticResult<()> {
+    for selection in selections {
+        match selection.item.reference() {
+            SelectionType::Scalar(scalar_selection) => {
+                // Loadable fields are fetched in a separate query
+                if let ScalarSelectionDirectiveSet::Loadable(_) =
+                    scalar_selection.scalar_selection_directive_set
+                {
+                    continue;
+                }
+                let key = (parent_entity_name, scalar_selection.name.item);
+                if !matches!(
+                    selectable_named(db, parent_entity_name, scalar_selection.name.item),
+                    Ok(Some(DefinitionLocation::Client(SelectionType::Scalar(_))))
+                ) || state.client_scalar_selectable_stack.contains(&key)
+                {
+                    continue;
+                }
+
+                let selection_set = selectable_reader_selection_set(db, key.0, key.1)
+                    .clone_err()?
+                    .lookup(db);
+                state.client_scalar_selectable_stack.push(key);
+                validate_defer_labels_in_selection_set(
+                    db,
+                    &selection_set.item.selections,
+                    parent_entity_name,
+                    state,
+                )?;
+                state.client_scalar_selectable_stack.pop();
+            }
+            SelectionType::Object(object_selection) => {
+                if let ObjectSelectionDirectiveSet::Defer(DeferDirectiveSet { defer }) =
+                    object_selection.object_selection_directive_set
+                {
+                    let first_path = state
+                        .defer_label_paths
+                        .entry(defer.label)
+                        .or_insert_with(|| state.path.clone());
+                    if *first_path != state.path {
+                        let (entrypoint_parent_entity_name, entrypoint_name) = state.entrypoint;
+                        return Diagnostic::new(
+                            format!(
+                                "The label \"{}\" is used by multiple @defer directives in the \
+                                `{entrypoint_parent_entity_name}.{entrypoint_name}` entrypoint. \
+                                Labels must be unique within an entrypoint.",
+                                defer.label
+                            ),
+                            object_selection.name.location.to::<Location>().wrap_some(),
+                        )
+                        .wrap_err();
+                    }
+                }
+
+                let target_entity_name =
+                    match selectable_named(db, parent_entity_name, object_selection.name.item)
+                        .clone_err()?
+                    {
+                        Some(DefinitionLocation::Server(s)) => {
+                            match s.lookup(db).target_entity.item.clone_err() {
+                                Ok(annotation) => annotation.inner().0,
+                                Err(_) => continue,
+                            }
+                        }
+                        Some(DefinitionLocation::Client(SelectionType::Object(o))) => {
+                            o.lookup(db).target_entity.inner().0
+                        }
+                        Some(DefinitionLocation::Client(SelectionType::Scalar(_))) | None => {
+                            continue;
+                        }
+                    };
+
+                state.path.push(object_selection.name.item);
+                validate_defer_labels_in_selection_set(
+                    db,
+                    &object_selection.selection_set.item.selections,
+                    target_entity_name,
+                    state,
+                )?;
+                state.path.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Each event of a subscription is normalized on its own, so a subscription entrypoint
+/// must select exactly one root field (including via client fields), and no field
+/// can be selected loadably, since there is no way to refetch part of an event.
+fn validate_subscription_entrypoint<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    subscription_entity_name: EntityName,
+    entrypoint_name: SelectableName,
+    location: Location,
+) -> DiagnosticResult<()> {
+    let mut root_field_names = BTreeSet::new();
+    let mut visited = HashSet::new();
+    let mut client_scalar_selectables_to_visit = vec![(subscription_entity_name, entrypoint_name)];
+
+    while let Some(key) = client_scalar_selectables_to_visit.pop() {
+        if !visited.insert(key) {
+            continue;
+        }
+        let (parent_entity_name, client_scalar_selectable_name) = key;
+
+        let selection_set =
+            selectable_reader_selection_set(db, parent_entity_name, client_scalar_selectable_name)
+                .clone_err()?
+                .lookup(db);
+        let parent_entity = flattened_entity_named(db, parent_entity_name)
+            .expect_entity_to_exist(parent_entity_name)
+            .lookup(db);
+
+        if parent_entity_name == subscription_entity_name {
+            for selection in selection_set.item.selections.iter() {
+                let selectable_name = selection.item.name();
+                if selectable_name != *TYPENAME_FIELD_NAME
+                    && let Some(DefinitionLocation::Server(_)) =
+                        selectable_named(db, parent_entity_name, selectable_name).clone_err()?
+                {
+                    root_field_names.insert(selectable_name);
+                }
+            }
+        }
+
+        let mut loadable_selection = None;
+        visit_selection_set(
+            db,
+            &selection_set.item.selections,
+            parent_entity,
+            &mut |selection, parent_entity| {
+                let SelectionType::Scalar(scalar_selection) = selection else {
+                    return;
+                };
+                if let ScalarSelectionDirectiveSet::Loadable(_) =
+                    scalar_selection.scalar_selection_directive_set
+                {
+                    loadable_selection.get_or_insert(scalar_selection.name);
+                } else if let Ok(Some(DefinitionLocation::Client(_))) =
+                    selectable_named(db, parent_entity.name.item, scalar_selection.name.item)
+                {
+                    client_scalar_selectables_to_visit
+                        .push((parent_entity.name.item, scalar_selection.name.item));
+                }
+            },
+        );
+
+        if let Some(loadable_selection) = loadable_selection {
+            return Diagnostic::new(
+                format!(
+                    "`{subscription_entity_name}.{entrypoint_name}` is a subscription entrypoint. \
+                    `{}` is selected loadably, which is not supported in subscriptions.",
+                    loadable_selection.item
+                ),
+                loadable_selection.location.to::<Location>().wrap_some(),
+            )
+            .wrap_err();
+        }
+    }
+
+    if root_field_names.len() != 1 {
+        return Diagnostic::new(
+            format!(
+                "`{subscription_entity_name}.{entrypoint_name}` is a subscription entrypoint, \
+                and must select exactly one root field. However, it selects {}{}.",
+                root_field_names.len(),
+                if root_field_names.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        ": {}",
+                        root_field_names
+                            .iter()
+                            .map(|name| format!("`{name}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                }
+            ),
+            location.wrap_some(),
+        )
+        .wrap_err();
+    }
+
+    Ok(())
+}
@@ -4,13 +4,14 @@ use pico_macros::memo;
 use prelude::{ErrClone, Postfix};
 
 use crate::{
-    CompilationProfile, IsographDatabase, MemoRefSelectable, deprecated_client_selectable_map,
+    CompilationProfile, IsographDatabase, MemoRefSelectable,
+    client_selectable_declaration_location, deprecated_client_selectable_map,
     deprecated_client_selectable_named, entity_not_defined_diagnostic, flattened_selectable_named,
     flattened_selectables, flattened_selectables_for_entity,
-    multiple_selectable_definitions_found_diagnostic,
+    multiple_selectable_definitions_found_diagnostic, selectable_cyclic_dependency_diagnostic,
 };
 
-#[memo]
+#[memo(cycle_result = selectable_named_cycle_result)]
 pub fn selectable_named<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     parent_server_object_entity_name: EntityName,
@@ -66,6 +67,23 @@ pub fn selectable_named<TCompilationProfile: CompilationProfile>(
     }
 }
 
+fn selectable_named_cycle_result<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_server_object_entity_name: EntityName,
+    selectable_name: SelectableName,
+) -> DiagnosticResult<Option<MemoRefSelectable<TCompilationProfile>>> {
+    selectable_cyclic_dependency_diagnostic(
+        parent_server_object_entity_name,
+        selectable_name,
+        client_selectable_declaration_location(
+            db,
+            parent_server_object_entity_name,
+            selectable_name,
+        ),
+    )
+    .wrap_err()
+}
+
 #[memo]
 pub fn selectables_for_entity<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
@@ -1,8 +1,8 @@
 use std::{collections::BTreeMap, fmt::Debug, hash::Hash};
 
 use common_lang_types::{
-    DiagnosticResult, EntityName, QueryExtraInfo, QueryOperationName, QueryText, SelectableName,
-    WithLocation,
+    DiagnosticResult, EntityName, EnumLiteralValue, QueryExtraInfo, QueryOperationName, QueryText,
+    SelectableName, WithLocation,
 };
 use isograph_lang_types::VariableDeclaration;
 
@@ -29,10 +29,38 @@ pub struct DeprecatedParseTypeSystemOutcome<TCompilationProfile: CompilationProf
 }
 
 pub trait NetworkProtocol:
-    Debug + Clone + Copy + Eq + PartialEq + Ord + PartialOrd + Hash + Default + Sized + 'static
+    Debug
+    + Clone
+    + Copy
+    + Eq
+    + PartialEq
+    + Ord
+    + PartialOrd
+    + Hash
+    + Default
+    + Sized
+    + Send
+    + Sync
+    + 'static
 {
-    type EntityAssociatedData: Debug + PartialEq + Eq + Clone + Hash + Ord + PartialOrd;
-    type SelectableAssociatedData: Debug + PartialEq + Eq + Clone + Hash + Ord + PartialOrd;
+    type EntityAssociatedData: Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Hash
+        + Ord
+        + PartialOrd
+        + Send
+        + Sync;
+    type SelectableAssociatedData: Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Hash
+        + Ord
+        + PartialOrd
+        + Send
+        + Sync;
 
     fn generate_query_text<'a, TCompilationProfile: CompilationProfile<NetworkProtocol = Self>>(
         db: &IsographDatabase<TCompilationProfile>,
@@ -57,6 +85,23 @@ pub trait NetworkProtocol:
         entity_name: EntityName,
     ) -> DiagnosticResult<EntityName>;
 
+    /// Returns true if fetching the given (root) entity results in a stream of
+    /// events, each of which has the shape of the selection set, instead of in a
+    /// single response. For GraphQL, this is the case for the subscription root type.
+    fn is_subscription_root_entity<
+        TCompilationProfile: CompilationProfile<NetworkProtocol = Self>,
+    >(
+        db: &IsographDatabase<TCompilationProfile>,
+        entity_name: EntityName,
+    ) -> bool;
+
+    /// Returns the values of the given entity, if it is an enum. Enum literals that
+    /// are passed as arguments are validated against these.
+    fn enum_values<TCompilationProfile: CompilationProfile<NetworkProtocol = Self>>(
+        db: &IsographDatabase<TCompilationProfile>,
+        entity_name: EntityName,
+    ) -> Option<&[EnumLiteralValue]>;
+
     // TODO: include `QueryText` to incrementally adopt persisted documents
     fn generate_query_extra_info(
         query_name: QueryOperationName,
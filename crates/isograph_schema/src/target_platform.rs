@@ -1,14 +1,54 @@
-use std::{fmt::Debug, hash::Hash};
+use std::{collections::BTreeSet, fmt::Debug, hash::Hash};
 
 use common_lang_types::{EntityName, JavascriptName, SelectableName};
 
 use crate::{CompilationProfile, IsographDatabase};
 
+/// The scalar types that are used in a generated type, so that the artifact
+/// containing that type can import them if necessary.
+#[derive(Debug, Default)]
+pub struct ScalarTypeImports {
+    /// The scalar entities whose types are used. Their types are imported if they
+    /// are custom scalar types.
+    pub scalar_entity_names: BTreeSet<EntityName>,
+    /// The generated enum types that are used. These are exported from the file
+    /// generated by [`TargetPlatform::generate_enum_types`].
+    pub enum_type_names: BTreeSet<JavascriptName>,
+}
+
 pub trait TargetPlatform:
-    Debug + Clone + Copy + Eq + PartialEq + Ord + PartialOrd + Hash + Default + Sized + 'static
+    Debug
+    + Clone
+    + Copy
+    + Eq
+    + PartialEq
+    + Ord
+    + PartialOrd
+    + Hash
+    + Default
+    + Sized
+    + Send
+    + Sync
+    + 'static
 {
-    type EntityAssociatedData: Debug + PartialEq + Eq + Clone + Hash + Ord + PartialOrd;
-    type SelectableAssociatedData: Debug + PartialEq + Eq + Clone + Hash + Ord + PartialOrd;
+    type EntityAssociatedData: Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Hash
+        + Ord
+        + PartialOrd
+        + Send
+        + Sync;
+    type SelectableAssociatedData: Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Hash
+        + Ord
+        + PartialOrd
+        + Send
+        + Sync;
 
     fn format_server_field_scalar_type<
         TCompilationProfile: CompilationProfile<TargetPlatform = Self>,
@@ -16,6 +56,7 @@ pub trait TargetPlatform:
         db: &IsographDatabase<TCompilationProfile>,
         entity_name: EntityName,
         indentation_level: u8,
+        scalar_type_imports: &mut ScalarTypeImports,
     ) -> String;
 
     fn get_inner_text_for_selectable<
@@ -24,8 +65,15 @@ pub trait TargetPlatform:
         db: &IsographDatabase<TCompilationProfile>,
         parent_object_entity_name: EntityName,
         selectable_name: SelectableName,
+        scalar_type_imports: &mut ScalarTypeImports,
     ) -> JavascriptName;
 
+    /// Generates the types of every enum, which are exported from a single file,
+    /// and imported where they are used. Returns `None` if there are no enums.
+    fn generate_enum_types<TCompilationProfile: CompilationProfile<TargetPlatform = Self>>(
+        db: &IsographDatabase<TCompilationProfile>,
+    ) -> Option<String>;
+
     // TODO replace this with an entity with a JavascriptName, similar to how __typename
     // fields work
     fn generate_link_type<TCompilationProfile: CompilationProfile<TargetPlatform = Self>>(
@@ -14,8 +14,8 @@ use crate::{
     entity_not_defined_diagnostic, flattened_entities, flattened_entity_named,
     flattened_selectables, flattened_server_object_entities, parse_iso_literals,
     process_iso_literals, selectables, server_id_selectable,
-    validate_selection_sets::validate_selection_sets, validate_use_of_arguments,
-    validated_entrypoints,
+    validate_client_selectables_are_not_cyclic, validate_selection_sets::validate_selection_sets,
+    validate_use_of_arguments, validated_entrypoints,
 };
 
 /// In the world of pico, we minimally validate. For example, if the
@@ -40,6 +40,8 @@ pub fn validate_entire_schema<TCompilationProfile: CompilationProfile>(
 
     errors.extend(validate_selection_sets(db));
 
+    errors.extend(validate_client_selectables_are_not_cyclic(db));
+
     maybe_extend(
         &mut errors,
         validate_all_server_selectables_point_to_defined_types(db),
@@ -1,8 +1,8 @@
 use std::collections::BTreeSet;
 
 use common_lang_types::{
-    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, Location, SelectableName,
-    ValueKeyName, WithEmbeddedLocation,
+    Diagnostic, DiagnosticResult, EmbeddedLocation, EntityName, EnumLiteralValue, Location,
+    SelectableName, ValueKeyName, WithEmbeddedLocation,
 };
 use graphql_lang_types::NameValuePair;
 use intern::{Lookup, string_key::StringKey};
@@ -15,8 +15,8 @@ use isograph_lang_types::{
 
 use crate::{
     BOOLEAN_ENTITY_NAME, CompilationProfile, FLOAT_ENTITY_NAME, ID_ENTITY_NAME, INT_ENTITY_NAME,
-    IsographDatabase, STRING_ENTITY_NAME, entity_not_defined_diagnostic,
-    flattened_selectables_for_entity,
+    IsographDatabase, NetworkProtocol, STRING_ENTITY_NAME, entity_not_defined_diagnostic,
+    flattened_entities, flattened_selectables_for_entity,
 };
 
 fn scalar_literal_satisfies_type(
@@ -51,6 +51,116 @@ fn scalar_literal_satisfies_type(
     Ok(())
 }
 
+fn enum_literal_satisfies_type<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    supplied_enum_literal: EnumLiteralValue,
+    target_type: &TypeAnnotationDeclaration,
+    location: EmbeddedLocation,
+) -> DiagnosticResult<()> {
+    let target_entity_names = match target_type {
+        TypeAnnotationDeclaration::Scalar(target_entity_name_wrapper) => {
+            vec![target_entity_name_wrapper.0]
+        }
+        TypeAnnotationDeclaration::Union(target_union) => target_union
+            .variants
+            .iter()
+            .filter_map(|variant| match variant {
+                UnionVariant::Scalar(entity_name_wrapper) => entity_name_wrapper.0.wrap_some(),
+                UnionVariant::Plural(_) => None,
+            })
+            .collect(),
+        TypeAnnotationDeclaration::Plural(_) => vec![],
+    };
+    let target_enums = target_entity_names
+        .into_iter()
+        .filter_map(|entity_name| {
+            (
+                entity_name,
+                TCompilationProfile::NetworkProtocol::enum_values(db, entity_name)?,
+            )
+                .wrap_some()
+        })
+        .collect::<Vec<_>>();
+
+    if target_enums
+        .iter()
+        .any(|(_, enum_values)| enum_values.contains(&supplied_enum_literal))
+    {
+        return Ok(());
+    }
+
+    let Some((target_enum_name, target_enum_values)) = target_enums.first() else {
+        return expected_type_found_something_else_named_diagnostic(
+            target_type,
+            supplied_enum_literal.unchecked_conversion(),
+            "an enum literal",
+            location,
+        )
+        .wrap_err();
+    };
+
+    let enum_containing_literal = flattened_entities(db).keys().find(|entity_name| {
+        TCompilationProfile::NetworkProtocol::enum_values(db, **entity_name)
+            .is_some_and(|enum_values| enum_values.contains(&supplied_enum_literal))
+    });
+    let mut message = match enum_containing_literal {
+        Some(enum_name) => format!(
+            "Expected a value of enum `{target_enum_name}`, but `{supplied_enum_literal}` \
+            is a value of enum `{enum_name}`."
+        ),
+        None => {
+            format!("`{supplied_enum_literal}` is not a value of enum `{target_enum_name}`.")
+        }
+    };
+    if let Some(suggestion) = did_you_mean(
+        supplied_enum_literal.lookup(),
+        target_enum_values
+            .iter()
+            .map(|enum_value| enum_value.lookup()),
+    ) {
+        message.push_str(&format!(" Did you mean `{suggestion}`?"));
+    }
+
+    Diagnostic::new(message, location.to::<Location>().wrap_some()).wrap_err()
+}
+
+/// Returns the candidate that is most similar to `value`, if any candidate is
+/// similar enough that `value` is plausibly a typo of it.
+fn did_you_mean<'a>(value: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (value.len() / 3).max(1);
+    candidates
+        .map(|candidate| {
+            let distance = if candidate.eq_ignore_ascii_case(value) {
+                0
+            } else {
+                edit_distance(value, candidate)
+            };
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row.push(
+                (previous_row[j] + substitution_cost)
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
+}
+
 fn variable_type_satisfies_argument_type(
     supplied_type: &TypeAnnotationDeclaration,
     target_type: &TypeAnnotationDeclaration,
@@ -221,9 +331,12 @@ pub fn value_satisfies_type<TCompilationProfile: CompilationProfile>(
             selection_supplied_argument_value.location,
             "a float literal",
         ),
-        NonConstantValue::Enum(_enum_literal_value) => {
-            todo!("Support validation of enum literals")
-        }
+        NonConstantValue::Enum(enum_literal_value) => enum_literal_satisfies_type(
+            db,
+            *enum_literal_value,
+            field_argument_definition_type,
+            selection_supplied_argument_value.location,
+        ),
         NonConstantValue::Null => {
             if field_argument_definition_type.is_nullable() {
                 Ok(())
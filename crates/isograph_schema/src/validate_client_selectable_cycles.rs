@@ -0,0 +1,103 @@
+use common_lang_types::{Diagnostic, DiagnosticResult, EntityName, SelectableName};
+use isograph_lang_types::{DefinitionLocation, ScalarSelectionDirectiveSet, SelectionType};
+use pico_macros::memo;
+use prelude::{ErrClone, Postfix};
+
+use crate::{
+    CompilationProfile, IsographDatabase, client_selectable_declaration_location,
+    flattened_entity_named, reader_selection_set_map, selectable_cyclic_dependency_diagnostic,
+    selectable_named, visit_selection_set::visit_selection_set,
+};
+
+/// Validate that no client selectables (transitively) select themselves, i.e. that
+/// we can generate a reader and a merged selection set for every client selectable.
+///
+/// Selections of client fields that are selected loadably are not followed, since
+/// those client fields are fetched separately.
+pub fn validate_client_selectables_are_not_cyclic<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+) -> Vec<Diagnostic> {
+    let mut keys = reader_selection_set_map(db)
+        .keys()
+        .copied()
+        .collect::<Vec<_>>();
+    keys.sort();
+
+    keys.into_iter()
+        .flat_map(|(parent_entity_name, selectable_name)| {
+            validate_client_selectable_is_not_cyclic(db, parent_entity_name, selectable_name)
+                .as_ref()
+                .err()
+                .cloned()
+        })
+        .collect()
+}
+
+#[memo(cycle_result = validate_client_selectable_is_not_cyclic_cycle_result)]
+fn validate_client_selectable_is_not_cyclic<TCompilationProfile: CompilationProfile>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_entity_name: EntityName,
+    selectable_name: SelectableName,
+) -> DiagnosticResult<()> {
+    // Other validations report missing selection sets and entities
+    let Some(Ok(selection_set)) = reader_selection_set_map(db)
+        .get(&(parent_entity_name, selectable_name))
+        .cloned()
+    else {
+        return Ok(());
+    };
+    let selection_set = match selection_set {
+        SelectionType::Scalar(s) => s.lookup(db),
+        SelectionType::Object(o) => o.lookup(db),
+    };
+    let Some(parent_entity) = flattened_entity_named(db, parent_entity_name) else {
+        return Ok(());
+    };
+
+    let mut client_selectables_to_visit = vec![];
+    visit_selection_set(
+        db,
+        &selection_set.item.selections,
+        parent_entity.lookup(db),
+        &mut |selection, parent_entity| {
+            let selectable_name = match selection {
+                SelectionType::Scalar(scalar_selection) => {
+                    if let ScalarSelectionDirectiveSet::Loadable(_) =
+                        scalar_selection.scalar_selection_directive_set
+                    {
+                        return;
+                    }
+                    scalar_selection.name.item
+                }
+                SelectionType::Object(object_selection) => object_selection.name.item,
+            };
+            if let Ok(Some(DefinitionLocation::Client(_))) =
+                selectable_named(db, parent_entity.name.item, selectable_name)
+            {
+                client_selectables_to_visit.push((parent_entity.name.item, selectable_name));
+            }
+        },
+    );
+
+    for (parent_entity_name, selectable_name) in client_selectables_to_visit {
+        validate_client_selectable_is_not_cyclic(db, parent_entity_name, selectable_name)
+            .clone_err()?;
+    }
+
+    Ok(())
+}
+
+fn validate_client_selectable_is_not_cyclic_cycle_result<
+    TCompilationProfile: CompilationProfile,
+>(
+    db: &IsographDatabase<TCompilationProfile>,
+    parent_entity_name: EntityName,
+    selectable_name: SelectableName,
+) -> DiagnosticResult<()> {
+    selectable_cyclic_dependency_diagnostic(
+        parent_entity_name,
+        selectable_name,
+        client_selectable_declaration_location(db, parent_entity_name, selectable_name),
+    )
+    .wrap_err()
+}
@@ -6,6 +6,7 @@ use isograph_lang_types::{
     DefinitionLocation, DefinitionLocationPostfix, ObjectSelectionDirectiveSet,
     ScalarSelectionDirectiveSet, SelectionSet, SelectionType,
 };
+use pico::Database;
 use prelude::{ErrClone, Postfix};
 use std::collections::HashSet;
 
@@ -20,13 +21,13 @@ pub(crate) fn validate_selection_sets<TCompilationProfile: CompilationProfile>(
 ) -> Vec<Diagnostic> {
     let selection_sets = reader_selection_set_map(db);
 
-    let mut errors = vec![];
-    for (key, selection_set) in selection_sets {
+    db.par_map(selection_sets, |db, (key, selection_set)| {
+        let mut errors = vec![];
         let selection_set = match selection_set.clone_err() {
             Ok(s) => s,
             Err(e) => {
                 errors.push(e);
-                continue;
+                return errors;
             }
         };
         let selection_set = match selection_set {
@@ -44,7 +45,7 @@ pub(crate) fn validate_selection_sets<TCompilationProfile: CompilationProfile>(
                     key.0,
                     Location::Generated.wrap_some(),
                 ));
-                continue;
+                return errors;
             }
         };
 
@@ -57,10 +58,12 @@ pub(crate) fn validate_selection_sets<TCompilationProfile: CompilationProfile>(
                 parent_entity_name: key.0,
                 selectable_name: key.1,
             },
-        )
-    }
-
-    errors
+        );
+        errors
+    })
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 /// for each selection, validate that it corresponds to a selectable of the correct SelectionType,
@@ -320,7 +323,7 @@ fn validate_selection_set<TCompilationProfile: CompilationProfile>(
                     },
                 };
 
-                // @updatable is not supported on client fields
+                // @updatable and @defer are not supported on client fields
                 let target_entity_name = match selectable {
                     DefinitionLocation::Server(s) => {
                         match s.lookup(db).target_entity.item.clone_err() {
@@ -343,6 +346,14 @@ fn validate_selection_set<TCompilationProfile: CompilationProfile>(
                                     object_selection.name.location.to::<Location>().wrap_some(),
                                 ))
                             }
+                            ObjectSelectionDirectiveSet::Defer(_) => errors.push(Diagnostic::new(
+                                format!(
+                                    "`{}.{}` is a client object field. \
+                                        @defer is not supported on client object fields.",
+                                    parent_entity.name, object_selection.name.item
+                                ),
+                                object_selection.name.location.to::<Location>().wrap_some(),
+                            )),
                             ObjectSelectionDirectiveSet::None(_) => {}
                         }
 
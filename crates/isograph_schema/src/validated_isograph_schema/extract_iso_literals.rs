@@ -0,0 +1,577 @@
+use std::collections::HashMap;
+
+use common_lang_types::{
+    Diagnostic, DiagnosticResult, Location, RelativePathToSourceFile, Span, TextSource,
+};
+use intern::Lookup;
+use prelude::Postfix;
+use swc_common::{BytePos, Spanned, input::StringInput};
+use swc_ecma_ast::{
+    Callee, Decl, ExportDecl, ExportDefaultExpr, ExportSpecifier, Expr, ModuleDecl,
+    ModuleExportName, ModuleItem, Pat, Program, TaggedTpl, Tpl, VarDecl, VarDeclKind,
+};
+use swc_ecma_parser::{EsSyntax, Parser, Syntax, TsSyntax};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::IsoLiteralExtraction;
+
+use super::script_blocks::{ScriptBlock, ScriptLanguage, script_blocks};
+
+/// swc treats `BytePos(0)` as a dummy position, so the file starts at `BytePos(1)`.
+const START_POS: u32 = 1;
+
+const ISO_FUNCTION_NAME: &str = "iso";
+
+/// The name with which a default export is imported, i.e. `import { default as resolver }`.
+const DEFAULT_EXPORT_NAME: &str = "default";
+
+/// Finds every call of the `iso` function in a file, by parsing the JavaScript or
+/// TypeScript it contains (i.e. the entire file, or e.g. the `<script>` tags of a Vue
+/// single-file component). Malformed calls of the `iso` function (e.g. ones that are not
+/// passed a template literal) are returned as errors, as are script blocks that cannot
+/// be parsed but appear to call the `iso` function.
+///
+/// Indexes and spans are relative to the entire file.
+pub(crate) fn extract_iso_literals(
+    content: &str,
+    relative_path_to_source_file: RelativePathToSourceFile,
+) -> Vec<DiagnosticResult<IsoLiteralExtraction>> {
+    script_blocks(relative_path_to_source_file.lookup(), content)
+        .into_iter()
+        .flat_map(|script_block| {
+            extract_iso_literals_from_script_block(
+                content,
+                script_block,
+                relative_path_to_source_file,
+            )
+        })
+        .collect()
+}
+
+fn extract_iso_literals_from_script_block(
+    content: &str,
+    script_block: ScriptBlock,
+    relative_path_to_source_file: RelativePathToSourceFile,
+) -> Vec<DiagnosticResult<IsoLiteralExtraction>> {
+    // Most files in a project do not use the iso function, so avoid parsing them.
+    if !script_block.content.contains(ISO_FUNCTION_NAME) {
+        return vec![];
+    }
+
+    // The positions of the block are its positions within the entire file, so that
+    // spans are relative to the entire file.
+    let start_pos = START_POS + script_block.start_index as u32;
+    let mut parser = Parser::new(
+        syntax_for_language(script_block.language),
+        StringInput::new(
+            script_block.content,
+            BytePos(start_pos),
+            BytePos(start_pos + script_block.content.len() as u32),
+        ),
+        None,
+    );
+
+    let program = match parser.parse_program() {
+        Ok(program) => program,
+        // Files that we cannot parse (e.g. because they use syntax that swc does not
+        // support) are only worth reporting if they contain iso literals.
+        Err(_) if !might_call_iso_function(script_block.content) => return vec![],
+        Err(error) => {
+            return Diagnostic::new(
+                format!(
+                    "Unable to parse this file, so the iso literals in it cannot be found. {}",
+                    error.kind().msg()
+                ),
+                Location::new(
+                    TextSource {
+                        relative_path_to_source_file,
+                        span: None,
+                    },
+                    to_isograph_span(error.span()),
+                )
+                .wrap_some(),
+            )
+            .wrap_err()
+            .wrap_vec();
+        }
+    };
+
+    let mut visitor = IsoLiteralVisitor {
+        content,
+        relative_path_to_source_file,
+        local_export_names: local_export_names(&program),
+        const_export_name: None,
+        extractions: vec![],
+    };
+    program.visit_with(&mut visitor);
+
+    visitor.extractions
+}
+
+fn syntax_for_language(language: ScriptLanguage) -> Syntax {
+    match language {
+        ScriptLanguage::TypeScript => Syntax::Typescript(TsSyntax {
+            decorators: true,
+            ..Default::default()
+        }),
+        ScriptLanguage::Tsx => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            decorators: true,
+            ..Default::default()
+        }),
+        ScriptLanguage::JavaScript => Syntax::Es(EsSyntax {
+            jsx: true,
+            decorators: true,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Local names that are exported under a (possibly different) name, i.e. `Foo`
+/// in `export { Foo }`, `export { Foo as Bar }` or `export default Foo`.
+fn local_export_names(program: &Program) -> HashMap<String, String> {
+    let mut local_export_names = HashMap::new();
+    let module = match program {
+        Program::Module(module) => module,
+        Program::Script(_) => return local_export_names,
+    };
+
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+                if named_export.src.is_none() =>
+            {
+                for specifier in &named_export.specifiers {
+                    if let ExportSpecifier::Named(specifier) = specifier {
+                        let local_name = module_export_name(&specifier.orig);
+                        let exported_name = specifier
+                            .exported
+                            .as_ref()
+                            .map(module_export_name)
+                            .unwrap_or_else(|| local_name.clone());
+                        local_export_names.insert(local_name, exported_name);
+                    }
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default_expr)) => {
+                if let Expr::Ident(ident) = unwrap_expr(&export_default_expr.expr) {
+                    local_export_names
+                        .insert(ident.sym.to_string(), DEFAULT_EXPORT_NAME.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    local_export_names
+}
+
+fn module_export_name(module_export_name: &ModuleExportName) -> String {
+    match module_export_name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(str) => str.value.to_string(),
+    }
+}
+
+/// Skips over parentheses and type assertions, e.g. the `as const` in
+/// `export const Foo = iso(...)(...) as const`.
+fn unwrap_expr(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren_expr) => unwrap_expr(&paren_expr.expr),
+        Expr::TsAs(ts_as_expr) => unwrap_expr(&ts_as_expr.expr),
+        Expr::TsSatisfies(ts_satisfies_expr) => unwrap_expr(&ts_satisfies_expr.expr),
+        Expr::TsConstAssertion(ts_const_assertion) => unwrap_expr(&ts_const_assertion.expr),
+        Expr::TsNonNull(ts_non_null_expr) => unwrap_expr(&ts_non_null_expr.expr),
+        _ => expr,
+    }
+}
+
+fn is_iso_identifier(expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if ident.sym == *ISO_FUNCTION_NAME)
+}
+
+/// Whether `content` contains something that looks like a call of the iso function,
+/// i.e. `iso(` or iso followed by a backtick, ignoring whitespace. This is used for
+/// content that cannot be parsed, so it is only a heuristic.
+fn might_call_iso_function(content: &str) -> bool {
+    content.match_indices(ISO_FUNCTION_NAME).any(|(index, _)| {
+        let is_start_of_identifier = content[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '.'));
+        let next_char = content[index + ISO_FUNCTION_NAME.len()..]
+            .chars()
+            .find(|c| !c.is_whitespace());
+        is_start_of_identifier && matches!(next_char, Some('(' | '`'))
+    })
+}
+
+fn iso_call_expr(callee: &Callee) -> Option<&swc_ecma_ast::CallExpr> {
+    match callee {
+        Callee::Expr(expr) => match &**expr {
+            Expr::Call(call_expr) if is_iso_callee(&call_expr.callee) => call_expr.wrap_some(),
+            _ => None,
+        },
+        Callee::Super(_) | Callee::Import(_) => None,
+    }
+}
+
+fn is_iso_callee(callee: &Callee) -> bool {
+    match callee {
+        Callee::Expr(expr) => is_iso_identifier(expr),
+        Callee::Super(_) | Callee::Import(_) => false,
+    }
+}
+
+fn to_isograph_span(span: swc_common::Span) -> Span {
+    Span::new(span.lo.0 - START_POS, span.hi.0 - START_POS)
+}
+
+struct IsoLiteralVisitor<'a> {
+    content: &'a str,
+    relative_path_to_source_file: RelativePathToSourceFile,
+    local_export_names: HashMap<String, String>,
+    /// The const export name, and the span of the expression it is assigned, e.g.
+    /// `Foo` and `iso(...)(...)` in `export const Foo = iso(...)(...)`.
+    const_export_name: Option<(swc_common::Span, String)>,
+    extractions: Vec<DiagnosticResult<IsoLiteralExtraction>>,
+}
+
+impl IsoLiteralVisitor<'_> {
+    fn visit_with_const_export_name(&mut self, expr: &Expr, const_export_name: String) {
+        let previous = self
+            .const_export_name
+            .replace((unwrap_expr(expr).span(), const_export_name));
+        expr.visit_with(self);
+        self.const_export_name = previous;
+    }
+
+    fn const_export_name_for(&self, span: swc_common::Span) -> Option<String> {
+        match &self.const_export_name {
+            Some((expr_span, const_export_name)) if *expr_span == span => {
+                const_export_name.clone().wrap_some()
+            }
+            _ => None,
+        }
+    }
+
+    /// `iso_call_expr` is the `iso(...)`, and `span` is the span of the entire usage,
+    /// i.e. including the `(...)` in `iso(...)(...)`.
+    fn push_iso_call(
+        &mut self,
+        iso_call_expr: &swc_ecma_ast::CallExpr,
+        span: swc_common::Span,
+        has_associated_js_function: bool,
+    ) {
+        let template_literal = match iso_call_expr.args.as_slice() {
+            [arg] if arg.spread.is_none() => match &*arg.expr {
+                Expr::Tpl(template_literal) => Some(template_literal),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let extraction = match template_literal {
+            Some(template_literal) => self.extraction_from_template_literal(
+                template_literal,
+                self.const_export_name_for(span),
+                has_associated_js_function,
+                true,
+            ),
+            None => self
+                .diagnostic(
+                    "The iso function must be passed a single template literal, \
+                    e.g. iso(`field Query.Foo { ... }`)."
+                        .to_string(),
+                    iso_call_expr.span,
+                )
+                .wrap_err(),
+        };
+        self.extractions.push(extraction);
+    }
+
+    fn extraction_from_template_literal(
+        &self,
+        template_literal: &Tpl,
+        const_export_name: Option<String>,
+        has_associated_js_function: bool,
+        iso_function_called_with_paren: bool,
+    ) -> DiagnosticResult<IsoLiteralExtraction> {
+        if !template_literal.exprs.is_empty() {
+            return self
+                .diagnostic(
+                    "Substitutions (i.e. `${...}`) are not allowed in iso literals.".to_string(),
+                    template_literal.span,
+                )
+                .wrap_err();
+        }
+
+        // Exclude the backticks
+        let span = to_isograph_span(template_literal.span);
+        let start = span.start as usize + 1;
+        let end = span.end as usize - 1;
+
+        IsoLiteralExtraction {
+            const_export_name,
+            iso_literal_text: self.content[start..end].to_string(),
+            iso_literal_start_index: start,
+            has_associated_js_function,
+            iso_function_called_with_paren,
+        }
+        .wrap_ok()
+    }
+
+    fn diagnostic(&self, message: String, span: swc_common::Span) -> Diagnostic {
+        let span = to_isograph_span(span);
+        Diagnostic::new(
+            message,
+            Location::new(
+                TextSource {
+                    relative_path_to_source_file: self.relative_path_to_source_file,
+                    span: span.wrap_some(),
+                },
+                Span::new(0, span.end - span.start),
+            )
+            .wrap_some(),
+        )
+    }
+}
+
+impl IsoLiteralVisitor<'_> {
+    fn visit_var_decl_with_export(&mut self, var_decl: &VarDecl, is_exported: bool) {
+        for declarator in &var_decl.decls {
+            let const_export_name = match (&declarator.name, &declarator.init) {
+                (Pat::Ident(binding_ident), Some(init)) if var_decl.kind == VarDeclKind::Const => {
+                    let local_name = binding_ident.id.sym.to_string();
+                    if is_exported {
+                        local_name.wrap_some()
+                    } else {
+                        self.local_export_names.get(&local_name).cloned()
+                    }
+                    .map(|const_export_name| (init, const_export_name))
+                }
+                _ => None,
+            };
+
+            match const_export_name {
+                Some((init, const_export_name)) => {
+                    declarator.name.visit_with(self);
+                    self.visit_with_const_export_name(init, const_export_name);
+                }
+                None => declarator.visit_with(self),
+            }
+        }
+    }
+}
+
+impl Visit for IsoLiteralVisitor<'_> {
+    fn visit_export_decl(&mut self, export_decl: &ExportDecl) {
+        match &export_decl.decl {
+            Decl::Var(var_decl) => self.visit_var_decl_with_export(var_decl, true),
+            decl => decl.visit_with(self),
+        }
+    }
+
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        self.visit_var_decl_with_export(var_decl, false);
+    }
+
+    fn visit_export_default_expr(&mut self, export_default_expr: &ExportDefaultExpr) {
+        self.visit_with_const_export_name(
+            &export_default_expr.expr,
+            DEFAULT_EXPORT_NAME.to_string(),
+        );
+    }
+
+    fn visit_call_expr(&mut self, call_expr: &swc_ecma_ast::CallExpr) {
+        if let Some(iso_call_expr) = iso_call_expr(&call_expr.callee) {
+            // iso(`...`)(...)
+            self.push_iso_call(iso_call_expr, call_expr.span, true);
+            call_expr.args.visit_with(self);
+        } else if is_iso_callee(&call_expr.callee) {
+            // iso(`...`)
+            self.push_iso_call(call_expr, call_expr.span, false);
+        } else {
+            call_expr.visit_children_with(self);
+        }
+    }
+
+    fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl) {
+        if is_iso_identifier(&tagged_tpl.tag) {
+            // iso`...`, which is not supported. We extract it anyway, so that we can
+            // report a helpful error.
+            let extraction = self.extraction_from_template_literal(
+                &tagged_tpl.tpl,
+                self.const_export_name_for(tagged_tpl.span),
+                false,
+                false,
+            );
+            self.extractions.push(extraction);
+        } else {
+            tagged_tpl.visit_children_with(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::{Span, line_and_column_range};
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    fn extract(path: &str, content: &str) -> Vec<IsoLiteralExtraction> {
+        extract_iso_literals(content, path.intern().into())
+            .into_iter()
+            .map(|extraction| extraction.expect("Expected extraction to succeed"))
+            .collect()
+    }
+
+    /// The one-indexed row and column of `index` within `content`
+    fn row_and_col(content: &str, index: usize) -> (u32, u32) {
+        let range = line_and_column_range(content, None, Span::new(index as u32, index as u32));
+        (range.start_row.0.get(), range.start_col.0.get())
+    }
+
+    /// Asserts that `extraction` is the iso literal `iso_literal_text`, which starts at
+    /// the given one-indexed row and column of the file.
+    fn assert_extraction(
+        content: &str,
+        extraction: &IsoLiteralExtraction,
+        iso_literal_text: &str,
+        row_and_col_of_start: (u32, u32),
+    ) {
+        assert_eq!(extraction.iso_literal_text, iso_literal_text);
+        assert_eq!(
+            &content[extraction.iso_literal_start_index..][..iso_literal_text.len()],
+            iso_literal_text
+        );
+        assert_eq!(
+            row_and_col(content, extraction.iso_literal_start_index),
+            row_and_col_of_start
+        );
+    }
+
+    #[test]
+    fn vue() {
+        let content = "<template>\n  <div />\n</template>\n\n\
+            <script lang=\"ts\">\n\
+            export const PetName = iso(`field Pet.PetName { name }`)(() => null);\n\
+            </script>\n\n\
+            <script setup lang=\"ts\">\n\
+            const props = defineProps<{ pet: unknown }>();\n\
+            </script>\n";
+
+        let extractions = extract("Pet.vue", content);
+        assert_eq!(extractions.len(), 1);
+        assert_extraction(
+            content,
+            &extractions[0],
+            "field Pet.PetName { name }",
+            (6, 29),
+        );
+        assert_eq!(
+            extractions[0].const_export_name,
+            Some("PetName".to_string())
+        );
+    }
+
+    #[test]
+    fn svelte() {
+        let content = "<script context=\"module\" lang=\"ts\">\n  \
+            export const PetName = iso(`field Pet.PetName { name }`)(() => null);\n\
+            </script>\n\n\
+            <script lang=\"ts\">\n  \
+            let { pet } = $props();\n\
+            </script>\n\n\
+            <p>{pet.name}</p>\n";
+
+        let extractions = extract("Pet.svelte", content);
+        assert_eq!(extractions.len(), 1);
+        assert_extraction(
+            content,
+            &extractions[0],
+            "field Pet.PetName { name }",
+            (2, 31),
+        );
+    }
+
+    #[test]
+    fn astro() {
+        let content = "---\n\
+            import Layout from '../layouts/Layout.astro';\n\n\
+            export const PetName = iso(`field Pet.PetName { name }`)(() => null);\n\
+            ---\n\n\
+            <Layout>\n  \
+            <p>iso(`field Pet.NotAField { name }`)</p>\n\
+            </Layout>\n\
+            <script>\n  \
+            export const PetAge = iso(`field Pet.PetAge { age }`)(() => null);\n\
+            </script>\n";
+
+        let extractions = extract("Pet.astro", content);
+        assert_eq!(extractions.len(), 2);
+        assert_extraction(
+            content,
+            &extractions[0],
+            "field Pet.PetName { name }",
+            (4, 29),
+        );
+        assert_extraction(
+            content,
+            &extractions[1],
+            "field Pet.PetAge { age }",
+            (11, 30),
+        );
+    }
+
+    #[test]
+    fn mdx_code_fence_next_to_esm() {
+        let content = "import { Layout } from './Layout';\n\n\
+            export const PetName = iso(`field Pet.PetName { name }`)(() => null);\n\n\
+            # Declaring fields\n\n\
+            ```js\n\
+            export const NotAField = iso(`field Pet.NotAField { name }`)();\n\
+            ```\n\
+            export const PetAge = iso(`field Pet.PetAge { age }`)(() => null);\n";
+
+        let extractions = extract("Pet.mdx", content);
+        assert_eq!(extractions.len(), 2);
+        assert_extraction(
+            content,
+            &extractions[0],
+            "field Pet.PetName { name }",
+            (3, 29),
+        );
+        assert_extraction(
+            content,
+            &extractions[1],
+            "field Pet.PetAge { age }",
+            (10, 28),
+        );
+    }
+
+    #[test]
+    fn diagnostic_spans_are_relative_to_the_file() {
+        let content = "<template>\n  <div />\n</template>\n\n\
+            <script lang=\"ts\">\n\
+            export const PetName = iso(fieldText)(() => null);\n\
+            </script>\n";
+
+        let extractions = extract_iso_literals(content, "Pet.vue".intern().into());
+        assert_eq!(extractions.len(), 1);
+        let location = extractions[0]
+            .as_ref()
+            .expect_err("Expected extraction to fail")
+            .location()
+            .expect("Expected diagnostic to have a location")
+            .as_embedded_location()
+            .expect("Expected location to be embedded");
+        let span = location.text_source.span.expect("Expected a span");
+        assert_eq!(
+            &content[span.start as usize..span.end as usize],
+            "iso(fieldText)"
+        );
+        assert_eq!(row_and_col(content, span.start as usize), (6, 24));
+    }
+}
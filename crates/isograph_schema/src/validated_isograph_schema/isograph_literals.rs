@@ -1,3 +1,4 @@
+use super::extract_iso_literals::extract_iso_literals;
 use crate::{
     CompilationProfile, IsoLiteralsSource, IsographDatabase, ParsedIsoLiteralsMap,
     UnprocessedSelectionSet, process_client_field_declaration, process_client_pointer_declaration,
@@ -8,11 +9,9 @@ use common_lang_types::{
 };
 use isograph_lang_parser::{IsoLiteralExtractionResult, parse_iso_literal};
 use isograph_lang_types::{EntrypointDeclaration, SelectionTypePostfix};
-use lazy_static::lazy_static;
 use pico::SourceId;
 use pico_macros::memo;
 use prelude::Postfix;
-use regex::Regex;
 
 // TODO this should return a Vec of Results, since a file can contain
 // both valid and invalid iso literals.
@@ -28,11 +27,14 @@ pub fn parse_iso_literals_in_file_content<TCompilationProfile: CompilationProfil
     for iso_literal_extraction in
         extract_iso_literals_from_file_content(db, relative_path_to_source_file).iter()
     {
-        extraction_results.push(process_iso_literal_extraction(
-            db,
-            iso_literal_extraction,
-            relative_path_to_source_file,
-        ))
+        extraction_results.push(match iso_literal_extraction {
+            Ok(iso_literal_extraction) => process_iso_literal_extraction(
+                db,
+                iso_literal_extraction,
+                relative_path_to_source_file,
+            ),
+            Err(e) => e.clone().wrap_err(),
+        })
     }
 
     extraction_results
@@ -47,8 +49,13 @@ pub fn parse_iso_literals_in_file_content_and_return_all<
 ) -> Vec<DiagnosticResult<(IsoLiteralExtractionResult, TextSource)>> {
     extract_iso_literals_from_file_content(db, relative_path_to_source_file)
         .iter()
-        .map(|iso_literal_extraction| {
-            process_iso_literal_extraction(db, iso_literal_extraction, relative_path_to_source_file)
+        .map(|iso_literal_extraction| match iso_literal_extraction {
+            Ok(iso_literal_extraction) => process_iso_literal_extraction(
+                db,
+                iso_literal_extraction,
+                relative_path_to_source_file,
+            ),
+            Err(e) => e.clone().wrap_err(),
         })
         .collect()
 }
@@ -215,17 +222,16 @@ pub fn process_iso_literal_extraction<TCompilationProfile: CompilationProfile>(
     Ok((iso_literal_extraction_result, text_source))
 }
 
-lazy_static! {
-    static ref EXTRACT_ISO_LITERAL: Regex =
-        Regex::new(r"(// )?(export const ([^ ]+) =\s+)?iso(\()?\s*`([^`]+)`,?\s*(\))?(\()?")
-            .unwrap();
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IsoLiteralExtraction {
+    /// The name under which the iso literal is exported, e.g. `Foo` in
+    /// `export const Foo = iso(...)`, `const Foo = iso(...); export { Foo }` or
+    /// `default` in `export default iso(...)`.
     pub const_export_name: Option<String>,
     pub iso_literal_text: String,
     pub iso_literal_start_index: usize,
+    /// true if the result of the iso function is immediately called, as in
+    /// iso(`...`)(...)
     pub has_associated_js_function: bool,
     /// true if the iso function is called as iso(`...`), and false if it is
     /// called as iso`...`. This is tracked as a separate field because some users
@@ -235,11 +241,13 @@ pub struct IsoLiteralExtraction {
     pub iso_function_called_with_paren: bool,
 }
 
+/// Malformed usages of the iso function, and files that cannot be parsed, are
+/// returned as errors.
 #[memo]
 pub fn extract_iso_literals_from_file_content<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     relative_path_to_source_file: RelativePathToSourceFile,
-) -> Vec<IsoLiteralExtraction> {
+) -> Vec<DiagnosticResult<IsoLiteralExtraction>> {
     let IsoLiteralsSource {
         relative_path: _,
         content,
@@ -249,29 +257,10 @@ pub fn extract_iso_literals_from_file_content<TCompilationProfile: CompilationPr
             panic!("Expected relative path (`{relative_path_to_source_file}`) to exist")
         });
 
-    EXTRACT_ISO_LITERAL
-        .captures_iter(content)
-        .flat_map(|captures| {
-            let iso_literal_match = captures.get(5).unwrap();
-            if captures.get(1).is_some() {
-                // HACK
-                // this iso literal is commented out using //, so skip it.
-                return None;
-            }
-            Some(IsoLiteralExtraction {
-                const_export_name: captures
-                    .get(2)
-                    .map(|_| captures.get(3).unwrap().as_str().to_string()),
-                iso_literal_text: iso_literal_match.as_str().to_string(),
-                iso_literal_start_index: iso_literal_match.start(),
-                has_associated_js_function: captures.get(7).is_some(),
-                iso_function_called_with_paren: captures.get(4).is_some(),
-            })
-        })
-        .collect()
+    extract_iso_literals(content, relative_path_to_source_file)
 }
 
-#[memo]
+#[memo(persist)]
 pub fn memoized_parse_iso_literal<TCompilationProfile: CompilationProfile>(
     db: &IsographDatabase<TCompilationProfile>,
     iso_literal_text: String,
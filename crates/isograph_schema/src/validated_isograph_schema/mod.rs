@@ -1,7 +1,10 @@
 mod add_link_fields;
+mod extract_iso_literals;
 mod isograph_literals;
 mod process_iso_literals;
+mod script_blocks;
 
 pub use add_link_fields::*;
 pub use isograph_literals::*;
 pub use process_iso_literals::*;
+pub use script_blocks::{is_iso_literal_file, is_javascript_file};
@@ -0,0 +1,219 @@
+use std::path::Path;
+
+/// The extensions of files in which the compiler looks for iso literals.
+const ISO_LITERAL_FILE_EXTENSIONS: [&str; 8] =
+    ["ts", "tsx", "js", "jsx", "vue", "svelte", "astro", "mdx"];
+
+pub fn is_iso_literal_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| ISO_LITERAL_FILE_EXTENSIONS.contains(&extension))
+}
+
+/// Whether the file is a JavaScript or TypeScript file, as opposed to a file
+/// (such as a Vue single-file component) that contains JavaScript.
+pub fn is_javascript_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension, "ts" | "tsx" | "js" | "jsx"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScriptLanguage {
+    JavaScript,
+    TypeScript,
+    Tsx,
+}
+
+/// A section of a file that contains JavaScript or TypeScript, e.g. the contents
+/// of a `<script>` tag in a Vue single-file component.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ScriptBlock<'a> {
+    pub content: &'a str,
+    /// The index of the start of the block within the file
+    pub start_index: usize,
+    pub language: ScriptLanguage,
+}
+
+pub(crate) fn script_blocks<'a>(path: &str, content: &'a str) -> Vec<ScriptBlock<'a>> {
+    let extension = path.rsplit_once('.').map(|(_, extension)| extension);
+    match extension {
+        Some("ts") => entire_file(content, ScriptLanguage::TypeScript),
+        Some("tsx") => entire_file(content, ScriptLanguage::Tsx),
+        Some("vue") | Some("svelte") => script_tags(content, 0),
+        Some("astro") => astro_script_blocks(content),
+        Some("mdx") => mdx_script_blocks(content),
+        _ => entire_file(content, ScriptLanguage::JavaScript),
+    }
+}
+
+fn entire_file(content: &str, language: ScriptLanguage) -> Vec<ScriptBlock<'_>> {
+    vec![ScriptBlock {
+        content,
+        start_index: 0,
+        language,
+    }]
+}
+
+/// The contents of every `<script>` tag, e.g. `<script setup lang="ts">...</script>`.
+/// `search_start_index` is the index at which we start looking for script tags.
+fn script_tags(content: &str, search_start_index: usize) -> Vec<ScriptBlock<'_>> {
+    let mut blocks = vec![];
+    let mut index = search_start_index;
+
+    while let Some(tag_start) = find_script_open_tag(content, index) {
+        let attributes_start = tag_start + "<script".len();
+        let Some(tag_end) = content[attributes_start..].find('>') else {
+            break;
+        };
+        let attributes = &content[attributes_start..attributes_start + tag_end];
+        let block_start = attributes_start + tag_end + 1;
+
+        let Some(block_len) = content[block_start..].find("</script>") else {
+            break;
+        };
+
+        // A self-closing tag (e.g. <script src="..." />) has no content.
+        if !attributes.trim_end().ends_with('/') {
+            blocks.push(ScriptBlock {
+                content: &content[block_start..block_start + block_len],
+                start_index: block_start,
+                language: script_tag_language(attributes),
+            });
+            index = block_start + block_len + "</script>".len();
+        } else {
+            index = block_start;
+        }
+    }
+
+    blocks
+}
+
+fn find_script_open_tag(content: &str, from_index: usize) -> Option<usize> {
+    let mut index = from_index;
+    while let Some(offset) = content[index..].find("<script") {
+        let tag_start = index + offset;
+        // Skip e.g. <scripts>
+        match content[tag_start + "<script".len()..].chars().next() {
+            Some(c) if c.is_whitespace() || c == '>' || c == '/' => return Some(tag_start),
+            _ => index = tag_start + "<script".len(),
+        }
+    }
+    None
+}
+
+fn script_tag_language(attributes: &str) -> ScriptLanguage {
+    for lang in ["lang=\"", "lang='"] {
+        if let Some(index) = attributes.find(lang) {
+            let value = &attributes[index + lang.len()..];
+            if value.starts_with("tsx") {
+                return ScriptLanguage::Tsx;
+            } else if value.starts_with("ts") {
+                return ScriptLanguage::TypeScript;
+            }
+        }
+    }
+    ScriptLanguage::JavaScript
+}
+
+/// The frontmatter of an Astro component (between the leading `---` fences),
+/// which is TypeScript, as well as the contents of any `<script>` tags.
+fn astro_script_blocks(content: &str) -> Vec<ScriptBlock<'_>> {
+    let mut blocks = vec![];
+    let mut template_start_index = 0;
+
+    let leading_whitespace_len = content.len() - content.trim_start().len();
+    if content[leading_whitespace_len..].starts_with("---") {
+        let frontmatter_start =
+            line_end_index(content, leading_whitespace_len + "---".len()).unwrap_or(content.len());
+        if let Some((frontmatter_end, fence_end)) = find_fence_line(content, frontmatter_start) {
+            blocks.push(ScriptBlock {
+                content: &content[frontmatter_start..frontmatter_end],
+                start_index: frontmatter_start,
+                language: ScriptLanguage::TypeScript,
+            });
+            template_start_index = fence_end;
+        }
+    }
+
+    blocks.extend(
+        script_tags(content, template_start_index)
+            .into_iter()
+            .map(|block| ScriptBlock {
+                // Astro script tags contain TypeScript
+                language: ScriptLanguage::TypeScript,
+                ..block
+            }),
+    );
+    blocks
+}
+
+/// The index after the newline that ends the line containing `index`.
+fn line_end_index(content: &str, index: usize) -> Option<usize> {
+    content[index..].find('\n').map(|offset| index + offset + 1)
+}
+
+/// Finds the next line (starting at `from_index`, which must be the start of a line)
+/// consisting of `---`, and returns the index of the start of that line and of the
+/// end of that line.
+fn find_fence_line(content: &str, from_index: usize) -> Option<(usize, usize)> {
+    let mut line_start = from_index;
+    while line_start < content.len() {
+        let line_end = line_end_index(content, line_start).unwrap_or(content.len());
+        if content[line_start..line_end].trim_end() == "---" {
+            return Some((line_start, line_end));
+        }
+        line_start = line_end;
+    }
+    None
+}
+
+/// MDX files can contain ESM, i.e. paragraphs that start with `import` or `export`,
+/// such as `export const Foo = iso(...)(...)`. Code fences are skipped.
+fn mdx_script_blocks(content: &str) -> Vec<ScriptBlock<'_>> {
+    let mut blocks = vec![];
+    let mut in_code_fence = false;
+    let mut current_block_start: Option<usize> = None;
+    let mut line_start = 0;
+
+    while line_start < content.len() {
+        let line_end = line_end_index(content, line_start).unwrap_or(content.len());
+        let line = &content[line_start..line_end];
+        let trimmed_line = line.trim();
+
+        match current_block_start {
+            Some(block_start) => {
+                // ESM ends at the next blank line
+                if trimmed_line.is_empty() {
+                    blocks.push(mdx_block(content, block_start, line_start));
+                    current_block_start = None;
+                }
+            }
+            None => {
+                if trimmed_line.starts_with("```") || trimmed_line.starts_with("~~~") {
+                    in_code_fence = !in_code_fence;
+                } else if !in_code_fence
+                    && (line.starts_with("export ") || line.starts_with("import "))
+                {
+                    current_block_start = Some(line_start);
+                }
+            }
+        }
+
+        line_start = line_end;
+    }
+
+    if let Some(block_start) = current_block_start {
+        blocks.push(mdx_block(content, block_start, content.len()));
+    }
+    blocks
+}
+
+fn mdx_block(content: &str, start_index: usize, end_index: usize) -> ScriptBlock<'_> {
+    ScriptBlock {
+        content: &content[start_index..end_index],
+        start_index,
+        // ESM in MDX can contain JSX
+        language: ScriptLanguage::JavaScript,
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use thiserror::Error;
+
+use crate::{Database, Storage};
+
+/// The payload with which memoized functions unwind if they are cancelled,
+/// i.e. if a [`CancellationHandle`] was used to cancel them.
+#[derive(Debug, Error)]
+#[error("The computation was cancelled, because a source is about to change.")]
+pub struct Cancelled;
+
+impl Cancelled {
+    /// Calls `f`, returning `Err(Cancelled)` if a memoized function called by `f` was
+    /// cancelled. Other panics are propagated.
+    pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Cancelled> {
+        panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match payload.downcast() {
+            Ok(cancelled) => *cancelled,
+            Err(payload) => panic::resume_unwind(payload),
+        })
+    }
+
+    fn throw() -> ! {
+        // Unlike panic!, this does not call the panic hook (which would e.g. print
+        // a message to stderr).
+        panic::resume_unwind(Box::new(Cancelled))
+    }
+}
+
+/// Cancels the memoized functions that are executing, from another thread.
+///
+/// Sources can only be set with a `&mut Database`, i.e. once no memoized
+/// function is executing. So, if a source is about to change, the memoized
+/// functions that are executing (whose results are about to be stale) can be
+/// cancelled, and they will unwind with [`Cancelled`] the next time they read
+/// a source or call a memoized function. That releases their borrow of the
+/// database, after which the source can be set.
+///
+/// Until then (or until [`Storage::clear_cancellation`] is called), every call
+/// to a memoized function unwinds.
+#[derive(Debug, Clone)]
+pub struct CancellationHandle(Arc<AtomicBool>);
+
+impl CancellationHandle {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<Db: Database> Storage<Db> {
+    pub fn cancellation_handle(&self) -> CancellationHandle {
+        CancellationHandle(self.cancellation_requested.clone())
+    }
+
+    /// Allows memoized functions to be called again after they have been
+    /// cancelled. This is called when a source is set or removed.
+    pub fn clear_cancellation(&self) {
+        self.cancellation_requested.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn unwind_if_cancelled(&self) {
+        if self.cancellation_requested.load(Ordering::Relaxed) {
+            Cancelled::throw();
+        }
+    }
+}
@@ -1,18 +1,31 @@
-use std::{any::Any, hash::Hash, num::NonZeroUsize};
+use std::{
+    any::Any,
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
 
 use crate::{
-    InnerFn, MemoRef, MemoRefKind, RawPtr, Singleton,
+    Durability, InnerFn, MemoRef, MemoRefKind, RawPtr, Singleton,
     dependency::{Dependency, DependencyStack, NodeKind},
+    durability::DurabilityEpochs,
     dyn_eq::DynEq,
     epoch::Epoch,
     index::Index,
     intern::{Key, ParamId},
     macro_fns::{hash, init_param_vec},
+    par_map::par_map,
+    persistence::{PersistedDerivedNode, SerializeValueFn},
+    profile::ProfiledCall,
     source::{Source, SourceId, SourceNode},
 };
 use boxcar::Vec as BoxcarVec;
 use dashmap::{DashMap, Entry};
 use lru::LruCache;
+use tracing::debug;
 
 use crate::derived_node::{DerivedNode, DerivedNodeId, DerivedNodeRevision};
 
@@ -30,6 +43,31 @@ pub trait Database: DatabaseDyn + Sized {
     fn remove<T>(&mut self, id: SourceId<T>);
     fn remove_singleton<T: Singleton + 'static>(&mut self);
     fn run_garbage_collection(&mut self);
+
+    /// Calls `f` with each item in parallel, and returns the results in order.
+    ///
+    /// Each call tracks its dependencies on its own thread. Once every call has
+    /// completed, those dependencies are registered in the memoized function
+    /// that called `par_map` (if any), as if `f` had been called serially.
+    fn par_map<T: Send, R: Send>(
+        &self,
+        items: impl IntoIterator<Item = T>,
+        f: impl Fn(&Self, T) -> R + Sync,
+    ) -> Vec<R>
+    where
+        Self: Sync,
+    {
+        par_map(self, items, f)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EvictionMetrics {
+    /// The number of garbage collections that evicted derived nodes, because more
+    /// than the [maximum number][Storage::set_max_derived_node_count] were retained.
+    pub eviction_count: usize,
+    /// The total number of derived nodes evicted by those garbage collections.
+    pub evicted_derived_node_count: usize,
 }
 
 pub trait StorageDyn {
@@ -38,7 +76,12 @@ pub trait StorageDyn {
         id: DerivedNodeId,
     ) -> Option<(&dyn Any, DerivedNodeRevision)>;
 
-    fn register_dependency_in_parent_memoized_fn(&self, node: NodeKind, time_updated: Epoch);
+    fn register_dependency_in_parent_memoized_fn(
+        &self,
+        node: NodeKind,
+        time_updated: Epoch,
+        durability: Durability,
+    );
 }
 
 #[derive(Debug)]
@@ -48,6 +91,27 @@ pub struct Storage<Db: Database> {
     pub(crate) top_level_calls: BoxcarVec<DerivedNodeId>,
     pub(crate) top_level_call_lru_cache: LruCache<DerivedNodeId, ()>,
     pub(crate) retained_calls: DashMap<DerivedNodeId, usize>,
+    /// The serializers of the functions annotated with `#[memo(persist)]`,
+    /// keyed by function.
+    pub(crate) persisted_value_serializers: DashMap<Key, SerializeValueFn>,
+    /// The persisted keys of the derived nodes of functions annotated with
+    /// `#[memo(persist)]`, or `None` if a derived node cannot be persisted.
+    pub(crate) persisted_derived_node_keys: DashMap<DerivedNodeId, Option<u64>>,
+    /// The sources with a [persisted key](Source::get_persisted_key), keyed by
+    /// that persisted key.
+    pub(crate) persisted_source_keys: DashMap<u64, Key>,
+    /// Values loaded from a previous process that have not yet been reused, keyed
+    /// by the persisted keys of their derived nodes.
+    pub(crate) persisted_derived_nodes: DashMap<u64, PersistedDerivedNode>,
+    /// The maximum number of derived nodes to keep when garbage collecting, or 0
+    /// if there is no maximum.
+    pub(crate) max_derived_node_count: AtomicUsize,
+    pub(crate) eviction_metrics: EvictionMetrics,
+    pub(crate) profiling_enabled: AtomicBool,
+    /// Set via a [`CancellationHandle`](crate::CancellationHandle).
+    pub(crate) cancellation_requested: Arc<AtomicBool>,
+    /// The calls made in the current epoch, if profiling is enabled.
+    pub(crate) profiled_calls: BoxcarVec<ProfiledCall>,
 }
 
 impl<Db: Database> StorageDyn for Storage<Db> {
@@ -60,8 +124,13 @@ impl<Db: Database> StorageDyn for Storage<Db> {
             .map(|(node, revision)| (node.value.as_ref().as_any(), revision))
     }
 
-    fn register_dependency_in_parent_memoized_fn(&self, node: NodeKind, time_updated: Epoch) {
-        Storage::register_dependency_in_parent_memoized_fn(self, node, time_updated);
+    fn register_dependency_in_parent_memoized_fn(
+        &self,
+        node: NodeKind,
+        time_updated: Epoch,
+        durability: Durability,
+    ) {
+        Storage::register_dependency_in_parent_memoized_fn(self, node, time_updated, durability);
     }
 }
 
@@ -74,8 +143,9 @@ pub(crate) struct InternalStorage<Db: Database> {
     pub(crate) derived_nodes: BoxcarVec<DerivedNode<Db>>,
     pub(crate) derived_node_dependencies: BoxcarVec<Vec<Dependency>>,
     pub(crate) source_nodes: BoxcarVec<Option<SourceNode>>,
-    pub(crate) params: BoxcarVec<Box<dyn Any>>,
+    pub(crate) params: BoxcarVec<Box<dyn Any + Send + Sync>>,
     pub(crate) current_epoch: Epoch,
+    pub(crate) durability_epochs: DurabilityEpochs,
 }
 
 static DEFAULT_CAPACITY: usize = 10_000;
@@ -99,10 +169,20 @@ impl<Db: Database> Storage<Db> {
                 params: BoxcarVec::new(),
 
                 current_epoch: Epoch::new(),
+                durability_epochs: DurabilityEpochs::new(),
             },
             top_level_calls: BoxcarVec::new(),
             top_level_call_lru_cache: LruCache::new(capacity),
             retained_calls: DashMap::new(),
+            persisted_value_serializers: DashMap::new(),
+            persisted_derived_node_keys: DashMap::new(),
+            persisted_source_keys: DashMap::new(),
+            persisted_derived_nodes: DashMap::new(),
+            max_derived_node_count: AtomicUsize::new(0),
+            eviction_metrics: EvictionMetrics::default(),
+            profiling_enabled: AtomicBool::new(false),
+            cancellation_requested: Arc::new(AtomicBool::new(false)),
+            profiled_calls: BoxcarVec::new(),
         }
     }
 
@@ -110,6 +190,7 @@ impl<Db: Database> Storage<Db> {
         &self,
         node: NodeKind,
         time_updated: Epoch,
+        durability: Durability,
     ) {
         self.dependency_stack.push_if_not_empty(
             Dependency {
@@ -117,6 +198,7 @@ impl<Db: Database> Storage<Db> {
                 time_verified_or_updated: self.internal.current_epoch,
             },
             time_updated,
+            durability,
         );
     }
 
@@ -132,11 +214,13 @@ impl<Db: Database> Storage<Db> {
     }
 
     fn get_impl<T: 'static>(&self, key: Key) -> Option<&T> {
+        self.unwind_if_cancelled();
         let source_node = self.internal.get_source_node(key)?;
 
         self.register_dependency_in_parent_memoized_fn(
             NodeKind::Source(key),
             source_node.time_updated,
+            source_node.durability,
         );
         Some(
             source_node
@@ -156,20 +240,37 @@ impl<Db: Database> Storage<Db> {
     /// Increment the current epoch if it is a new and different item
     pub fn set<T: Source + DynEq>(&mut self, source: T) -> SourceId<T> {
         self.assert_empty_dependency_stack();
+        let previous_epoch = self.internal.current_epoch;
         let source_id = SourceId::new(&source);
         self.internal.set_source(source, source_id);
+        if let Some(persisted_key) = self
+            .internal
+            .get_source_node(source_id.key)
+            .and_then(|source_node| source_node.persisted_key)
+        {
+            self.persisted_source_keys
+                .insert(persisted_key, source_id.key);
+        }
+        self.clear_profile_if_epoch_changed(previous_epoch);
+        self.clear_cancellation();
         source_id
     }
 
     pub fn remove<T>(&mut self, id: SourceId<T>) {
         self.assert_empty_dependency_stack();
+        let previous_epoch = self.internal.current_epoch;
         self.internal.remove_source(id);
+        self.clear_profile_if_epoch_changed(previous_epoch);
+        self.clear_cancellation();
     }
 
     pub fn remove_singleton<T: Singleton + 'static>(&mut self) {
         self.assert_empty_dependency_stack();
+        let previous_epoch = self.internal.current_epoch;
         self.internal
             .remove_source::<T>(T::get_singleton_key().into());
+        self.clear_profile_if_epoch_changed(previous_epoch);
+        self.clear_cancellation();
     }
 
     pub fn run_garbage_collection(&mut self) {
@@ -181,6 +282,10 @@ impl<Db: Database> Storage<Db> {
             self.top_level_call_lru_cache.put(derived_node_id, ());
         }
 
+        if let Some(max_derived_node_count) = self.max_derived_node_count() {
+            self.evict_least_recently_verified_derived_nodes(max_derived_node_count.get());
+        }
+
         // Retain the queries in the LRU cache and the queries that are permanently retained,
         // and everything reachable from them.
         //
@@ -194,6 +299,61 @@ impl<Db: Database> Storage<Db> {
 
         self.internal
             .run_garbage_collection(retained_derived_node_ids);
+
+        let derived_node_id_to_revision = &self.internal.derived_node_id_to_revision;
+        self.persisted_derived_node_keys
+            .retain(|derived_node_id, _| derived_node_id_to_revision.contains_key(derived_node_id));
+    }
+
+    /// Limits the number of derived nodes (i.e. memoized values) that are kept when
+    /// garbage collecting. If more derived nodes are reachable from the retained
+    /// calls, the least recently verified derived nodes are evicted, along with
+    /// every derived node that depends on them. Source nodes are never evicted,
+    /// and neither are derived nodes that are reachable from permanently retained
+    /// calls.
+    ///
+    /// Values are type-erased, so their size is unknown. Hence, the budget is
+    /// a number of derived nodes rather than a number of bytes, and it does not
+    /// bound memory usage. It is also only enforced by
+    /// [`Storage::run_garbage_collection`], so more derived nodes may exist
+    /// between garbage collections.
+    pub fn set_max_derived_node_count(&self, max_derived_node_count: Option<NonZeroUsize>) {
+        self.max_derived_node_count.store(
+            max_derived_node_count.map_or(0, NonZeroUsize::get),
+            Ordering::Relaxed,
+        );
+    }
+
+    pub fn max_derived_node_count(&self) -> Option<NonZeroUsize> {
+        NonZeroUsize::new(self.max_derived_node_count.load(Ordering::Relaxed))
+    }
+
+    pub fn eviction_metrics(&self) -> EvictionMetrics {
+        self.eviction_metrics
+    }
+
+    fn evict_least_recently_verified_derived_nodes(&mut self, max_derived_node_count: usize) {
+        let evicted_derived_node_ids = self.internal.derived_nodes_to_evict(
+            self.top_level_call_lru_cache.iter().map(|(k, _v)| *k),
+            self.retained_calls.iter().map(|ref_multi| *ref_multi.key()),
+            max_derived_node_count,
+        );
+        if evicted_derived_node_ids.is_empty() {
+            return;
+        }
+
+        // Evicted derived nodes are not reachable from the remaining top level calls,
+        // so they are dropped when garbage collecting.
+        for derived_node_id in &evicted_derived_node_ids {
+            self.top_level_call_lru_cache.pop(derived_node_id);
+        }
+
+        self.eviction_metrics.eviction_count += 1;
+        self.eviction_metrics.evicted_derived_node_count += evicted_derived_node_ids.len();
+        debug!(
+            "Evicted {} derived nodes, since more than {max_derived_node_count} were retained.",
+            evicted_derived_node_ids.len()
+        );
     }
 
     fn assert_empty_dependency_stack(&self) {
@@ -205,7 +365,7 @@ impl<Db: Database> Storage<Db> {
 }
 
 impl<Db: Database> InternalStorage<Db> {
-    pub(crate) fn get_param(&self, param_id: ParamId) -> Option<&Box<dyn Any>> {
+    pub(crate) fn get_param(&self, param_id: ParamId) -> Option<&Box<dyn Any + Send + Sync>> {
         let index = self.param_id_to_index.get(&param_id)?;
         Some(self.params.get(index.idx).expect(
             "indexes should always be valid. \
@@ -284,6 +444,7 @@ impl<Db: Database> InternalStorage<Db> {
         derived_node_id: DerivedNodeId,
         time_updated: Epoch,
         time_verified: Epoch,
+        durability: Durability,
         node_index: Index<DerivedNodeId>,
         dependency_index: Index<Dependency>,
     ) {
@@ -292,6 +453,7 @@ impl<Db: Database> InternalStorage<Db> {
             DerivedNodeRevision {
                 time_updated,
                 time_verified,
+                durability,
                 node_index,
                 dependency_index,
             },
@@ -322,7 +484,8 @@ impl<Db: Database> InternalStorage<Db> {
 
     /// Sets a source in the database. If there is an existing item and it does not equal
     /// the new source, increment the current epoch.
-    fn set_source<T: DynEq>(&mut self, source: T, source_id: SourceId<T>) {
+    fn set_source<T: Source + DynEq>(&mut self, source: T, source_id: SourceId<T>) {
+        let durability = source.get_durability();
         match self.source_node_key_to_index.entry(source_id.key) {
             Entry::Occupied(occupied_entry) => {
                 let source_node = self
@@ -341,19 +504,22 @@ impl<Db: Database> InternalStorage<Db> {
                     // We cannot call self.increment_epoch() because that borrows
                     // the entire struct, but self.source_nodes is already borrowed
                     let next_epoch = self.current_epoch.increment();
-                    *source_node = SourceNode {
-                        time_updated: next_epoch,
-                        value: Box::new(source),
-                    };
+                    // Derived nodes recorded the previous durability when they read this source
+                    self.durability_epochs.record_change(
+                        std::cmp::max(source_node.durability, durability),
+                        next_epoch,
+                    );
+                    *source_node = SourceNode::new(next_epoch, source, durability);
                 } else {
                     source_node.time_updated = self.current_epoch;
                 }
             }
             Entry::Vacant(vacant_entry) => {
-                let index = self.insert_source_node(SourceNode {
-                    time_updated: self.current_epoch,
-                    value: Box::new(source),
-                });
+                let index = self.insert_source_node(SourceNode::new(
+                    self.current_epoch,
+                    source,
+                    durability,
+                ));
                 vacant_entry.insert(index);
             }
         }
@@ -361,14 +527,19 @@ impl<Db: Database> InternalStorage<Db> {
 
     pub fn remove_source<T>(&mut self, id: SourceId<T>) {
         if let Some((_, index)) = self.source_node_key_to_index.remove(&id.key) {
-            self.current_epoch.increment();
-            self.source_nodes
+            let next_epoch = self.current_epoch.increment();
+            if let Some(source_node) = self
+                .source_nodes
                 .get_mut(index.idx)
                 .expect(
                     "indexes should always be valid. \
                     This is indicative of a bug in Pico.",
                 )
-                .take();
+                .take()
+            {
+                self.durability_epochs
+                    .record_change(source_node.durability, next_epoch);
+            }
         }
     }
 }
@@ -401,7 +572,7 @@ pub fn intern_value<Db: Database, T: Clone + Hash + DynEq + 'static>(
     {
         Entry::Vacant(vacant) => {
             let node_index = db.get_storage().internal.insert_derived_node(DerivedNode {
-                inner_fn: InnerFn::new(|_, _| {
+                inner_fn: InnerFn::new("intern_value", |_, _| {
                     unreachable!("interned derived node should never be executed")
                 }),
                 value: Box::new(value),
@@ -412,6 +583,8 @@ pub fn intern_value<Db: Database, T: Clone + Hash + DynEq + 'static>(
             vacant.insert(DerivedNodeRevision {
                 time_updated: current_epoch,
                 time_verified: current_epoch,
+                // Interned values have no dependencies
+                durability: Durability::High,
                 node_index,
                 dependency_index,
             });
@@ -432,6 +605,7 @@ pub fn intern_value<Db: Database, T: Clone + Hash + DynEq + 'static>(
     db.get_storage().register_dependency_in_parent_memoized_fn(
         NodeKind::Derived(derived_node_id),
         time_updated,
+        Durability::High,
     );
 
     MemoRef::new(derived_node_id)
@@ -522,7 +696,7 @@ pub fn intern_ref<Db: Database, T: Clone + Hash + DynEq + 'static>(
     {
         Entry::Vacant(vacant) => {
             let node_index = db.get_storage().internal.insert_derived_node(DerivedNode {
-                inner_fn: InnerFn::new(|_, _| {
+                inner_fn: InnerFn::new("intern_ref", |_, _| {
                     unreachable!("intern_ref derived node should never be executed")
                 }),
                 value: Box::new(new_ptr),
@@ -533,6 +707,8 @@ pub fn intern_ref<Db: Database, T: Clone + Hash + DynEq + 'static>(
             vacant.insert(DerivedNodeRevision {
                 time_updated: current_epoch,
                 time_verified: current_epoch,
+                // Interned values have no dependencies
+                durability: Durability::High,
                 node_index,
                 dependency_index,
             });
@@ -586,6 +762,7 @@ pub fn intern_ref<Db: Database, T: Clone + Hash + DynEq + 'static>(
     db.get_storage().register_dependency_in_parent_memoized_fn(
         NodeKind::Derived(derived_node_id),
         time_updated,
+        Durability::High,
     );
 
     MemoRef::new_with_kind(derived_node_id, MemoRefKind::RawPtr)
@@ -1,6 +1,8 @@
-use std::cell::RefCell;
+use std::thread::{self, ThreadId};
 
-use crate::{derived_node::DerivedNodeId, epoch::Epoch, intern::Key};
+use dashmap::DashMap;
+
+use crate::{Durability, derived_node::DerivedNodeId, epoch::Epoch, intern::Key};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Dependency {
@@ -14,11 +16,20 @@ pub enum NodeKind {
     Derived(DerivedNodeId),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TrackedDependencies {
     pub dependencies: Vec<Dependency>,
     pub max_time_updated: Epoch,
+    /// The lowest durability of the dependencies.
+    pub durability: Durability,
     pub derived_node_id: DerivedNodeId,
+    /// Whether this call (transitively) called itself. If so, the memoized
+    /// function returns its cycle result instead of the value it computed.
+    pub in_cycle: bool,
+    /// Whether this call is executing a memoized function that has a cycle
+    /// result. If a cycle is entered through a memoized function without a cycle
+    /// result, we unwind to such a call, which then returns its cycle result.
+    pub can_recover_from_cycle: bool,
 }
 
 impl TrackedDependencies {
@@ -26,12 +37,16 @@ impl TrackedDependencies {
         Self {
             dependencies: vec![],
             max_time_updated: Epoch::new(),
+            durability: Durability::High,
             derived_node_id,
+            in_cycle: false,
+            can_recover_from_cycle: false,
         }
     }
 
-    pub fn push(&mut self, dependency: Dependency, time_updated: Epoch) {
+    pub fn push(&mut self, dependency: Dependency, time_updated: Epoch, durability: Durability) {
         self.max_time_updated = std::cmp::max(time_updated, self.max_time_updated);
+        self.durability = std::cmp::min(durability, self.durability);
         if let Some(last_dependency) = self.dependencies.last_mut()
             && last_dependency.node_to == dependency.node_to
         {
@@ -42,29 +57,37 @@ impl TrackedDependencies {
     }
 }
 
-/// This is a `RefCell` containing a `Vec` of [`TrackedDependencies`] where:
+/// This is a map from each thread to a `Vec` of [`TrackedDependencies`] where:
 /// - The `Vec` acts as a stack. Each time a memoized function is called,
 ///   a new `TrackedDependencies` struct is pushed onto the stack.
 /// - When a memoized function calls another memoized function or accesses a source,
 ///   a [`Dependency`] pushed to the current top `TrackedDependencies` struct
-///   and updates its `max_time_updated` field.
+///   and updates its `max_time_updated` and `durability` fields.
 ///
-/// `RefCell` gives us dynamically checked borrow checking rules.
-/// This is required because calling a memoized function only takes an `&Database`.
+/// Each thread has its own stack, so that memoized functions can be called from
+/// multiple threads at once (see [`Database::par_map`](crate::Database::par_map)).
+/// The map gives us interior mutability, which is required because calling a
+/// memoized function only takes an `&Database`.
 #[derive(Debug, Default)]
-pub struct DependencyStack(RefCell<Vec<TrackedDependencies>>);
+pub struct DependencyStack(DashMap<ThreadId, Vec<TrackedDependencies>>);
 
 impl DependencyStack {
     pub fn new() -> Self {
-        Self(RefCell::new(Vec::new()))
+        Self(DashMap::new())
     }
 
-    pub fn enter(&self, derived_node_id: DerivedNodeId) -> DependencyStackGuard<'_> {
-        self.assert_no_cycles(derived_node_id);
-
+    pub fn enter(
+        &self,
+        derived_node_id: DerivedNodeId,
+        can_recover_from_cycle: bool,
+    ) -> DependencyStackGuard<'_> {
         self.0
-            .borrow_mut()
-            .push(TrackedDependencies::new(derived_node_id));
+            .entry(thread::current().id())
+            .or_default()
+            .push(TrackedDependencies {
+                can_recover_from_cycle,
+                ..TrackedDependencies::new(derived_node_id)
+            });
         DependencyStackGuard {
             stack: self,
             released: false,
@@ -72,15 +95,35 @@ impl DependencyStack {
     }
 
     pub fn leave(&self) -> TrackedDependencies {
-        self.0
-            .borrow_mut()
-            .pop()
-            .expect("Dependency stack should not be empty. Leave must be called after enter.")
+        let thread_id = thread::current().id();
+        let (tracked_dependencies, is_empty) = {
+            let mut stack = self.0.get_mut(&thread_id);
+            let tracked_dependencies = stack
+                .as_mut()
+                .and_then(|stack| stack.pop())
+                .expect("Dependency stack should not be empty. Leave must be called after enter.");
+            (
+                tracked_dependencies,
+                stack.is_none_or(|stack| stack.is_empty()),
+            )
+        };
+        if is_empty {
+            // Do not keep an entry around for every thread that ever called a memoized function
+            self.0.remove_if(&thread_id, |_, stack| stack.is_empty());
+        }
+        tracked_dependencies
     }
 
-    pub fn push_if_not_empty(&self, dependency: Dependency, time_updated: Epoch) {
-        if let Some(entry) = self.0.borrow_mut().last_mut() {
-            entry.push(dependency, time_updated);
+    pub fn push_if_not_empty(
+        &self,
+        dependency: Dependency,
+        time_updated: Epoch,
+        durability: Durability,
+    ) {
+        if let Some(mut stack) = self.0.get_mut(&thread::current().id())
+            && let Some(entry) = stack.last_mut()
+        {
+            entry.push(dependency, time_updated, durability);
         } else {
             // If the dependency stack is empty, this function call is the outermost invocation
             // (i.e., the user directly called the memoized function). So, there's no parent
@@ -89,13 +132,124 @@ impl DependencyStack {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.borrow().is_empty()
+        self.0
+            .get(&thread::current().id())
+            .is_none_or(|stack| stack.is_empty())
+    }
+
+    /// If `derived_node_id` is already on the stack, calling it again would
+    /// create a cycle. In that case, marks every call from its first occurrence
+    /// onward as being in a cycle and returns `true`.
+    pub fn mark_cycle(&self, derived_node_id: DerivedNodeId) -> bool {
+        let Some(mut stack) = self.0.get_mut(&thread::current().id()) else {
+            return false;
+        };
+        match stack
+            .iter()
+            .position(|tracked_call| tracked_call.derived_node_id == derived_node_id)
+        {
+            Some(index) => {
+                for tracked_call in &mut stack[index..] {
+                    tracked_call.in_cycle = true;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The innermost call in the cycle that `derived_node_id` would create that can
+    /// recover from the cycle, if any, and the call it made that led to the cycle.
+    /// See [`DependencyStack::mark_cycle`].
+    pub fn cycle_recovery_point(
+        &self,
+        derived_node_id: DerivedNodeId,
+    ) -> Option<(DerivedNodeId, DerivedNodeId)> {
+        let stack = self.0.get(&thread::current().id())?;
+        let index = stack
+            .iter()
+            .position(|tracked_call| tracked_call.derived_node_id == derived_node_id)?;
+        let recovery_index = (index..stack.len())
+            .rev()
+            .find(|&index| stack[index].can_recover_from_cycle)?;
+        let called = stack
+            .get(recovery_index + 1)
+            .map_or(derived_node_id, |tracked_call| tracked_call.derived_node_id);
+        Some((stack[recovery_index].derived_node_id, called))
+    }
+
+    /// Whether `derived_node_id` is executing on this thread.
+    pub fn contains(&self, derived_node_id: DerivedNodeId) -> bool {
+        self.0.get(&thread::current().id()).is_some_and(|stack| {
+            stack
+                .iter()
+                .any(|tracked_call| tracked_call.derived_node_id == derived_node_id)
+        })
+    }
+
+    pub fn top_in_cycle(&self) -> bool {
+        self.0
+            .get(&thread::current().id())
+            .and_then(|stack| stack.last().map(|tracked_call| tracked_call.in_cycle))
+            .unwrap_or(false)
+    }
+
+    /// The memoized function call that is currently executing on this thread, if any.
+    pub fn top_derived_node_id(&self) -> Option<DerivedNodeId> {
+        self.0.get(&thread::current().id()).and_then(|stack| {
+            stack
+                .last()
+                .map(|tracked_call| tracked_call.derived_node_id)
+        })
+    }
+
+    /// Replaces the current thread's stack, returning the previous stack.
+    pub fn replace(&self, stack: Vec<TrackedDependencies>) -> Vec<TrackedDependencies> {
+        let thread_id = thread::current().id();
+        let previous_stack = if stack.is_empty() {
+            self.0
+                .remove(&thread_id)
+                .map(|(_, previous_stack)| previous_stack)
+        } else {
+            self.0.insert(thread_id, stack)
+        };
+        previous_stack.unwrap_or_default()
     }
 
-    fn assert_no_cycles(&self, derived_node_id: DerivedNodeId) {
-        for parent_tracked_call in self.0.borrow().iter() {
-            if parent_tracked_call.derived_node_id == derived_node_id {
-                panic!("Cyclic dependency detected. This is not supported in pico.")
+    /// Creates a stack for another thread that calls memoized functions on
+    /// behalf of the current thread's memoized function. The calls on the
+    /// current thread's stack are included, so that cycles are detected.
+    pub fn fork(&self) -> Vec<TrackedDependencies> {
+        self.0
+            .get(&thread::current().id())
+            .map(|stack| {
+                stack
+                    .iter()
+                    .map(|tracked_call| TrackedDependencies {
+                        can_recover_from_cycle: tracked_call.can_recover_from_cycle,
+                        ..TrackedDependencies::new(tracked_call.derived_node_id)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Merges a stack created by [`DependencyStack::fork`] back into the
+    /// current thread's stack.
+    pub fn join(&self, forked_stack: Vec<TrackedDependencies>) {
+        let Some(mut stack) = self.0.get_mut(&thread::current().id()) else {
+            return;
+        };
+        for (tracked_call, forked_tracked_call) in stack.iter_mut().zip(&forked_stack) {
+            tracked_call.in_cycle |= forked_tracked_call.in_cycle;
+        }
+        if let (Some(top), Some(forked_top)) = (stack.last_mut(), forked_stack.last()) {
+            for dependency in &forked_top.dependencies {
+                top.push(
+                    *dependency,
+                    forked_top.max_time_updated,
+                    forked_top.durability,
+                );
             }
         }
     }
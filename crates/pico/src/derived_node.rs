@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use tinyvec::ArrayVec;
 
 use crate::{
-    Database,
+    Database, Durability,
     dependency::Dependency,
     dyn_eq::DynEq,
     epoch::Epoch,
@@ -35,12 +35,38 @@ impl From<ParamId> for DerivedNodeId {
     }
 }
 
+type InnerFnPtr<Db> = fn(&Db, DerivedNodeId) -> Option<Box<dyn DynEq>>;
+
 #[derive(Debug)]
-pub struct InnerFn<Db: Database>(pub fn(&Db, DerivedNodeId) -> Option<Box<dyn DynEq>>);
+pub struct InnerFn<Db: Database> {
+    /// The name of the memoized function, used when profiling.
+    pub name: &'static str,
+    pub call: InnerFnPtr<Db>,
+    /// Computes the value that the memoized function returns if it participates
+    /// in a cycle, i.e. if it (transitively) calls itself with the same params.
+    /// If this is `None`, a cycle that starts at this function panics.
+    pub cycle_result: Option<InnerFnPtr<Db>>,
+}
 
 impl<Db: Database> InnerFn<Db> {
-    pub fn new(inner_fn: fn(&Db, DerivedNodeId) -> Option<Box<dyn DynEq>>) -> Self {
-        InnerFn(inner_fn)
+    pub fn new(name: &'static str, inner_fn: InnerFnPtr<Db>) -> Self {
+        InnerFn {
+            name,
+            call: inner_fn,
+            cycle_result: None,
+        }
+    }
+
+    pub fn new_with_cycle_result(
+        name: &'static str,
+        inner_fn: InnerFnPtr<Db>,
+        cycle_result: InnerFnPtr<Db>,
+    ) -> Self {
+        InnerFn {
+            name,
+            call: inner_fn,
+            cycle_result: Some(cycle_result),
+        }
     }
 }
 
@@ -69,6 +95,9 @@ impl<Db: Database> fmt::Debug for DerivedNode<Db> {
 pub struct DerivedNodeRevision {
     pub time_updated: Epoch,
     pub time_verified: Epoch,
+    /// The lowest durability of the sources that this derived node
+    /// (transitively) depends on.
+    pub durability: Durability,
     pub node_index: Index<DerivedNodeId>,
     pub dependency_index: Index<Dependency>,
 }
@@ -0,0 +1,50 @@
+use crate::epoch::Epoch;
+
+/// How often a [`Source`](crate::Source) is expected to change.
+///
+/// Each derived node records the lowest durability of the sources that it
+/// (transitively) depends on. If no source of at least that durability has
+/// changed since the derived node was last verified, it is reused without
+/// checking its dependencies. For example, a derived node that only depends on
+/// high-durability sources (e.g. a schema) is verified in O(1) when only
+/// low-durability sources (e.g. open files) change.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Durability {
+    /// The source changes often, e.g. on every keystroke.
+    #[default]
+    Low,
+    /// The source changes rarely.
+    High,
+}
+
+impl Durability {
+    const COUNT: usize = 2;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The epoch in which a source of each durability (or higher) last changed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DurabilityEpochs([Epoch; Durability::COUNT]);
+
+impl DurabilityEpochs {
+    pub(crate) fn new() -> Self {
+        Self([Epoch::new(); Durability::COUNT])
+    }
+
+    /// Records that a source with the given durability changed in `epoch`. This
+    /// invalidates every derived node whose durability is at most `durability`.
+    pub(crate) fn record_change(&mut self, durability: Durability, epoch: Epoch) {
+        for last_changed in &mut self.0[..=durability.index()] {
+            *last_changed = epoch;
+        }
+    }
+
+    /// Whether no source that a derived node with the given durability can
+    /// depend on has changed after `time_verified`.
+    pub(crate) fn unchanged_since(&self, durability: Durability, time_verified: Epoch) -> bool {
+        self.0[durability.index()] <= time_verified
+    }
+}
@@ -1,6 +1,6 @@
 use std::any::Any;
 
-pub trait DynEq: Any {
+pub trait DynEq: Any + Send + Sync {
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
     fn dyn_eq(&self, other: &dyn DynEq) -> bool;
@@ -8,7 +8,7 @@ pub trait DynEq: Any {
 
 impl<T> DynEq for T
 where
-    T: Any + PartialEq,
+    T: Any + PartialEq + Send + Sync,
 {
     fn as_any(&self) -> &dyn Any {
         self
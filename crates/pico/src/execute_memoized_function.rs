@@ -1,15 +1,19 @@
+use std::panic::{self, AssertUnwindSafe};
+
 use dashmap::Entry;
 use tracing::{Level, debug_span, event, trace_span};
 
 use crate::{
-    Database, InnerFn,
+    Database, Durability, InnerFn,
     dependency::{NodeKind, TrackedDependencies},
-    derived_node::{DerivedNode, DerivedNodeId},
+    derived_node::{DerivedNode, DerivedNodeId, DerivedNodeRevision},
     dyn_eq::DynEq,
     epoch::Epoch,
     intern::Key,
+    profile::profile_call,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DidRecalculate {
     ReusedMemoizedValue,
     Recalculated,
@@ -36,6 +40,8 @@ pub enum DidRecalculate {
 ///   - This is done by checking:
 ///     - whether the dependency was
 ///       [verified in the current epoch][crate::DatabaseStorage::node_verified_in_current_epoch], or
+///     - whether no source of at least the [`DerivedNode`]'s [durability][Durability]
+///       has changed since it was last verified, or
 ///     - whether [any dependency has changed][any_dependency_changed]
 ///       since this [`DerivedNode`] was last verified.
 ///   - Memoized functions are assumed to be pure functions of their params
@@ -52,14 +58,83 @@ pub enum DidRecalculate {
 ///     this relies on backdating, i.e. `ast`'s `time_updated` field must not
 ///     increase, even though we re-invoked it.
 ///
+/// **Cycles**:
+///   - if the function is called while it is already executing (i.e. it
+///     transitively calls itself with the same params), we
+///     [recover from the cycle][recover_from_cycle].
+///
 /// After this function is called, we guarantee that a [`DerivedNode`]
 /// (with a value identical to what we would get if we actually invoked the
 /// function) is present in the [`Database`].
+///
+/// If profiling is [enabled][crate::Storage::enable_profiling], the call is
+/// recorded.
+///
+/// **Cancellation**:
+///   - if the memoized functions have been [cancelled][crate::CancellationHandle],
+///     this unwinds with [`Cancelled`][crate::Cancelled] instead.
 pub fn execute_memoized_function<Db: Database>(
     db: &Db,
     derived_node_id: DerivedNodeId,
     inner_fn: InnerFn<Db>,
 ) -> DidRecalculate {
+    db.get_storage().unwind_if_cancelled();
+
+    let _forget_on_unwind = ForgetOnUnwind {
+        db,
+        derived_node_id,
+    };
+    profile_call(db, derived_node_id, inner_fn.name, || {
+        execute_memoized_function_impl(db, derived_node_id, inner_fn)
+    })
+}
+
+/// If a memoized function unwinds (e.g. because it was cancelled), its derived
+/// node may be left in an inconsistent state. For example, it may be marked as
+/// verified without all of its dependencies having been verified, or it may
+/// hold the result of a cycle that never completed. So, we forget the derived
+/// node, and it is recreated the next time it is called.
+///
+/// If the same derived node is still executing further up the stack (i.e. this
+/// call created a cycle), that call will overwrite the derived node, so it is
+/// not forgotten.
+struct ForgetOnUnwind<'db, Db: Database> {
+    db: &'db Db,
+    derived_node_id: DerivedNodeId,
+}
+
+impl<Db: Database> Drop for ForgetOnUnwind<'_, Db> {
+    fn drop(&mut self) {
+        if !std::thread::panicking()
+            || self
+                .db
+                .get_storage()
+                .dependency_stack
+                .contains(self.derived_node_id)
+        {
+            return;
+        }
+        self.db
+            .get_storage()
+            .internal
+            .derived_node_id_to_revision
+            .remove(&self.derived_node_id);
+    }
+}
+
+fn execute_memoized_function_impl<Db: Database>(
+    db: &Db,
+    derived_node_id: DerivedNodeId,
+    inner_fn: InnerFn<Db>,
+) -> DidRecalculate {
+    if db
+        .get_storage()
+        .dependency_stack
+        .mark_cycle(derived_node_id)
+    {
+        return recover_from_cycle(db, derived_node_id, inner_fn);
+    }
+
     if db.get_storage().dependency_stack.is_empty() {
         // This is the outermost call to a memoized function. Keep track of all top_level_calls
         // for the purposes of later garbage collection. (Note that we also cannot update the LRU
@@ -68,7 +143,7 @@ pub fn execute_memoized_function<Db: Database>(
         db.get_storage().top_level_calls.push(derived_node_id);
     }
 
-    let (did_recalculate, time_updated) = if let Some((derived_node, revision)) = db
+    let (did_recalculate, time_updated, durability) = if let Some((derived_node, revision)) = db
         .get_storage()
         .internal
         .get_derived_node_and_revision(derived_node_id)
@@ -79,17 +154,55 @@ pub fn execute_memoized_function<Db: Database>(
             .node_verified_in_current_epoch(derived_node_id)
         {
             event!(Level::TRACE, "epoch not changed");
-            (DidRecalculate::ReusedMemoizedValue, revision.time_updated)
+            (
+                DidRecalculate::ReusedMemoizedValue,
+                revision.time_updated,
+                revision.durability,
+            )
+        } else if db
+            .get_storage()
+            .internal
+            .durability_epochs
+            .unchanged_since(revision.durability, revision.time_verified)
+        {
+            event!(Level::TRACE, "no source of this durability changed");
+            db.get_storage()
+                .internal
+                .verify_derived_node(derived_node_id);
+            (
+                DidRecalculate::ReusedMemoizedValue,
+                revision.time_updated,
+                revision.durability,
+            )
         } else {
             db.get_storage()
                 .internal
                 .verify_derived_node(derived_node_id);
-            if any_dependency_changed(db, derived_node_id) {
+            // Verifying the dependencies can re-execute them, which can in turn call
+            // this function. So, this function must be on the dependency stack in
+            // order for such cycles to be detected.
+            let guard = db
+                .get_storage()
+                .dependency_stack
+                .enter(derived_node_id, false);
+            let dependency_changed = any_dependency_changed(db, derived_node_id);
+            let in_cycle = guard.release().in_cycle;
+            if dependency_changed {
                 let _recalc_span = trace_span!("recalculating_due_to_dependency_change").entered();
-                update_derived_node(db, derived_node_id, derived_node.value.as_ref(), inner_fn)
+                update_derived_node(
+                    db,
+                    derived_node_id,
+                    derived_node.value.as_ref(),
+                    inner_fn,
+                    in_cycle,
+                )
             } else {
                 event!(Level::TRACE, "dependencies up-to-date");
-                (DidRecalculate::ReusedMemoizedValue, revision.time_updated)
+                (
+                    DidRecalculate::ReusedMemoizedValue,
+                    revision.time_updated,
+                    revision.durability,
+                )
             }
         }
     } else {
@@ -99,17 +212,93 @@ pub fn execute_memoized_function<Db: Database>(
     db.get_storage().register_dependency_in_parent_memoized_fn(
         NodeKind::Derived(derived_node_id),
         time_updated,
+        durability,
     );
     did_recalculate
 }
 
+/// The payload with which we unwind to the call that recovers from a cycle, if the
+/// memoized function that was called while it was already executing has no cycle
+/// result.
+struct CycleRecovery {
+    recover_at: DerivedNodeId,
+    /// The call made by `recover_at` that led to the cycle. Its dependencies are
+    /// lost while unwinding, so `recover_at` depends on it instead.
+    called: DerivedNodeId,
+}
+
+/// Called when a memoized function is called while it is already executing.
+/// Instead of executing it again, we store and return its cycle result.
+///
+/// Every call that participates in the cycle has been marked as such, and
+/// returns its own cycle result (if it has one) once it completes. So, the
+/// values do not depend on which call entered the cycle first.
+///
+/// If the function has no cycle result, we instead unwind to the innermost call
+/// in the cycle that has one, which returns its cycle result. If no call in the
+/// cycle has a cycle result, we panic.
+fn recover_from_cycle<Db: Database>(
+    db: &Db,
+    derived_node_id: DerivedNodeId,
+    inner_fn: InnerFn<Db>,
+) -> DidRecalculate {
+    let Some(cycle_result) = inner_fn.cycle_result else {
+        match db
+            .get_storage()
+            .dependency_stack
+            .cycle_recovery_point(derived_node_id)
+        {
+            // Unlike panic!, this does not call the panic hook.
+            Some((recover_at, called)) => {
+                panic::resume_unwind(Box::new(CycleRecovery { recover_at, called }))
+            }
+            None => panic!(
+                "Cyclic dependency detected. At least one memoized function in a cycle \
+                must be annotated with #[memo(cycle_result = ...)]."
+            ),
+        }
+    };
+    let Some(value) = cycle_result(db, derived_node_id) else {
+        return DidRecalculate::Error;
+    };
+
+    let internal = &db.get_storage().internal;
+    let current_epoch = internal.current_epoch;
+    let node_index = internal.insert_derived_node(DerivedNode { inner_fn, value });
+    match internal.derived_node_id_to_revision.entry(derived_node_id) {
+        Entry::Occupied(mut occupied) => {
+            // The outer call, which is being recalculated, will overwrite this
+            // once it completes.
+            occupied.get_mut().node_index = node_index;
+        }
+        Entry::Vacant(vacant) => {
+            // The outer call, which is creating this derived node, will
+            // overwrite this once it completes.
+            vacant.insert(DerivedNodeRevision {
+                time_updated: current_epoch,
+                time_verified: current_epoch,
+                durability: Durability::Low,
+                node_index,
+                dependency_index: internal.insert_dependencies(vec![]),
+            });
+        }
+    }
+
+    db.get_storage().register_dependency_in_parent_memoized_fn(
+        NodeKind::Derived(derived_node_id),
+        current_epoch,
+        Durability::Low,
+    );
+    DidRecalculate::Recalculated
+}
+
 fn create_derived_node<Db: Database>(
     db: &Db,
     derived_node_id: DerivedNodeId,
     inner_fn: InnerFn<Db>,
-) -> (DidRecalculate, Epoch) {
+) -> (DidRecalculate, Epoch, Durability) {
     let (value, tracked_dependencies) =
-        invoke_with_dependency_tracking(db, derived_node_id, inner_fn).expect(
+        invoke_with_dependency_tracking(db, derived_node_id, inner_fn, false).expect(
             "InnerFn call cannot fail for a new derived node. This is indicative of a bug in Pico.",
         );
     let node_index = db
@@ -124,12 +313,14 @@ fn create_derived_node<Db: Database>(
         derived_node_id,
         tracked_dependencies.max_time_updated,
         db.get_storage().internal.current_epoch,
+        tracked_dependencies.durability,
         node_index,
         dependency_index,
     );
     (
         DidRecalculate::Recalculated,
         tracked_dependencies.max_time_updated,
+        tracked_dependencies.durability,
     )
 }
 
@@ -138,8 +329,9 @@ fn update_derived_node<Db: Database>(
     derived_node_id: DerivedNodeId,
     prev_value: &dyn DynEq,
     inner_fn: InnerFn<Db>,
-) -> (DidRecalculate, Epoch) {
-    match invoke_with_dependency_tracking(db, derived_node_id, inner_fn) {
+    in_cycle: bool,
+) -> (DidRecalculate, Epoch, Durability) {
+    match invoke_with_dependency_tracking(db, derived_node_id, inner_fn, in_cycle) {
         Some((value, tracked_dependencies)) => {
             let mut occupied = if let Entry::Occupied(occupied) = db
                 .get_storage()
@@ -158,6 +350,7 @@ fn update_derived_node<Db: Database>(
                 .insert_dependencies(tracked_dependencies.dependencies);
             let rev = occupied.get_mut();
             rev.dependency_index = dependency_index;
+            rev.durability = tracked_dependencies.durability;
 
             let did_recalculate = if *prev_value != *value {
                 event!(Level::TRACE, "value changed");
@@ -173,9 +366,13 @@ fn update_derived_node<Db: Database>(
                 DidRecalculate::ReusedMemoizedValue
             };
 
-            (did_recalculate, tracked_dependencies.max_time_updated)
+            (
+                did_recalculate,
+                tracked_dependencies.max_time_updated,
+                tracked_dependencies.durability,
+            )
         }
-        None => (DidRecalculate::Error, Epoch::new()),
+        None => (DidRecalculate::Error, Epoch::new(), Durability::Low),
     }
 }
 
@@ -251,9 +448,53 @@ fn invoke_with_dependency_tracking<Db: Database>(
     db: &Db,
     derived_node_id: DerivedNodeId,
     inner_fn: InnerFn<Db>,
+    in_cycle: bool,
 ) -> Option<(Box<dyn DynEq>, TrackedDependencies)> {
-    let guard = db.get_storage().dependency_stack.enter(derived_node_id);
-    let result = inner_fn.0(db, derived_node_id);
+    let guard = db
+        .get_storage()
+        .dependency_stack
+        .enter(derived_node_id, inner_fn.cycle_result.is_some());
+    if in_cycle {
+        // A cycle was detected while verifying this function's dependencies.
+        db.get_storage()
+            .dependency_stack
+            .mark_cycle(derived_node_id);
+    }
+    let result = match inner_fn.cycle_result {
+        Some(cycle_result) => {
+            match catch_cycle_recovery(derived_node_id, || (inner_fn.call)(db, derived_node_id)) {
+                Ok(result) if !db.get_storage().dependency_stack.top_in_cycle() => result,
+                Ok(_) => cycle_result(db, derived_node_id),
+                Err(called) => {
+                    db.get_storage().register_dependency_in_parent_memoized_fn(
+                        NodeKind::Derived(called),
+                        db.get_storage().internal.current_epoch,
+                        Durability::Low,
+                    );
+                    cycle_result(db, derived_node_id)
+                }
+            }
+        }
+        None => (inner_fn.call)(db, derived_node_id),
+    };
     let dependencies = guard.release();
     Some((result?, dependencies))
 }
+
+/// Calls `f`. If a call in a cycle that cannot recover from it unwound to
+/// `derived_node_id` (see [`recover_from_cycle`]), returns the call made by
+/// `derived_node_id` that led to the cycle instead. Other panics are propagated.
+fn catch_cycle_recovery<T>(
+    derived_node_id: DerivedNodeId,
+    f: impl FnOnce() -> T,
+) -> Result<T, DerivedNodeId> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        match payload.downcast::<CycleRecovery>() {
+            Ok(cycle_recovery) if cycle_recovery.recover_at == derived_node_id => {
+                cycle_recovery.called
+            }
+            Ok(cycle_recovery) => panic::resume_unwind(cycle_recovery),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use boxcar::Vec as BoxcarVec;
 use dashmap::DashMap;
@@ -79,6 +79,7 @@ impl<Db: Database> InternalStorage<Db> {
                 DerivedNodeRevision {
                     time_updated: old_derived_node_revision.time_updated,
                     time_verified: old_derived_node_revision.time_verified,
+                    durability: old_derived_node_revision.durability,
                     node_index: new_index,
                     dependency_index: new_dependencies_index,
                 },
@@ -128,6 +129,94 @@ impl<Db: Database> InternalStorage<Db> {
     }
 }
 
+impl<Db: Database> InternalStorage<Db> {
+    /// If more than `max_derived_node_count` derived nodes are reachable from
+    /// `retained_derived_node_ids` and `permanently_retained_derived_node_ids`,
+    /// returns the least recently verified derived nodes, which should be evicted
+    /// so that at most `max_derived_node_count` remain.
+    ///
+    /// Derived nodes that are reachable from `permanently_retained_derived_node_ids`
+    /// are never evicted, so more than `max_derived_node_count` may remain. When a derived
+    /// node is evicted, every derived node that (transitively) depends on it is also
+    /// evicted, since their values may contain `MemoRef`s to the evicted value.
+    pub(crate) fn derived_nodes_to_evict(
+        &self,
+        retained_derived_node_ids: impl Iterator<Item = DerivedNodeId>,
+        permanently_retained_derived_node_ids: impl Iterator<Item = DerivedNodeId> + Clone,
+        max_derived_node_count: usize,
+    ) -> HashSet<DerivedNodeId> {
+        let reachable_derived_node_ids = self.reachable_derived_node_ids(
+            retained_derived_node_ids.chain(permanently_retained_derived_node_ids.clone()),
+        );
+        if reachable_derived_node_ids.len() <= max_derived_node_count {
+            return HashSet::new();
+        }
+        let permanently_retained_derived_node_ids =
+            self.reachable_derived_node_ids(permanently_retained_derived_node_ids);
+
+        let mut dependents = HashMap::<DerivedNodeId, Vec<DerivedNodeId>>::new();
+        for derived_node_id in &reachable_derived_node_ids {
+            for dependency in self
+                .get_dependencies(*derived_node_id)
+                .into_iter()
+                .flatten()
+            {
+                if let NodeKind::Derived(dependency_id) = dependency.node_to {
+                    dependents
+                        .entry(dependency_id)
+                        .or_default()
+                        .push(*derived_node_id);
+                }
+            }
+        }
+
+        let mut candidates = reachable_derived_node_ids
+            .iter()
+            .filter(|derived_node_id| {
+                !permanently_retained_derived_node_ids.contains(derived_node_id)
+            })
+            .filter_map(|derived_node_id| {
+                let revision = self.get_derived_node_revision(*derived_node_id)?;
+                Some((revision.time_verified, *derived_node_id))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(time_verified, _)| *time_verified);
+
+        let mut evicted_derived_node_ids = HashSet::new();
+        for (_, derived_node_id) in candidates {
+            if reachable_derived_node_ids.len() - evicted_derived_node_ids.len()
+                <= max_derived_node_count
+            {
+                break;
+            }
+            let mut queue = vec![derived_node_id];
+            while let Some(derived_node_id) = queue.pop() {
+                if evicted_derived_node_ids.insert(derived_node_id) {
+                    queue.extend(dependents.get(&derived_node_id).into_iter().flatten());
+                }
+            }
+        }
+        evicted_derived_node_ids
+    }
+
+    fn reachable_derived_node_ids(
+        &self,
+        derived_node_ids: impl Iterator<Item = DerivedNodeId>,
+    ) -> HashSet<DerivedNodeId> {
+        let mut derived_node_id_queue = derived_node_ids.collect::<Vec<_>>();
+        let mut reachable_derived_node_ids = HashSet::new();
+        while let Some(derived_node_id) = derived_node_id_queue.pop() {
+            if !reachable_derived_node_ids.insert(derived_node_id) {
+                continue;
+            }
+            if let Some(dependencies) = self.get_dependencies(derived_node_id) {
+                add_dependencies_to_queue(&mut derived_node_id_queue, dependencies.iter());
+            }
+        }
+        reachable_derived_node_ids
+    }
+}
+
 fn add_dependencies_to_queue<'a>(
     derived_node_id_queue: &mut Vec<DerivedNodeId>,
     dependencies: impl Iterator<Item = &'a Dependency>,
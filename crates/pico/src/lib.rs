@@ -1,6 +1,8 @@
+mod cancellation;
 mod database;
 mod dependency;
 mod derived_node;
+mod durability;
 mod dyn_eq;
 mod epoch;
 mod execute_memoized_function;
@@ -9,19 +11,26 @@ mod index;
 mod intern;
 pub mod macro_fns;
 mod memo_ref;
+mod par_map;
+mod persistence;
+mod profile;
 mod raw_ptr;
 mod retained_query;
 mod source;
 mod view;
 mod with_serialize;
 
+pub use cancellation::*;
 pub use database::*;
 pub use derived_node::*;
+pub use durability::*;
 pub use dyn_eq::*;
 pub use execute_memoized_function::*;
 pub use index::*;
 pub use intern::*;
 pub use memo_ref::*;
+pub use persistence::*;
+pub use profile::*;
 pub use raw_ptr::*;
 pub use retained_query::*;
 pub use source::*;
@@ -1,18 +1,19 @@
 use std::{
-    any::{Any, TypeId},
+    any::{Any, TypeId, type_name},
     hash::{DefaultHasher, Hash, Hasher},
 };
 
 use dashmap::Entry;
+use serde::Serialize;
 use tinyvec::ArrayVec;
 
-use crate::{Database, ParamId, index::Index};
+use crate::{Database, Key, ParamId, index::Index};
 
 pub fn init_param_vec() -> ArrayVec<[ParamId; 8]> {
     ArrayVec::<[ParamId; 8]>::default()
 }
 
-pub fn intern_borrowed_param<Db: Database, T: Hash + Clone + 'static>(
+pub fn intern_borrowed_param<Db: Database, T: Hash + Clone + Send + Sync + 'static>(
     db: &Db,
     param: &T,
 ) -> ParamId {
@@ -28,7 +29,10 @@ pub fn intern_borrowed_param<Db: Database, T: Hash + Clone + 'static>(
     param_id
 }
 
-pub fn intern_owned_param<Db: Database, T: Hash + Clone + 'static>(db: &Db, param: T) -> ParamId {
+pub fn intern_owned_param<Db: Database, T: Hash + Clone + Send + Sync + 'static>(
+    db: &Db,
+    param: T,
+) -> ParamId {
     let param_id = hash(&param).into();
     if let Entry::Vacant(v) = db.get_storage().internal.param_id_to_index.entry(param_id) {
         let idx = db.get_storage().internal.params.push(Box::new(param));
@@ -37,7 +41,7 @@ pub fn intern_owned_param<Db: Database, T: Hash + Clone + 'static>(db: &Db, para
     param_id
 }
 
-pub fn get_param<Db: Database>(db: &Db, param_id: ParamId) -> Option<&Box<dyn Any>> {
+pub fn get_param<Db: Database>(db: &Db, param_id: ParamId) -> Option<&Box<dyn Any + Send + Sync>> {
     db.get_storage().internal.get_param(param_id)
 }
 
@@ -48,3 +52,36 @@ pub fn hash<T: Hash + 'static>(value: &T) -> u64 {
     value.hash(&mut s);
     s.finish()
 }
+
+/// Hashes the serialized value. Unlike [`hash`], this does not depend on interned
+/// ids (which `Hash` implementations of interned values typically hash), so the
+/// result is stable across processes. Returns `None` if the value cannot be
+/// serialized.
+pub fn persisted_hash<T: Serialize + ?Sized>(value: &T) -> Option<u64> {
+    let bytes = bincode::serde::encode_to_vec(value, bincode::config::standard()).ok()?;
+    let mut s = DefaultHasher::new();
+    // hash the type name to prevent collisions for newtypes
+    type_name::<T>().hash(&mut s);
+    bytes.hash(&mut s);
+    Some(s.finish())
+}
+
+/// The [`persisted_hash`] of a param of a function annotated with `#[memo(persist)]`.
+pub fn persisted_param_hash<Db: Database, T: Serialize + 'static>(
+    db: &Db,
+    param_id: ParamId,
+) -> Option<u64> {
+    let param = get_param(db, param_id)?
+        .downcast_ref::<T>()
+        .expect("Unexpected param type. This is indicative of a bug in Pico.");
+    persisted_hash(param)
+}
+
+/// The [persisted key](crate::Source::get_persisted_key) of a source that is
+/// passed to a function annotated with `#[memo(persist)]`.
+pub fn persisted_source_key<Db: Database>(db: &Db, param_id: ParamId) -> Option<u64> {
+    db.get_storage()
+        .internal
+        .get_source_node(Key::from(param_id.inner()))?
+        .persisted_key
+}
@@ -61,6 +61,7 @@ impl<T: 'static> MemoRef<T> {
         storage.register_dependency_in_parent_memoized_fn(
             NodeKind::Derived(self.derived_node_id),
             revision.time_updated,
+            revision.durability,
         );
         match self.kind {
             MemoRefKind::Value => value
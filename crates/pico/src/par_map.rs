@@ -0,0 +1,72 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    Database,
+    dependency::{DependencyStack, TrackedDependencies},
+};
+
+/// See [`Database::par_map`].
+pub(crate) fn par_map<Db, T, R>(
+    db: &Db,
+    items: impl IntoIterator<Item = T>,
+    f: impl Fn(&Db, T) -> R + Sync,
+) -> Vec<R>
+where
+    Db: Database + Sync,
+    T: Send,
+    R: Send,
+{
+    let dependency_stack = &db.get_storage().dependency_stack;
+    let forked_stack = dependency_stack.fork();
+
+    let results = items
+        .into_iter()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|item| {
+            // Rayon may run this on any thread, including on the current thread, or on
+            // a thread that is itself in the middle of calling memoized functions. So,
+            // we swap in a stack for this item, and restore the thread's stack afterward.
+            let guard = SwappedStackGuard {
+                dependency_stack,
+                previous_stack: Some(dependency_stack.replace(forked_stack.clone())),
+            };
+            let result = f(db, item);
+            (result, guard.restore())
+        })
+        .collect::<Vec<_>>();
+
+    results
+        .into_iter()
+        .map(|(result, forked_stack)| {
+            dependency_stack.join(forked_stack);
+            result
+        })
+        .collect()
+}
+
+/// Restores the thread's stack once the item has been mapped, including if
+/// mapping it unwinds (e.g. because it was cancelled).
+struct SwappedStackGuard<'a> {
+    dependency_stack: &'a DependencyStack,
+    previous_stack: Option<Vec<TrackedDependencies>>,
+}
+
+impl SwappedStackGuard<'_> {
+    /// Returns the stack that was swapped in.
+    fn restore(mut self) -> Vec<TrackedDependencies> {
+        let previous_stack = self
+            .previous_stack
+            .take()
+            .expect("Expected stack to not yet be restored. This is indicative of a bug in Pico.");
+        self.dependency_stack.replace(previous_stack)
+    }
+}
+
+impl Drop for SwappedStackGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(previous_stack) = self.previous_stack.take() {
+            self.dependency_stack.replace(previous_stack);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+use crate::{
+    Database, DerivedNode, DidRecalculate, Durability, InnerFn, Storage,
+    dependency::{Dependency, NodeKind},
+    derived_node::DerivedNodeId,
+    dyn_eq::DynEq,
+    epoch::Epoch,
+    execute_memoized_function,
+};
+
+/// Serializes the value of a derived node. Returns `None` if the value is not of
+/// the expected type or cannot be serialized.
+pub(crate) type SerializeValueFn = fn(&dyn DynEq) -> Option<Vec<u8>>;
+
+/// Computes the persisted key of a derived node, i.e. a hash of the function and
+/// of the [persisted hashes](crate::macro_fns::persisted_hash) of its params.
+/// Returns `None` if a param cannot be persisted. Generated by `#[memo(persist)]`.
+pub type PersistedKeyFn<Db> = fn(&Db, DerivedNodeId) -> Option<u64>;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedDatabase {
+    version: String,
+    derived_nodes: Vec<PersistedDerivedNode>,
+}
+
+/// The value of a derived node, as persisted by one process and loaded by another.
+/// The derived node and sources are identified by their persisted keys, since
+/// interned ids (and hence keys and params) are not stable across processes.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct PersistedDerivedNode {
+    key: u64,
+    /// The persisted key and content hash of every source that the value
+    /// (transitively) depends on.
+    source_content_hashes: Vec<(u64, u64)>,
+    value: Vec<u8>,
+}
+
+#[derive(Debug, Error)]
+pub enum LoadPersistedValuesError {
+    #[error(
+        "The persisted values were created by a different version ({persisted_version}), \
+        and cannot be reused."
+    )]
+    VersionMismatch { persisted_version: String },
+    #[error("Unable to deserialize the persisted values. {0}")]
+    Decode(#[from] bincode::error::DecodeError),
+}
+
+impl<Db: Database> Storage<Db> {
+    /// Serializes the values of every function annotated with `#[memo(persist)]`
+    /// that is up-to-date, along with the content hashes of the sources they
+    /// depend on.
+    ///
+    /// Values that (transitively) depend on a source without a content hash
+    /// (see [`Source::get_content_hash`](crate::Source::get_content_hash)) or
+    /// persisted key (see [`Source::get_persisted_key`](crate::Source::get_persisted_key))
+    /// are not persisted.
+    ///
+    /// `version` should change whenever the persisted functions may return
+    /// different values for the same params and sources, e.g. when the binary
+    /// changes.
+    pub fn serialize_persisted_values(&self, version: &str) -> Vec<u8> {
+        // Collect first, so that we do not hold a lock on the dashmap while reading
+        // the dependencies of each derived node.
+        let derived_node_ids = self
+            .internal
+            .derived_node_id_to_revision
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+
+        let derived_nodes = derived_node_ids
+            .into_iter()
+            .filter_map(|derived_node_id| self.persisted_derived_node(derived_node_id))
+            .collect();
+
+        bincode::serde::encode_to_vec(
+            PersistedDatabase {
+                version: version.to_string(),
+                derived_nodes,
+            },
+            bincode::config::standard(),
+        )
+        .expect(
+            "Expected persisted values to be serializable. This is indicative of a bug in Pico.",
+        )
+    }
+
+    /// Loads values serialized by [`Storage::serialize_persisted_values`]. The
+    /// first time a function annotated with `#[memo(persist)]` is called with
+    /// the same params, it reuses the loaded value instead of executing, as
+    /// long as the content hashes of the sources it depends on have not changed.
+    ///
+    /// Loaded values are validated lazily, so sources can be set before or after
+    /// calling this.
+    pub fn load_persisted_values(
+        &self,
+        bytes: &[u8],
+        version: &str,
+    ) -> Result<(), LoadPersistedValuesError> {
+        let (persisted_database, _): (PersistedDatabase, usize) =
+            bincode::serde::decode_from_slice(bytes, bincode::config::standard())?;
+
+        if persisted_database.version != version {
+            return Err(LoadPersistedValuesError::VersionMismatch {
+                persisted_version: persisted_database.version,
+            });
+        }
+
+        for persisted_derived_node in persisted_database.derived_nodes {
+            self.persisted_derived_nodes
+                .insert(persisted_derived_node.key, persisted_derived_node);
+        }
+
+        Ok(())
+    }
+
+    fn persisted_derived_node(
+        &self,
+        derived_node_id: DerivedNodeId,
+    ) -> Option<PersistedDerivedNode> {
+        let serialize_value = *self
+            .persisted_value_serializers
+            .get(&derived_node_id.key)?
+            .value();
+        let key = (*self
+            .persisted_derived_node_keys
+            .get(&derived_node_id)?
+            .value())?;
+        let source_content_hashes = self.source_content_hashes(derived_node_id)?;
+        let derived_node = self.internal.get_derived_node(derived_node_id)?;
+
+        Some(PersistedDerivedNode {
+            key,
+            source_content_hashes,
+            value: serialize_value(derived_node.value.as_ref())?,
+        })
+    }
+
+    /// The content hashes of every source that the derived node transitively
+    /// depends on. Returns `None` if the derived node is not up-to-date (i.e. a
+    /// source that it depends on has changed since it was last verified), or if
+    /// a source it depends on has no content hash.
+    fn source_content_hashes(&self, derived_node_id: DerivedNodeId) -> Option<Vec<(u64, u64)>> {
+        let mut source_content_hashes = BTreeMap::new();
+        let mut visited = HashSet::new();
+        let mut queue = vec![derived_node_id];
+
+        while let Some(derived_node_id) = queue.pop() {
+            if !visited.insert(derived_node_id) {
+                continue;
+            }
+            for dependency in self.internal.get_dependencies(derived_node_id)? {
+                match dependency.node_to {
+                    NodeKind::Source(key) => {
+                        let source_node = self.internal.get_source_node(key)?;
+                        if source_node.time_updated > dependency.time_verified_or_updated {
+                            return None;
+                        }
+                        source_content_hashes
+                            .insert(source_node.persisted_key?, source_node.content_hash()?);
+                    }
+                    NodeKind::Derived(dependency_id) => {
+                        let revision = self.internal.get_derived_node_revision(dependency_id)?;
+                        if revision.time_updated > dependency.time_verified_or_updated {
+                            return None;
+                        }
+                        queue.push(dependency_id);
+                    }
+                }
+            }
+        }
+
+        Some(source_content_hashes.into_iter().collect())
+    }
+}
+
+/// Executes a function annotated with `#[memo(persist)]`. If the function has not
+/// yet been called with these params, and a value loaded via
+/// [`Storage::load_persisted_values`] is still valid, that value is reused.
+pub fn execute_persisted_memoized_function<Db, T>(
+    db: &Db,
+    derived_node_id: DerivedNodeId,
+    inner_fn: InnerFn<Db>,
+    persisted_key: PersistedKeyFn<Db>,
+) -> DidRecalculate
+where
+    Db: Database,
+    T: Serialize + DeserializeOwned + DynEq,
+{
+    let storage = db.get_storage();
+    if !storage
+        .persisted_value_serializers
+        .contains_key(&derived_node_id.key)
+    {
+        storage
+            .persisted_value_serializers
+            .insert(derived_node_id.key, serialize_value::<T>);
+    }
+
+    // Params are only serialized and hashed the first time that the function is
+    // called with them.
+    let persisted_key = match storage.persisted_derived_node_keys.get(&derived_node_id) {
+        Some(persisted_key) => *persisted_key,
+        None => {
+            let persisted_key = persisted_key(db, derived_node_id);
+            storage
+                .persisted_derived_node_keys
+                .insert(derived_node_id, persisted_key);
+            persisted_key
+        }
+    };
+
+    if storage.internal.get_derived_node(derived_node_id).is_none()
+        && let Some(persisted_key) = persisted_key
+        && let Some((_, persisted_derived_node)) =
+            storage.persisted_derived_nodes.remove(&persisted_key)
+    {
+        restore_derived_node::<Db, T>(db, derived_node_id, inner_fn, persisted_derived_node);
+    }
+
+    execute_memoized_function(db, derived_node_id, inner_fn)
+}
+
+/// Inserts the persisted value as a derived node that depends directly on the
+/// sources that the value was computed from, if their content hashes are unchanged.
+fn restore_derived_node<Db: Database, T: DeserializeOwned + DynEq>(
+    db: &Db,
+    derived_node_id: DerivedNodeId,
+    inner_fn: InnerFn<Db>,
+    persisted_derived_node: PersistedDerivedNode,
+) {
+    let internal = &db.get_storage().internal;
+    let current_epoch = internal.current_epoch;
+
+    let mut dependencies = vec![];
+    let mut time_updated = Epoch::new();
+    let mut durability = Durability::High;
+    for (persisted_key, content_hash) in persisted_derived_node.source_content_hashes {
+        let Some(key) = db
+            .get_storage()
+            .persisted_source_keys
+            .get(&persisted_key)
+            .map(|key| *key)
+        else {
+            return;
+        };
+        let Some(source_node) = internal.get_source_node(key) else {
+            return;
+        };
+        if source_node.content_hash() != Some(content_hash) {
+            return;
+        }
+        time_updated = std::cmp::max(time_updated, source_node.time_updated);
+        durability = std::cmp::min(durability, source_node.durability);
+        dependencies.push(Dependency {
+            node_to: NodeKind::Source(key),
+            time_verified_or_updated: current_epoch,
+        });
+    }
+
+    let Ok((value, _)) = bincode::serde::decode_from_slice::<T, _>(
+        &persisted_derived_node.value,
+        bincode::config::standard(),
+    ) else {
+        return;
+    };
+
+    let node_index = internal.insert_derived_node(DerivedNode {
+        inner_fn,
+        value: Box::new(value),
+    });
+    let dependency_index = internal.insert_dependencies(dependencies);
+    internal.insert_derived_node_revision(
+        derived_node_id,
+        time_updated,
+        current_epoch,
+        durability,
+        node_index,
+        dependency_index,
+    );
+}
+
+fn serialize_value<T: Serialize + DynEq>(value: &dyn DynEq) -> Option<Vec<u8>> {
+    let value = value.as_any().downcast_ref::<T>()?;
+    bincode::serde::encode_to_vec(value, bincode::config::standard()).ok()
+}
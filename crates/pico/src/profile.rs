@@ -0,0 +1,320 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Write,
+    sync::atomic::Ordering,
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    Database, DidRecalculate, Storage, dependency::NodeKind, derived_node::DerivedNodeId,
+    epoch::Epoch, intern::Key,
+};
+
+/// A call to a memoized function, recorded while profiling is enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct ProfiledCall {
+    pub derived_node_id: DerivedNodeId,
+    pub name: &'static str,
+    /// The memoized function call that made this call, or `None` if this is
+    /// a top-level call.
+    pub parent: Option<DerivedNodeId>,
+    pub did_recalculate: DidRecalculate,
+    pub thread_id: ThreadId,
+    pub start: Instant,
+    /// Includes the time spent in the memoized functions that this call made.
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfiledDependency {
+    Source(Key),
+    Derived(DerivedNodeId),
+}
+
+#[derive(Debug)]
+pub struct ProfiledNode {
+    pub name: &'static str,
+    pub dependencies: Vec<ProfiledDependency>,
+}
+
+/// The memoized function calls made since the last time a source changed,
+/// along with the dependencies of the derived nodes that they read or created.
+#[derive(Debug, Default)]
+pub struct Profile {
+    /// In the order in which the calls completed.
+    pub calls: Vec<ProfiledCall>,
+    /// Every derived node that was called, and every derived node that those
+    /// derived nodes depend on.
+    pub nodes: HashMap<DerivedNodeId, ProfiledNode>,
+}
+
+impl<Db: Database> Storage<Db> {
+    /// Starts recording every call to a memoized function. The recorded calls
+    /// are available via [`Storage::profile`].
+    pub fn enable_profiling(&self) {
+        self.profiling_enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Stops recording calls to memoized functions. The calls that were already
+    /// recorded are kept until a source changes.
+    pub fn disable_profiling(&self) {
+        self.profiling_enabled.store(false, Ordering::Relaxed);
+    }
+
+    pub fn is_profiling_enabled(&self) -> bool {
+        self.profiling_enabled.load(Ordering::Relaxed)
+    }
+
+    /// The calls to memoized functions that were made since the last time a
+    /// source changed, i.e. in the current epoch.
+    pub fn profile(&self) -> Profile {
+        let calls = self
+            .profiled_calls
+            .iter()
+            .map(|(_, call)| *call)
+            .collect::<Vec<_>>();
+
+        let mut nodes = HashMap::new();
+        for call in &calls {
+            let Entry::Vacant(vacant) = nodes.entry(call.derived_node_id) else {
+                continue;
+            };
+            let dependencies = self
+                .internal
+                .get_dependencies(call.derived_node_id)
+                .map(|dependencies| {
+                    dependencies
+                        .iter()
+                        .map(|dependency| match dependency.node_to {
+                            NodeKind::Source(key) => ProfiledDependency::Source(key),
+                            NodeKind::Derived(derived_node_id) => {
+                                ProfiledDependency::Derived(derived_node_id)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            vacant.insert(ProfiledNode {
+                name: call.name,
+                dependencies,
+            });
+        }
+
+        // Derived nodes that were not called (e.g. interned values) are
+        // included, so that every dependency can be labeled.
+        let uncalled_dependencies = nodes
+            .values()
+            .flat_map(|node| node.dependencies.iter())
+            .filter_map(|dependency| match dependency {
+                ProfiledDependency::Derived(derived_node_id)
+                    if !nodes.contains_key(derived_node_id) =>
+                {
+                    Some(*derived_node_id)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for derived_node_id in uncalled_dependencies {
+            if let Some(derived_node) = self.internal.get_derived_node(derived_node_id) {
+                nodes.insert(
+                    derived_node_id,
+                    ProfiledNode {
+                        name: derived_node.inner_fn.name,
+                        dependencies: vec![],
+                    },
+                );
+            }
+        }
+
+        Profile { calls, nodes }
+    }
+
+    /// Recorded calls are only kept until a source changes.
+    pub(crate) fn clear_profile_if_epoch_changed(&mut self, previous_epoch: Epoch) {
+        if self.internal.current_epoch != previous_epoch {
+            self.profiled_calls.clear();
+        }
+    }
+}
+
+/// Calls `execute`, which executes a memoized function, and records the call
+/// if profiling is enabled.
+pub(crate) fn profile_call<Db: Database>(
+    db: &Db,
+    derived_node_id: DerivedNodeId,
+    name: &'static str,
+    execute: impl FnOnce() -> DidRecalculate,
+) -> DidRecalculate {
+    let storage = db.get_storage();
+    if !storage.profiling_enabled.load(Ordering::Relaxed) {
+        return execute();
+    }
+
+    let parent = storage.dependency_stack.top_derived_node_id();
+    let start = Instant::now();
+    let did_recalculate = execute();
+    storage.profiled_calls.push(ProfiledCall {
+        derived_node_id,
+        name,
+        parent,
+        did_recalculate,
+        thread_id: thread::current().id(),
+        start,
+        duration: start.elapsed(),
+    });
+    did_recalculate
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<ChromeTraceEvent>,
+    display_time_unit: &'static str,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceEvent {
+    name: &'static str,
+    cat: &'static str,
+    ph: &'static str,
+    /// In microseconds
+    ts: f64,
+    /// In microseconds
+    dur: f64,
+    pid: usize,
+    tid: usize,
+}
+
+impl Profile {
+    /// Serializes the calls in the Chrome trace event format, which can be
+    /// viewed in e.g. `chrome://tracing` or Perfetto. Each thread is shown
+    /// on its own track, with nested calls below the calls that made them.
+    pub fn to_chrome_trace(&self) -> String {
+        let first_start = self.calls.iter().map(|call| call.start).min();
+        let mut thread_ids = HashMap::new();
+        let trace_events = self
+            .calls
+            .iter()
+            .map(|call| {
+                let thread_count = thread_ids.len();
+                ChromeTraceEvent {
+                    name: call.name,
+                    cat: did_recalculate_label(call.did_recalculate),
+                    ph: "X",
+                    ts: call
+                        .start
+                        .duration_since(first_start.unwrap_or(call.start))
+                        .as_secs_f64()
+                        * 1_000_000.0,
+                    dur: call.duration.as_secs_f64() * 1_000_000.0,
+                    pid: 1,
+                    tid: *thread_ids.entry(call.thread_id).or_insert(thread_count),
+                }
+            })
+            .collect();
+
+        serde_json::to_string(&ChromeTrace {
+            trace_events,
+            display_time_unit: "ms",
+        })
+        .expect("Expected trace to be serializable. This is indicative of a bug in Pico.")
+    }
+
+    /// Serializes the dependency graph in the Graphviz DOT format. Each derived
+    /// node is labeled with the number of times it was called and the total time
+    /// spent in those calls. Derived nodes that were recalculated are filled.
+    pub fn to_dot(&self) -> String {
+        // The call count, total duration and whether any call recalculated,
+        // in the order in which the derived nodes were first called.
+        let mut called_derived_node_ids = vec![];
+        let mut stats = HashMap::<DerivedNodeId, (usize, Duration, bool)>::new();
+        for call in &self.calls {
+            let (call_count, total_duration, recalculated) =
+                stats.entry(call.derived_node_id).or_insert_with(|| {
+                    called_derived_node_ids.push(call.derived_node_id);
+                    Default::default()
+                });
+            *call_count += 1;
+            *total_duration += call.duration;
+            *recalculated |= matches!(call.did_recalculate, DidRecalculate::Recalculated);
+        }
+
+        let mut derived_node_indexes = called_derived_node_ids
+            .iter()
+            .enumerate()
+            .map(|(index, derived_node_id)| (*derived_node_id, index))
+            .collect::<HashMap<_, _>>();
+        let mut uncalled_derived_node_ids = vec![];
+        let mut source_count = 0;
+        let mut source_indexes = HashMap::<Key, usize>::new();
+
+        let mut edges = String::new();
+        for (index, derived_node_id) in called_derived_node_ids.iter().enumerate() {
+            let Some(node) = self.nodes.get(derived_node_id) else {
+                continue;
+            };
+            for dependency in &node.dependencies {
+                let to = match *dependency {
+                    ProfiledDependency::Source(key) => {
+                        let source_index = *source_indexes.entry(key).or_insert_with(|| {
+                            source_count += 1;
+                            source_count - 1
+                        });
+                        format!("s{source_index}")
+                    }
+                    ProfiledDependency::Derived(dependency_id) => {
+                        let derived_node_count = derived_node_indexes.len();
+                        let dependency_index = *derived_node_indexes
+                            .entry(dependency_id)
+                            .or_insert_with(|| {
+                                uncalled_derived_node_ids.push(dependency_id);
+                                derived_node_count
+                            });
+                        format!("n{dependency_index}")
+                    }
+                };
+                writeln!(edges, "  n{index} -> {to};").expect("Writing to a string cannot fail");
+            }
+        }
+
+        let mut dot = String::from("digraph {\n  node [shape=box];\n");
+        for derived_node_id in called_derived_node_ids
+            .iter()
+            .chain(&uncalled_derived_node_ids)
+        {
+            let index = derived_node_indexes[derived_node_id];
+            let name = self
+                .nodes
+                .get(derived_node_id)
+                .map(|node| node.name)
+                .unwrap_or("unknown");
+            match stats.get(derived_node_id) {
+                Some((call_count, total_duration, recalculated)) => writeln!(
+                    dot,
+                    "  n{index} [label=\"{name}\\n{call_count} call(s), {total_duration:.2?}\"{}];",
+                    if *recalculated { ", style=filled" } else { "" }
+                ),
+                None => writeln!(dot, "  n{index} [label=\"{name}\"];"),
+            }
+            .expect("Writing to a string cannot fail");
+        }
+        for source_index in 0..source_count {
+            writeln!(dot, "  s{source_index} [label=\"source\", shape=ellipse];")
+                .expect("Writing to a string cannot fail");
+        }
+        dot.push_str(&edges);
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn did_recalculate_label(did_recalculate: DidRecalculate) -> &'static str {
+    match did_recalculate {
+        DidRecalculate::ReusedMemoizedValue => "reused",
+        DidRecalculate::Recalculated => "recalculated",
+        DidRecalculate::Error => "error",
+    }
+}
@@ -22,6 +22,11 @@ impl<T: ?Sized> PartialEq for RawPtr<T> {
 
 impl<T: ?Sized> Eq for RawPtr<T> {}
 
+// SAFETY: a `RawPtr<T>` is only ever dereferenced into a `&T`, so it can be sent
+// and shared between threads exactly when a `&T` can be, i.e. when `T: Sync`.
+unsafe impl<T: ?Sized + Sync> Send for RawPtr<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RawPtr<T> {}
+
 impl<T: ?Sized> RawPtr<T> {
     #[inline]
     pub fn from_ref(r: &T) -> Self {
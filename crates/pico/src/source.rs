@@ -1,12 +1,44 @@
 use std::{
     hash::{Hash, Hasher},
     marker::PhantomData,
+    sync::OnceLock,
 };
 
-use crate::{HashId, ParamId, dyn_eq::DynEq, epoch::Epoch, intern::Key};
+use crate::{Durability, HashId, ParamId, dyn_eq::DynEq, epoch::Epoch, intern::Key};
 
 pub trait Source {
     fn get_key(&self) -> Key;
+
+    /// A hash of the contents of the source. Values of functions annotated with
+    /// `#[memo(persist)]` are only persisted if every source that they depend on
+    /// has a content hash, and are only reused by a later process if those
+    /// content hashes have not changed.
+    ///
+    /// Since the value is persisted, this must not depend on interned ids (see
+    /// [`persisted_hash`](crate::macro_fns::persisted_hash)).
+    ///
+    /// `#[derive(Source)]` only implements this for structs annotated with
+    /// `#[source(persist)]`. It is called lazily, i.e. when values are persisted or
+    /// loaded, and not when the source is set.
+    fn get_content_hash(&self) -> Option<u64> {
+        None
+    }
+
+    /// Identifies the source across processes, unlike [`Source::get_key`], which
+    /// may hash interned ids. Values of functions annotated with `#[memo(persist)]`
+    /// that depend on the source or accept its [`SourceId`] are only persisted if
+    /// this is implemented.
+    ///
+    /// `#[derive(Source)]` only implements this for structs annotated with
+    /// `#[source(persist)]`.
+    fn get_persisted_key(&self) -> Option<u64> {
+        None
+    }
+
+    /// How often this source is expected to change. See [`Durability`].
+    fn get_durability(&self) -> Durability {
+        Durability::Low
+    }
 }
 
 pub trait Singleton: Source {
@@ -77,8 +109,45 @@ impl<T> From<ParamId> for SourceId<T> {
     }
 }
 
+/// Calls [`Source::get_content_hash`] on the value of a source node.
+pub(crate) type GetContentHashFn = fn(&dyn DynEq) -> Option<u64>;
+
 #[derive(Debug)]
 pub struct SourceNode {
     pub time_updated: Epoch,
     pub value: Box<dyn DynEq>,
+    pub(crate) get_content_hash: GetContentHashFn,
+    /// The result of `get_content_hash`, which is only computed if the node is
+    /// persisted or a persisted value depends on it.
+    pub(crate) content_hash: OnceLock<Option<u64>>,
+    /// See [`Source::get_persisted_key`].
+    pub(crate) persisted_key: Option<u64>,
+    pub durability: Durability,
+}
+
+impl SourceNode {
+    pub(crate) fn new<T: Source + DynEq>(
+        time_updated: Epoch,
+        value: T,
+        durability: Durability,
+    ) -> Self {
+        Self {
+            time_updated,
+            persisted_key: value.get_persisted_key(),
+            value: Box::new(value),
+            get_content_hash: get_content_hash::<T>,
+            content_hash: OnceLock::new(),
+            durability,
+        }
+    }
+
+    pub(crate) fn content_hash(&self) -> Option<u64> {
+        *self
+            .content_hash
+            .get_or_init(|| (self.get_content_hash)(self.value.as_ref()))
+    }
+}
+
+fn get_content_hash<T: Source + DynEq>(value: &dyn DynEq) -> Option<u64> {
+    value.as_any().downcast_ref::<T>()?.get_content_hash()
 }
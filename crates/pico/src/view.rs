@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{Database, Singleton};
 
-pub trait Counter: Singleton + Default + Copy + Eq + 'static {
+pub trait Counter: Singleton + Default + Copy + Eq + Send + Sync + 'static {
     fn increment(self) -> Self;
 }
 
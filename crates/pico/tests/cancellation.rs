@@ -0,0 +1,89 @@
+use std::sync::{
+    Barrier, LazyLock,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use pico::{Cancelled, Database, SourceId, Storage};
+use pico_macros::{Db, Source, memo};
+
+static BARRIER: LazyLock<Barrier> = LazyLock::new(|| Barrier::new(2));
+static FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static SLOW_FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn executing_memoized_functions_unwind_when_cancelled() {
+    let mut db = TestDatabase::default();
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    let cancellation_handle = db.get_storage().cancellation_handle();
+
+    let result = std::thread::scope(|scope| {
+        let result = scope.spawn(|| Cancelled::catch(|| *slow_first_letter(&db, input_id)));
+        // Wait until slow_first_letter is executing, then cancel it
+        BARRIER.wait();
+        cancellation_handle.cancel();
+        BARRIER.wait();
+        result.join().unwrap()
+    });
+    assert!(result.is_err());
+    assert_eq!(SLOW_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 1);
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 0);
+
+    // Until a source is set, every call unwinds
+    assert!(Cancelled::catch(|| *slow_first_letter(&db, input_id)).is_err());
+    assert_eq!(SLOW_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 1);
+
+    db.set(Input {
+        key: "key",
+        value: "qwer".to_string(),
+    });
+    assert_eq!(
+        Cancelled::catch(|| *slow_first_letter(&db, input_id)).unwrap(),
+        'q'
+    );
+    assert_eq!(SLOW_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 1);
+
+    // The value is memoized as usual
+    assert_eq!(*slow_first_letter(&db, input_id), 'q');
+    assert_eq!(SLOW_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn other_panics_are_propagated() {
+    let result = std::panic::catch_unwind(|| {
+        let _ = Cancelled::catch(|| panic!("Not a cancellation"));
+    });
+    assert!(result.is_err());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct Input {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[memo]
+fn first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let input = db.get(input_id);
+    input.value.chars().next().unwrap()
+}
+
+#[memo]
+fn slow_first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    if SLOW_FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst) == 0 {
+        BARRIER.wait();
+        BARRIER.wait();
+    }
+    // Calling a memoized function unwinds if this has been cancelled
+    *first_letter(db, input_id)
+}
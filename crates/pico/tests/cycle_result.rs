@@ -0,0 +1,133 @@
+use pico::{Database, SourceId, Storage};
+use pico_macros::{Db, Source, memo};
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn calls_itself_returns_cycle_result() {
+    let db = TestDatabase::default();
+
+    // calls_itself calls calls_itself, which returns the cycle result instead of panicking
+    assert_eq!(*calls_itself(&db, 1), 2);
+}
+
+#[test]
+fn every_participant_returns_its_cycle_result() {
+    let mut db = TestDatabase::default();
+
+    let input_id = db.set(Input {
+        key: "key",
+        value: "cycle".to_string(),
+    });
+
+    // a calls b, which calls a, which returns its cycle result
+    assert_eq!(a(&db, input_id), "a-cycle");
+    assert_eq!(b(&db, input_id), "b-cycle");
+
+    db.set(Input {
+        key: "key",
+        value: "no cycle".to_string(),
+    });
+
+    assert_eq!(a(&db, input_id), "a");
+    assert_eq!(b(&db, input_id), "b(a)");
+
+    db.set(Input {
+        key: "key",
+        value: "cycle".to_string(),
+    });
+
+    // The values do not depend on which function entered the cycle first
+    assert_eq!(b(&db, input_id), "b-cycle");
+    assert_eq!(a(&db, input_id), "a-cycle");
+}
+
+#[test]
+fn cycle_entered_without_cycle_result_unwinds_to_participant_with_cycle_result() {
+    let mut db = TestDatabase::default();
+
+    let input_id = db.set(Input {
+        key: "key",
+        value: "cycle".to_string(),
+    });
+
+    // outer calls inner, which calls outer. outer has no cycle result, so we unwind
+    // to inner, which returns its cycle result.
+    assert_eq!(outer(&db, input_id), "outer(inner-cycle)");
+    assert_eq!(inner(&db, input_id), "inner-cycle");
+
+    db.set(Input {
+        key: "key",
+        value: "no cycle".to_string(),
+    });
+
+    assert_eq!(outer(&db, input_id), "outer");
+    assert_eq!(inner(&db, input_id), "inner(outer)");
+
+    db.set(Input {
+        key: "key",
+        value: "cycle".to_string(),
+    });
+
+    assert_eq!(inner(&db, input_id), "inner-cycle");
+    assert_eq!(outer(&db, input_id), "outer(inner-cycle)");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct Input {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[memo(cycle_result = calls_itself_cycle_result)]
+fn calls_itself(db: &TestDatabase, value: usize) -> usize {
+    *calls_itself(db, value)
+}
+
+fn calls_itself_cycle_result(_db: &TestDatabase, value: usize) -> usize {
+    value + 1
+}
+
+#[memo(cycle_result = a_cycle_result)]
+fn a(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    if db.get(input_id).value == "cycle" {
+        format!("a({})", b(db, input_id))
+    } else {
+        "a".to_string()
+    }
+}
+
+fn a_cycle_result(_db: &TestDatabase, _input_id: SourceId<Input>) -> String {
+    "a-cycle".to_string()
+}
+
+#[memo(cycle_result = b_cycle_result)]
+fn b(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    format!("b({})", a(db, input_id))
+}
+
+fn b_cycle_result(_db: &TestDatabase, _input_id: SourceId<Input>) -> String {
+    "b-cycle".to_string()
+}
+
+#[memo]
+fn outer(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    if db.get(input_id).value == "cycle" {
+        format!("outer({})", inner(db, input_id))
+    } else {
+        "outer".to_string()
+    }
+}
+
+#[memo(cycle_result = inner_cycle_result)]
+fn inner(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    format!("inner({})", outer(db, input_id))
+}
+
+fn inner_cycle_result(_db: &TestDatabase, _input_id: SourceId<Input>) -> String {
+    "inner-cycle".to_string()
+}
@@ -0,0 +1,105 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pico::{Database, DidRecalculate, Durability, Source, SourceId, Storage};
+use pico_macros::{Db, Source, memo};
+
+static SCHEMA_LENGTH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static TOTAL_LENGTH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn derived_nodes_that_only_depend_on_high_durability_sources_are_not_reverified() {
+    let mut db = TestDatabase::default();
+    db.get_storage().enable_profiling();
+
+    let schema = Schema {
+        key: "schema",
+        value: "type Query".to_string(),
+    };
+    assert_eq!(schema.get_durability(), Durability::High);
+    let schema_id = db.set(schema);
+    let open_file_id = db.set(OpenFile {
+        key: "file",
+        value: "abc".to_string(),
+    });
+
+    assert_eq!(*doubled_schema_length(&db, schema_id), 20);
+    assert_eq!(*total_length(&db, schema_id, open_file_id), 13);
+    assert_eq!(SCHEMA_LENGTH_COUNTER.load(Ordering::SeqCst), 1);
+    assert_eq!(TOTAL_LENGTH_COUNTER.load(Ordering::SeqCst), 1);
+
+    // Only a low durability source changed, so doubled_schema_length is reused
+    // without verifying schema_length
+    db.set(OpenFile {
+        key: "file",
+        value: "abcd".to_string(),
+    });
+    assert_eq!(*doubled_schema_length(&db, schema_id), 20);
+    let calls = db
+        .get_storage()
+        .profile()
+        .calls
+        .iter()
+        .map(|call| (call.name, call.did_recalculate))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        calls,
+        vec![("doubled_schema_length", DidRecalculate::ReusedMemoizedValue)]
+    );
+
+    // Derived nodes that depend on a low durability source are re-verified as usual
+    assert_eq!(*total_length(&db, schema_id, open_file_id), 14);
+    assert_eq!(SCHEMA_LENGTH_COUNTER.load(Ordering::SeqCst), 1);
+    assert_eq!(TOTAL_LENGTH_COUNTER.load(Ordering::SeqCst), 2);
+
+    // If a high durability source changes, every derived node that depends on it
+    // is re-verified
+    db.set(Schema {
+        key: "schema",
+        value: "type Mutation".to_string(),
+    });
+    assert_eq!(*doubled_schema_length(&db, schema_id), 26);
+    assert_eq!(*total_length(&db, schema_id, open_file_id), 17);
+    assert_eq!(SCHEMA_LENGTH_COUNTER.load(Ordering::SeqCst), 2);
+    assert_eq!(TOTAL_LENGTH_COUNTER.load(Ordering::SeqCst), 3);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+#[durability(high)]
+struct Schema {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct OpenFile {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[memo]
+fn schema_length(db: &TestDatabase, schema_id: SourceId<Schema>) -> usize {
+    SCHEMA_LENGTH_COUNTER.fetch_add(1, Ordering::SeqCst);
+    db.get(schema_id).value.len()
+}
+
+#[memo]
+fn doubled_schema_length(db: &TestDatabase, schema_id: SourceId<Schema>) -> usize {
+    *schema_length(db, schema_id) * 2
+}
+
+#[memo]
+fn total_length(
+    db: &TestDatabase,
+    schema_id: SourceId<Schema>,
+    open_file_id: SourceId<OpenFile>,
+) -> usize {
+    TOTAL_LENGTH_COUNTER.fetch_add(1, Ordering::SeqCst);
+    *schema_length(db, schema_id) + db.get(open_file_id).value.len()
+}
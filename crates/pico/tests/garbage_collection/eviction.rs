@@ -0,0 +1,115 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pico::{Database, EvictionMetrics, SourceId, Storage, retain};
+use pico_macros::{Db, Source, memo};
+
+static FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static CAPITALIZED_FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+/// When more derived nodes than the maximum are retained, the least recently
+/// verified derived nodes are evicted.
+#[test]
+fn eviction() {
+    let mut db = TestDatabase::default();
+    db.get_storage()
+        .set_max_derived_node_count(Some(2.try_into().unwrap()));
+
+    let input_a = db.set(Input {
+        key: "a",
+        value: "asdf".to_string(),
+    });
+    let input_b = db.set(Input {
+        key: "b",
+        value: "qwer".to_string(),
+    });
+    db.set(Input {
+        key: "c",
+        value: "zxcv".to_string(),
+    });
+
+    assert_eq!(*capitalized_first_letter(&db, input_a), 'A');
+
+    // Increment the epoch, so that the derived nodes of input_b are verified
+    // more recently than those of input_a
+    db.set(Input {
+        key: "c",
+        value: "hjkl".to_string(),
+    });
+    assert_eq!(*capitalized_first_letter(&db, input_b), 'Q');
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+    assert_eq!(CAPITALIZED_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+
+    // Four derived nodes are retained, so the two derived nodes of input_a are evicted
+    db.run_garbage_collection();
+    assert_eq!(
+        db.get_storage().eviction_metrics(),
+        EvictionMetrics {
+            eviction_count: 1,
+            evicted_derived_node_count: 2,
+        }
+    );
+
+    assert_eq!(*capitalized_first_letter(&db, input_b), 'Q');
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+    assert_eq!(CAPITALIZED_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+
+    assert_eq!(*capitalized_first_letter(&db, input_a), 'A');
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 3);
+    assert_eq!(CAPITALIZED_FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 3);
+}
+
+/// Derived nodes that are reachable from a permanently retained call are never
+/// evicted, even if that means more than the maximum are kept.
+#[test]
+fn permanently_retained_derived_nodes_are_not_evicted() {
+    let mut db = TestDatabase::default();
+    db.get_storage()
+        .set_max_derived_node_count(Some(1.try_into().unwrap()));
+
+    let input_a = db.set(Input {
+        key: "a",
+        value: "asdf".to_string(),
+    });
+    retain(&db, retained_uppercase(&db, input_a)).never_garbage_collect();
+
+    db.run_garbage_collection();
+    assert_eq!(
+        db.get_storage().eviction_metrics(),
+        EvictionMetrics::default()
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct Input {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[memo]
+fn first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let input = db.get(input_id);
+    input.value.chars().next().unwrap()
+}
+
+#[memo]
+fn capitalized_first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    CAPITALIZED_FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst);
+    first_letter(db, input_id).to_ascii_uppercase()
+}
+
+#[memo]
+fn uppercase(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    db.get(input_id).value.to_uppercase()
+}
+
+#[memo(raw)]
+fn retained_uppercase(db: &TestDatabase, input_id: SourceId<Input>) -> String {
+    uppercase(db, input_id).clone()
+}
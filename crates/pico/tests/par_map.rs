@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pico::{Database, SourceId, Storage};
+use pico_macros::{Db, Source, memo};
+
+static FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static FIRST_LETTERS_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn par_map() {
+    let mut db = TestDatabase::default();
+
+    let input_ids = ["asdf", "qwer", "zxcv", "hjkl"]
+        .into_iter()
+        .enumerate()
+        .map(|(key, value)| {
+            db.set(Input {
+                key,
+                value: value.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // The results are in the same order as the items
+    assert_eq!(*first_letters(&db, input_ids.clone()), "aqzh");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 4);
+    assert_eq!(FIRST_LETTERS_COUNTER.load(Ordering::SeqCst), 1);
+
+    // The memoized functions called in parallel are reused
+    let letters = db.par_map(input_ids.clone(), |db, input_id| {
+        *first_letter(db, input_id)
+    });
+    assert_eq!(letters, vec!['a', 'q', 'z', 'h']);
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 4);
+
+    // The dependencies of the calls made in parallel are registered in the parent
+    db.set(Input {
+        key: 2,
+        value: "bnm".to_string(),
+    });
+    assert_eq!(*first_letters(&db, input_ids.clone()), "aqbh");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 5);
+    assert_eq!(FIRST_LETTERS_COUNTER.load(Ordering::SeqCst), 2);
+
+    // A change that does not affect any call made in parallel does not cause the parent
+    // to be re-executed
+    db.set(Input {
+        key: 3,
+        value: "hello".to_string(),
+    });
+    assert_eq!(*first_letters(&db, input_ids), "aqbh");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 6);
+    assert_eq!(FIRST_LETTERS_COUNTER.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn cycles_through_par_map_are_detected() {
+    let db = TestDatabase::default();
+
+    // calls_itself_in_parallel calls itself on another thread, which returns the cycle result
+    assert_eq!(*calls_itself_in_parallel(&db, 1), 0);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct Input {
+    #[key]
+    pub key: usize,
+    pub value: String,
+}
+
+#[memo]
+fn first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let input = db.get(input_id);
+    input.value.chars().next().unwrap()
+}
+
+#[memo]
+fn first_letters(db: &TestDatabase, input_ids: Vec<SourceId<Input>>) -> String {
+    FIRST_LETTERS_COUNTER.fetch_add(1, Ordering::SeqCst);
+    db.par_map(input_ids, |db, input_id| *first_letter(db, input_id))
+        .into_iter()
+        .collect()
+}
+
+#[memo(cycle_result = calls_itself_in_parallel_cycle_result)]
+fn calls_itself_in_parallel(db: &TestDatabase, value: usize) -> usize {
+    db.par_map([value], |db, value| *calls_itself_in_parallel(db, value))
+        .into_iter()
+        .sum::<usize>()
+        + 1
+}
+
+fn calls_itself_in_parallel_cycle_result(_db: &TestDatabase, _value: usize) -> usize {
+    0
+}
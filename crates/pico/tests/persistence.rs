@@ -0,0 +1,275 @@
+use std::{
+    fs,
+    process::Command,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use intern::{
+    Lookup,
+    string_key::{Intern, StringKey},
+};
+use pico::{Database, LoadPersistedValuesError, SourceId, Storage};
+use pico_macros::{Db, Singleton, Source, memo};
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+static FIRST_LETTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static UPPERCASE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static SUFFIXED_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static CONTENT_HASH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static GREETING_COUNTER: AtomicUsize = AtomicUsize::new(0);
+static SHOUTED_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The path to which `persist_values_in_child_process` writes the persisted values.
+const PERSISTED_VALUES_PATH: &str = "PICO_TEST_PERSISTED_VALUES_PATH";
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn persisted_values_are_reused_if_sources_are_unchanged() {
+    let mut db = TestDatabase::default();
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    assert_eq!(*first_letter(&db, input_id), 'a');
+    assert_eq!(*uppercase(&db, "qwer".to_string()), "QWER");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 1);
+    assert_eq!(UPPERCASE_COUNTER.load(Ordering::SeqCst), 1);
+    let bytes = db.get_storage().serialize_persisted_values("1");
+
+    // An unchanged source, so the persisted values are reused
+    let mut db = TestDatabase::default();
+    db.get_storage()
+        .load_persisted_values(&bytes, "1")
+        .expect("Expected persisted values to load");
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    assert_eq!(*first_letter(&db, input_id), 'a');
+    assert_eq!(*uppercase(&db, "qwer".to_string()), "QWER");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 1);
+    assert_eq!(UPPERCASE_COUNTER.load(Ordering::SeqCst), 1);
+
+    // A reused value is invalidated like any other value
+    db.set(Input {
+        key: "key",
+        value: "zxcv".to_string(),
+    });
+    assert_eq!(*first_letter(&db, input_id), 'z');
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 2);
+
+    // A changed source, so the persisted value that depends on it is not reused
+    let mut db = TestDatabase::default();
+    let input_id = db.set(Input {
+        key: "key",
+        value: "hjkl".to_string(),
+    });
+    db.get_storage()
+        .load_persisted_values(&bytes, "1")
+        .expect("Expected persisted values to load");
+    assert_eq!(*first_letter(&db, input_id), 'h');
+    assert_eq!(*uppercase(&db, "qwer".to_string()), "QWER");
+    assert_eq!(FIRST_LETTER_COUNTER.load(Ordering::SeqCst), 3);
+    assert_eq!(UPPERCASE_COUNTER.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn values_that_depend_on_sources_without_content_hashes_are_not_persisted() {
+    let mut db = TestDatabase::default();
+    db.set(Suffix {
+        value: "!".to_string(),
+    });
+    assert_eq!(*suffixed(&db, "asdf".to_string()), "asdf!");
+    assert_eq!(SUFFIXED_COUNTER.load(Ordering::SeqCst), 1);
+    let bytes = db.get_storage().serialize_persisted_values("1");
+
+    let mut db = TestDatabase::default();
+    db.get_storage()
+        .load_persisted_values(&bytes, "1")
+        .expect("Expected persisted values to load");
+    db.set(Suffix {
+        value: "!".to_string(),
+    });
+    assert_eq!(*suffixed(&db, "asdf".to_string()), "asdf!");
+    assert_eq!(SUFFIXED_COUNTER.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn persisted_values_from_another_version_are_not_loaded() {
+    let db = TestDatabase::default();
+    let bytes = db.get_storage().serialize_persisted_values("1");
+
+    assert!(matches!(
+        db.get_storage().load_persisted_values(&bytes, "2"),
+        Err(LoadPersistedValuesError::VersionMismatch { .. })
+    ));
+}
+
+#[test]
+fn sources_are_only_hashed_when_values_are_persisted() {
+    let mut db = TestDatabase::default();
+    let input_id = db.set(HashCountingInput {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    db.set(HashCountingInput {
+        key: "key",
+        value: "qwer".to_string(),
+    });
+    assert_eq!(*last_letter(&db, input_id), 'r');
+    assert_eq!(CONTENT_HASH_COUNTER.load(Ordering::SeqCst), 0);
+
+    db.get_storage().serialize_persisted_values("1");
+    assert_eq!(CONTENT_HASH_COUNTER.load(Ordering::SeqCst), 1);
+
+    // The content hash of an unchanged source is only computed once
+    db.get_storage().serialize_persisted_values("1");
+    assert_eq!(CONTENT_HASH_COUNTER.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn persisted_values_are_reused_by_a_freshly_interned_process() {
+    // Interned ids are only stable within a process, so persist the values in
+    // a separate process, which interns the same strings in a different order.
+    let path = std::env::temp_dir().join(format!("pico_persisted_values_{}", std::process::id()));
+    let output = Command::new(std::env::current_exe().expect("Expected current exe"))
+        .args(["persist_values_in_child_process", "--exact", "--ignored"])
+        .env(PERSISTED_VALUES_PATH, &path)
+        .output()
+        .expect("Expected child process to run");
+    assert!(
+        output.status.success(),
+        "Child process failed:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let bytes = fs::read(&path).expect("Expected persisted values to be written");
+    fs::remove_file(&path).expect("Expected persisted values to be removed");
+
+    let mut db = TestDatabase::default();
+    db.get_storage()
+        .load_persisted_values(&bytes, "1")
+        .expect("Expected persisted values to load");
+    let dog_id = db.set(NamedInput {
+        name: "dog".intern(),
+        value: "woof".to_string(),
+    });
+    let cat_id = db.set(NamedInput {
+        name: "cat".intern(),
+        value: "meow".to_string(),
+    });
+    assert_eq!(*greeting(&db, dog_id), "dog says woof");
+    assert_eq!(*greeting(&db, cat_id), "cat says meow");
+    assert_eq!(*shouted(&db, "dog".intern()), "DOG");
+    assert_eq!(*shouted(&db, "cat".intern()), "CAT");
+    assert_eq!(GREETING_COUNTER.load(Ordering::SeqCst), 0);
+    assert_eq!(SHOUTED_COUNTER.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+#[ignore = "Run in a child process by persisted_values_are_reused_by_a_freshly_interned_process"]
+fn persist_values_in_child_process() {
+    let Some(path) = std::env::var_os(PERSISTED_VALUES_PATH) else {
+        return;
+    };
+
+    let mut db = TestDatabase::default();
+    let cat_id = db.set(NamedInput {
+        name: "cat".intern(),
+        value: "meow".to_string(),
+    });
+    let dog_id = db.set(NamedInput {
+        name: "dog".intern(),
+        value: "woof".to_string(),
+    });
+    assert_eq!(*greeting(&db, cat_id), "cat says meow");
+    assert_eq!(*greeting(&db, dog_id), "dog says woof");
+    assert_eq!(*shouted(&db, "cat".intern()), "CAT");
+    assert_eq!(*shouted(&db, "dog".intern()), "DOG");
+    fs::write(path, db.get_storage().serialize_persisted_values("1"))
+        .expect("Expected persisted values to be written");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Source)]
+#[source(persist)]
+struct Input {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+#[source(persist)]
+struct HashCountingInput {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+impl Serialize for HashCountingInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CONTENT_HASH_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let mut state = serializer.serialize_struct("HashCountingInput", 2)?;
+        state.serialize_field("key", self.key)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+/// Both the key of the source and its contents contain interned strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Source)]
+#[source(persist)]
+struct NamedInput {
+    #[key]
+    pub name: StringKey,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Singleton)]
+struct Suffix {
+    pub value: String,
+}
+
+#[memo(persist)]
+fn first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    FIRST_LETTER_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let input = db.get(input_id);
+    input.value.chars().next().unwrap()
+}
+
+#[memo(persist)]
+fn uppercase(_db: &TestDatabase, value: String) -> String {
+    UPPERCASE_COUNTER.fetch_add(1, Ordering::SeqCst);
+    value.to_uppercase()
+}
+
+#[memo(persist)]
+fn suffixed(db: &TestDatabase, value: String) -> String {
+    SUFFIXED_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let suffix = db
+        .get_singleton::<Suffix>()
+        .expect("Expected suffix to have been set");
+    format!("{value}{}", suffix.value)
+}
+
+#[memo(persist)]
+fn last_letter(db: &TestDatabase, input_id: SourceId<HashCountingInput>) -> char {
+    let input = db.get(input_id);
+    input.value.chars().last().unwrap()
+}
+
+#[memo(persist)]
+fn greeting(db: &TestDatabase, input_id: SourceId<NamedInput>) -> String {
+    GREETING_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let input = db.get(input_id);
+    format!("{} says {}", input.name, input.value)
+}
+
+#[memo(persist)]
+fn shouted(_db: &TestDatabase, name: StringKey) -> String {
+    SHOUTED_COUNTER.fetch_add(1, Ordering::SeqCst);
+    name.lookup().to_uppercase()
+}
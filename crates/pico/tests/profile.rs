@@ -0,0 +1,146 @@
+use pico::{Database, DidRecalculate, ProfiledDependency, SourceId, Storage};
+use pico_macros::{Db, Source, memo};
+
+#[derive(Db, Default)]
+struct TestDatabase {
+    storage: Storage<Self>,
+}
+
+#[test]
+fn profile() {
+    let mut db = TestDatabase::default();
+    db.get_storage().enable_profiling();
+
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'A');
+
+    // Calls are recorded in the order in which they complete
+    let profile = db.get_storage().profile();
+    let calls = profile
+        .calls
+        .iter()
+        .map(|call| (call.name, call.did_recalculate))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        calls,
+        vec![
+            ("first_letter", DidRecalculate::Recalculated),
+            ("first_letter_uppercase", DidRecalculate::Recalculated),
+        ]
+    );
+    let first_letter_call = profile.calls[0];
+    let first_letter_uppercase_call = profile.calls[1];
+    assert_eq!(
+        first_letter_call.parent,
+        Some(first_letter_uppercase_call.derived_node_id)
+    );
+    assert_eq!(first_letter_uppercase_call.parent, None);
+    assert!(first_letter_uppercase_call.duration >= first_letter_call.duration);
+
+    // The dependency edges are recorded
+    assert_eq!(
+        profile.nodes[&first_letter_uppercase_call.derived_node_id].dependencies,
+        vec![ProfiledDependency::Derived(
+            first_letter_call.derived_node_id
+        )]
+    );
+    assert!(matches!(
+        profile.nodes[&first_letter_call.derived_node_id].dependencies[..],
+        [ProfiledDependency::Source(_)]
+    ));
+
+    // Reused values are recorded
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'A');
+    let profile = db.get_storage().profile();
+    assert_eq!(profile.calls.len(), 3);
+    assert_eq!(
+        profile.calls[2].did_recalculate,
+        DidRecalculate::ReusedMemoizedValue
+    );
+
+    let dot = profile.to_dot();
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.contains("first_letter_uppercase\\n2 call(s)"));
+    assert!(dot.contains("n0 -> s0;"));
+    assert!(dot.contains("n1 -> n0;"));
+
+    let chrome_trace = serde_json::from_str::<serde_json::Value>(&profile.to_chrome_trace())
+        .expect("Expected chrome trace to be valid JSON");
+    assert_eq!(chrome_trace["traceEvents"].as_array().unwrap().len(), 3);
+    assert_eq!(chrome_trace["traceEvents"][0]["name"], "first_letter");
+    assert_eq!(chrome_trace["traceEvents"][0]["cat"], "recalculated");
+
+    // Only the calls made since a source last changed are recorded
+    db.set(Input {
+        key: "key",
+        value: "qwer".to_string(),
+    });
+    assert!(db.get_storage().profile().calls.is_empty());
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'Q');
+    let calls = db
+        .get_storage()
+        .profile()
+        .calls
+        .iter()
+        .map(|call| (call.name, call.did_recalculate))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        calls,
+        vec![
+            // While verifying first_letter_uppercase's dependencies
+            ("first_letter", DidRecalculate::Recalculated),
+            // While re-executing first_letter_uppercase
+            ("first_letter", DidRecalculate::ReusedMemoizedValue),
+            ("first_letter_uppercase", DidRecalculate::Recalculated),
+        ]
+    );
+}
+
+#[test]
+fn calls_are_not_recorded_unless_profiling_is_enabled() {
+    let mut db = TestDatabase::default();
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'A');
+    assert!(db.get_storage().profile().calls.is_empty());
+}
+
+#[test]
+fn calls_are_not_recorded_once_profiling_is_disabled() {
+    let mut db = TestDatabase::default();
+    db.get_storage().enable_profiling();
+    let input_id = db.set(Input {
+        key: "key",
+        value: "asdf".to_string(),
+    });
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'A');
+    assert_eq!(db.get_storage().profile().calls.len(), 2);
+
+    db.get_storage().disable_profiling();
+    assert!(!db.get_storage().is_profiling_enabled());
+    assert_eq!(*first_letter_uppercase(&db, input_id), 'A');
+    assert_eq!(db.get_storage().profile().calls.len(), 2);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Source)]
+struct Input {
+    #[key]
+    pub key: &'static str,
+    pub value: String,
+}
+
+#[memo]
+fn first_letter(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    let input = db.get(input_id);
+    input.value.chars().next().unwrap()
+}
+
+#[memo]
+fn first_letter_uppercase(db: &TestDatabase, input_id: SourceId<Input>) -> char {
+    first_letter(db, input_id).to_ascii_uppercase()
+}
@@ -1,5 +1,6 @@
 mod garbage_collection {
     mod basic_gc;
+    mod eviction;
     mod inner_retained;
     mod multiple_calls;
     mod outer_retained;
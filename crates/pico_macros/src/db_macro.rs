@@ -1,12 +1,18 @@
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Attribute, ItemStruct, Type, Visibility, parse_macro_input, spanned::Spanned};
+use syn::{
+    Attribute, Ident, ItemStruct, Meta, Token, Type, Visibility, parse::ParseStream,
+    parse_macro_input, spanned::Spanned,
+};
+
+use crate::source_macro::durability_variant;
 
 pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
     struct TrackedField {
         field_ident: syn::Ident,
         field_ty: Type,
+        durability: Option<Ident>,
     }
 
     let input = parse_macro_input!(item as ItemStruct);
@@ -28,7 +34,7 @@ pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
 
     for f in fields.named.iter() {
         let field_ident = f.ident.as_ref().unwrap();
-        if has_attr(&f.attrs, "tracked") {
+        if let Some(tracked_attr) = f.attrs.iter().find(|attr| attr.path().is_ident("tracked")) {
             if !matches!(f.vis, Visibility::Inherited) {
                 errors.push(
                     syn::Error::new(
@@ -38,9 +44,17 @@ pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
                     .to_compile_error(),
                 );
             }
+            let durability = match parse_tracked_durability(tracked_attr) {
+                Ok(durability) => durability,
+                Err(err) => {
+                    errors.push(err.to_compile_error());
+                    None
+                }
+            };
             tracked.push(TrackedField {
                 field_ident: field_ident.clone(),
                 field_ty: f.ty.clone(),
+                durability,
             });
         }
     }
@@ -55,6 +69,7 @@ pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
     for TrackedField {
         field_ident,
         field_ty,
+        durability,
     } in tracked
     {
         let counter_ident =
@@ -70,8 +85,11 @@ pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
         let get_ident = format_ident!("get_{field_ident}");
         let get_ident_mut = format_ident!("get_{field_ident}_mut");
 
+        let durability_attr = durability.map(|durability| quote! { #[durability(#durability)] });
+
         aux_struct_defs.push(quote! {
             #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, ::pico_macros::Singleton)]
+            #durability_attr
             pub struct #counter_ident(u64);
             impl ::pico::Counter for #counter_ident {
                 #[inline]
@@ -161,6 +179,23 @@ pub(crate) fn db_macro(item: TokenStream) -> TokenStream {
     output.into()
 }
 
-fn has_attr(attrs: &[Attribute], name: &str) -> bool {
-    attrs.iter().any(|a| a.path().is_ident(name))
+/// Parses `#[tracked]` or `#[tracked(durability = high)]`. The durability
+/// applies to the counter that is incremented whenever the field is mutated.
+fn parse_tracked_durability(attr: &Attribute) -> syn::Result<Option<Ident>> {
+    if !matches!(attr.meta, Meta::List(_)) {
+        return Ok(None);
+    }
+    attr.parse_args_with(|input: ParseStream| {
+        let name = input.parse::<Ident>()?;
+        if name != "durability" {
+            return Err(syn::Error::new_spanned(
+                name,
+                "expected `#[tracked]` or `#[tracked(durability = ...)]`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let durability = input.parse::<Ident>()?;
+        durability_variant(&durability)?;
+        Ok(Some(durability))
+    })
 }
@@ -12,12 +12,12 @@ pub fn memo(args: TokenStream, input: TokenStream) -> TokenStream {
     memo_macro::memo_macro(args, input)
 }
 
-#[proc_macro_derive(Source, attributes(key))]
+#[proc_macro_derive(Source, attributes(key, durability, source))]
 pub fn source(input: TokenStream) -> TokenStream {
     source_macro::source_macro(input)
 }
 
-#[proc_macro_derive(Singleton)]
+#[proc_macro_derive(Singleton, attributes(durability))]
 pub fn singleton(input: TokenStream) -> TokenStream {
     singleton_macro::singleton_macro(input)
 }
@@ -4,8 +4,8 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{ToTokens, quote};
 use syn::{
-    Error, FnArg, GenericParam, ItemFn, Lifetime, LifetimeParam, PatType, ReturnType, Signature,
-    parse_macro_input, parse_quote,
+    Error, FnArg, GenericParam, ItemFn, Lifetime, LifetimeParam, Pat, PatType, ReturnType,
+    Signature, parse_macro_input, parse_quote,
 };
 
 #[derive(Default, deluxe::ParseMetaItem)]
@@ -13,6 +13,17 @@ use syn::{
 struct LegacyMemoArgs {
     #[deluxe(default)]
     raw: bool,
+    /// Whether the values of this function are persisted by
+    /// `Storage::serialize_persisted_values`. The return type must implement
+    /// `Serialize` and `DeserializeOwned`.
+    #[deluxe(default)]
+    persist: bool,
+    /// A function that is called, with the same arguments as the memoized
+    /// function, to compute the value that is returned if the memoized function
+    /// participates in a cycle (i.e. it transitively calls itself with the
+    /// same arguments). Without this, such cycles panic.
+    #[deluxe(default)]
+    cycle_result: Option<syn::Path>,
 }
 
 pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
@@ -23,7 +34,11 @@ pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
         attrs,
     } = parse_macro_input!(item as ItemFn);
 
-    let LegacyMemoArgs { raw } = match deluxe::parse::<LegacyMemoArgs>(args) {
+    let LegacyMemoArgs {
+        raw,
+        persist,
+        cycle_result,
+    } = match deluxe::parse::<LegacyMemoArgs>(args) {
         Ok(args) => args,
         Err(err) => return err.into_compile_error().into(),
     };
@@ -73,6 +88,20 @@ pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
 
+    if persist
+        && let Some((_, ty)) = args
+            .clone()
+            .find(|(_, ty)| matches!(ArgType::parse(ty), ArgType::MemoRef))
+    {
+        return Error::new_spanned(
+            ty,
+            "Memoized functions that accept a MemoRef cannot be persisted, \
+            because MemoRefs are not stable across processes",
+        )
+        .to_compile_error()
+        .into();
+    }
+
     let return_type = match &sig.output {
         ReturnType::Type(_, ty) => ty.clone(),
         ReturnType::Default => parse_quote!(()),
@@ -110,6 +139,7 @@ pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
 
     new_sig.output = ReturnType::Type(parse_quote!(->), Box::new(return_ty));
 
+    let args_for_persisted_key = args.clone();
     let extract_parameters = args
         .enumerate()
         .map(|(i, (arg, ty))| {
@@ -154,9 +184,96 @@ pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
                     }
                 }
             }
-        });
+        })
+        .collect::<Vec<_>>();
+
+    let inner_fn = quote! {
+        |#db_arg, derived_node_id| {
+            use ::pico::Database;
+            #(
+                #extract_parameters
+            )*
+            let value: #return_type = (|| #block)();
+            Some(Box::new(value))
+        }
+    };
 
     let fn_name = sig.ident.to_string();
+    let inner_fn = match cycle_result {
+        Some(cycle_result) => {
+            let arg_names = match sig
+                .inputs
+                .iter()
+                .skip(1)
+                .map(|arg| match arg {
+                    FnArg::Typed(PatType { pat, .. }) => match &**pat {
+                        Pat::Ident(pat_ident) => Ok(&pat_ident.ident),
+                        other => Err(Error::new_spanned(
+                            other,
+                            "Memoized functions with a cycle_result must bind each argument \
+                            to an identifier",
+                        )),
+                    },
+                    FnArg::Receiver(_) => unreachable!(),
+                })
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(arg_names) => arg_names,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            quote! {
+                ::pico::InnerFn::new_with_cycle_result(
+                    #fn_name,
+                    #inner_fn,
+                    |#db_arg, derived_node_id| {
+                        use ::pico::Database;
+                        #(
+                            #extract_parameters
+                        )*
+                        let value: #return_type = #cycle_result(#db_arg, #(#arg_names),*);
+                        Some(Box::new(value))
+                    },
+                )
+            }
+        }
+        None => quote!(::pico::InnerFn::new(#fn_name, #inner_fn)),
+    };
+
+    let (execute_memoized_function, persisted_key_fn) = if persist {
+        let persisted_params = args_for_persisted_key.enumerate().map(|(i, (_, ty))| {
+            match ArgType::parse(ty) {
+                ArgType::Source => quote! {
+                    ::pico::macro_fns::persisted_source_key(#db_arg, derived_node_id.params[#i])?
+                },
+                ArgType::MemoRef => unreachable!(),
+                ArgType::Other => {
+                    let target_type = match **ty {
+                        syn::Type::Reference(ref reference) => &reference.elem,
+                        _ => ty,
+                    };
+                    quote! {
+                        ::pico::macro_fns::persisted_param_hash::<_, #target_type>(
+                            #db_arg,
+                            derived_node_id.params[#i],
+                        )?
+                    }
+                }
+            }
+        });
+        (
+            quote!(::pico::execute_persisted_memoized_function::<_, #return_type>),
+            quote! {
+                , |#db_arg, derived_node_id| {
+                    let persisted_params: ::std::vec::Vec<u64> =
+                        ::std::vec![#(#persisted_params),*];
+                    ::pico::macro_fns::persisted_hash(&(#fn_hash, persisted_params))
+                }
+            },
+        )
+    } else {
+        (quote!(::pico::execute_memoized_function), quote!())
+    };
+
     let output = quote! {
         #(#attrs)*
         #vis #new_sig {
@@ -166,17 +283,11 @@ pub(crate) fn memo_macro(args: TokenStream, item: TokenStream) -> TokenStream {
                 #param_ids_blocks
             )*
             let derived_node_id = ::pico::DerivedNodeId::new(#fn_hash.into(), param_ids);
-            let did_recalculate = ::pico::execute_memoized_function(
+            let did_recalculate = #execute_memoized_function(
                 #db_arg,
                 derived_node_id,
-                ::pico::InnerFn::new(|#db_arg, derived_node_id| {
-                    use ::pico::Database;
-                    #(
-                        #extract_parameters
-                    )*
-                    let value: #return_type = (|| #block)();
-                    Some(Box::new(value))
-                })
+                #inner_fn
+                #persisted_key_fn
             );
             debug_assert!(
                 !matches!(did_recalculate, pico::DidRecalculate::Error),
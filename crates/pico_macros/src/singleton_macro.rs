@@ -2,10 +2,17 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, parse_macro_input};
 
+use crate::source_macro::get_durability_fn;
+
 pub(crate) fn singleton_macro(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
     let struct_name = input.ident.clone();
 
+    let get_durability = match get_durability_fn(&input.attrs) {
+        Ok(get_durability) => get_durability,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let output = quote! {
         impl ::pico::Singleton for #struct_name {
             fn get_singleton_key() -> ::pico::Key {
@@ -20,6 +27,8 @@ pub(crate) fn singleton_macro(item: TokenStream) -> TokenStream {
             fn get_key(&self) -> ::pico::Key {
                 <#struct_name as ::pico::Singleton>::get_singleton_key()
             }
+
+            #get_durability
         }
     };
 
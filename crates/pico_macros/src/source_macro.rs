@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Error, Fields, parse_macro_input};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, parse_macro_input};
 
 pub(crate) fn source_macro(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
@@ -46,6 +46,16 @@ pub(crate) fn source_macro(item: TokenStream) -> TokenStream {
         }
     };
 
+    let get_durability = match get_durability_fn(&input.attrs) {
+        Ok(get_durability) => get_durability,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let persisted_fns = match persisted_fns(&input.attrs, &field_name) {
+        Ok(persisted_fns) => persisted_fns,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let output = quote! {
         impl ::pico::Source for #struct_name {
             fn get_key(&self) -> ::pico::Key {
@@ -55,8 +65,64 @@ pub(crate) fn source_macro(item: TokenStream) -> TokenStream {
                 self.#field_name.hash(&mut s);
                 s.finish().into()
             }
+
+            #persisted_fns
+
+            #get_durability
         }
     };
 
     output.into()
 }
+
+/// Generates `Source::get_content_hash` and `Source::get_persisted_key` from a
+/// `#[source(persist)]` attribute on the struct, which must then implement
+/// `Serialize`. Without the attribute, the default implementations are used, so
+/// values that depend on the source are not persisted.
+fn persisted_fns(attrs: &[Attribute], field_name: &Ident) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("source")) else {
+        return Ok(quote! {});
+    };
+    let arg = attr.parse_args::<Ident>()?;
+    if arg != "persist" {
+        return Err(Error::new_spanned(arg, "expected `persist`"));
+    }
+    Ok(quote! {
+        fn get_content_hash(&self) -> Option<u64> {
+            ::pico::macro_fns::persisted_hash(self)
+        }
+
+        fn get_persisted_key(&self) -> Option<u64> {
+            ::pico::macro_fns::persisted_hash(&(
+                ::core::any::type_name::<Self>(),
+                &self.#field_name,
+            ))
+        }
+    })
+}
+
+/// Generates `Source::get_durability` from a `#[durability(low)]` or
+/// `#[durability(high)]` attribute on the struct. Without the attribute, the
+/// default implementation (low durability) is used.
+pub(crate) fn get_durability_fn(attrs: &[Attribute]) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("durability")) else {
+        return Ok(quote! {});
+    };
+    let durability = durability_variant(&attr.parse_args::<Ident>()?)?;
+    Ok(quote! {
+        fn get_durability(&self) -> ::pico::Durability {
+            ::pico::Durability::#durability
+        }
+    })
+}
+
+pub(crate) fn durability_variant(durability: &Ident) -> syn::Result<Ident> {
+    match durability.to_string().as_str() {
+        "low" => Ok(Ident::new("Low", durability.span())),
+        "high" => Ok(Ident::new("High", durability.span())),
+        _ => Err(Error::new_spanned(
+            durability,
+            "expected durability to be `low` or `high`",
+        )),
+    }
+}
@@ -1 +1,156 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
+use common_lang_types::{CurrentWorkingDirectory, Diagnostic};
+use graphql_network_protocol::GraphQLAndJavascriptProfile;
+use intern::string_key::Intern;
+use isograph_compiler::CompilerState;
+use isograph_config::create_configs;
+use isograph_schema::{IsographDatabase, validate_entire_schema};
+
+/// A project (i.e. a config, a schema and files containing iso literals) that is
+/// copied from a fixture folder into a temporary folder and loaded like the compiler
+/// loads it. Creating the project creates the artifact directory, so we do not load
+/// it from the fixture folder directly.
+pub struct TestProject {
+    pub directory: PathBuf,
+    pub state: CompilerState<GraphQLAndJavascriptProfile>,
+}
+
+impl TestProject {
+    /// `fixture_directory` is either absolute or relative to the `tests` crate, and
+    /// must contain an `isograph.config.json` file.
+    pub fn new(fixture_directory: &str) -> Self {
+        static PROJECT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let directory = std::env::temp_dir().join(format!(
+            "isograph_test_project_{}_{}",
+            std::process::id(),
+            PROJECT_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        copy_directory(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join(fixture_directory),
+            &directory,
+        );
+        let directory = directory
+            .canonicalize()
+            .expect("Expected project directory to exist");
+
+        let current_working_directory: CurrentWorkingDirectory =
+            directory.to_string_lossy().intern().into();
+        let config = create_configs(
+            &directory.join("isograph.config.json"),
+            current_working_directory,
+        )
+        .into_iter()
+        .next()
+        .expect("Expected config to contain a project");
+        let state = CompilerState::new(config, current_working_directory)
+            .unwrap_or_else(|e| panic!("Expected project to be loaded. Error: {e}"));
+
+        Self { directory, state }
+    }
+
+    pub fn db(&self) -> &IsographDatabase<GraphQLAndJavascriptProfile> {
+        &self.state.db
+    }
+
+    /// The messages of the diagnostics reported when validating the project
+    pub fn diagnostic_messages(&self) -> Vec<String> {
+        self.diagnostics()
+            .into_iter()
+            .map(|diagnostic| diagnostic.0.message)
+            .collect()
+    }
+
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        validate_entire_schema(self.db())
+            .clone()
+            .err()
+            .unwrap_or_default()
+    }
+
+    /// The generated artifacts, keyed by their path within the artifact directory,
+    /// e.g. `Query/HomePage/query_text.ts`.
+    pub fn artifacts(&self) -> BTreeMap<String, String> {
+        let (artifacts, _) = artifact_content::get_artifact_path_and_content(self.db())
+            .unwrap_or_else(|diagnostics| {
+                panic!(
+                    "Expected project to compile. Errors: {:?}",
+                    diagnostics
+                        .into_iter()
+                        .map(|diagnostic| diagnostic.0.message)
+                        .collect::<Vec<_>>()
+                )
+            });
+
+        artifacts
+            .into_iter()
+            .map(|artifact| {
+                let path = match artifact.artifact_path.type_and_field {
+                    Some(type_and_field) => format!(
+                        "{}/{}/{}",
+                        type_and_field.parent_entity_name,
+                        type_and_field.selectable_name,
+                        artifact.artifact_path.file_name
+                    ),
+                    None => artifact.artifact_path.file_name.to_string(),
+                };
+                (path, artifact.file_content.0)
+            })
+            .collect()
+    }
+
+    /// The absolute path of a file in the project, e.g. `src/HomePage.ts`
+    pub fn path(&self, relative_path: &str) -> PathBuf {
+        self.directory.join(relative_path)
+    }
+
+    /// The zero-based line and character of the first occurrence of `needle` in a
+    /// file in the project, which is how editors refer to positions.
+    pub fn line_and_character(&self, relative_path: &str, needle: &str) -> (u32, u32) {
+        let content = std::fs::read_to_string(self.path(relative_path))
+            .unwrap_or_else(|_| panic!("Expected {relative_path} to be readable"));
+        let offset = content
+            .find(needle)
+            .unwrap_or_else(|| panic!("Expected {relative_path} to contain {needle}"));
+        let before = &content[..offset];
+        let line = before.matches('\n').count();
+        let character = before.len() - before.rfind('\n').map_or(0, |index| index + 1);
+        (line as u32, character as u32)
+    }
+
+    /// The content of a single generated artifact
+    pub fn artifact(&self, path: &str) -> String {
+        self.artifacts()
+            .remove(path)
+            .unwrap_or_else(|| panic!("Expected artifact {path} to be generated"))
+    }
+}
+
+impl Drop for TestProject {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.directory);
+    }
+}
+
+fn copy_directory(source: &Path, target: &Path) {
+    std::fs::create_dir_all(target).expect("Expected to be able to create directory");
+    for entry in std::fs::read_dir(source)
+        .unwrap_or_else(|_| panic!("Expected {source:?} to be a readable directory"))
+    {
+        let path = entry
+            .expect("Expected directory entry to be readable")
+            .path();
+        let target_path = target.join(path.file_name().expect("Expected file name"));
+        if path.is_dir() {
+            copy_directory(&path, &target_path);
+        } else {
+            std::fs::copy(&path, &target_path)
+                .unwrap_or_else(|_| panic!("Expected {path:?} to be copied"));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use tests::TestProject;
+
+#[test]
+fn test_custom_scalar_types_are_imported_into_param_types() {
+    let project = TestProject::new("tests/fixtures/custom_scalars");
+
+    // The description of `price` mentions `JsonValue`, but no field of type `Json`
+    // is selected, so it is not imported.
+    let param_type = project.artifact("Product/ProductPrice/param_type.ts");
+    assert!(
+        param_type.starts_with(
+            "import type { Decimal } from '../../../types';\n\
+            import type { Product__ProductPrice__parameters } from './parameters_type';\n"
+        ),
+        "Unexpected param type:\n{param_type}"
+    );
+    assert!(param_type.contains("    readonly price: Decimal,\n"));
+
+    let param_type = project.artifact("Query/ProductPage/param_type.ts");
+    assert!(
+        param_type.contains("import type { JsonValue } from 'json-types';\n"),
+        "Unexpected param type:\n{param_type}"
+    );
+    assert!(!param_type.contains("Decimal"));
+}
+
+#[test]
+fn test_custom_scalar_types_are_imported_into_parameters_types() {
+    let project = TestProject::new("tests/fixtures/custom_scalars");
+
+    assert_eq!(
+        project.artifact("Product/ProductPrice/parameters_type.ts"),
+        "import type { Decimal } from '../../../types';\n\
+        export type Product__ProductPrice__parameters = {\n  \
+          readonly time: string,\n  \
+          readonly minimum?: Decimal | null | void,\n\
+        };\n"
+    );
+    assert_eq!(
+        project.artifact("Query/ProductPage/parameters_type.ts"),
+        "export type Query__ProductPage__parameters = {\n  \
+          readonly id: string,\n  \
+          readonly time: string,\n\
+        };\n"
+    );
+}
+
+#[test]
+fn test_custom_scalar_types_are_imported_into_raw_response_types() {
+    let project = TestProject::new("tests/fixtures/custom_scalars");
+
+    let raw_response_type = project.artifact("Query/ProductPage/raw_response_type.ts");
+    assert!(
+        raw_response_type.starts_with(
+            "import type { Decimal } from '../../../types';\n\
+            import type { JsonValue } from 'json-types';\n\
+            export type Query__ProductPage__raw_response_type = {\n"
+        ),
+        "Unexpected raw response type:\n{raw_response_type}"
+    );
+}
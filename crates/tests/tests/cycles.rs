@@ -0,0 +1,26 @@
+use common_lang_types::{EmbeddedLocation, Location, Span};
+use tests::TestProject;
+
+#[test]
+fn test_client_fields_that_select_each_other_are_rejected() {
+    let project = TestProject::new("tests/fixtures/cycles");
+    let diagnostics = project.diagnostics();
+
+    assert_eq!(
+        project.diagnostic_messages(),
+        vec![
+            "`Pet.A` could not be processed, because it (transitively) depends on itself."
+                .to_string(),
+            "`Pet.B` could not be processed, because it (transitively) depends on itself."
+                .to_string(),
+        ]
+    );
+
+    // Each diagnostic points at the name of the client field in its iso literal
+    for diagnostic in diagnostics.iter() {
+        let Some(Location::Embedded(EmbeddedLocation { span, .. })) = diagnostic.location() else {
+            panic!("Expected {diagnostic:?} to have an embedded location");
+        };
+        assert_eq!(span, Span::new(13, 14));
+    }
+}
@@ -0,0 +1,49 @@
+use tests::TestProject;
+
+#[test]
+fn test_deferred_selection_in_query_text() {
+    let project = TestProject::new("tests/fixtures/defer/valid");
+    let query_text = project.artifact("Query/HomePage/query_text.ts");
+
+    assert!(
+        query_text.contains(
+            "    ... @defer(label: \"pets\") {\\\n      pets {\\\n        id,\\\n        name,\\\n      },\\\n    },\\\n"
+        ),
+        "Unexpected query text:\n{query_text}"
+    );
+}
+
+#[test]
+fn test_deferred_selection_in_normalization_ast() {
+    let project = TestProject::new("tests/fixtures/defer/valid");
+    let normalization_ast = project.artifact("Query/HomePage/normalization_ast.ts");
+
+    let (eager_selections, deferred_selections) = normalization_ast
+        .split_once("  deferredSelections: {\n")
+        .expect("Expected the normalization AST to contain deferred selections");
+    assert!(
+        !eager_selections.contains("fieldName: \"pets\""),
+        "Deferred selections should not be normalized eagerly:\n{normalization_ast}"
+    );
+    assert!(
+        deferred_selections.starts_with("    \"pets\": [\n      {\n        kind: \"Linked\",\n"),
+        "Unexpected deferred selections:\n{normalization_ast}"
+    );
+    assert!(deferred_selections.contains("fieldName: \"pets\""));
+}
+
+#[test]
+fn test_duplicate_defer_labels_are_rejected() {
+    let project = TestProject::new("tests/fixtures/defer/duplicate_labels");
+    let diagnostics = project.diagnostics();
+
+    assert_eq!(
+        project.diagnostic_messages(),
+        vec![
+            "The label \"pets\" is used by multiple @defer directives in the `Query.HomePage` \
+             entrypoint. Labels must be unique within an entrypoint."
+                .to_string()
+        ]
+    );
+    assert!(diagnostics[0].location().is_some());
+}
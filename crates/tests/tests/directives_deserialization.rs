@@ -1,19 +1,27 @@
 use common_lang_types::{
-    Diagnostic, Location, PrintLocationFn, SelectableName, StringLiteralValue, TextSource,
+    Diagnostic, EmbeddedLocation, Location, PrintLocationFn, SelectableName, StringLiteralValue,
+    TextSource, WithEmbeddedLocation,
 };
 use graphql_lang_types::{GraphQLConstantValue, GraphQLDirective, from_graphql_directives};
 use graphql_lang_types::{GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionOrDefinition};
 use intern::string_key::Intern;
+use isograph_lang_types::{
+    IsographFieldDirective, NonConstantValue, SelectionFieldArgument,
+    from_isograph_field_directives,
+};
 use isograph_schema::ServerEntityDirectives;
 use isograph_schema::{ExposeFieldDirective, FieldMapItem, ID_FIELD_NAME};
 use prelude::Postfix;
+use serde::Deserialize;
 use std::error::Error;
 
 fn unwrap_directive(
     extension_or_definition: GraphQLTypeSystemExtensionOrDefinition,
 ) -> Result<Vec<GraphQLDirective<GraphQLConstantValue>>, Diagnostic> {
-    if let GraphQLTypeSystemExtensionOrDefinition::Extension(extension) = extension_or_definition {
-        let GraphQLTypeSystemExtension::ObjectTypeExtension(object_type_extension) = extension;
+    if let GraphQLTypeSystemExtensionOrDefinition::Extension(
+        GraphQLTypeSystemExtension::ObjectTypeExtension(object_type_extension),
+    ) = extension_or_definition
+    {
         return Ok(object_type_extension.directives.clone());
     }
     Diagnostic::new("unexpected structure of directive".to_string(), None).wrap_err()
@@ -173,3 +181,83 @@ fn print_diagnostic_for_test(e: Diagnostic) -> String {
 fn noop_printer<'a>() -> PrintLocationFn<'a> {
     (|_: Location, _: &mut std::fmt::Formatter<'_>| ().wrap_ok()).boxed()
 }
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Species {
+    Cat,
+    Dog,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct PetsDirective {
+    species: Species,
+    names: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct PetsDirectiveSet {
+    pets: PetsDirective,
+}
+
+fn generated<T>(item: T) -> WithEmbeddedLocation<T> {
+    WithEmbeddedLocation::new(item, EmbeddedLocation::todo_generated())
+}
+
+/// The directive `@pets(species: <species>, names: ["Fido", "Rex"])`
+fn pets_directive(
+    species: NonConstantValue,
+) -> WithEmbeddedLocation<Vec<WithEmbeddedLocation<IsographFieldDirective>>> {
+    let argument = |name: &str, value: NonConstantValue| {
+        generated(SelectionFieldArgument {
+            name: generated(name.intern().into()),
+            value: generated(value),
+        })
+    };
+    generated(vec![generated(IsographFieldDirective {
+        name: generated("pets".intern().into()),
+        arguments: vec![
+            argument("species", species),
+            argument(
+                "names",
+                NonConstantValue::List(vec![
+                    generated(NonConstantValue::String("Fido".intern().into())),
+                    generated(NonConstantValue::String("Rex".intern().into())),
+                ]),
+            ),
+        ],
+    })])
+}
+
+#[test]
+fn test_isograph_directive_with_enum_and_list_arguments() -> Result<(), Diagnostic> {
+    let directives = pets_directive(NonConstantValue::Enum("DOG".intern().into()));
+    let directive_set: PetsDirectiveSet = from_isograph_field_directives(&directives)?;
+
+    assert_eq!(
+        directive_set,
+        PetsDirectiveSet {
+            pets: PetsDirective {
+                species: Species::Dog,
+                names: vec!["Fido".to_string(), "Rex".to_string()],
+            },
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_isograph_directive_with_unknown_enum_value() {
+    let directives = pets_directive(NonConstantValue::Enum("FERRET".intern().into()));
+    let error = from_isograph_field_directives::<PetsDirectiveSet>(&directives)
+        .expect_err("Expected FERRET not to be a variant of Species");
+
+    assert!(
+        error
+            .0
+            .message
+            .contains("unknown variant `FERRET`, expected `CAT` or `DOG`"),
+        "Unexpected error: {}",
+        error.0.message
+    );
+}
@@ -0,0 +1,47 @@
+use common_lang_types::{EmbeddedLocation, Location};
+use tests::TestProject;
+
+#[test]
+fn test_enum_literals_are_validated_against_their_enum() {
+    let project = TestProject::new("tests/fixtures/enum_arguments");
+
+    // `ValidEnumLiteral` passes values of the expected enums, so it is not reported
+    let mut messages = project.diagnostic_messages();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            "Expected a value of enum `Species`, but `SMALL` is a value of enum `Size`.",
+            "Expected input of type (String | null), found CAT an enum literal",
+            "`DOGG` is not a value of enum `Species`. Did you mean `DOG`?",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_enum_literals_are_located() {
+    let project = TestProject::new("tests/fixtures/enum_arguments");
+    let content = std::fs::read_to_string(project.path("src/Pets.ts"))
+        .expect("Expected src/Pets.ts to be readable");
+
+    // Each diagnostic points at the enum literal within its iso literal
+    let mut located_text = project
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            let Some(Location::Embedded(EmbeddedLocation { text_source, span })) =
+                diagnostic.location()
+            else {
+                panic!("Expected {diagnostic:?} to have an embedded location");
+            };
+            let iso_literal_start = text_source
+                .span
+                .expect("Expected iso literal to have a span")
+                .start as usize;
+            let (start, end) = span.as_usize();
+            content[iso_literal_start + start..iso_literal_start + end].to_string()
+        })
+        .collect::<Vec<_>>();
+    located_text.sort();
+    assert_eq!(located_text, vec!["CAT", "DOGG", "SMALL"]);
+}
@@ -0,0 +1,60 @@
+use tests::TestProject;
+
+#[test]
+fn test_enum_types_are_generated() {
+    let project = TestProject::new("tests/fixtures/enums");
+    assert_eq!(project.diagnostic_messages(), Vec::<String>::new());
+
+    // Deprecated values can be read, so they are part of the output type, but they
+    // are marked as deprecated in the input type.
+    assert_eq!(
+        project.artifact("enums.ts"),
+        "export type Species =\n  \
+          | \"CAT\"\n  \
+          | \"DOG\"\n  \
+          | \"FERRET\"\n  \
+          | \"CANINE\"\n  \
+          | \"%future added value\";\n\
+        \n\
+        export type Species__input =\n  \
+          | \"CAT\"\n  \
+          /** @deprecated Use `CANINE` instead, see *\\/ docs. */\n  \
+          | \"DOG\"\n  \
+          /** @deprecated No longer supported */\n  \
+          | \"FERRET\"\n  \
+          | \"CANINE\";\n\
+        \n\
+        export type Unused =\n  \
+          | \"%future added value\";\n\
+        \n\
+        export type Unused__input = never;\n"
+    );
+}
+
+#[test]
+fn test_enum_types_are_imported_into_param_types() {
+    let project = TestProject::new("tests/fixtures/enums");
+
+    let param_type = project.artifact("Query/PetList/param_type.ts");
+    assert!(
+        param_type.starts_with(
+            "import type { Species } from '../../enums';\n\
+            import type { Query__PetList__parameters } from './parameters_type';\n"
+        ),
+        "Unexpected param type:\n{param_type}"
+    );
+    assert!(param_type.contains("      readonly species: Species,\n"));
+}
+
+#[test]
+fn test_enum_input_types_are_imported_into_parameters_types() {
+    let project = TestProject::new("tests/fixtures/enums");
+
+    assert_eq!(
+        project.artifact("Query/PetList/parameters_type.ts"),
+        "import type { Species__input } from '../../enums';\n\
+        export type Query__PetList__parameters = {\n  \
+          readonly species?: Species__input | null | void,\n\
+        };\n"
+    );
+}
@@ -0,0 +1,24 @@
+import { iso } from '@iso';
+
+export const ProductPrice = iso(`
+  field Product.ProductPrice($time: DateTime!, $minimum: Decimal) {
+    price
+    priceAt(time: $time, minimum: $minimum)
+  }
+`)(function ProductPrice({ data }) {
+  return data.priceAt ?? data.price;
+});
+
+export const ProductPage = iso(`
+  field Query.ProductPage($id: ID!, $time: DateTime!) {
+    product(id: $id) {
+      name
+      metadata
+      ProductPrice(time: $time)
+    }
+  }
+`)(function ProductPage({ data }) {
+  return data.product?.ProductPrice;
+});
+
+export const ProductPageEntrypoint = iso(`entrypoint Query.ProductPage`);
@@ -0,0 +1 @@
+export type Decimal = string;
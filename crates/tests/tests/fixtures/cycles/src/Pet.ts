@@ -0,0 +1,23 @@
+import { iso } from '@iso';
+
+export const PetA = iso(`
+  field Pet.A {
+    B
+  }
+`)(() => null);
+
+export const PetB = iso(`
+  field Pet.B {
+    A
+  }
+`)(() => null);
+
+export const HomePage = iso(`
+  field Query.HomePage {
+    pets {
+      A
+    }
+  }
+`)(() => null);
+
+export const HomePageEntrypoint = iso(`entrypoint Query.HomePage`);
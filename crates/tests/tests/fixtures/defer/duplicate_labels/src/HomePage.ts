@@ -0,0 +1,26 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage @component {
+    me {
+      PetNames
+      bestFriend {
+        PetNames
+      }
+    }
+  }
+`)(function HomePage({ data }) {
+  return data.me;
+});
+
+export const PetNames = iso(`
+  field User.PetNames {
+    pets @defer(label: "pets") {
+      name
+    }
+  }
+`)(function PetNames({ data }) {
+  return data.pets;
+});
+
+export const HomePageEntrypoint = iso(`entrypoint Query.HomePage`);
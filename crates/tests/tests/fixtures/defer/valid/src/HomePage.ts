@@ -0,0 +1,16 @@
+import { iso } from '@iso';
+
+export const HomePage = iso(`
+  field Query.HomePage @component {
+    me {
+      name
+      pets @defer(label: "pets") {
+        name
+      }
+    }
+  }
+`)(function HomePage({ data }) {
+  return data.me?.name;
+});
+
+export const HomePageEntrypoint = iso(`entrypoint Query.HomePage`);
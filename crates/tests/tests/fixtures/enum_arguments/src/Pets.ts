@@ -0,0 +1,44 @@
+import { iso } from '@iso';
+
+export const ValidEnumLiteral = iso(`
+  field Query.ValidEnumLiteral {
+    pets(species: CAT) {
+      name
+    }
+    petsBySize(size: LARGE) {
+      name
+    }
+  }
+`)(function ValidEnumLiteral({ data }) {
+  return data;
+});
+
+export const UnknownEnumLiteral = iso(`
+  field Query.UnknownEnumLiteral {
+    pets(species: DOGG) {
+      name
+    }
+  }
+`)(function UnknownEnumLiteral({ data }) {
+  return data;
+});
+
+export const EnumLiteralOfAnotherEnum = iso(`
+  field Query.EnumLiteralOfAnotherEnum {
+    pets(species: SMALL) {
+      name
+    }
+  }
+`)(function EnumLiteralOfAnotherEnum({ data }) {
+  return data;
+});
+
+export const EnumLiteralForString = iso(`
+  field Query.EnumLiteralForString {
+    petsByName(name: CAT) {
+      name
+    }
+  }
+`)(function EnumLiteralForString({ data }) {
+  return data;
+});
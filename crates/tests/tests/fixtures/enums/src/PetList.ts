@@ -0,0 +1,13 @@
+import { iso } from '@iso';
+
+export const PetList = iso(`
+  field Query.PetList($species: Species) {
+    pets(species: $species) {
+      species
+    }
+  }
+`)(function PetList({ data }) {
+  return data.pets.map((pet) => pet.species);
+});
+
+export const PetListEntrypoint = iso(`entrypoint Query.PetList`);
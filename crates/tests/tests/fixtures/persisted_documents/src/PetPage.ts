@@ -0,0 +1,14 @@
+import { iso } from '@iso';
+
+export const PetPage = iso(`
+  field Query.PetPage($id: ID!) {
+    pet(id: $id) {
+      name
+      __refetch
+    }
+  }
+`)(function PetPage({ data }) {
+  return data.pet?.name;
+});
+
+export const PetPageEntrypoint = iso(`entrypoint Query.PetPage`);
@@ -0,0 +1,24 @@
+import { iso } from '@iso';
+
+export const PetChanges = iso(`
+  field Subscription.PetChanges {
+    petAdded {
+      name
+    }
+    PetRemovedName
+  }
+`)(function PetChanges({ data }) {
+  return data;
+});
+
+export const PetRemovedName = iso(`
+  field Subscription.PetRemovedName {
+    petRemoved {
+      name
+    }
+  }
+`)(function PetRemovedName({ data }) {
+  return data.petRemoved.name;
+});
+
+export const PetChangesEntrypoint = iso(`entrypoint Subscription.PetChanges`);
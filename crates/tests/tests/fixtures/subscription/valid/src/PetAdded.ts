@@ -0,0 +1,13 @@
+import { iso } from '@iso';
+
+export const PetAdded = iso(`
+  field Subscription.PetAdded {
+    petAdded {
+      name
+    }
+  }
+`)(function PetAdded({ data }) {
+  return data.petAdded.name;
+});
+
+export const PetAddedEntrypoint = iso(`entrypoint Subscription.PetAdded`);
@@ -0,0 +1,106 @@
+use common_lang_types::{Diagnostic, Location, TextSource};
+use graphql_schema_parser::{parse_introspection_schema, parse_schema};
+use intern::string_key::Intern;
+use serde_json::Value;
+
+fn text_source() -> TextSource {
+    TextSource {
+        relative_path_to_source_file: "dummy".intern().into(),
+        span: None,
+    }
+}
+
+/// Replaces every `{ "item": ..., "location": ... }` with its item, so that documents
+/// parsed from different sources can be compared.
+fn without_locations(value: Value) -> Value {
+    match value {
+        Value::Object(mut object) => {
+            if object.len() == 2
+                && object.contains_key("location")
+                && let Some(item) = object.remove("item")
+            {
+                return without_locations(item);
+            }
+            Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, without_locations(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(without_locations).collect()),
+        value => value,
+    }
+}
+
+/// The message of the diagnostic and the source text at its location
+fn parse_error(source: &str) -> (String, String) {
+    let diagnostic = parse_introspection_schema(source, text_source())
+        .expect_err("Expected introspection result to be invalid");
+    let Some(Location::Embedded(location)) = diagnostic.0.location else {
+        panic!("Expected diagnostic to have a location");
+    };
+    let (start, end) = location.span.as_usize();
+    (diagnostic.0.message, source[start..end].to_string())
+}
+
+#[test]
+fn test_introspection_result_matches_sdl() -> Result<(), Diagnostic> {
+    let from_introspection = parse_introspection_schema(
+        include_str!("fixtures/introspection/schema.json"),
+        text_source(),
+    )?;
+    let from_sdl = parse_schema(
+        include_str!("fixtures/introspection/schema.graphql"),
+        text_source(),
+    )?;
+
+    assert_eq!(
+        without_locations(serde_json::to_value(from_introspection).unwrap()),
+        without_locations(serde_json::to_value(from_sdl).unwrap())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_malformed_json() {
+    let (message, text) = parse_error("{\"__schema\": {\"types\": [");
+    assert_eq!(
+        message,
+        "Invalid introspection result: EOF while parsing a list"
+    );
+    assert_eq!(text, "[");
+}
+
+#[test]
+fn test_invalid_type_kind() {
+    let (message, text) =
+        parse_error(r#"{"__schema": {"types": [{"kind": "OBJEKT", "name": "Query"}]}}"#);
+    assert_eq!(
+        message,
+        "Invalid introspection result: unknown variant `OBJEKT`, expected one of `SCALAR`, \
+        `OBJECT`, `INTERFACE`, `UNION`, `ENUM`, `INPUT_OBJECT`, `LIST`, `NON_NULL`"
+    );
+    // serde_json reports the end of the invalid string
+    assert_eq!(text, "\"");
+}
+
+#[test]
+fn test_list_without_of_type() {
+    let field = r#"{"name": "pets", "args": [], "type": {"kind": "LIST", "name": null}}"#;
+    let (message, text) = parse_error(&format!(
+        r#"{{"__schema": {{"types": [{{"kind": "OBJECT", "name": "Query", "fields": [{field}]}}]}}}}"#
+    ));
+    assert_eq!(message, "Expected the LIST type to have an `ofType`.");
+    assert_eq!(text, field);
+}
+
+#[test]
+fn test_missing_schema() {
+    let (message, text) = parse_error(r#"{"errors": []}"#);
+    assert_eq!(
+        message,
+        "Expected the introspection result to contain `data.__schema` or `__schema`."
+    );
+    assert_eq!(text, r#"{"errors": []}"#);
+}
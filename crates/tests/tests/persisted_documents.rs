@@ -0,0 +1,60 @@
+use serde_json::Value;
+use tests::TestProject;
+
+/// The id of the operation in `persisted_documents.json` whose text starts with
+/// `operation_text_prefix`
+fn persisted_operation_id(project: &TestProject, operation_text_prefix: &str) -> String {
+    let persisted_documents: Value =
+        serde_json::from_str(&project.artifact("persisted_documents.json"))
+            .expect("Expected persisted documents to be valid JSON");
+    persisted_documents
+        .as_object()
+        .expect("Expected persisted documents to be an object")
+        .iter()
+        .find(|(_, text)| {
+            text.as_str()
+                .is_some_and(|text| text.starts_with(operation_text_prefix))
+        })
+        .map(|(operation_id, _)| operation_id.clone())
+        .unwrap_or_else(|| panic!("Expected an operation starting with {operation_text_prefix}"))
+}
+
+#[test]
+fn test_stripped_query_text_is_not_generated() {
+    let project = TestProject::new("tests/fixtures/persisted_documents");
+    let artifacts = project.artifacts();
+
+    assert!(!artifacts.contains_key("Query/PetPage/query_text.ts"));
+    assert!(!artifacts["Query/PetPage/entrypoint.ts"].contains("query_text"));
+    assert!(!artifacts["Query/PetPage/__refetch__0.ts"].contains("query_text"));
+}
+
+#[test]
+fn test_entrypoint_exports_operation_id() {
+    let project = TestProject::new("tests/fixtures/persisted_documents");
+    let operation_id = persisted_operation_id(&project, "query PetPage(");
+    let entrypoint = project.artifact("Query/PetPage/entrypoint.ts");
+
+    assert!(
+        entrypoint.contains(&format!(
+            "    operation: {{\n      \
+            kind: \"PersistedOperation\",\n      \
+            operationId: \"{operation_id}\",\n"
+        )),
+        "Unexpected entrypoint:\n{entrypoint}"
+    );
+    assert!(entrypoint.ends_with(&format!(
+        "export const operationId = \"{operation_id}\";\nexport default artifact;\n"
+    )));
+}
+
+#[test]
+fn test_refetch_query_exports_operation_id() {
+    let project = TestProject::new("tests/fixtures/persisted_documents");
+    let operation_id = persisted_operation_id(&project, "query Query____refetch(");
+    let refetch_query = project.artifact("Query/PetPage/__refetch__0.ts");
+
+    assert!(refetch_query.ends_with(&format!(
+        "export const operationId = \"{operation_id}\";\nexport default artifact;\n"
+    )));
+}
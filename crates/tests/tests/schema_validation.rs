@@ -0,0 +1,144 @@
+use common_lang_types::{Diagnostic, TextSource};
+use graphql_network_protocol::validate_graphql_type_system_documents;
+use intern::string_key::Intern;
+
+fn validate(source: &str) -> Result<Vec<String>, Diagnostic> {
+    validate_with_extensions(source, "")
+}
+
+fn validate_with_extensions(
+    source: &str,
+    extension_source: &str,
+) -> Result<Vec<String>, Diagnostic> {
+    let text_source = TextSource {
+        relative_path_to_source_file: "dummy".intern().into(),
+        span: None,
+    };
+    let document = graphql_schema_parser::parse_schema(source, text_source)?;
+    let extension_document =
+        graphql_schema_parser::parse_schema_extensions(extension_source, text_source)?;
+    Ok(
+        validate_graphql_type_system_documents(&document, [&extension_document])
+            .into_iter()
+            .map(|diagnostic| diagnostic.0.message)
+            .collect(),
+    )
+}
+
+#[test]
+fn test_valid_schema() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!("fixtures/schema_validation/valid.graphql"))?;
+    assert_eq!(messages, Vec::<String>::new());
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_definitions() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!(
+        "fixtures/schema_validation/duplicates.graphql"
+    ))?;
+    assert_eq!(
+        messages,
+        vec![
+            "Multiple definitions of directive `@tag` were found.",
+            "Multiple definitions of `Pet` were found.",
+            "Multiple definitions of `Pet.name` were found.",
+            "Multiple definitions of argument `first` of `Pet.friends` were found.",
+            "Multiple definitions of `Color.RED` were found.",
+            "Multiple definitions of `PetFilter.name` were found.",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interface_implementations() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!(
+        "fixtures/schema_validation/interface_implementations.graphql"
+    ))?;
+    assert_eq!(
+        messages,
+        vec![
+            "`Pet.id` has type `ID`, which is not compatible with the type `ID!` of `Animal.id`.",
+            "`Pet.name` has type `Int!`, which is not compatible with the type `String!` of \
+            `Animal.name`.",
+            "`Pet.name` must accept argument `format`, because `Animal.name` does.",
+            "Argument `locale` of `Pet.name` cannot be required, because `Animal.name` does not \
+            accept it.",
+            "`Pet` must implement `Node`, because it implements `Animal`, which implements `Node`.",
+            "`Pet` can only implement interfaces, but `DateTime` is a scalar.",
+            "`Pet` implements `Missing`, which is not defined.",
+            "`Pet` cannot implement itself.",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_union_members() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!(
+        "fixtures/schema_validation/union_members.graphql"
+    ))?;
+    assert_eq!(
+        messages,
+        vec![
+            "Union `SearchResult` can only include object types, but `Node` is an interface.",
+            "Union `SearchResult` includes `Missing`, which is not defined.",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_input_object_cycles() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!(
+        "fixtures/schema_validation/input_object_cycles.graphql"
+    ))?;
+    assert_eq!(
+        messages,
+        vec![
+            "`A` cannot reference itself via non-null fields. Make one of the following fields \
+            nullable: A.b, B.a.",
+            "`C` cannot reference itself via non-null fields. Make one of the following fields \
+            nullable: C.c.",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_directive_locations() -> Result<(), Diagnostic> {
+    let messages = validate(include_str!(
+        "fixtures/schema_validation/directive_locations.graphql"
+    ))?;
+    assert_eq!(
+        messages,
+        vec![
+            "Directive `@tag` is not repeatable, but was used more than once.",
+            "Directive `@tag` cannot be used on FIELD_DEFINITION. It can only be used on OBJECT.",
+            "Directive `@oneOf` cannot be used on SCALAR. It can only be used on INPUT_OBJECT.",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extensions() -> Result<(), Diagnostic> {
+    let messages = validate_with_extensions(
+        include_str!("fixtures/schema_validation/extensions.graphql"),
+        include_str!("fixtures/schema_validation/extensions_extension.graphql"),
+    )?;
+    assert_eq!(
+        messages,
+        vec![
+            "Cannot extend the schema with a query type, because it already has one.",
+            "Cannot extend `Missing`, because it is not defined.",
+            "Cannot extend `Color` with `extend input`, because it is an enum.",
+            "Multiple definitions of `Color.RED` were found.",
+            "Multiple definitions of `PetFilter.name` were found.",
+            "Directive `@deprecated` cannot be used on INTERFACE. It can only be used on \
+            FIELD_DEFINITION, ARGUMENT_DEFINITION, INPUT_FIELD_DEFINITION, ENUM_VALUE.",
+        ]
+    );
+    Ok(())
+}
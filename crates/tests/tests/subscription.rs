@@ -0,0 +1,40 @@
+use tests::TestProject;
+
+#[test]
+fn test_subscription_entrypoint_artifacts() {
+    let project = TestProject::new("tests/fixtures/subscription/valid");
+    assert_eq!(project.diagnostic_messages(), Vec::<String>::new());
+
+    let entrypoint = project.artifact("Subscription/PetAdded/entrypoint.ts");
+    assert!(entrypoint.starts_with("import type {IsographSubscriptionEntrypoint, "));
+    assert!(entrypoint.contains("const artifact: IsographSubscriptionEntrypoint<\n"));
+    assert!(entrypoint.contains("  kind: \"SubscriptionEntrypoint\",\n"));
+
+    assert!(
+        project
+            .artifact("Subscription/PetAdded/query_text.ts")
+            .starts_with("export default 'subscription PetAdded {\\\n  petAdded {\\\n")
+    );
+
+    let iso = project.artifact("iso.ts");
+    assert!(iso.starts_with(
+        "import type { IsographEntrypoint, IsographSubscriptionEntrypoint } from '@isograph/react';\n"
+    ));
+    assert!(iso.contains("  | IsographSubscriptionEntrypoint<any, any, any, any>\n"));
+}
+
+#[test]
+fn test_subscription_entrypoint_must_select_one_root_field() {
+    let project = TestProject::new("tests/fixtures/subscription/multiple_root_fields");
+    let diagnostics = project.diagnostics();
+
+    assert_eq!(
+        project.diagnostic_messages(),
+        vec![
+            "`Subscription.PetChanges` is a subscription entrypoint, and must select exactly one \
+             root field. However, it selects 2: `petAdded`, `petRemoved`."
+                .to_string()
+        ]
+    );
+    assert!(diagnostics[0].location().is_some());
+}
@@ -0,0 +1,3335 @@
+export type ActorType =
+  | "TEAM"
+  | "USER"
+  | "%future added value";
+
+export type ActorType__input =
+  | "TEAM"
+  | "USER";
+
+export type AuditLogOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type AuditLogOrderField__input =
+  | "CREATED_AT";
+
+export type CheckAnnotationLevel =
+  | "FAILURE"
+  | "NOTICE"
+  | "WARNING"
+  | "%future added value";
+
+export type CheckAnnotationLevel__input =
+  | "FAILURE"
+  | "NOTICE"
+  | "WARNING";
+
+export type CheckConclusionState =
+  | "ACTION_REQUIRED"
+  | "CANCELLED"
+  | "FAILURE"
+  | "NEUTRAL"
+  | "SKIPPED"
+  | "STALE"
+  | "STARTUP_FAILURE"
+  | "SUCCESS"
+  | "TIMED_OUT"
+  | "%future added value";
+
+export type CheckConclusionState__input =
+  | "ACTION_REQUIRED"
+  | "CANCELLED"
+  | "FAILURE"
+  | "NEUTRAL"
+  | "SKIPPED"
+  | "STALE"
+  | "STARTUP_FAILURE"
+  | "SUCCESS"
+  | "TIMED_OUT";
+
+export type CheckRunState =
+  | "ACTION_REQUIRED"
+  | "CANCELLED"
+  | "COMPLETED"
+  | "FAILURE"
+  | "IN_PROGRESS"
+  | "NEUTRAL"
+  | "PENDING"
+  | "QUEUED"
+  | "SKIPPED"
+  | "STALE"
+  | "STARTUP_FAILURE"
+  | "SUCCESS"
+  | "TIMED_OUT"
+  | "WAITING"
+  | "%future added value";
+
+export type CheckRunState__input =
+  | "ACTION_REQUIRED"
+  | "CANCELLED"
+  | "COMPLETED"
+  | "FAILURE"
+  | "IN_PROGRESS"
+  | "NEUTRAL"
+  | "PENDING"
+  | "QUEUED"
+  | "SKIPPED"
+  | "STALE"
+  | "STARTUP_FAILURE"
+  | "SUCCESS"
+  | "TIMED_OUT"
+  | "WAITING";
+
+export type CheckRunType =
+  | "ALL"
+  | "LATEST"
+  | "%future added value";
+
+export type CheckRunType__input =
+  | "ALL"
+  | "LATEST";
+
+export type CheckStatusState =
+  | "COMPLETED"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "REQUESTED"
+  | "WAITING"
+  | "%future added value";
+
+export type CheckStatusState__input =
+  | "COMPLETED"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "REQUESTED"
+  | "WAITING";
+
+export type CollaboratorAffiliation =
+  | "ALL"
+  | "DIRECT"
+  | "OUTSIDE"
+  | "%future added value";
+
+export type CollaboratorAffiliation__input =
+  | "ALL"
+  | "DIRECT"
+  | "OUTSIDE";
+
+export type CommentAuthorAssociation =
+  | "COLLABORATOR"
+  | "CONTRIBUTOR"
+  | "FIRST_TIMER"
+  | "FIRST_TIME_CONTRIBUTOR"
+  | "MANNEQUIN"
+  | "MEMBER"
+  | "NONE"
+  | "OWNER"
+  | "%future added value";
+
+export type CommentAuthorAssociation__input =
+  | "COLLABORATOR"
+  | "CONTRIBUTOR"
+  | "FIRST_TIMER"
+  | "FIRST_TIME_CONTRIBUTOR"
+  | "MANNEQUIN"
+  | "MEMBER"
+  | "NONE"
+  | "OWNER";
+
+export type CommentCannotUpdateReason =
+  | "ARCHIVED"
+  | "DENIED"
+  | "INSUFFICIENT_ACCESS"
+  | "LOCKED"
+  | "LOGIN_REQUIRED"
+  | "MAINTENANCE"
+  | "VERIFIED_EMAIL_REQUIRED"
+  | "%future added value";
+
+export type CommentCannotUpdateReason__input =
+  | "ARCHIVED"
+  | "DENIED"
+  | "INSUFFICIENT_ACCESS"
+  | "LOCKED"
+  | "LOGIN_REQUIRED"
+  | "MAINTENANCE"
+  | "VERIFIED_EMAIL_REQUIRED";
+
+export type CommitContributionOrderField =
+  | "COMMIT_COUNT"
+  | "OCCURRED_AT"
+  | "%future added value";
+
+export type CommitContributionOrderField__input =
+  | "COMMIT_COUNT"
+  | "OCCURRED_AT";
+
+export type ComparisonStatus =
+  | "AHEAD"
+  | "BEHIND"
+  | "DIVERGED"
+  | "IDENTICAL"
+  | "%future added value";
+
+export type ComparisonStatus__input =
+  | "AHEAD"
+  | "BEHIND"
+  | "DIVERGED"
+  | "IDENTICAL";
+
+export type ContributionLevel =
+  | "FIRST_QUARTILE"
+  | "FOURTH_QUARTILE"
+  | "NONE"
+  | "SECOND_QUARTILE"
+  | "THIRD_QUARTILE"
+  | "%future added value";
+
+export type ContributionLevel__input =
+  | "FIRST_QUARTILE"
+  | "FOURTH_QUARTILE"
+  | "NONE"
+  | "SECOND_QUARTILE"
+  | "THIRD_QUARTILE";
+
+export type DefaultRepositoryPermissionField =
+  | "ADMIN"
+  | "NONE"
+  | "READ"
+  | "WRITE"
+  | "%future added value";
+
+export type DefaultRepositoryPermissionField__input =
+  | "ADMIN"
+  | "NONE"
+  | "READ"
+  | "WRITE";
+
+export type DependencyGraphEcosystem =
+  | "ACTIONS"
+  | "COMPOSER"
+  | "GO"
+  | "MAVEN"
+  | "NPM"
+  | "NUGET"
+  | "PIP"
+  | "PUB"
+  | "RUBYGEMS"
+  | "RUST"
+  | "SWIFT"
+  | "%future added value";
+
+export type DependencyGraphEcosystem__input =
+  | "ACTIONS"
+  | "COMPOSER"
+  | "GO"
+  | "MAVEN"
+  | "NPM"
+  | "NUGET"
+  | "PIP"
+  | "PUB"
+  | "RUBYGEMS"
+  | "RUST"
+  | "SWIFT";
+
+export type DeploymentOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type DeploymentOrderField__input =
+  | "CREATED_AT";
+
+export type DeploymentProtectionRuleType =
+  | "REQUIRED_REVIEWERS"
+  | "WAIT_TIMER"
+  | "%future added value";
+
+export type DeploymentProtectionRuleType__input =
+  | "REQUIRED_REVIEWERS"
+  | "WAIT_TIMER";
+
+export type DeploymentReviewState =
+  | "APPROVED"
+  | "REJECTED"
+  | "%future added value";
+
+export type DeploymentReviewState__input =
+  | "APPROVED"
+  | "REJECTED";
+
+export type DeploymentState =
+  | "ABANDONED"
+  | "ACTIVE"
+  | "DESTROYED"
+  | "ERROR"
+  | "FAILURE"
+  | "INACTIVE"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "SUCCESS"
+  | "WAITING"
+  | "%future added value";
+
+export type DeploymentState__input =
+  | "ABANDONED"
+  | "ACTIVE"
+  | "DESTROYED"
+  | "ERROR"
+  | "FAILURE"
+  | "INACTIVE"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "SUCCESS"
+  | "WAITING";
+
+export type DeploymentStatusState =
+  | "ERROR"
+  | "FAILURE"
+  | "INACTIVE"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "SUCCESS"
+  | "WAITING"
+  | "%future added value";
+
+export type DeploymentStatusState__input =
+  | "ERROR"
+  | "FAILURE"
+  | "INACTIVE"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "SUCCESS"
+  | "WAITING";
+
+export type DiffSide =
+  | "LEFT"
+  | "RIGHT"
+  | "%future added value";
+
+export type DiffSide__input =
+  | "LEFT"
+  | "RIGHT";
+
+export type DiscussionCloseReason =
+  | "DUPLICATE"
+  | "OUTDATED"
+  | "RESOLVED"
+  | "%future added value";
+
+export type DiscussionCloseReason__input =
+  | "DUPLICATE"
+  | "OUTDATED"
+  | "RESOLVED";
+
+export type DiscussionOrderField =
+  | "CREATED_AT"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type DiscussionOrderField__input =
+  | "CREATED_AT"
+  | "UPDATED_AT";
+
+export type DiscussionPollOptionOrderField =
+  | "AUTHORED_ORDER"
+  | "VOTE_COUNT"
+  | "%future added value";
+
+export type DiscussionPollOptionOrderField__input =
+  | "AUTHORED_ORDER"
+  | "VOTE_COUNT";
+
+export type DiscussionState =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type DiscussionState__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type DiscussionStateReason =
+  | "DUPLICATE"
+  | "OUTDATED"
+  | "REOPENED"
+  | "RESOLVED"
+  | "%future added value";
+
+export type DiscussionStateReason__input =
+  | "DUPLICATE"
+  | "OUTDATED"
+  | "REOPENED"
+  | "RESOLVED";
+
+export type DismissReason =
+  | "FIX_STARTED"
+  | "INACCURATE"
+  | "NOT_USED"
+  | "NO_BANDWIDTH"
+  | "TOLERABLE_RISK"
+  | "%future added value";
+
+export type DismissReason__input =
+  | "FIX_STARTED"
+  | "INACCURATE"
+  | "NOT_USED"
+  | "NO_BANDWIDTH"
+  | "TOLERABLE_RISK";
+
+export type EnterpriseAdministratorInvitationOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type EnterpriseAdministratorInvitationOrderField__input =
+  | "CREATED_AT";
+
+export type EnterpriseAdministratorRole =
+  | "BILLING_MANAGER"
+  | "OWNER"
+  | "%future added value";
+
+export type EnterpriseAdministratorRole__input =
+  | "BILLING_MANAGER"
+  | "OWNER";
+
+export type EnterpriseAllowPrivateRepositoryForkingPolicyValue =
+  | "ENTERPRISE_ORGANIZATIONS"
+  | "ENTERPRISE_ORGANIZATIONS_USER_ACCOUNTS"
+  | "EVERYWHERE"
+  | "SAME_ORGANIZATION"
+  | "SAME_ORGANIZATION_USER_ACCOUNTS"
+  | "USER_ACCOUNTS"
+  | "%future added value";
+
+export type EnterpriseAllowPrivateRepositoryForkingPolicyValue__input =
+  | "ENTERPRISE_ORGANIZATIONS"
+  | "ENTERPRISE_ORGANIZATIONS_USER_ACCOUNTS"
+  | "EVERYWHERE"
+  | "SAME_ORGANIZATION"
+  | "SAME_ORGANIZATION_USER_ACCOUNTS"
+  | "USER_ACCOUNTS";
+
+export type EnterpriseDefaultRepositoryPermissionSettingValue =
+  | "ADMIN"
+  | "NONE"
+  | "NO_POLICY"
+  | "READ"
+  | "WRITE"
+  | "%future added value";
+
+export type EnterpriseDefaultRepositoryPermissionSettingValue__input =
+  | "ADMIN"
+  | "NONE"
+  | "NO_POLICY"
+  | "READ"
+  | "WRITE";
+
+export type EnterpriseEnabledDisabledSettingValue =
+  | "DISABLED"
+  | "ENABLED"
+  | "NO_POLICY"
+  | "%future added value";
+
+export type EnterpriseEnabledDisabledSettingValue__input =
+  | "DISABLED"
+  | "ENABLED"
+  | "NO_POLICY";
+
+export type EnterpriseEnabledSettingValue =
+  | "ENABLED"
+  | "NO_POLICY"
+  | "%future added value";
+
+export type EnterpriseEnabledSettingValue__input =
+  | "ENABLED"
+  | "NO_POLICY";
+
+export type EnterpriseMemberOrderField =
+  | "CREATED_AT"
+  | "LOGIN"
+  | "%future added value";
+
+export type EnterpriseMemberOrderField__input =
+  | "CREATED_AT"
+  | "LOGIN";
+
+export type EnterpriseMembersCanCreateRepositoriesSettingValue =
+  | "ALL"
+  | "DISABLED"
+  | "NO_POLICY"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type EnterpriseMembersCanCreateRepositoriesSettingValue__input =
+  | "ALL"
+  | "DISABLED"
+  | "NO_POLICY"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type EnterpriseMembersCanMakePurchasesSettingValue =
+  | "DISABLED"
+  | "ENABLED"
+  | "%future added value";
+
+export type EnterpriseMembersCanMakePurchasesSettingValue__input =
+  | "DISABLED"
+  | "ENABLED";
+
+export type EnterpriseMembershipType =
+  | "ADMIN"
+  | "ALL"
+  | "BILLING_MANAGER"
+  | "ORG_MEMBERSHIP"
+  | "%future added value";
+
+export type EnterpriseMembershipType__input =
+  | "ADMIN"
+  | "ALL"
+  | "BILLING_MANAGER"
+  | "ORG_MEMBERSHIP";
+
+export type EnterpriseOrderField =
+  | "NAME"
+  | "%future added value";
+
+export type EnterpriseOrderField__input =
+  | "NAME";
+
+export type EnterpriseServerInstallationOrderField =
+  | "CREATED_AT"
+  | "CUSTOMER_NAME"
+  | "HOST_NAME"
+  | "%future added value";
+
+export type EnterpriseServerInstallationOrderField__input =
+  | "CREATED_AT"
+  | "CUSTOMER_NAME"
+  | "HOST_NAME";
+
+export type EnterpriseServerUserAccountEmailOrderField =
+  | "EMAIL"
+  | "%future added value";
+
+export type EnterpriseServerUserAccountEmailOrderField__input =
+  | "EMAIL";
+
+export type EnterpriseServerUserAccountOrderField =
+  | "LOGIN"
+  | "REMOTE_CREATED_AT"
+  | "%future added value";
+
+export type EnterpriseServerUserAccountOrderField__input =
+  | "LOGIN"
+  | "REMOTE_CREATED_AT";
+
+export type EnterpriseServerUserAccountsUploadOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type EnterpriseServerUserAccountsUploadOrderField__input =
+  | "CREATED_AT";
+
+export type EnterpriseServerUserAccountsUploadSyncState =
+  | "FAILURE"
+  | "PENDING"
+  | "SUCCESS"
+  | "%future added value";
+
+export type EnterpriseServerUserAccountsUploadSyncState__input =
+  | "FAILURE"
+  | "PENDING"
+  | "SUCCESS";
+
+export type EnterpriseUserAccountMembershipRole =
+  | "MEMBER"
+  | "OWNER"
+  | "UNAFFILIATED"
+  | "%future added value";
+
+export type EnterpriseUserAccountMembershipRole__input =
+  | "MEMBER"
+  | "OWNER"
+  | "UNAFFILIATED";
+
+export type EnterpriseUserDeployment =
+  | "CLOUD"
+  | "SERVER"
+  | "%future added value";
+
+export type EnterpriseUserDeployment__input =
+  | "CLOUD"
+  | "SERVER";
+
+export type EnvironmentOrderField =
+  | "NAME"
+  | "%future added value";
+
+export type EnvironmentOrderField__input =
+  | "NAME";
+
+export type FileViewedState =
+  | "DISMISSED"
+  | "UNVIEWED"
+  | "VIEWED"
+  | "%future added value";
+
+export type FileViewedState__input =
+  | "DISMISSED"
+  | "UNVIEWED"
+  | "VIEWED";
+
+export type FundingPlatform =
+  | "COMMUNITY_BRIDGE"
+  | "CUSTOM"
+  | "GITHUB"
+  | "ISSUEHUNT"
+  | "KO_FI"
+  | "LFX_CROWDFUNDING"
+  | "LIBERAPAY"
+  | "OPEN_COLLECTIVE"
+  | "OTECHIE"
+  | "PATREON"
+  | "TIDELIFT"
+  | "%future added value";
+
+export type FundingPlatform__input =
+  | "COMMUNITY_BRIDGE"
+  | "CUSTOM"
+  | "GITHUB"
+  | "ISSUEHUNT"
+  | "KO_FI"
+  | "LFX_CROWDFUNDING"
+  | "LIBERAPAY"
+  | "OPEN_COLLECTIVE"
+  | "OTECHIE"
+  | "PATREON"
+  | "TIDELIFT";
+
+export type GistOrderField =
+  | "CREATED_AT"
+  | "PUSHED_AT"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type GistOrderField__input =
+  | "CREATED_AT"
+  | "PUSHED_AT"
+  | "UPDATED_AT";
+
+export type GistPrivacy =
+  | "ALL"
+  | "PUBLIC"
+  | "SECRET"
+  | "%future added value";
+
+export type GistPrivacy__input =
+  | "ALL"
+  | "PUBLIC"
+  | "SECRET";
+
+export type GitSignatureState =
+  | "BAD_CERT"
+  | "BAD_EMAIL"
+  | "EXPIRED_KEY"
+  | "GPGVERIFY_ERROR"
+  | "GPGVERIFY_UNAVAILABLE"
+  | "INVALID"
+  | "MALFORMED_SIG"
+  | "NOT_SIGNING_KEY"
+  | "NO_USER"
+  | "OCSP_ERROR"
+  | "OCSP_PENDING"
+  | "OCSP_REVOKED"
+  | "UNKNOWN_KEY"
+  | "UNKNOWN_SIG_TYPE"
+  | "UNSIGNED"
+  | "UNVERIFIED_EMAIL"
+  | "VALID"
+  | "%future added value";
+
+export type GitSignatureState__input =
+  | "BAD_CERT"
+  | "BAD_EMAIL"
+  | "EXPIRED_KEY"
+  | "GPGVERIFY_ERROR"
+  | "GPGVERIFY_UNAVAILABLE"
+  | "INVALID"
+  | "MALFORMED_SIG"
+  | "NOT_SIGNING_KEY"
+  | "NO_USER"
+  | "OCSP_ERROR"
+  | "OCSP_PENDING"
+  | "OCSP_REVOKED"
+  | "UNKNOWN_KEY"
+  | "UNKNOWN_SIG_TYPE"
+  | "UNSIGNED"
+  | "UNVERIFIED_EMAIL"
+  | "VALID";
+
+export type IdentityProviderConfigurationState =
+  | "CONFIGURED"
+  | "ENFORCED"
+  | "UNCONFIGURED"
+  | "%future added value";
+
+export type IdentityProviderConfigurationState__input =
+  | "CONFIGURED"
+  | "ENFORCED"
+  | "UNCONFIGURED";
+
+export type IpAllowListEnabledSettingValue =
+  | "DISABLED"
+  | "ENABLED"
+  | "%future added value";
+
+export type IpAllowListEnabledSettingValue__input =
+  | "DISABLED"
+  | "ENABLED";
+
+export type IpAllowListEntryOrderField =
+  | "ALLOW_LIST_VALUE"
+  | "CREATED_AT"
+  | "%future added value";
+
+export type IpAllowListEntryOrderField__input =
+  | "ALLOW_LIST_VALUE"
+  | "CREATED_AT";
+
+export type IpAllowListForInstalledAppsEnabledSettingValue =
+  | "DISABLED"
+  | "ENABLED"
+  | "%future added value";
+
+export type IpAllowListForInstalledAppsEnabledSettingValue__input =
+  | "DISABLED"
+  | "ENABLED";
+
+export type IssueClosedStateReason =
+  | "COMPLETED"
+  | "NOT_PLANNED"
+  | "%future added value";
+
+export type IssueClosedStateReason__input =
+  | "COMPLETED"
+  | "NOT_PLANNED";
+
+export type IssueCommentOrderField =
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type IssueCommentOrderField__input =
+  | "UPDATED_AT";
+
+export type IssueOrderField =
+  | "COMMENTS"
+  | "CREATED_AT"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type IssueOrderField__input =
+  | "COMMENTS"
+  | "CREATED_AT"
+  | "UPDATED_AT";
+
+export type IssueState =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type IssueState__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type IssueStateReason =
+  | "COMPLETED"
+  | "NOT_PLANNED"
+  | "REOPENED"
+  | "%future added value";
+
+export type IssueStateReason__input =
+  | "COMPLETED"
+  | "NOT_PLANNED"
+  | "REOPENED";
+
+export type IssueTimelineItemsItemType =
+  | "ADDED_TO_PROJECT_EVENT"
+  | "ASSIGNED_EVENT"
+  | "CLOSED_EVENT"
+  | "COMMENT_DELETED_EVENT"
+  | "CONNECTED_EVENT"
+  | "CONVERTED_NOTE_TO_ISSUE_EVENT"
+  | "CONVERTED_TO_DISCUSSION_EVENT"
+  | "CROSS_REFERENCED_EVENT"
+  | "DEMILESTONED_EVENT"
+  | "DISCONNECTED_EVENT"
+  | "ISSUE_COMMENT"
+  | "LABELED_EVENT"
+  | "LOCKED_EVENT"
+  | "MARKED_AS_DUPLICATE_EVENT"
+  | "MENTIONED_EVENT"
+  | "MILESTONED_EVENT"
+  | "MOVED_COLUMNS_IN_PROJECT_EVENT"
+  | "PINNED_EVENT"
+  | "REFERENCED_EVENT"
+  | "REMOVED_FROM_PROJECT_EVENT"
+  | "RENAMED_TITLE_EVENT"
+  | "REOPENED_EVENT"
+  | "SUBSCRIBED_EVENT"
+  | "TRANSFERRED_EVENT"
+  | "UNASSIGNED_EVENT"
+  | "UNLABELED_EVENT"
+  | "UNLOCKED_EVENT"
+  | "UNMARKED_AS_DUPLICATE_EVENT"
+  | "UNPINNED_EVENT"
+  | "UNSUBSCRIBED_EVENT"
+  | "USER_BLOCKED_EVENT"
+  | "%future added value";
+
+export type IssueTimelineItemsItemType__input =
+  | "ADDED_TO_PROJECT_EVENT"
+  | "ASSIGNED_EVENT"
+  | "CLOSED_EVENT"
+  | "COMMENT_DELETED_EVENT"
+  | "CONNECTED_EVENT"
+  | "CONVERTED_NOTE_TO_ISSUE_EVENT"
+  | "CONVERTED_TO_DISCUSSION_EVENT"
+  | "CROSS_REFERENCED_EVENT"
+  | "DEMILESTONED_EVENT"
+  | "DISCONNECTED_EVENT"
+  | "ISSUE_COMMENT"
+  | "LABELED_EVENT"
+  | "LOCKED_EVENT"
+  | "MARKED_AS_DUPLICATE_EVENT"
+  | "MENTIONED_EVENT"
+  | "MILESTONED_EVENT"
+  | "MOVED_COLUMNS_IN_PROJECT_EVENT"
+  | "PINNED_EVENT"
+  | "REFERENCED_EVENT"
+  | "REMOVED_FROM_PROJECT_EVENT"
+  | "RENAMED_TITLE_EVENT"
+  | "REOPENED_EVENT"
+  | "SUBSCRIBED_EVENT"
+  | "TRANSFERRED_EVENT"
+  | "UNASSIGNED_EVENT"
+  | "UNLABELED_EVENT"
+  | "UNLOCKED_EVENT"
+  | "UNMARKED_AS_DUPLICATE_EVENT"
+  | "UNPINNED_EVENT"
+  | "UNSUBSCRIBED_EVENT"
+  | "USER_BLOCKED_EVENT";
+
+export type LabelOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "%future added value";
+
+export type LabelOrderField__input =
+  | "CREATED_AT"
+  | "NAME";
+
+export type LanguageOrderField =
+  | "SIZE"
+  | "%future added value";
+
+export type LanguageOrderField__input =
+  | "SIZE";
+
+export type LockReason =
+  | "OFF_TOPIC"
+  | "RESOLVED"
+  | "SPAM"
+  | "TOO_HEATED"
+  | "%future added value";
+
+export type LockReason__input =
+  | "OFF_TOPIC"
+  | "RESOLVED"
+  | "SPAM"
+  | "TOO_HEATED";
+
+export type MannequinOrderField =
+  | "CREATED_AT"
+  | "LOGIN"
+  | "%future added value";
+
+export type MannequinOrderField__input =
+  | "CREATED_AT"
+  | "LOGIN";
+
+export type MergeCommitMessage =
+  | "BLANK"
+  | "PR_BODY"
+  | "PR_TITLE"
+  | "%future added value";
+
+export type MergeCommitMessage__input =
+  | "BLANK"
+  | "PR_BODY"
+  | "PR_TITLE";
+
+export type MergeCommitTitle =
+  | "MERGE_MESSAGE"
+  | "PR_TITLE"
+  | "%future added value";
+
+export type MergeCommitTitle__input =
+  | "MERGE_MESSAGE"
+  | "PR_TITLE";
+
+export type MergeQueueEntryState =
+  | "AWAITING_CHECKS"
+  | "LOCKED"
+  | "MERGEABLE"
+  | "QUEUED"
+  | "UNMERGEABLE"
+  | "%future added value";
+
+export type MergeQueueEntryState__input =
+  | "AWAITING_CHECKS"
+  | "LOCKED"
+  | "MERGEABLE"
+  | "QUEUED"
+  | "UNMERGEABLE";
+
+export type MergeQueueMergingStrategy =
+  | "ALLGREEN"
+  | "HEADGREEN"
+  | "%future added value";
+
+export type MergeQueueMergingStrategy__input =
+  | "ALLGREEN"
+  | "HEADGREEN";
+
+export type MergeStateStatus =
+  | "BEHIND"
+  | "BLOCKED"
+  | "CLEAN"
+  | "DIRTY"
+  | "DRAFT"
+  | "HAS_HOOKS"
+  | "UNKNOWN"
+  | "UNSTABLE"
+  | "%future added value";
+
+export type MergeStateStatus__input =
+  | "BEHIND"
+  | "BLOCKED"
+  | "CLEAN"
+  | "DIRTY"
+  /** @deprecated DRAFT state will be removed from this enum and `isDraft` should be used instead Use PullRequest.isDraft instead. Removal on 2021-01-01 UTC. */
+  | "DRAFT"
+  | "HAS_HOOKS"
+  | "UNKNOWN"
+  | "UNSTABLE";
+
+export type MergeableState =
+  | "CONFLICTING"
+  | "MERGEABLE"
+  | "UNKNOWN"
+  | "%future added value";
+
+export type MergeableState__input =
+  | "CONFLICTING"
+  | "MERGEABLE"
+  | "UNKNOWN";
+
+export type MigrationSourceType =
+  | "AZURE_DEVOPS"
+  | "BITBUCKET_SERVER"
+  | "GITHUB_ARCHIVE"
+  | "%future added value";
+
+export type MigrationSourceType__input =
+  | "AZURE_DEVOPS"
+  | "BITBUCKET_SERVER"
+  | "GITHUB_ARCHIVE";
+
+export type MigrationState =
+  | "FAILED"
+  | "FAILED_VALIDATION"
+  | "IN_PROGRESS"
+  | "NOT_STARTED"
+  | "PENDING_VALIDATION"
+  | "QUEUED"
+  | "SUCCEEDED"
+  | "%future added value";
+
+export type MigrationState__input =
+  | "FAILED"
+  | "FAILED_VALIDATION"
+  | "IN_PROGRESS"
+  | "NOT_STARTED"
+  | "PENDING_VALIDATION"
+  | "QUEUED"
+  | "SUCCEEDED";
+
+export type MilestoneOrderField =
+  | "CREATED_AT"
+  | "DUE_DATE"
+  | "NUMBER"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type MilestoneOrderField__input =
+  | "CREATED_AT"
+  | "DUE_DATE"
+  | "NUMBER"
+  | "UPDATED_AT";
+
+export type MilestoneState =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type MilestoneState__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type NotificationRestrictionSettingValue =
+  | "DISABLED"
+  | "ENABLED"
+  | "%future added value";
+
+export type NotificationRestrictionSettingValue__input =
+  | "DISABLED"
+  | "ENABLED";
+
+export type OIDCProviderType =
+  | "AAD"
+  | "%future added value";
+
+export type OIDCProviderType__input =
+  | "AAD";
+
+export type OauthApplicationCreateAuditEntryState =
+  | "ACTIVE"
+  | "PENDING_DELETION"
+  | "SUSPENDED"
+  | "%future added value";
+
+export type OauthApplicationCreateAuditEntryState__input =
+  | "ACTIVE"
+  | "PENDING_DELETION"
+  | "SUSPENDED";
+
+export type OperationType =
+  | "ACCESS"
+  | "AUTHENTICATION"
+  | "CREATE"
+  | "MODIFY"
+  | "REMOVE"
+  | "RESTORE"
+  | "TRANSFER"
+  | "%future added value";
+
+export type OperationType__input =
+  | "ACCESS"
+  | "AUTHENTICATION"
+  | "CREATE"
+  | "MODIFY"
+  | "REMOVE"
+  | "RESTORE"
+  | "TRANSFER";
+
+export type OrderDirection =
+  | "ASC"
+  | "DESC"
+  | "%future added value";
+
+export type OrderDirection__input =
+  | "ASC"
+  | "DESC";
+
+export type OrgAddMemberAuditEntryPermission =
+  | "ADMIN"
+  | "READ"
+  | "%future added value";
+
+export type OrgAddMemberAuditEntryPermission__input =
+  | "ADMIN"
+  | "READ";
+
+export type OrgCreateAuditEntryBillingPlan =
+  | "BUSINESS"
+  | "BUSINESS_PLUS"
+  | "FREE"
+  | "TIERED_PER_SEAT"
+  | "UNLIMITED"
+  | "%future added value";
+
+export type OrgCreateAuditEntryBillingPlan__input =
+  | "BUSINESS"
+  | "BUSINESS_PLUS"
+  | "FREE"
+  | "TIERED_PER_SEAT"
+  | "UNLIMITED";
+
+export type OrgEnterpriseOwnerOrderField =
+  | "LOGIN"
+  | "%future added value";
+
+export type OrgEnterpriseOwnerOrderField__input =
+  | "LOGIN";
+
+export type OrgRemoveBillingManagerAuditEntryReason =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "SAML_SSO_ENFORCEMENT_REQUIRES_EXTERNAL_IDENTITY"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE"
+  | "%future added value";
+
+export type OrgRemoveBillingManagerAuditEntryReason__input =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "SAML_SSO_ENFORCEMENT_REQUIRES_EXTERNAL_IDENTITY"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE";
+
+export type OrgRemoveMemberAuditEntryMembershipType =
+  | "ADMIN"
+  | "BILLING_MANAGER"
+  | "DIRECT_MEMBER"
+  | "OUTSIDE_COLLABORATOR"
+  | "SUSPENDED"
+  | "UNAFFILIATED"
+  | "%future added value";
+
+export type OrgRemoveMemberAuditEntryMembershipType__input =
+  | "ADMIN"
+  | "BILLING_MANAGER"
+  | "DIRECT_MEMBER"
+  | "OUTSIDE_COLLABORATOR"
+  | "SUSPENDED"
+  | "UNAFFILIATED";
+
+export type OrgRemoveMemberAuditEntryReason =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "SAML_SSO_ENFORCEMENT_REQUIRES_EXTERNAL_IDENTITY"
+  | "TWO_FACTOR_ACCOUNT_RECOVERY"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE"
+  | "USER_ACCOUNT_DELETED"
+  | "%future added value";
+
+export type OrgRemoveMemberAuditEntryReason__input =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "SAML_SSO_ENFORCEMENT_REQUIRES_EXTERNAL_IDENTITY"
+  | "TWO_FACTOR_ACCOUNT_RECOVERY"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE"
+  | "USER_ACCOUNT_DELETED";
+
+export type OrgRemoveOutsideCollaboratorAuditEntryMembershipType =
+  | "BILLING_MANAGER"
+  | "OUTSIDE_COLLABORATOR"
+  | "UNAFFILIATED"
+  | "%future added value";
+
+export type OrgRemoveOutsideCollaboratorAuditEntryMembershipType__input =
+  | "BILLING_MANAGER"
+  | "OUTSIDE_COLLABORATOR"
+  | "UNAFFILIATED";
+
+export type OrgRemoveOutsideCollaboratorAuditEntryReason =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE"
+  | "%future added value";
+
+export type OrgRemoveOutsideCollaboratorAuditEntryReason__input =
+  | "SAML_EXTERNAL_IDENTITY_MISSING"
+  | "TWO_FACTOR_REQUIREMENT_NON_COMPLIANCE";
+
+export type OrgUpdateDefaultRepositoryPermissionAuditEntryPermission =
+  | "ADMIN"
+  | "NONE"
+  | "READ"
+  | "WRITE"
+  | "%future added value";
+
+export type OrgUpdateDefaultRepositoryPermissionAuditEntryPermission__input =
+  | "ADMIN"
+  | "NONE"
+  | "READ"
+  | "WRITE";
+
+export type OrgUpdateMemberAuditEntryPermission =
+  | "ADMIN"
+  | "READ"
+  | "%future added value";
+
+export type OrgUpdateMemberAuditEntryPermission__input =
+  | "ADMIN"
+  | "READ";
+
+export type OrgUpdateMemberRepositoryCreationPermissionAuditEntryVisibility =
+  | "ALL"
+  | "INTERNAL"
+  | "NONE"
+  | "PRIVATE"
+  | "PRIVATE_INTERNAL"
+  | "PUBLIC"
+  | "PUBLIC_INTERNAL"
+  | "PUBLIC_PRIVATE"
+  | "%future added value";
+
+export type OrgUpdateMemberRepositoryCreationPermissionAuditEntryVisibility__input =
+  | "ALL"
+  | "INTERNAL"
+  | "NONE"
+  | "PRIVATE"
+  | "PRIVATE_INTERNAL"
+  | "PUBLIC"
+  | "PUBLIC_INTERNAL"
+  | "PUBLIC_PRIVATE";
+
+export type OrganizationInvitationRole =
+  | "ADMIN"
+  | "BILLING_MANAGER"
+  | "DIRECT_MEMBER"
+  | "REINSTATE"
+  | "%future added value";
+
+export type OrganizationInvitationRole__input =
+  | "ADMIN"
+  | "BILLING_MANAGER"
+  | "DIRECT_MEMBER"
+  | "REINSTATE";
+
+export type OrganizationInvitationSource =
+  | "MEMBER"
+  | "SCIM"
+  | "UNKNOWN"
+  | "%future added value";
+
+export type OrganizationInvitationSource__input =
+  | "MEMBER"
+  | "SCIM"
+  | "UNKNOWN";
+
+export type OrganizationInvitationType =
+  | "EMAIL"
+  | "USER"
+  | "%future added value";
+
+export type OrganizationInvitationType__input =
+  | "EMAIL"
+  | "USER";
+
+export type OrganizationMemberRole =
+  | "ADMIN"
+  | "MEMBER"
+  | "%future added value";
+
+export type OrganizationMemberRole__input =
+  | "ADMIN"
+  | "MEMBER";
+
+export type OrganizationMembersCanCreateRepositoriesSettingValue =
+  | "ALL"
+  | "DISABLED"
+  | "INTERNAL"
+  | "PRIVATE"
+  | "%future added value";
+
+export type OrganizationMembersCanCreateRepositoriesSettingValue__input =
+  | "ALL"
+  | "DISABLED"
+  | "INTERNAL"
+  | "PRIVATE";
+
+export type OrganizationMigrationState =
+  | "FAILED"
+  | "FAILED_VALIDATION"
+  | "IN_PROGRESS"
+  | "NOT_STARTED"
+  | "PENDING_VALIDATION"
+  | "POST_REPO_MIGRATION"
+  | "PRE_REPO_MIGRATION"
+  | "QUEUED"
+  | "REPO_MIGRATION"
+  | "SUCCEEDED"
+  | "%future added value";
+
+export type OrganizationMigrationState__input =
+  | "FAILED"
+  | "FAILED_VALIDATION"
+  | "IN_PROGRESS"
+  | "NOT_STARTED"
+  | "PENDING_VALIDATION"
+  | "POST_REPO_MIGRATION"
+  | "PRE_REPO_MIGRATION"
+  | "QUEUED"
+  | "REPO_MIGRATION"
+  | "SUCCEEDED";
+
+export type OrganizationOrderField =
+  | "CREATED_AT"
+  | "LOGIN"
+  | "%future added value";
+
+export type OrganizationOrderField__input =
+  | "CREATED_AT"
+  | "LOGIN";
+
+export type PackageFileOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type PackageFileOrderField__input =
+  | "CREATED_AT";
+
+export type PackageOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type PackageOrderField__input =
+  | "CREATED_AT";
+
+export type PackageType =
+  | "DEBIAN"
+  | "DOCKER"
+  | "MAVEN"
+  | "NPM"
+  | "NUGET"
+  | "PYPI"
+  | "RUBYGEMS"
+  | "%future added value";
+
+export type PackageType__input =
+  | "DEBIAN"
+  /** @deprecated DOCKER will be removed from this enum as this type will be migrated to only be used by the Packages REST API. Removal on 2021-06-21 UTC. */
+  | "DOCKER"
+  /** @deprecated MAVEN will be removed from this enum as this type will be migrated to only be used by the Packages REST API. Removal on 2023-02-10 UTC. */
+  | "MAVEN"
+  /** @deprecated NPM will be removed from this enum as this type will be migrated to only be used by the Packages REST API. Removal on 2022-11-21 UTC. */
+  | "NPM"
+  /** @deprecated NUGET will be removed from this enum as this type will be migrated to only be used by the Packages REST API. Removal on 2022-11-21 UTC. */
+  | "NUGET"
+  | "PYPI"
+  /** @deprecated RUBYGEMS will be removed from this enum as this type will be migrated to only be used by the Packages REST API. Removal on 2022-12-28 UTC. */
+  | "RUBYGEMS";
+
+export type PackageVersionOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type PackageVersionOrderField__input =
+  | "CREATED_AT";
+
+export type PatchStatus =
+  | "ADDED"
+  | "CHANGED"
+  | "COPIED"
+  | "DELETED"
+  | "MODIFIED"
+  | "RENAMED"
+  | "%future added value";
+
+export type PatchStatus__input =
+  | "ADDED"
+  | "CHANGED"
+  | "COPIED"
+  | "DELETED"
+  | "MODIFIED"
+  | "RENAMED";
+
+export type PinnableItemType =
+  | "GIST"
+  | "ISSUE"
+  | "ORGANIZATION"
+  | "PROJECT"
+  | "PULL_REQUEST"
+  | "REPOSITORY"
+  | "TEAM"
+  | "USER"
+  | "%future added value";
+
+export type PinnableItemType__input =
+  | "GIST"
+  | "ISSUE"
+  | "ORGANIZATION"
+  | "PROJECT"
+  | "PULL_REQUEST"
+  | "REPOSITORY"
+  | "TEAM"
+  | "USER";
+
+export type PinnedDiscussionGradient =
+  | "BLUE_MINT"
+  | "BLUE_PURPLE"
+  | "PINK_BLUE"
+  | "PURPLE_CORAL"
+  | "RED_ORANGE"
+  | "%future added value";
+
+export type PinnedDiscussionGradient__input =
+  | "BLUE_MINT"
+  | "BLUE_PURPLE"
+  | "PINK_BLUE"
+  | "PURPLE_CORAL"
+  | "RED_ORANGE";
+
+export type PinnedDiscussionPattern =
+  | "CHEVRON_UP"
+  | "DOT"
+  | "DOT_FILL"
+  | "HEART_FILL"
+  | "PLUS"
+  | "ZAP"
+  | "%future added value";
+
+export type PinnedDiscussionPattern__input =
+  | "CHEVRON_UP"
+  | "DOT"
+  | "DOT_FILL"
+  | "HEART_FILL"
+  | "PLUS"
+  | "ZAP";
+
+export type ProjectCardArchivedState =
+  | "ARCHIVED"
+  | "NOT_ARCHIVED"
+  | "%future added value";
+
+export type ProjectCardArchivedState__input =
+  | "ARCHIVED"
+  | "NOT_ARCHIVED";
+
+export type ProjectCardState =
+  | "CONTENT_ONLY"
+  | "NOTE_ONLY"
+  | "REDACTED"
+  | "%future added value";
+
+export type ProjectCardState__input =
+  | "CONTENT_ONLY"
+  | "NOTE_ONLY"
+  | "REDACTED";
+
+export type ProjectColumnPurpose =
+  | "DONE"
+  | "IN_PROGRESS"
+  | "TODO"
+  | "%future added value";
+
+export type ProjectColumnPurpose__input =
+  | "DONE"
+  | "IN_PROGRESS"
+  | "TODO";
+
+export type ProjectOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type ProjectOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "UPDATED_AT";
+
+export type ProjectState =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type ProjectState__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type ProjectTemplate =
+  | "AUTOMATED_KANBAN_V2"
+  | "AUTOMATED_REVIEWS_KANBAN"
+  | "BASIC_KANBAN"
+  | "BUG_TRIAGE"
+  | "%future added value";
+
+export type ProjectTemplate__input =
+  | "AUTOMATED_KANBAN_V2"
+  | "AUTOMATED_REVIEWS_KANBAN"
+  | "BASIC_KANBAN"
+  | "BUG_TRIAGE";
+
+export type ProjectV2CustomFieldType =
+  | "DATE"
+  | "NUMBER"
+  | "SINGLE_SELECT"
+  | "TEXT"
+  | "%future added value";
+
+export type ProjectV2CustomFieldType__input =
+  | "DATE"
+  | "NUMBER"
+  | "SINGLE_SELECT"
+  | "TEXT";
+
+export type ProjectV2FieldOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "POSITION"
+  | "%future added value";
+
+export type ProjectV2FieldOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "POSITION";
+
+export type ProjectV2FieldType =
+  | "ASSIGNEES"
+  | "DATE"
+  | "ITERATION"
+  | "LABELS"
+  | "LINKED_PULL_REQUESTS"
+  | "MILESTONE"
+  | "NUMBER"
+  | "REPOSITORY"
+  | "REVIEWERS"
+  | "SINGLE_SELECT"
+  | "TEXT"
+  | "TITLE"
+  | "TRACKED_BY"
+  | "TRACKS"
+  | "%future added value";
+
+export type ProjectV2FieldType__input =
+  | "ASSIGNEES"
+  | "DATE"
+  | "ITERATION"
+  | "LABELS"
+  | "LINKED_PULL_REQUESTS"
+  | "MILESTONE"
+  | "NUMBER"
+  | "REPOSITORY"
+  | "REVIEWERS"
+  | "SINGLE_SELECT"
+  | "TEXT"
+  | "TITLE"
+  | "TRACKED_BY"
+  | "TRACKS";
+
+export type ProjectV2ItemFieldValueOrderField =
+  | "POSITION"
+  | "%future added value";
+
+export type ProjectV2ItemFieldValueOrderField__input =
+  | "POSITION";
+
+export type ProjectV2ItemOrderField =
+  | "POSITION"
+  | "%future added value";
+
+export type ProjectV2ItemOrderField__input =
+  | "POSITION";
+
+export type ProjectV2ItemType =
+  | "DRAFT_ISSUE"
+  | "ISSUE"
+  | "PULL_REQUEST"
+  | "REDACTED"
+  | "%future added value";
+
+export type ProjectV2ItemType__input =
+  | "DRAFT_ISSUE"
+  | "ISSUE"
+  | "PULL_REQUEST"
+  | "REDACTED";
+
+export type ProjectV2OrderField =
+  | "CREATED_AT"
+  | "NUMBER"
+  | "TITLE"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type ProjectV2OrderField__input =
+  | "CREATED_AT"
+  | "NUMBER"
+  | "TITLE"
+  | "UPDATED_AT";
+
+export type ProjectV2Roles =
+  | "ADMIN"
+  | "NONE"
+  | "READER"
+  | "WRITER"
+  | "%future added value";
+
+export type ProjectV2Roles__input =
+  | "ADMIN"
+  | "NONE"
+  | "READER"
+  | "WRITER";
+
+export type ProjectV2SingleSelectFieldOptionColor =
+  | "BLUE"
+  | "GRAY"
+  | "GREEN"
+  | "ORANGE"
+  | "PINK"
+  | "PURPLE"
+  | "RED"
+  | "YELLOW"
+  | "%future added value";
+
+export type ProjectV2SingleSelectFieldOptionColor__input =
+  | "BLUE"
+  | "GRAY"
+  | "GREEN"
+  | "ORANGE"
+  | "PINK"
+  | "PURPLE"
+  | "RED"
+  | "YELLOW";
+
+export type ProjectV2State =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type ProjectV2State__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type ProjectV2ViewLayout =
+  | "BOARD_LAYOUT"
+  | "ROADMAP_LAYOUT"
+  | "TABLE_LAYOUT"
+  | "%future added value";
+
+export type ProjectV2ViewLayout__input =
+  | "BOARD_LAYOUT"
+  | "ROADMAP_LAYOUT"
+  | "TABLE_LAYOUT";
+
+export type ProjectV2ViewOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "POSITION"
+  | "%future added value";
+
+export type ProjectV2ViewOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "POSITION";
+
+export type ProjectV2WorkflowsOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "NUMBER"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type ProjectV2WorkflowsOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "NUMBER"
+  | "UPDATED_AT";
+
+export type PullRequestBranchUpdateMethod =
+  | "MERGE"
+  | "REBASE"
+  | "%future added value";
+
+export type PullRequestBranchUpdateMethod__input =
+  | "MERGE"
+  | "REBASE";
+
+export type PullRequestMergeMethod =
+  | "MERGE"
+  | "REBASE"
+  | "SQUASH"
+  | "%future added value";
+
+export type PullRequestMergeMethod__input =
+  | "MERGE"
+  | "REBASE"
+  | "SQUASH";
+
+export type PullRequestOrderField =
+  | "CREATED_AT"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type PullRequestOrderField__input =
+  | "CREATED_AT"
+  | "UPDATED_AT";
+
+export type PullRequestReviewCommentState =
+  | "PENDING"
+  | "SUBMITTED"
+  | "%future added value";
+
+export type PullRequestReviewCommentState__input =
+  | "PENDING"
+  | "SUBMITTED";
+
+export type PullRequestReviewDecision =
+  | "APPROVED"
+  | "CHANGES_REQUESTED"
+  | "REVIEW_REQUIRED"
+  | "%future added value";
+
+export type PullRequestReviewDecision__input =
+  | "APPROVED"
+  | "CHANGES_REQUESTED"
+  | "REVIEW_REQUIRED";
+
+export type PullRequestReviewEvent =
+  | "APPROVE"
+  | "COMMENT"
+  | "DISMISS"
+  | "REQUEST_CHANGES"
+  | "%future added value";
+
+export type PullRequestReviewEvent__input =
+  | "APPROVE"
+  | "COMMENT"
+  | "DISMISS"
+  | "REQUEST_CHANGES";
+
+export type PullRequestReviewState =
+  | "APPROVED"
+  | "CHANGES_REQUESTED"
+  | "COMMENTED"
+  | "DISMISSED"
+  | "PENDING"
+  | "%future added value";
+
+export type PullRequestReviewState__input =
+  | "APPROVED"
+  | "CHANGES_REQUESTED"
+  | "COMMENTED"
+  | "DISMISSED"
+  | "PENDING";
+
+export type PullRequestReviewThreadSubjectType =
+  | "FILE"
+  | "LINE"
+  | "%future added value";
+
+export type PullRequestReviewThreadSubjectType__input =
+  | "FILE"
+  | "LINE";
+
+export type PullRequestState =
+  | "CLOSED"
+  | "MERGED"
+  | "OPEN"
+  | "%future added value";
+
+export type PullRequestState__input =
+  | "CLOSED"
+  | "MERGED"
+  | "OPEN";
+
+export type PullRequestTimelineItemsItemType =
+  | "ADDED_TO_MERGE_QUEUE_EVENT"
+  | "ADDED_TO_PROJECT_EVENT"
+  | "ASSIGNED_EVENT"
+  | "AUTOMATIC_BASE_CHANGE_FAILED_EVENT"
+  | "AUTOMATIC_BASE_CHANGE_SUCCEEDED_EVENT"
+  | "AUTO_MERGE_DISABLED_EVENT"
+  | "AUTO_MERGE_ENABLED_EVENT"
+  | "AUTO_REBASE_ENABLED_EVENT"
+  | "AUTO_SQUASH_ENABLED_EVENT"
+  | "BASE_REF_CHANGED_EVENT"
+  | "BASE_REF_DELETED_EVENT"
+  | "BASE_REF_FORCE_PUSHED_EVENT"
+  | "CLOSED_EVENT"
+  | "COMMENT_DELETED_EVENT"
+  | "CONNECTED_EVENT"
+  | "CONVERTED_NOTE_TO_ISSUE_EVENT"
+  | "CONVERTED_TO_DISCUSSION_EVENT"
+  | "CONVERT_TO_DRAFT_EVENT"
+  | "CROSS_REFERENCED_EVENT"
+  | "DEMILESTONED_EVENT"
+  | "DEPLOYED_EVENT"
+  | "DEPLOYMENT_ENVIRONMENT_CHANGED_EVENT"
+  | "DISCONNECTED_EVENT"
+  | "HEAD_REF_DELETED_EVENT"
+  | "HEAD_REF_FORCE_PUSHED_EVENT"
+  | "HEAD_REF_RESTORED_EVENT"
+  | "ISSUE_COMMENT"
+  | "LABELED_EVENT"
+  | "LOCKED_EVENT"
+  | "MARKED_AS_DUPLICATE_EVENT"
+  | "MENTIONED_EVENT"
+  | "MERGED_EVENT"
+  | "MILESTONED_EVENT"
+  | "MOVED_COLUMNS_IN_PROJECT_EVENT"
+  | "PINNED_EVENT"
+  | "PULL_REQUEST_COMMIT"
+  | "PULL_REQUEST_COMMIT_COMMENT_THREAD"
+  | "PULL_REQUEST_REVIEW"
+  | "PULL_REQUEST_REVIEW_THREAD"
+  | "PULL_REQUEST_REVISION_MARKER"
+  | "READY_FOR_REVIEW_EVENT"
+  | "REFERENCED_EVENT"
+  | "REMOVED_FROM_MERGE_QUEUE_EVENT"
+  | "REMOVED_FROM_PROJECT_EVENT"
+  | "RENAMED_TITLE_EVENT"
+  | "REOPENED_EVENT"
+  | "REVIEW_DISMISSED_EVENT"
+  | "REVIEW_REQUESTED_EVENT"
+  | "REVIEW_REQUEST_REMOVED_EVENT"
+  | "SUBSCRIBED_EVENT"
+  | "TRANSFERRED_EVENT"
+  | "UNASSIGNED_EVENT"
+  | "UNLABELED_EVENT"
+  | "UNLOCKED_EVENT"
+  | "UNMARKED_AS_DUPLICATE_EVENT"
+  | "UNPINNED_EVENT"
+  | "UNSUBSCRIBED_EVENT"
+  | "USER_BLOCKED_EVENT"
+  | "%future added value";
+
+export type PullRequestTimelineItemsItemType__input =
+  | "ADDED_TO_MERGE_QUEUE_EVENT"
+  | "ADDED_TO_PROJECT_EVENT"
+  | "ASSIGNED_EVENT"
+  | "AUTOMATIC_BASE_CHANGE_FAILED_EVENT"
+  | "AUTOMATIC_BASE_CHANGE_SUCCEEDED_EVENT"
+  | "AUTO_MERGE_DISABLED_EVENT"
+  | "AUTO_MERGE_ENABLED_EVENT"
+  | "AUTO_REBASE_ENABLED_EVENT"
+  | "AUTO_SQUASH_ENABLED_EVENT"
+  | "BASE_REF_CHANGED_EVENT"
+  | "BASE_REF_DELETED_EVENT"
+  | "BASE_REF_FORCE_PUSHED_EVENT"
+  | "CLOSED_EVENT"
+  | "COMMENT_DELETED_EVENT"
+  | "CONNECTED_EVENT"
+  | "CONVERTED_NOTE_TO_ISSUE_EVENT"
+  | "CONVERTED_TO_DISCUSSION_EVENT"
+  | "CONVERT_TO_DRAFT_EVENT"
+  | "CROSS_REFERENCED_EVENT"
+  | "DEMILESTONED_EVENT"
+  | "DEPLOYED_EVENT"
+  | "DEPLOYMENT_ENVIRONMENT_CHANGED_EVENT"
+  | "DISCONNECTED_EVENT"
+  | "HEAD_REF_DELETED_EVENT"
+  | "HEAD_REF_FORCE_PUSHED_EVENT"
+  | "HEAD_REF_RESTORED_EVENT"
+  | "ISSUE_COMMENT"
+  | "LABELED_EVENT"
+  | "LOCKED_EVENT"
+  | "MARKED_AS_DUPLICATE_EVENT"
+  | "MENTIONED_EVENT"
+  | "MERGED_EVENT"
+  | "MILESTONED_EVENT"
+  | "MOVED_COLUMNS_IN_PROJECT_EVENT"
+  | "PINNED_EVENT"
+  | "PULL_REQUEST_COMMIT"
+  | "PULL_REQUEST_COMMIT_COMMENT_THREAD"
+  | "PULL_REQUEST_REVIEW"
+  | "PULL_REQUEST_REVIEW_THREAD"
+  | "PULL_REQUEST_REVISION_MARKER"
+  | "READY_FOR_REVIEW_EVENT"
+  | "REFERENCED_EVENT"
+  | "REMOVED_FROM_MERGE_QUEUE_EVENT"
+  | "REMOVED_FROM_PROJECT_EVENT"
+  | "RENAMED_TITLE_EVENT"
+  | "REOPENED_EVENT"
+  | "REVIEW_DISMISSED_EVENT"
+  | "REVIEW_REQUESTED_EVENT"
+  | "REVIEW_REQUEST_REMOVED_EVENT"
+  | "SUBSCRIBED_EVENT"
+  | "TRANSFERRED_EVENT"
+  | "UNASSIGNED_EVENT"
+  | "UNLABELED_EVENT"
+  | "UNLOCKED_EVENT"
+  | "UNMARKED_AS_DUPLICATE_EVENT"
+  | "UNPINNED_EVENT"
+  | "UNSUBSCRIBED_EVENT"
+  | "USER_BLOCKED_EVENT";
+
+export type PullRequestUpdateState =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type PullRequestUpdateState__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type ReactionContent =
+  | "CONFUSED"
+  | "EYES"
+  | "HEART"
+  | "HOORAY"
+  | "LAUGH"
+  | "ROCKET"
+  | "THUMBS_DOWN"
+  | "THUMBS_UP"
+  | "%future added value";
+
+export type ReactionContent__input =
+  | "CONFUSED"
+  | "EYES"
+  | "HEART"
+  | "HOORAY"
+  | "LAUGH"
+  | "ROCKET"
+  | "THUMBS_DOWN"
+  | "THUMBS_UP";
+
+export type ReactionOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type ReactionOrderField__input =
+  | "CREATED_AT";
+
+export type RefOrderField =
+  | "ALPHABETICAL"
+  | "TAG_COMMIT_DATE"
+  | "%future added value";
+
+export type RefOrderField__input =
+  | "ALPHABETICAL"
+  | "TAG_COMMIT_DATE";
+
+export type ReleaseOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "%future added value";
+
+export type ReleaseOrderField__input =
+  | "CREATED_AT"
+  | "NAME";
+
+export type RepoAccessAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoAccessAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepoAddMemberAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoAddMemberAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepoArchivedAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoArchivedAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepoChangeMergeSettingAuditEntryMergeType =
+  | "MERGE"
+  | "REBASE"
+  | "SQUASH"
+  | "%future added value";
+
+export type RepoChangeMergeSettingAuditEntryMergeType__input =
+  | "MERGE"
+  | "REBASE"
+  | "SQUASH";
+
+export type RepoCreateAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoCreateAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepoDestroyAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoDestroyAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepoRemoveMemberAuditEntryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepoRemoveMemberAuditEntryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type ReportedContentClassifiers =
+  | "ABUSE"
+  | "DUPLICATE"
+  | "OFF_TOPIC"
+  | "OUTDATED"
+  | "RESOLVED"
+  | "SPAM"
+  | "%future added value";
+
+export type ReportedContentClassifiers__input =
+  | "ABUSE"
+  | "DUPLICATE"
+  | "OFF_TOPIC"
+  | "OUTDATED"
+  | "RESOLVED"
+  | "SPAM";
+
+export type RepositoryAffiliation =
+  | "COLLABORATOR"
+  | "ORGANIZATION_MEMBER"
+  | "OWNER"
+  | "%future added value";
+
+export type RepositoryAffiliation__input =
+  | "COLLABORATOR"
+  | "ORGANIZATION_MEMBER"
+  | "OWNER";
+
+export type RepositoryContributionType =
+  | "COMMIT"
+  | "ISSUE"
+  | "PULL_REQUEST"
+  | "PULL_REQUEST_REVIEW"
+  | "REPOSITORY"
+  | "%future added value";
+
+export type RepositoryContributionType__input =
+  | "COMMIT"
+  | "ISSUE"
+  | "PULL_REQUEST"
+  | "PULL_REQUEST_REVIEW"
+  | "REPOSITORY";
+
+export type RepositoryInteractionLimit =
+  | "COLLABORATORS_ONLY"
+  | "CONTRIBUTORS_ONLY"
+  | "EXISTING_USERS"
+  | "NO_LIMIT"
+  | "%future added value";
+
+export type RepositoryInteractionLimit__input =
+  | "COLLABORATORS_ONLY"
+  | "CONTRIBUTORS_ONLY"
+  | "EXISTING_USERS"
+  | "NO_LIMIT";
+
+export type RepositoryInteractionLimitExpiry =
+  | "ONE_DAY"
+  | "ONE_MONTH"
+  | "ONE_WEEK"
+  | "SIX_MONTHS"
+  | "THREE_DAYS"
+  | "%future added value";
+
+export type RepositoryInteractionLimitExpiry__input =
+  | "ONE_DAY"
+  | "ONE_MONTH"
+  | "ONE_WEEK"
+  | "SIX_MONTHS"
+  | "THREE_DAYS";
+
+export type RepositoryInteractionLimitOrigin =
+  | "ORGANIZATION"
+  | "REPOSITORY"
+  | "USER"
+  | "%future added value";
+
+export type RepositoryInteractionLimitOrigin__input =
+  | "ORGANIZATION"
+  | "REPOSITORY"
+  | "USER";
+
+export type RepositoryInvitationOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type RepositoryInvitationOrderField__input =
+  | "CREATED_AT";
+
+export type RepositoryLockReason =
+  | "BILLING"
+  | "MIGRATING"
+  | "MOVING"
+  | "RENAME"
+  | "TRADE_RESTRICTION"
+  | "TRANSFERRING_OWNERSHIP"
+  | "%future added value";
+
+export type RepositoryLockReason__input =
+  | "BILLING"
+  | "MIGRATING"
+  | "MOVING"
+  | "RENAME"
+  | "TRADE_RESTRICTION"
+  | "TRANSFERRING_OWNERSHIP";
+
+export type RepositoryMigrationOrderDirection =
+  | "ASC"
+  | "DESC"
+  | "%future added value";
+
+export type RepositoryMigrationOrderDirection__input =
+  | "ASC"
+  | "DESC";
+
+export type RepositoryMigrationOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type RepositoryMigrationOrderField__input =
+  | "CREATED_AT";
+
+export type RepositoryOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "PUSHED_AT"
+  | "STARGAZERS"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type RepositoryOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "PUSHED_AT"
+  | "STARGAZERS"
+  | "UPDATED_AT";
+
+export type RepositoryPermission =
+  | "ADMIN"
+  | "MAINTAIN"
+  | "READ"
+  | "TRIAGE"
+  | "WRITE"
+  | "%future added value";
+
+export type RepositoryPermission__input =
+  | "ADMIN"
+  | "MAINTAIN"
+  | "READ"
+  | "TRIAGE"
+  | "WRITE";
+
+export type RepositoryPrivacy =
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepositoryPrivacy__input =
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepositoryRuleOrderField =
+  | "CREATED_AT"
+  | "TYPE"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type RepositoryRuleOrderField__input =
+  | "CREATED_AT"
+  | "TYPE"
+  | "UPDATED_AT";
+
+export type RepositoryRuleType =
+  | "AUTHORIZATION"
+  | "BRANCH_NAME_PATTERN"
+  | "COMMITTER_EMAIL_PATTERN"
+  | "COMMIT_AUTHOR_EMAIL_PATTERN"
+  | "COMMIT_MESSAGE_PATTERN"
+  | "CREATION"
+  | "DELETION"
+  | "LOCK_BRANCH"
+  | "MAX_REF_UPDATES"
+  | "MERGE_QUEUE"
+  | "MERGE_QUEUE_LOCKED_REF"
+  | "NON_FAST_FORWARD"
+  | "PULL_REQUEST"
+  | "REQUIRED_DEPLOYMENTS"
+  | "REQUIRED_LINEAR_HISTORY"
+  | "REQUIRED_REVIEW_THREAD_RESOLUTION"
+  | "REQUIRED_SIGNATURES"
+  | "REQUIRED_STATUS_CHECKS"
+  | "REQUIRED_WORKFLOW_STATUS_CHECKS"
+  | "RULESET_REQUIRED_SIGNATURES"
+  | "SECRET_SCANNING"
+  | "TAG"
+  | "TAG_NAME_PATTERN"
+  | "UPDATE"
+  | "WORKFLOWS"
+  | "WORKFLOW_UPDATES"
+  | "%future added value";
+
+export type RepositoryRuleType__input =
+  | "AUTHORIZATION"
+  | "BRANCH_NAME_PATTERN"
+  | "COMMITTER_EMAIL_PATTERN"
+  | "COMMIT_AUTHOR_EMAIL_PATTERN"
+  | "COMMIT_MESSAGE_PATTERN"
+  | "CREATION"
+  | "DELETION"
+  | "LOCK_BRANCH"
+  | "MAX_REF_UPDATES"
+  | "MERGE_QUEUE"
+  | "MERGE_QUEUE_LOCKED_REF"
+  | "NON_FAST_FORWARD"
+  | "PULL_REQUEST"
+  | "REQUIRED_DEPLOYMENTS"
+  | "REQUIRED_LINEAR_HISTORY"
+  | "REQUIRED_REVIEW_THREAD_RESOLUTION"
+  | "REQUIRED_SIGNATURES"
+  | "REQUIRED_STATUS_CHECKS"
+  | "REQUIRED_WORKFLOW_STATUS_CHECKS"
+  | "RULESET_REQUIRED_SIGNATURES"
+  | "SECRET_SCANNING"
+  | "TAG"
+  | "TAG_NAME_PATTERN"
+  | "UPDATE"
+  | "WORKFLOWS"
+  | "WORKFLOW_UPDATES";
+
+export type RepositoryRulesetBypassActorBypassMode =
+  | "ALWAYS"
+  | "PULL_REQUEST"
+  | "%future added value";
+
+export type RepositoryRulesetBypassActorBypassMode__input =
+  | "ALWAYS"
+  | "PULL_REQUEST";
+
+export type RepositoryRulesetTarget =
+  | "BRANCH"
+  | "TAG"
+  | "%future added value";
+
+export type RepositoryRulesetTarget__input =
+  | "BRANCH"
+  | "TAG";
+
+export type RepositoryVisibility =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type RepositoryVisibility__input =
+  | "INTERNAL"
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type RepositoryVulnerabilityAlertDependencyScope =
+  | "DEVELOPMENT"
+  | "RUNTIME"
+  | "%future added value";
+
+export type RepositoryVulnerabilityAlertDependencyScope__input =
+  | "DEVELOPMENT"
+  | "RUNTIME";
+
+export type RepositoryVulnerabilityAlertState =
+  | "AUTO_DISMISSED"
+  | "DISMISSED"
+  | "FIXED"
+  | "OPEN"
+  | "%future added value";
+
+export type RepositoryVulnerabilityAlertState__input =
+  | "AUTO_DISMISSED"
+  | "DISMISSED"
+  | "FIXED"
+  | "OPEN";
+
+export type RequestableCheckStatusState =
+  | "COMPLETED"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "WAITING"
+  | "%future added value";
+
+export type RequestableCheckStatusState__input =
+  | "COMPLETED"
+  | "IN_PROGRESS"
+  | "PENDING"
+  | "QUEUED"
+  | "WAITING";
+
+export type RoleInOrganization =
+  | "DIRECT_MEMBER"
+  | "OWNER"
+  | "UNAFFILIATED"
+  | "%future added value";
+
+export type RoleInOrganization__input =
+  | "DIRECT_MEMBER"
+  | "OWNER"
+  | "UNAFFILIATED";
+
+export type RuleEnforcement =
+  | "ACTIVE"
+  | "DISABLED"
+  | "EVALUATE"
+  | "%future added value";
+
+export type RuleEnforcement__input =
+  | "ACTIVE"
+  | "DISABLED"
+  | "EVALUATE";
+
+export type SamlDigestAlgorithm =
+  | "SHA1"
+  | "SHA256"
+  | "SHA384"
+  | "SHA512"
+  | "%future added value";
+
+export type SamlDigestAlgorithm__input =
+  | "SHA1"
+  | "SHA256"
+  | "SHA384"
+  | "SHA512";
+
+export type SamlSignatureAlgorithm =
+  | "RSA_SHA1"
+  | "RSA_SHA256"
+  | "RSA_SHA384"
+  | "RSA_SHA512"
+  | "%future added value";
+
+export type SamlSignatureAlgorithm__input =
+  | "RSA_SHA1"
+  | "RSA_SHA256"
+  | "RSA_SHA384"
+  | "RSA_SHA512";
+
+export type SavedReplyOrderField =
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type SavedReplyOrderField__input =
+  | "UPDATED_AT";
+
+export type SearchType =
+  | "DISCUSSION"
+  | "ISSUE"
+  | "REPOSITORY"
+  | "USER"
+  | "%future added value";
+
+export type SearchType__input =
+  | "DISCUSSION"
+  | "ISSUE"
+  | "REPOSITORY"
+  | "USER";
+
+export type SecurityAdvisoryClassification =
+  | "GENERAL"
+  | "MALWARE"
+  | "%future added value";
+
+export type SecurityAdvisoryClassification__input =
+  | "GENERAL"
+  | "MALWARE";
+
+export type SecurityAdvisoryEcosystem =
+  | "ACTIONS"
+  | "COMPOSER"
+  | "ERLANG"
+  | "GO"
+  | "MAVEN"
+  | "NPM"
+  | "NUGET"
+  | "PIP"
+  | "PUB"
+  | "RUBYGEMS"
+  | "RUST"
+  | "SWIFT"
+  | "%future added value";
+
+export type SecurityAdvisoryEcosystem__input =
+  | "ACTIONS"
+  | "COMPOSER"
+  | "ERLANG"
+  | "GO"
+  | "MAVEN"
+  | "NPM"
+  | "NUGET"
+  | "PIP"
+  | "PUB"
+  | "RUBYGEMS"
+  | "RUST"
+  | "SWIFT";
+
+export type SecurityAdvisoryIdentifierType =
+  | "CVE"
+  | "GHSA"
+  | "%future added value";
+
+export type SecurityAdvisoryIdentifierType__input =
+  | "CVE"
+  | "GHSA";
+
+export type SecurityAdvisoryOrderField =
+  | "PUBLISHED_AT"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type SecurityAdvisoryOrderField__input =
+  | "PUBLISHED_AT"
+  | "UPDATED_AT";
+
+export type SecurityAdvisorySeverity =
+  | "CRITICAL"
+  | "HIGH"
+  | "LOW"
+  | "MODERATE"
+  | "%future added value";
+
+export type SecurityAdvisorySeverity__input =
+  | "CRITICAL"
+  | "HIGH"
+  | "LOW"
+  | "MODERATE";
+
+export type SecurityVulnerabilityOrderField =
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type SecurityVulnerabilityOrderField__input =
+  | "UPDATED_AT";
+
+export type SocialAccountProvider =
+  | "FACEBOOK"
+  | "GENERIC"
+  | "HOMETOWN"
+  | "INSTAGRAM"
+  | "LINKEDIN"
+  | "MASTODON"
+  | "NPM"
+  | "REDDIT"
+  | "TWITCH"
+  | "TWITTER"
+  | "YOUTUBE"
+  | "%future added value";
+
+export type SocialAccountProvider__input =
+  | "FACEBOOK"
+  | "GENERIC"
+  | "HOMETOWN"
+  | "INSTAGRAM"
+  | "LINKEDIN"
+  | "MASTODON"
+  | "NPM"
+  | "REDDIT"
+  | "TWITCH"
+  | "TWITTER"
+  | "YOUTUBE";
+
+export type SponsorAndLifetimeValueOrderField =
+  | "LIFETIME_VALUE"
+  | "SPONSOR_LOGIN"
+  | "SPONSOR_RELEVANCE"
+  | "%future added value";
+
+export type SponsorAndLifetimeValueOrderField__input =
+  | "LIFETIME_VALUE"
+  | "SPONSOR_LOGIN"
+  | "SPONSOR_RELEVANCE";
+
+export type SponsorOrderField =
+  | "LOGIN"
+  | "RELEVANCE"
+  | "%future added value";
+
+export type SponsorOrderField__input =
+  | "LOGIN"
+  | "RELEVANCE";
+
+export type SponsorableOrderField =
+  | "LOGIN"
+  | "%future added value";
+
+export type SponsorableOrderField__input =
+  | "LOGIN";
+
+export type SponsorsActivityAction =
+  | "CANCELLED_SPONSORSHIP"
+  | "NEW_SPONSORSHIP"
+  | "PENDING_CHANGE"
+  | "REFUND"
+  | "SPONSOR_MATCH_DISABLED"
+  | "TIER_CHANGE"
+  | "%future added value";
+
+export type SponsorsActivityAction__input =
+  | "CANCELLED_SPONSORSHIP"
+  | "NEW_SPONSORSHIP"
+  | "PENDING_CHANGE"
+  | "REFUND"
+  | "SPONSOR_MATCH_DISABLED"
+  | "TIER_CHANGE";
+
+export type SponsorsActivityOrderField =
+  | "TIMESTAMP"
+  | "%future added value";
+
+export type SponsorsActivityOrderField__input =
+  | "TIMESTAMP";
+
+export type SponsorsActivityPeriod =
+  | "ALL"
+  | "DAY"
+  | "MONTH"
+  | "WEEK"
+  | "%future added value";
+
+export type SponsorsActivityPeriod__input =
+  | "ALL"
+  | "DAY"
+  | "MONTH"
+  | "WEEK";
+
+export type SponsorsCountryOrRegionCode =
+  | "AD"
+  | "AE"
+  | "AF"
+  | "AG"
+  | "AI"
+  | "AL"
+  | "AM"
+  | "AO"
+  | "AQ"
+  | "AR"
+  | "AS"
+  | "AT"
+  | "AU"
+  | "AW"
+  | "AX"
+  | "AZ"
+  | "BA"
+  | "BB"
+  | "BD"
+  | "BE"
+  | "BF"
+  | "BG"
+  | "BH"
+  | "BI"
+  | "BJ"
+  | "BL"
+  | "BM"
+  | "BN"
+  | "BO"
+  | "BQ"
+  | "BR"
+  | "BS"
+  | "BT"
+  | "BV"
+  | "BW"
+  | "BY"
+  | "BZ"
+  | "CA"
+  | "CC"
+  | "CD"
+  | "CF"
+  | "CG"
+  | "CH"
+  | "CI"
+  | "CK"
+  | "CL"
+  | "CM"
+  | "CN"
+  | "CO"
+  | "CR"
+  | "CV"
+  | "CW"
+  | "CX"
+  | "CY"
+  | "CZ"
+  | "DE"
+  | "DJ"
+  | "DK"
+  | "DM"
+  | "DO"
+  | "DZ"
+  | "EC"
+  | "EE"
+  | "EG"
+  | "EH"
+  | "ER"
+  | "ES"
+  | "ET"
+  | "FI"
+  | "FJ"
+  | "FK"
+  | "FM"
+  | "FO"
+  | "FR"
+  | "GA"
+  | "GB"
+  | "GD"
+  | "GE"
+  | "GF"
+  | "GG"
+  | "GH"
+  | "GI"
+  | "GL"
+  | "GM"
+  | "GN"
+  | "GP"
+  | "GQ"
+  | "GR"
+  | "GS"
+  | "GT"
+  | "GU"
+  | "GW"
+  | "GY"
+  | "HK"
+  | "HM"
+  | "HN"
+  | "HR"
+  | "HT"
+  | "HU"
+  | "ID"
+  | "IE"
+  | "IL"
+  | "IM"
+  | "IN"
+  | "IO"
+  | "IQ"
+  | "IR"
+  | "IS"
+  | "IT"
+  | "JE"
+  | "JM"
+  | "JO"
+  | "JP"
+  | "KE"
+  | "KG"
+  | "KH"
+  | "KI"
+  | "KM"
+  | "KN"
+  | "KR"
+  | "KW"
+  | "KY"
+  | "KZ"
+  | "LA"
+  | "LB"
+  | "LC"
+  | "LI"
+  | "LK"
+  | "LR"
+  | "LS"
+  | "LT"
+  | "LU"
+  | "LV"
+  | "LY"
+  | "MA"
+  | "MC"
+  | "MD"
+  | "ME"
+  | "MF"
+  | "MG"
+  | "MH"
+  | "MK"
+  | "ML"
+  | "MM"
+  | "MN"
+  | "MO"
+  | "MP"
+  | "MQ"
+  | "MR"
+  | "MS"
+  | "MT"
+  | "MU"
+  | "MV"
+  | "MW"
+  | "MX"
+  | "MY"
+  | "MZ"
+  | "NA"
+  | "NC"
+  | "NE"
+  | "NF"
+  | "NG"
+  | "NI"
+  | "NL"
+  | "NO"
+  | "NP"
+  | "NR"
+  | "NU"
+  | "NZ"
+  | "OM"
+  | "PA"
+  | "PE"
+  | "PF"
+  | "PG"
+  | "PH"
+  | "PK"
+  | "PL"
+  | "PM"
+  | "PN"
+  | "PR"
+  | "PS"
+  | "PT"
+  | "PW"
+  | "PY"
+  | "QA"
+  | "RE"
+  | "RO"
+  | "RS"
+  | "RU"
+  | "RW"
+  | "SA"
+  | "SB"
+  | "SC"
+  | "SD"
+  | "SE"
+  | "SG"
+  | "SH"
+  | "SI"
+  | "SJ"
+  | "SK"
+  | "SL"
+  | "SM"
+  | "SN"
+  | "SO"
+  | "SR"
+  | "SS"
+  | "ST"
+  | "SV"
+  | "SX"
+  | "SZ"
+  | "TC"
+  | "TD"
+  | "TF"
+  | "TG"
+  | "TH"
+  | "TJ"
+  | "TK"
+  | "TL"
+  | "TM"
+  | "TN"
+  | "TO"
+  | "TR"
+  | "TT"
+  | "TV"
+  | "TW"
+  | "TZ"
+  | "UA"
+  | "UG"
+  | "UM"
+  | "US"
+  | "UY"
+  | "UZ"
+  | "VA"
+  | "VC"
+  | "VE"
+  | "VG"
+  | "VI"
+  | "VN"
+  | "VU"
+  | "WF"
+  | "WS"
+  | "YE"
+  | "YT"
+  | "ZA"
+  | "ZM"
+  | "ZW"
+  | "%future added value";
+
+export type SponsorsCountryOrRegionCode__input =
+  | "AD"
+  | "AE"
+  | "AF"
+  | "AG"
+  | "AI"
+  | "AL"
+  | "AM"
+  | "AO"
+  | "AQ"
+  | "AR"
+  | "AS"
+  | "AT"
+  | "AU"
+  | "AW"
+  | "AX"
+  | "AZ"
+  | "BA"
+  | "BB"
+  | "BD"
+  | "BE"
+  | "BF"
+  | "BG"
+  | "BH"
+  | "BI"
+  | "BJ"
+  | "BL"
+  | "BM"
+  | "BN"
+  | "BO"
+  | "BQ"
+  | "BR"
+  | "BS"
+  | "BT"
+  | "BV"
+  | "BW"
+  | "BY"
+  | "BZ"
+  | "CA"
+  | "CC"
+  | "CD"
+  | "CF"
+  | "CG"
+  | "CH"
+  | "CI"
+  | "CK"
+  | "CL"
+  | "CM"
+  | "CN"
+  | "CO"
+  | "CR"
+  | "CV"
+  | "CW"
+  | "CX"
+  | "CY"
+  | "CZ"
+  | "DE"
+  | "DJ"
+  | "DK"
+  | "DM"
+  | "DO"
+  | "DZ"
+  | "EC"
+  | "EE"
+  | "EG"
+  | "EH"
+  | "ER"
+  | "ES"
+  | "ET"
+  | "FI"
+  | "FJ"
+  | "FK"
+  | "FM"
+  | "FO"
+  | "FR"
+  | "GA"
+  | "GB"
+  | "GD"
+  | "GE"
+  | "GF"
+  | "GG"
+  | "GH"
+  | "GI"
+  | "GL"
+  | "GM"
+  | "GN"
+  | "GP"
+  | "GQ"
+  | "GR"
+  | "GS"
+  | "GT"
+  | "GU"
+  | "GW"
+  | "GY"
+  | "HK"
+  | "HM"
+  | "HN"
+  | "HR"
+  | "HT"
+  | "HU"
+  | "ID"
+  | "IE"
+  | "IL"
+  | "IM"
+  | "IN"
+  | "IO"
+  | "IQ"
+  | "IR"
+  | "IS"
+  | "IT"
+  | "JE"
+  | "JM"
+  | "JO"
+  | "JP"
+  | "KE"
+  | "KG"
+  | "KH"
+  | "KI"
+  | "KM"
+  | "KN"
+  | "KR"
+  | "KW"
+  | "KY"
+  | "KZ"
+  | "LA"
+  | "LB"
+  | "LC"
+  | "LI"
+  | "LK"
+  | "LR"
+  | "LS"
+  | "LT"
+  | "LU"
+  | "LV"
+  | "LY"
+  | "MA"
+  | "MC"
+  | "MD"
+  | "ME"
+  | "MF"
+  | "MG"
+  | "MH"
+  | "MK"
+  | "ML"
+  | "MM"
+  | "MN"
+  | "MO"
+  | "MP"
+  | "MQ"
+  | "MR"
+  | "MS"
+  | "MT"
+  | "MU"
+  | "MV"
+  | "MW"
+  | "MX"
+  | "MY"
+  | "MZ"
+  | "NA"
+  | "NC"
+  | "NE"
+  | "NF"
+  | "NG"
+  | "NI"
+  | "NL"
+  | "NO"
+  | "NP"
+  | "NR"
+  | "NU"
+  | "NZ"
+  | "OM"
+  | "PA"
+  | "PE"
+  | "PF"
+  | "PG"
+  | "PH"
+  | "PK"
+  | "PL"
+  | "PM"
+  | "PN"
+  | "PR"
+  | "PS"
+  | "PT"
+  | "PW"
+  | "PY"
+  | "QA"
+  | "RE"
+  | "RO"
+  | "RS"
+  | "RU"
+  | "RW"
+  | "SA"
+  | "SB"
+  | "SC"
+  | "SD"
+  | "SE"
+  | "SG"
+  | "SH"
+  | "SI"
+  | "SJ"
+  | "SK"
+  | "SL"
+  | "SM"
+  | "SN"
+  | "SO"
+  | "SR"
+  | "SS"
+  | "ST"
+  | "SV"
+  | "SX"
+  | "SZ"
+  | "TC"
+  | "TD"
+  | "TF"
+  | "TG"
+  | "TH"
+  | "TJ"
+  | "TK"
+  | "TL"
+  | "TM"
+  | "TN"
+  | "TO"
+  | "TR"
+  | "TT"
+  | "TV"
+  | "TW"
+  | "TZ"
+  | "UA"
+  | "UG"
+  | "UM"
+  | "US"
+  | "UY"
+  | "UZ"
+  | "VA"
+  | "VC"
+  | "VE"
+  | "VG"
+  | "VI"
+  | "VN"
+  | "VU"
+  | "WF"
+  | "WS"
+  | "YE"
+  | "YT"
+  | "ZA"
+  | "ZM"
+  | "ZW";
+
+export type SponsorsGoalKind =
+  | "MONTHLY_SPONSORSHIP_AMOUNT"
+  | "TOTAL_SPONSORS_COUNT"
+  | "%future added value";
+
+export type SponsorsGoalKind__input =
+  | "MONTHLY_SPONSORSHIP_AMOUNT"
+  | "TOTAL_SPONSORS_COUNT";
+
+export type SponsorsListingFeaturedItemFeatureableType =
+  | "REPOSITORY"
+  | "USER"
+  | "%future added value";
+
+export type SponsorsListingFeaturedItemFeatureableType__input =
+  | "REPOSITORY"
+  | "USER";
+
+export type SponsorsTierOrderField =
+  | "CREATED_AT"
+  | "MONTHLY_PRICE_IN_CENTS"
+  | "%future added value";
+
+export type SponsorsTierOrderField__input =
+  | "CREATED_AT"
+  | "MONTHLY_PRICE_IN_CENTS";
+
+export type SponsorshipNewsletterOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type SponsorshipNewsletterOrderField__input =
+  | "CREATED_AT";
+
+export type SponsorshipOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type SponsorshipOrderField__input =
+  | "CREATED_AT";
+
+export type SponsorshipPaymentSource =
+  | "GITHUB"
+  | "PATREON"
+  | "%future added value";
+
+export type SponsorshipPaymentSource__input =
+  | "GITHUB"
+  | "PATREON";
+
+export type SponsorshipPrivacy =
+  | "PRIVATE"
+  | "PUBLIC"
+  | "%future added value";
+
+export type SponsorshipPrivacy__input =
+  | "PRIVATE"
+  | "PUBLIC";
+
+export type SquashMergeCommitMessage =
+  | "BLANK"
+  | "COMMIT_MESSAGES"
+  | "PR_BODY"
+  | "%future added value";
+
+export type SquashMergeCommitMessage__input =
+  | "BLANK"
+  | "COMMIT_MESSAGES"
+  | "PR_BODY";
+
+export type SquashMergeCommitTitle =
+  | "COMMIT_OR_PR_TITLE"
+  | "PR_TITLE"
+  | "%future added value";
+
+export type SquashMergeCommitTitle__input =
+  | "COMMIT_OR_PR_TITLE"
+  | "PR_TITLE";
+
+export type StarOrderField =
+  | "STARRED_AT"
+  | "%future added value";
+
+export type StarOrderField__input =
+  | "STARRED_AT";
+
+export type StatusState =
+  | "ERROR"
+  | "EXPECTED"
+  | "FAILURE"
+  | "PENDING"
+  | "SUCCESS"
+  | "%future added value";
+
+export type StatusState__input =
+  | "ERROR"
+  | "EXPECTED"
+  | "FAILURE"
+  | "PENDING"
+  | "SUCCESS";
+
+export type SubscriptionState =
+  | "IGNORED"
+  | "SUBSCRIBED"
+  | "UNSUBSCRIBED"
+  | "%future added value";
+
+export type SubscriptionState__input =
+  | "IGNORED"
+  | "SUBSCRIBED"
+  | "UNSUBSCRIBED";
+
+export type TeamDiscussionCommentOrderField =
+  | "NUMBER"
+  | "%future added value";
+
+export type TeamDiscussionCommentOrderField__input =
+  | "NUMBER";
+
+export type TeamDiscussionOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type TeamDiscussionOrderField__input =
+  | "CREATED_AT";
+
+export type TeamMemberOrderField =
+  | "CREATED_AT"
+  | "LOGIN"
+  | "%future added value";
+
+export type TeamMemberOrderField__input =
+  | "CREATED_AT"
+  | "LOGIN";
+
+export type TeamMemberRole =
+  | "MAINTAINER"
+  | "MEMBER"
+  | "%future added value";
+
+export type TeamMemberRole__input =
+  | "MAINTAINER"
+  | "MEMBER";
+
+export type TeamMembershipType =
+  | "ALL"
+  | "CHILD_TEAM"
+  | "IMMEDIATE"
+  | "%future added value";
+
+export type TeamMembershipType__input =
+  | "ALL"
+  | "CHILD_TEAM"
+  | "IMMEDIATE";
+
+export type TeamNotificationSetting =
+  | "NOTIFICATIONS_DISABLED"
+  | "NOTIFICATIONS_ENABLED"
+  | "%future added value";
+
+export type TeamNotificationSetting__input =
+  | "NOTIFICATIONS_DISABLED"
+  | "NOTIFICATIONS_ENABLED";
+
+export type TeamOrderField =
+  | "NAME"
+  | "%future added value";
+
+export type TeamOrderField__input =
+  | "NAME";
+
+export type TeamPrivacy =
+  | "SECRET"
+  | "VISIBLE"
+  | "%future added value";
+
+export type TeamPrivacy__input =
+  | "SECRET"
+  | "VISIBLE";
+
+export type TeamRepositoryOrderField =
+  | "CREATED_AT"
+  | "NAME"
+  | "PERMISSION"
+  | "PUSHED_AT"
+  | "STARGAZERS"
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type TeamRepositoryOrderField__input =
+  | "CREATED_AT"
+  | "NAME"
+  | "PERMISSION"
+  | "PUSHED_AT"
+  | "STARGAZERS"
+  | "UPDATED_AT";
+
+export type TeamReviewAssignmentAlgorithm =
+  | "LOAD_BALANCE"
+  | "ROUND_ROBIN"
+  | "%future added value";
+
+export type TeamReviewAssignmentAlgorithm__input =
+  | "LOAD_BALANCE"
+  | "ROUND_ROBIN";
+
+export type TeamRole =
+  | "ADMIN"
+  | "MEMBER"
+  | "%future added value";
+
+export type TeamRole__input =
+  | "ADMIN"
+  | "MEMBER";
+
+export type ThreadSubscriptionFormAction =
+  | "NONE"
+  | "SUBSCRIBE"
+  | "UNSUBSCRIBE"
+  | "%future added value";
+
+export type ThreadSubscriptionFormAction__input =
+  | "NONE"
+  | "SUBSCRIBE"
+  | "UNSUBSCRIBE";
+
+export type ThreadSubscriptionState =
+  | "DISABLED"
+  | "IGNORING_LIST"
+  | "IGNORING_THREAD"
+  | "NONE"
+  | "SUBSCRIBED_TO_LIST"
+  | "SUBSCRIBED_TO_THREAD"
+  | "SUBSCRIBED_TO_THREAD_EVENTS"
+  | "SUBSCRIBED_TO_THREAD_TYPE"
+  | "UNAVAILABLE"
+  | "%future added value";
+
+export type ThreadSubscriptionState__input =
+  | "DISABLED"
+  | "IGNORING_LIST"
+  | "IGNORING_THREAD"
+  | "NONE"
+  | "SUBSCRIBED_TO_LIST"
+  | "SUBSCRIBED_TO_THREAD"
+  | "SUBSCRIBED_TO_THREAD_EVENTS"
+  | "SUBSCRIBED_TO_THREAD_TYPE"
+  | "UNAVAILABLE";
+
+export type TopicSuggestionDeclineReason =
+  | "NOT_RELEVANT"
+  | "PERSONAL_PREFERENCE"
+  | "TOO_GENERAL"
+  | "TOO_SPECIFIC"
+  | "%future added value";
+
+export type TopicSuggestionDeclineReason__input =
+  /** @deprecated Suggested topics are no longer supported Removal on 2024-04-01 UTC. */
+  | "NOT_RELEVANT"
+  /** @deprecated Suggested topics are no longer supported Removal on 2024-04-01 UTC. */
+  | "PERSONAL_PREFERENCE"
+  /** @deprecated Suggested topics are no longer supported Removal on 2024-04-01 UTC. */
+  | "TOO_GENERAL"
+  /** @deprecated Suggested topics are no longer supported Removal on 2024-04-01 UTC. */
+  | "TOO_SPECIFIC";
+
+export type TrackedIssueStates =
+  | "CLOSED"
+  | "OPEN"
+  | "%future added value";
+
+export type TrackedIssueStates__input =
+  | "CLOSED"
+  | "OPEN";
+
+export type UserBlockDuration =
+  | "ONE_DAY"
+  | "ONE_MONTH"
+  | "ONE_WEEK"
+  | "PERMANENT"
+  | "THREE_DAYS"
+  | "%future added value";
+
+export type UserBlockDuration__input =
+  | "ONE_DAY"
+  | "ONE_MONTH"
+  | "ONE_WEEK"
+  | "PERMANENT"
+  | "THREE_DAYS";
+
+export type UserStatusOrderField =
+  | "UPDATED_AT"
+  | "%future added value";
+
+export type UserStatusOrderField__input =
+  | "UPDATED_AT";
+
+export type VerifiableDomainOrderField =
+  | "CREATED_AT"
+  | "DOMAIN"
+  | "%future added value";
+
+export type VerifiableDomainOrderField__input =
+  | "CREATED_AT"
+  | "DOMAIN";
+
+export type WorkflowRunOrderField =
+  | "CREATED_AT"
+  | "%future added value";
+
+export type WorkflowRunOrderField__input =
+  | "CREATED_AT";
+
+export type WorkflowState =
+  | "ACTIVE"
+  | "DELETED"
+  | "DISABLED_FORK"
+  | "DISABLED_INACTIVITY"
+  | "DISABLED_MANUALLY"
+  | "%future added value";
+
+export type WorkflowState__input =
+  | "ACTIVE"
+  | "DELETED"
+  | "DISABLED_FORK"
+  | "DISABLED_INACTIVITY"
+  | "DISABLED_MANUALLY";
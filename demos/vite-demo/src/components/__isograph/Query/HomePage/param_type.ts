@@ -1,4 +1,5 @@
 import { type Pokemon__Pokemon__output_type } from '../../Pokemon/Pokemon/output_type';
+import type { PokemonEnum } from '../../enums';
 
 export type Query__HomePage__param = {
   readonly data: {
@@ -21,7 +22,7 @@ It is advisable to cache responses of this query.
       /**
 The key of the Pokémon as stored in the API
       */
-      readonly key: string,
+      readonly key: PokemonEnum,
       /**
 The form identifier of a Pokémon
       */
@@ -1,8 +1,9 @@
+import type { PokemonEnum } from '../../enums';
 export type Query__HomePage__raw_response_type = {
   getAllPokemon____take___l_232____offset___l_93: ReadonlyArray<{
     bulbapediaPage: string,
     forme?: (string | null),
-    key: string,
+    key: PokemonEnum,
     num: number,
     species: string,
     sprite: string,
@@ -115,11 +115,12 @@ function compileImportStatement(t, path, type, field, artifactType, config) {
   const filename = path.state.filename;
   const folder = pathModule.dirname(filename);
   const cwd = pathModule.dirname(config.filepath);
+  const projectConfig = getProjectConfig(config, filename);
   const artifactDirectory = pathModule.join(
     cwd,
-    config.config['artifact_directory'] ?? config.config['project_root'],
+    projectConfig['artifact_directory'] ?? projectConfig['project_root'],
   );
-  const module = config.config['options']?.['module'];
+  const module = projectConfig['options']?.['module'];
 
   const fileToArtifactDir = pathModule.relative(folder, artifactDirectory);
   const artifactDirToArtifact = `/__isograph/${type}/${field}/${artifactType}.ts`;
@@ -165,4 +166,38 @@ function compileImportStatement(t, path, type, field, artifactType, config) {
   }
 }
 
+/**
+ * If the config contains multiple projects, returns the project whose
+ * project_root contains the file. Otherwise, the config is a single project.
+ *
+ * @param {NonNullable<import("cosmiconfig").CosmiconfigResult>} config
+ * @param {string} filename
+ */
+function getProjectConfig(config, filename) {
+  const projects = config.config['projects'];
+  if (projects == null) {
+    return config.config;
+  }
+
+  const cwd = pathModule.dirname(config.filepath);
+  const project = projects.find(
+    (/** @type {{ project_root: string }} */ project) => {
+      const fileRelativeToProjectRoot = pathModule.relative(
+        pathModule.join(cwd, project['project_root']),
+        filename,
+      );
+      return (
+        !fileRelativeToProjectRoot.startsWith('..') &&
+        !pathModule.isAbsolute(fileRelativeToProjectRoot)
+      );
+    },
+  );
+  if (project == null) {
+    throw new Error(
+      `BabelPluginIsograph: ${filename} is not contained in the project_root of any project in ${config.filepath}.`,
+    );
+  }
+  return project;
+}
+
 module.exports = compileTag;
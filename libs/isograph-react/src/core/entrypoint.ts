@@ -92,6 +92,26 @@ export type IsographEntrypoint<
   readonly '~TRawResponseType'?: PhantomData<Contravariant<TRawResponseType>>;
 };
 
+// This type should be treated as an opaque type. The network request results in
+// a stream of events, each of which has the shape of TRawEventType and is
+// normalized using the normalization AST.
+export type IsographSubscriptionEntrypoint<
+  TReadFromStore extends UnknownTReadFromStore,
+  TClientFieldValue,
+  TNormalizationAst extends NormalizationAst | NormalizationAstLoader,
+  TRawEventType extends NetworkResponseObject,
+> = Omit<
+  IsographEntrypoint<
+    TReadFromStore,
+    TClientFieldValue,
+    TNormalizationAst,
+    TRawEventType
+  >,
+  'kind'
+> & {
+  readonly kind: 'SubscriptionEntrypoint';
+};
+
 export type FragmentReferenceOfEntrypoint<
   TEntrypoint extends IsographEntrypoint<any, any, any, any>,
 > = FragmentReference<
@@ -129,6 +149,11 @@ export type NormalizationAstNodes = ReadonlyArray<NormalizationAstNode>;
 export type NormalizationAst = {
   readonly kind: 'NormalizationAst';
   readonly selections: NormalizationAstNodes;
+  // Selections marked with @defer, keyed by label. These are not part of
+  // the initial payload, and are used to normalize incremental payloads.
+  readonly deferredSelections?: {
+    readonly [label: string]: NormalizationAstNodes;
+  };
 };
 
 export type NormalizationAstLoader = {
@@ -77,6 +77,7 @@ export {
   type NormalizationLinkedField,
   type NormalizationScalarField,
   type IsographEntrypoint,
+  type IsographSubscriptionEntrypoint,
   type IsographOperation,
   type IsographPersistedOperation,
   type IsographPersistedOperationExtraInfo,